@@ -23,6 +23,14 @@ pub enum ConfigErrorCause {
 	/// The input provided is not a valid value for the diff renames.
 	#[error("Must match one of 'true', 'false', 'copy', or 'copies'")]
 	InvalidDiffRenames,
+	/// The input provided is not a valid value for the missing commits check.
+	#[error("Must match one of 'ignore', 'warn' or 'error'")]
+	InvalidMissingCommitsCheck,
+	/// The input provided is not a valid list of list columns.
+	#[error(
+		"Must be a comma separated list of 'action', 'hash', 'author', 'date', 'age', 'refs', 'signature' or 'subject'"
+	)]
+	InvalidListColumns,
 	/// The input provided is not a valid boolean value.
 	#[error("The input provided is not a valid boolean value")]
 	InvalidBoolean,
@@ -5,7 +5,8 @@ use git::Config;
 use crate::{
 	errors::ConfigError,
 	get_string,
-	utils::{get_unsigned_integer, git_diff_renames},
+	utils::{get_missing_commits_check, get_unsigned_integer, git_diff_renames},
+	MissingCommitsCheckSetting,
 };
 
 fn editor_from_env() -> String {
@@ -46,6 +47,10 @@ pub struct GitConfig {
 	///     https://git-scm.com/docs/git-config#Documentation/git-config.txt-coreeditor
 	/// ).
 	pub editor: String,
+	/// How to handle commits removed from the todo list, from [`rebase.missingCommitsCheck`](
+	///     https://git-scm.com/docs/git-config#Documentation/git-config.txt-rebasemissingCommitsCheck
+	/// ).
+	pub missing_commits_check: MissingCommitsCheckSetting,
 }
 
 impl GitConfig {
@@ -73,6 +78,7 @@ impl GitConfig {
 			diff_renames,
 			diff_copies,
 			editor: get_string(git_config, "core.editor", editor_from_env().as_str())?,
+			missing_commits_check: get_missing_commits_check(git_config, "rebase.missingCommitsCheck")?,
 		})
 	}
 }
@@ -226,6 +232,28 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn missing_commits_check() {
+		with_git_config(&["[rebase]", "missingCommitsCheck = warn"], |git_config| {
+			let config = GitConfig::new_with_config(Some(&git_config)).unwrap();
+			assert_eq!(config.missing_commits_check, MissingCommitsCheckSetting::Warn);
+		});
+	}
+
+	#[test]
+	fn missing_commits_check_invalid() {
+		with_git_config(&["[rebase]", "missingCommitsCheck = invalid"], |git_config| {
+			assert_err_eq!(
+				GitConfig::new_with_config(Some(&git_config)),
+				ConfigError::new(
+					"rebase.missingCommitsCheck",
+					"invalid",
+					ConfigErrorCause::InvalidMissingCommitsCheck
+				),
+			);
+		});
+	}
+
 	#[test]
 	fn diff_renames_invalid() {
 		with_git_config(&["[diff]", "renames = invalid"], |git_config| {
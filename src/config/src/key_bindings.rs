@@ -37,6 +37,14 @@ pub struct KeyBindings {
 	pub confirm_yes: Vec<String>,
 	/// Key bindings for editing.
 	pub edit: Vec<String>,
+	/// Key bindings for expanding a collapsed file in a diff.
+	pub expand_file: Vec<String>,
+	/// Key bindings for folding or unfolding all files in a diff.
+	pub fold_all: Vec<String>,
+	/// Key bindings for folding or unfolding a file in a diff.
+	pub fold_file: Vec<String>,
+	/// Key bindings for folding or unfolding a hunk in a diff.
+	pub fold_hunk: Vec<String>,
 	/// Key bindings for forcing a abort.
 	pub force_abort: Vec<String>,
 	/// Key bindings for forcing a rebase.
@@ -66,6 +74,12 @@ pub struct KeyBindings {
 	pub move_selection_down: Vec<String>,
 	/// Key bindings for moving the selection up.
 	pub move_selection_up: Vec<String>,
+	/// Key bindings for moving the merge branch block down.
+	pub move_block_down: Vec<String>,
+	/// Key bindings for moving the merge branch block up.
+	pub move_block_up: Vec<String>,
+	/// Key bindings for moving the update-ref of a branch after the selected commit.
+	pub move_update_ref: Vec<String>,
 
 	/// Key bindings for scrolling down.
 	pub scroll_down: Vec<String>,
@@ -84,28 +98,54 @@ pub struct KeyBindings {
 	/// Key bindings for scrolling up a step.
 	pub scroll_step_up: Vec<String>,
 
+	/// Key bindings for jumping to the next file in a diff.
+	pub next_file: Vec<String>,
+	/// Key bindings for jumping to the next hunk in a diff.
+	pub next_hunk: Vec<String>,
 	/// Key bindings for opening the external editor.
 	pub open_in_external_editor: Vec<String>,
+	/// Key bindings for jumping to the previous file in a diff.
+	pub previous_file: Vec<String>,
+	/// Key bindings for jumping to the previous hunk in a diff.
+	pub previous_hunk: Vec<String>,
 	/// Key bindings for rebasing.
 	pub rebase: Vec<String>,
 	/// Key bindings for redoing a change.
 	pub redo: Vec<String>,
 	/// Key bindings for removing a line.
 	pub remove_line: Vec<String>,
+	/// Key bindings for running a command.
+	pub run_command: Vec<String>,
 	/// Key bindings for starting search.
 	pub search_start: Vec<String>,
 	/// Key bindings for next search match.
 	pub search_next: Vec<String>,
 	/// Key bindings for previous search match.
 	pub search_previous: Vec<String>,
+	/// Key bindings for selecting the merge branch block.
+	pub select_block: Vec<String>,
 	/// Key bindings for showing a commit.
 	pub show_commit: Vec<String>,
 	/// Key bindings for showing a diff.
 	pub show_diff: Vec<String>,
+	/// Key bindings for showing the diff against the next parent of a merge commit.
+	pub show_next_parent: Vec<String>,
+	/// Key bindings for showing the preview of squashing the selected commit with its fixup and squash commits.
+	pub show_squash_preview: Vec<String>,
+	/// Key bindings for showing the changes made to the todo list.
+	pub show_todo_diff: Vec<String>,
+	/// Key bindings for toggling the preview of the selected commit.
+	pub toggle_preview: Vec<String>,
+	/// Key bindings for adding or removing the update-ref of a branch.
+	pub toggle_update_ref: Vec<String>,
 	/// Key bindings for toggling visual mode.
 	pub toggle_visual_mode: Vec<String>,
+	/// Key bindings for toggling the wrapping of long lines.
+	pub toggle_wrap: Vec<String>,
 	/// Key bindings for undoing a change.
 	pub undo: Vec<String>,
+	/// Key bindings for managing the update-refs of branches.
+	pub update_refs: Vec<String>,
 	/// Key bindings for the fixup specific action to toggle the c option.
 	pub fixup_keep_message_with_editor: Vec<String>,
 	/// Key bindings for the fixup specific action to toggle the c option.
@@ -142,6 +182,10 @@ impl KeyBindings {
 			confirm_no,
 			confirm_yes,
 			edit: get_input(git_config, "interactive-rebase-tool.inputEdit", "E")?,
+			expand_file: get_input(git_config, "interactive-rebase-tool.inputExpandFile", "x")?,
+			fold_all: get_input(git_config, "interactive-rebase-tool.inputFoldAll", "F")?,
+			fold_file: get_input(git_config, "interactive-rebase-tool.inputFoldFile", "Z")?,
+			fold_hunk: get_input(git_config, "interactive-rebase-tool.inputFoldHunk", "z")?,
 			force_abort: get_input(git_config, "interactive-rebase-tool.inputForceAbort", "Q")?,
 			force_rebase: get_input(git_config, "interactive-rebase-tool.inputForceRebase", "W")?,
 			help: get_input(git_config, "interactive-rebase-tool.inputHelp", "?")?,
@@ -156,6 +200,9 @@ impl KeyBindings {
 			move_up: get_input(git_config, "interactive-rebase-tool.inputMoveUp", "Up")?,
 			move_selection_down: get_input(git_config, "interactive-rebase-tool.inputMoveSelectionDown", "j")?,
 			move_selection_up: get_input(git_config, "interactive-rebase-tool.inputMoveSelectionUp", "k")?,
+			move_block_down: get_input(git_config, "interactive-rebase-tool.inputMoveBlockDown", "J")?,
+			move_block_up: get_input(git_config, "interactive-rebase-tool.inputMoveBlockUp", "K")?,
+			move_update_ref: get_input(git_config, "interactive-rebase-tool.inputMoveUpdateRef", "m")?,
 			scroll_down: get_input(git_config, "interactive-rebase-tool.inputScrollDown", "Down")?,
 			scroll_end: get_input(git_config, "interactive-rebase-tool.inputScrollEnd", "End")?,
 			scroll_home: get_input(git_config, "interactive-rebase-tool.inputScrollHome", "Home")?,
@@ -164,17 +211,34 @@ impl KeyBindings {
 			scroll_up: get_input(git_config, "interactive-rebase-tool.inputScrollUp", "Up")?,
 			scroll_step_down: get_input(git_config, "interactive-rebase-tool.inputScrollStepDown", "PageDown")?,
 			scroll_step_up: get_input(git_config, "interactive-rebase-tool.inputScrollStepUp", "PageUp")?,
+			next_file: get_input(git_config, "interactive-rebase-tool.inputNextFile", "}")?,
+			next_hunk: get_input(git_config, "interactive-rebase-tool.inputNextHunk", "]")?,
 			open_in_external_editor: get_input(git_config, "interactive-rebase-tool.inputOpenInExternalEditor", "!")?,
+			previous_file: get_input(git_config, "interactive-rebase-tool.inputPreviousFile", "{")?,
+			previous_hunk: get_input(git_config, "interactive-rebase-tool.inputPreviousHunk", "[")?,
 			rebase: get_input(git_config, "interactive-rebase-tool.inputRebase", "w")?,
 			redo: get_input(git_config, "interactive-rebase-tool.inputRedo", "control+y")?,
 			remove_line: get_input(git_config, "interactive-rebase-tool.removeLine", "delete")?,
+			run_command: get_input(git_config, "interactive-rebase-tool.inputRunCommand", "x")?,
 			search_start: get_input(git_config, "interactive-rebase-tool.searchStart", "/")?,
 			search_next: get_input(git_config, "interactive-rebase-tool.searchNext", "n")?,
 			search_previous: get_input(git_config, "interactive-rebase-tool.searchPrevious", "N")?,
+			select_block: get_input(git_config, "interactive-rebase-tool.inputSelectBlock", "B")?,
 			show_commit: get_input(git_config, "interactive-rebase-tool.inputShowCommit", "c")?,
 			show_diff: get_input(git_config, "interactive-rebase-tool.inputShowDiff", "d")?,
+			show_next_parent: get_input(git_config, "interactive-rebase-tool.inputShowNextParent", "p")?,
+			show_squash_preview: get_input(git_config, "interactive-rebase-tool.inputShowSquashPreview", "S")?,
+			show_todo_diff: get_input(git_config, "interactive-rebase-tool.inputShowTodoDiff", "D")?,
+			toggle_preview: get_input(git_config, "interactive-rebase-tool.inputTogglePreview", "P")?,
+			toggle_update_ref: get_input(
+				git_config,
+				"interactive-rebase-tool.inputToggleUpdateRef",
+				"Space Enter",
+			)?,
 			toggle_visual_mode: get_input(git_config, "interactive-rebase-tool.inputToggleVisualMode", "v")?,
+			toggle_wrap: get_input(git_config, "interactive-rebase-tool.inputToggleWrap", "w")?,
 			undo: get_input(git_config, "interactive-rebase-tool.inputUndo", "control+z")?,
+			update_refs: get_input(git_config, "interactive-rebase-tool.inputUpdateRefs", "R")?,
 			fixup_keep_message_with_editor: get_input(
 				git_config,
 				"interactive-rebase-tool.fixupKeepMessageWithEditor",
@@ -262,6 +326,10 @@ mod tests {
 		config_test!(confirm_no, "inputConfirmNo", "n");
 		config_test!(confirm_yes, "inputConfirmYes", "y");
 		config_test!(edit, "inputEdit", "E");
+		config_test!(expand_file, "inputExpandFile", "x");
+		config_test!(fold_all, "inputFoldAll", "F");
+		config_test!(fold_file, "inputFoldFile", "Z");
+		config_test!(fold_hunk, "inputFoldHunk", "z");
 		config_test!(force_abort, "inputForceAbort", "Q");
 		config_test!(force_rebase, "inputForceRebase", "W");
 		config_test!(help, "inputHelp", "?");
@@ -276,6 +344,9 @@ mod tests {
 		config_test!(move_up_step, "inputMoveStepUp", "PageUp");
 		config_test!(move_selection_down, "inputMoveSelectionDown", "j");
 		config_test!(move_selection_up, "inputMoveSelectionUp", "k");
+		config_test!(move_block_down, "inputMoveBlockDown", "J");
+		config_test!(move_block_up, "inputMoveBlockUp", "K");
+		config_test!(move_update_ref, "inputMoveUpdateRef", "m");
 		config_test!(scroll_down, "inputScrollDown", "Down");
 		config_test!(scroll_end, "inputScrollEnd", "End");
 		config_test!(scroll_home, "inputScrollHome", "Home");
@@ -284,17 +355,30 @@ mod tests {
 		config_test!(scroll_up, "inputScrollUp", "Up");
 		config_test!(scroll_step_down, "inputScrollStepDown", "PageDown");
 		config_test!(scroll_step_up, "inputScrollStepUp", "PageUp");
+		config_test!(next_file, "inputNextFile", "}");
+		config_test!(next_hunk, "inputNextHunk", "]");
 		config_test!(open_in_external_editor, "inputOpenInExternalEditor", "!");
+		config_test!(previous_file, "inputPreviousFile", "{");
+		config_test!(previous_hunk, "inputPreviousHunk", "[");
 		config_test!(rebase, "inputRebase", "w");
 		config_test!(redo, "inputRedo", "Controly");
 		config_test!(remove_line, "removeLine", "Delete");
+		config_test!(run_command, "inputRunCommand", "x");
 		config_test!(search_start, "searchStart", "/");
 		config_test!(search_next, "searchNext", "n");
 		config_test!(search_previous, "searchPrevious", "N");
+		config_test!(select_block, "inputSelectBlock", "B");
 		config_test!(show_commit, "inputShowCommit", "c");
 		config_test!(show_diff, "inputShowDiff", "d");
+		config_test!(show_next_parent, "inputShowNextParent", "p");
+		config_test!(show_squash_preview, "inputShowSquashPreview", "S");
+		config_test!(show_todo_diff, "inputShowTodoDiff", "D");
+		config_test!(toggle_preview, "inputTogglePreview", "P");
+		config_test!(toggle_update_ref, "inputToggleUpdateRef", "Space");
 		config_test!(toggle_visual_mode, "inputToggleVisualMode", "v");
+		config_test!(toggle_wrap, "inputToggleWrap", "w");
 		config_test!(undo, "inputUndo", "Controlz");
+		config_test!(update_refs, "inputUpdateRefs", "R");
 		config_test!(fixup_keep_message_with_editor, "fixupKeepMessageWithEditor", "U");
 		config_test!(fixup_keep_message, "fixupKeepMessage", "u");
 	}
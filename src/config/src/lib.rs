@@ -145,6 +145,8 @@ mod diff_show_whitespace_setting;
 pub mod errors;
 mod git_config;
 mod key_bindings;
+mod list_column;
+mod missing_commits_check_setting;
 mod theme;
 mod utils;
 
@@ -153,13 +155,22 @@ mod testutils;
 
 use git::Repository;
 
-use self::utils::{get_bool, get_diff_ignore_whitespace, get_diff_show_whitespace, get_string, get_unsigned_integer};
+use self::utils::{
+	get_bool,
+	get_diff_ignore_whitespace,
+	get_diff_show_whitespace,
+	get_list_columns,
+	get_string,
+	get_unsigned_integer,
+};
 pub use self::{
 	color::Color,
 	diff_ignore_whitespace_setting::DiffIgnoreWhitespaceSetting,
 	diff_show_whitespace_setting::DiffShowWhitespaceSetting,
 	git_config::GitConfig,
 	key_bindings::KeyBindings,
+	list_column::ListColumn,
+	missing_commits_check_setting::MissingCommitsCheckSetting,
 	theme::Theme,
 };
 use crate::errors::{ConfigError, ConfigErrorCause};
@@ -170,9 +181,16 @@ const DEFAULT_TAB_SYMBOL: &str = "\u{2192}"; // →
 /// Represents the configuration options.
 #[derive(Clone, Debug)]
 #[non_exhaustive]
+#[allow(clippy::struct_excessive_bools)]
 pub struct Config {
 	/// If to select the next line in the list after performing an action.
 	pub auto_select_next: bool,
+	/// If to show a preview of the selected commit alongside the todo list.
+	pub commit_preview: bool,
+	/// If to collapse files marked as generated with the `linguist-generated` attribute in diffs.
+	pub diff_collapse_generated: bool,
+	/// The maximum number of lines shown for a single file in diffs, larger files are collapsed. Zero is no limit.
+	pub diff_file_line_limit: u32,
 	/// How to handle whitespace when calculating diffs.
 	pub diff_ignore_whitespace: DiffIgnoreWhitespaceSetting,
 	/// If to ignore blank lines when calculating diffs.
@@ -185,6 +203,12 @@ pub struct Config {
 	pub diff_tab_symbol: String,
 	/// The display width of the tab character.
 	pub diff_tab_width: u32,
+	/// The maximum number of lines shown for all files in diffs, files past the limit are collapsed. Zero is no limit.
+	pub diff_total_line_limit: u32,
+	/// The columns shown for each line in the todo list view.
+	pub list_columns: Vec<ListColumn>,
+	/// If to show a status line with the rebase context below the todo list.
+	pub status_bar: bool,
 	/// The maximum number of undo steps.
 	pub undo_limit: u32,
 	/// Configuration options loaded directly from Git.
@@ -207,6 +231,9 @@ impl Config {
 	fn new_with_config(git_config: Option<&git::Config>) -> Result<Self, ConfigError> {
 		Ok(Self {
 			auto_select_next: get_bool(git_config, "interactive-rebase-tool.autoSelectNext", false)?,
+			commit_preview: get_bool(git_config, "interactive-rebase-tool.commitPreview", false)?,
+			diff_collapse_generated: get_bool(git_config, "interactive-rebase-tool.diffCollapseGenerated", true)?,
+			diff_file_line_limit: get_unsigned_integer(git_config, "interactive-rebase-tool.diffFileLineLimit", 1000)?,
 			diff_ignore_whitespace: get_diff_ignore_whitespace(
 				git_config,
 				"interactive-rebase-tool.diffIgnoreWhitespace",
@@ -220,6 +247,13 @@ impl Config {
 			)?,
 			diff_tab_symbol: get_string(git_config, "interactive-rebase-tool.diffTabSymbol", DEFAULT_TAB_SYMBOL)?,
 			diff_tab_width: get_unsigned_integer(git_config, "interactive-rebase-tool.diffTabWidth", 4)?,
+			diff_total_line_limit: get_unsigned_integer(
+				git_config,
+				"interactive-rebase-tool.diffTotalLineLimit",
+				10000,
+			)?,
+			list_columns: get_list_columns(git_config, "interactive-rebase-tool.listColumns")?,
+			status_bar: get_bool(git_config, "interactive-rebase-tool.statusBar", false)?,
 			undo_limit: get_unsigned_integer(git_config, "interactive-rebase-tool.undoLimit", 5000)?,
 			git: GitConfig::new_with_config(git_config)?,
 			key_bindings: KeyBindings::new_with_config(git_config)?,
@@ -299,6 +333,8 @@ mod tests {
 	#[case::auto_select_next_default("autoSelectNext", "", false, |config: Config| config.auto_select_next)]
 	#[case::auto_select_next_false("autoSelectNext", "false", false, |config: Config| config.auto_select_next)]
 	#[case::auto_select_next_true("autoSelectNext", "true", true, |config: Config| config.auto_select_next)]
+	#[case::commit_preview_default("commitPreview", "", false, |config: Config| config.commit_preview)]
+	#[case::commit_preview_true("commitPreview", "true", true, |config: Config| config.commit_preview)]
 	#[case::diff_ignore_whitespace_default(
 		"diffIgnoreWhitespace",
 		"",
@@ -353,6 +389,20 @@ mod tests {
 		DiffIgnoreWhitespaceSetting::Change,
 		|config: Config| config.diff_ignore_whitespace)
 	]
+	#[case::diff_collapse_generated_default(
+		"diffCollapseGenerated",
+		"",
+		true,
+		|config: Config| config.diff_collapse_generated
+	)]
+	#[case::diff_collapse_generated_false(
+		"diffCollapseGenerated",
+		"false",
+		false,
+		|config: Config| config.diff_collapse_generated
+	)]
+	#[case::diff_file_line_limit_default("diffFileLineLimit", "", 1000, |config: Config| config.diff_file_line_limit)]
+	#[case::diff_file_line_limit("diffFileLineLimit", "42", 42, |config: Config| config.diff_file_line_limit)]
 	#[case::diff_ignore_blank_lines_default(
 		"diffIgnoreBlankLines",
 		"",
@@ -433,6 +483,13 @@ mod tests {
 	]
 	#[case::diff_tab_width_default("diffTabWidth", "", 4, |config: Config| config.diff_tab_width)]
 	#[case::diff_tab_width("diffTabWidth", "42", 42, |config: Config| config.diff_tab_width)]
+	#[case::diff_total_line_limit_default(
+		"diffTotalLineLimit",
+		"",
+		10000,
+		|config: Config| config.diff_total_line_limit
+	)]
+	#[case::diff_total_line_limit("diffTotalLineLimit", "42", 42, |config: Config| config.diff_total_line_limit)]
 	#[case::diff_tab_symbol_default("diffTabSymbol", "", String::from("→"), |config: Config| config.diff_tab_symbol)]
 	#[case::diff_tab_symbol("diffTabSymbol", "|", String::from("|"), |config: Config| config.diff_tab_symbol)]
 	#[case::diff_tab_symbol("diffTabSymbol", "|", String::from("|"), |config: Config| config.diff_tab_symbol)]
@@ -443,6 +500,20 @@ mod tests {
 		|config: Config| config.diff_space_symbol)
 	]
 	#[case::diff_space_symbol("diffSpaceSymbol", "-", String::from("-"), |config: Config| config.diff_space_symbol)]
+	#[case::list_columns_default(
+		"listColumns",
+		"",
+		vec![ListColumn::Action, ListColumn::Hash, ListColumn::Subject],
+		|config: Config| config.list_columns
+	)]
+	#[case::list_columns(
+		"listColumns",
+		"action,author,subject",
+		vec![ListColumn::Action, ListColumn::Author, ListColumn::Subject],
+		|config: Config| config.list_columns
+	)]
+	#[case::status_bar_default("statusBar", "", false, |config: Config| config.status_bar)]
+	#[case::status_bar_true("statusBar", "true", true, |config: Config| config.status_bar)]
 	#[case::undo_limit_default("undoLimit", "", 5000, |config: Config| config.undo_limit)]
 	#[case::undo_limit_default("undoLimit", "42", 42, |config: Config| config.undo_limit)]
 	pub(crate) fn theme_color<F, T>(
@@ -469,11 +540,17 @@ mod tests {
 
 	#[rstest]
 	#[case::auto_select_next("autoSelectNext", "invalid", ConfigErrorCause::InvalidBoolean)]
+	#[case::commit_preview("commitPreview", "invalid", ConfigErrorCause::InvalidBoolean)]
+	#[case::diff_collapse_generated("diffCollapseGenerated", "invalid", ConfigErrorCause::InvalidBoolean)]
+	#[case::diff_file_line_limit("diffFileLineLimit", "invalid", ConfigErrorCause::InvalidUnsignedInteger)]
 	#[case::diff_ignore_whitespace("diffIgnoreWhitespace", "invalid", ConfigErrorCause::InvalidDiffIgnoreWhitespace)]
 	#[case::diff_ignore_blank_lines("diffIgnoreBlankLines", "invalid", ConfigErrorCause::InvalidBoolean)]
 	#[case::diff_show_whitespace("diffShowWhitespace", "invalid", ConfigErrorCause::InvalidShowWhitespace)]
 	#[case::diff_tab_width_non_integer("diffTabWidth", "invalid", ConfigErrorCause::InvalidUnsignedInteger)]
 	#[case::diff_tab_width_non_poitive_integer("diffTabWidth", "-100", ConfigErrorCause::InvalidUnsignedInteger)]
+	#[case::diff_total_line_limit("diffTotalLineLimit", "invalid", ConfigErrorCause::InvalidUnsignedInteger)]
+	#[case::list_columns_invalid("listColumns", "action,invalid", ConfigErrorCause::InvalidListColumns)]
+	#[case::status_bar("statusBar", "invalid", ConfigErrorCause::InvalidBoolean)]
 	#[case::undo_limit_non_integer("undoLimit", "invalid", ConfigErrorCause::InvalidUnsignedInteger)]
 	#[case::undo_limit_non_positive_integer("undoLimit", "-100", ConfigErrorCause::InvalidUnsignedInteger)]
 	fn value_parsing_invalid(#[case] config_name: &str, #[case] config_value: &str, #[case] cause: ConfigErrorCause) {
@@ -500,6 +577,7 @@ mod tests {
 	#[case::diff_tab_symbol("diffShowWhitespace")]
 	#[case::diff_tab_symbol("diffTabSymbol")]
 	#[case::diff_space_symbol("diffSpaceSymbol")]
+	#[case::list_columns("listColumns")]
 	fn value_parsing_invalid_utf(#[case] config_name: &str) {
 		with_git_config(
 			&[
@@ -0,0 +1,21 @@
+/// A column shown for each line in the todo list view.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ListColumn {
+	/// The action of the line.
+	Action,
+	/// The abbreviated hash of the commit.
+	Hash,
+	/// The name of the author of the commit.
+	Author,
+	/// The date the commit was committed.
+	Date,
+	/// The time since the commit was committed.
+	Age,
+	/// The branches, remote branches and tags that point at the commit.
+	Refs,
+	/// An indicator for signed commits, and if the commit will lose its signature when rewritten by the rebase.
+	Signature,
+	/// The content of the line, which is the commit subject for commit lines.
+	Subject,
+}
@@ -0,0 +1,14 @@
+/// Configuration option for how to handle commits removed from the rebase todo list, from
+/// [`rebase.missingCommitsCheck`](
+///     https://git-scm.com/docs/git-config#Documentation/git-config.txt-rebasemissingCommitsCheck
+/// ).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MissingCommitsCheckSetting {
+	/// Do not check for removed commits.
+	Ignore,
+	/// Warn about removed commits before rebasing.
+	Warn,
+	/// Refuse to rebase while commits have been removed.
+	Error,
+}
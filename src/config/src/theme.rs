@@ -68,6 +68,12 @@ pub struct Theme {
 	pub color_foreground: Color,
 	/// The color for indicator text.
 	pub color_indicator: Color,
+	/// The color for branch references.
+	pub color_ref_branch: Color,
+	/// The color for remote references.
+	pub color_ref_remote: Color,
+	/// The color for tag references.
+	pub color_ref_tag: Color,
 	/// The background color for selected lines.
 	pub color_selected_background: Color,
 }
@@ -121,6 +127,9 @@ impl Theme {
 			color_diff_whitespace: get_color(git_config, "interactive-rebase-tool.diffWhitespace", Color::LightBlack)?,
 			color_foreground: get_color(git_config, "interactive-rebase-tool.foregroundColor", Color::Default)?,
 			color_indicator: get_color(git_config, "interactive-rebase-tool.indicatorColor", Color::LightCyan)?,
+			color_ref_branch: get_color(git_config, "interactive-rebase-tool.refBranchColor", Color::LightGreen)?,
+			color_ref_remote: get_color(git_config, "interactive-rebase-tool.refRemoteColor", Color::LightRed)?,
+			color_ref_tag: get_color(git_config, "interactive-rebase-tool.refTagColor", Color::LightYellow)?,
 			color_selected_background: get_color(
 				git_config,
 				"interactive-rebase-tool.selectedBackgroundColor",
@@ -234,6 +243,9 @@ mod tests {
 		config_test!(color_diff_whitespace, "diffWhitespace", Color::LightBlack);
 		config_test!(color_foreground, "foregroundColor", Color::Default);
 		config_test!(color_indicator, "indicatorColor", Color::LightCyan);
+		config_test!(color_ref_branch, "refBranchColor", Color::LightGreen);
+		config_test!(color_ref_remote, "refRemoteColor", Color::LightRed);
+		config_test!(color_ref_tag, "refTagColor", Color::LightYellow);
 		config_test!(color_selected_background, "selectedBackgroundColor", Color::Index(237));
 	}
 
@@ -36,6 +36,7 @@ pub(crate) fn get_input(config: Option<&Config>, name: &str, default: &str) -> R
 			"pagedown" => String::from("PageDown"),
 			"pageup" => String::from("PageUp"),
 			"right" => String::from("Right"),
+			"space" => String::from("Space"),
 			"tab" => String::from("Tab"),
 			"up" => String::from("Up"),
 			v => {
@@ -99,6 +100,7 @@ mod tests {
 	#[case::pagedown("pagedown", "PageDown")]
 	#[case::pageup("pageup", "PageUp")]
 	#[case::right("right", "Right")]
+	#[case::space("space", "Space")]
 	#[case::tab("tab", "Tab")]
 	#[case::up("up", "Up")]
 	#[case::f1("f1", "F1")]
@@ -0,0 +1,101 @@
+use git::Config;
+
+use crate::{utils::get_string, ConfigError, ConfigErrorCause, ListColumn};
+
+pub(crate) fn get_list_columns(git_config: Option<&Config>, name: &str) -> Result<Vec<ListColumn>, ConfigError> {
+	let value = get_string(git_config, name, "action,hash,subject")?;
+	value
+		.split(',')
+		.map(str::trim)
+		.filter(|column| !column.is_empty())
+		.map(|column| {
+			match column.to_lowercase().as_str() {
+				"action" => Ok(ListColumn::Action),
+				"hash" => Ok(ListColumn::Hash),
+				"author" => Ok(ListColumn::Author),
+				"date" => Ok(ListColumn::Date),
+				"age" => Ok(ListColumn::Age),
+				"refs" => Ok(ListColumn::Refs),
+				"signature" => Ok(ListColumn::Signature),
+				"subject" => Ok(ListColumn::Subject),
+				_ => Err(ConfigError::new(name, value.as_str(), ConfigErrorCause::InvalidListColumns)),
+			}
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::assert_ok_eq;
+	use rstest::rstest;
+	use testutils::assert_err_eq;
+
+	use super::*;
+	use crate::testutils::{invalid_utf, with_git_config};
+
+	#[rstest]
+	#[case::single("subject", vec![ListColumn::Subject])]
+	#[case::all(
+		"action,hash,author,date,age,refs,signature,subject",
+		vec![
+			ListColumn::Action,
+			ListColumn::Hash,
+			ListColumn::Author,
+			ListColumn::Date,
+			ListColumn::Age,
+			ListColumn::Refs,
+			ListColumn::Signature,
+			ListColumn::Subject,
+		]
+	)]
+	#[case::whitespace(" action , subject ", vec![ListColumn::Action, ListColumn::Subject])]
+	#[case::mixed_case("AcTiOn,Subject", vec![ListColumn::Action, ListColumn::Subject])]
+	#[case::empty_entries("action,,subject,", vec![ListColumn::Action, ListColumn::Subject])]
+	fn read_ok(#[case] value: &str, #[case] expected: Vec<ListColumn>) {
+		with_git_config(&["[test]", format!("value = \"{value}\"").as_str()], |git_config| {
+			assert_ok_eq!(get_list_columns(Some(&git_config), "test.value"), expected);
+		});
+	}
+
+	#[test]
+	fn read_default() {
+		with_git_config(&[], |git_config| {
+			assert_ok_eq!(
+				get_list_columns(Some(&git_config), "test.value"),
+				vec![ListColumn::Action, ListColumn::Hash, ListColumn::Subject]
+			);
+		});
+	}
+
+	#[test]
+	fn read_invalid_value() {
+		with_git_config(&["[test]", "value = \"action,invalid\""], |git_config| {
+			assert_err_eq!(
+				get_list_columns(Some(&git_config), "test.value"),
+				ConfigError::new("test.value", "action,invalid", ConfigErrorCause::InvalidListColumns)
+			);
+		});
+	}
+
+	#[test]
+	fn invalid_value_message() {
+		assert_eq!(
+			ConfigErrorCause::InvalidListColumns.to_string(),
+			"Must be a comma separated list of 'action', 'hash', 'author', 'date', 'age', 'refs', 'signature' or \
+			 'subject'"
+		);
+	}
+
+	#[test]
+	fn read_invalid_non_utf() {
+		with_git_config(
+			&["[test]", format!("value = {}", invalid_utf()).as_str()],
+			|git_config| {
+				assert_err_eq!(
+					get_list_columns(Some(&git_config), "test.value"),
+					ConfigError::new_read_error("test.value", ConfigErrorCause::InvalidUtf)
+				);
+			},
+		);
+	}
+}
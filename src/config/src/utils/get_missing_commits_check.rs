@@ -0,0 +1,69 @@
+use git::Config;
+
+use crate::{utils::get_string, ConfigError, ConfigErrorCause, MissingCommitsCheckSetting};
+
+pub(crate) fn get_missing_commits_check(
+	git_config: Option<&Config>,
+	name: &str,
+) -> Result<MissingCommitsCheckSetting, ConfigError> {
+	match get_string(git_config, name, "ignore")?.to_lowercase().as_str() {
+		"ignore" => Ok(MissingCommitsCheckSetting::Ignore),
+		"warn" => Ok(MissingCommitsCheckSetting::Warn),
+		"error" => Ok(MissingCommitsCheckSetting::Error),
+		input => Err(ConfigError::new(name, input, ConfigErrorCause::InvalidMissingCommitsCheck)),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use claims::assert_ok_eq;
+	use rstest::rstest;
+	use testutils::assert_err_eq;
+
+	use super::*;
+	use crate::testutils::{invalid_utf, with_git_config};
+
+	#[rstest]
+	#[case::ignore("ignore", MissingCommitsCheckSetting::Ignore)]
+	#[case::warn("warn", MissingCommitsCheckSetting::Warn)]
+	#[case::error("error", MissingCommitsCheckSetting::Error)]
+	#[case::mixed_case("WaRn", MissingCommitsCheckSetting::Warn)]
+	fn read_ok(#[case] value: &str, #[case] expected: MissingCommitsCheckSetting) {
+		with_git_config(&["[test]", format!("value = \"{value}\"").as_str()], |git_config| {
+			assert_ok_eq!(get_missing_commits_check(Some(&git_config), "test.value"), expected);
+		});
+	}
+
+	#[test]
+	fn read_default() {
+		with_git_config(&[], |git_config| {
+			assert_ok_eq!(
+				get_missing_commits_check(Some(&git_config), "test.value"),
+				MissingCommitsCheckSetting::Ignore
+			);
+		});
+	}
+
+	#[test]
+	fn read_invalid_value() {
+		with_git_config(&["[test]", "value = invalid"], |git_config| {
+			assert_err_eq!(
+				get_missing_commits_check(Some(&git_config), "test.value"),
+				ConfigError::new("test.value", "invalid", ConfigErrorCause::InvalidMissingCommitsCheck)
+			);
+		});
+	}
+
+	#[test]
+	fn read_invalid_non_utf() {
+		with_git_config(
+			&["[test]", format!("value = {}", invalid_utf()).as_str()],
+			|git_config| {
+				assert_err_eq!(
+					get_missing_commits_check(Some(&git_config), "test.value"),
+					ConfigError::new_read_error("test.value", ConfigErrorCause::InvalidUtf)
+				);
+			},
+		);
+	}
+}
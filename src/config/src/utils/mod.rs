@@ -3,6 +3,8 @@ mod get_diff_ignore_whitespace;
 mod get_diff_rename;
 mod get_diff_show_whitespace;
 mod get_input;
+mod get_list_columns;
+mod get_missing_commits_check;
 mod get_string;
 mod get_unsigned_integer;
 
@@ -12,6 +14,8 @@ pub(crate) use self::{
 	get_diff_rename::git_diff_renames,
 	get_diff_show_whitespace::get_diff_show_whitespace,
 	get_input::get_input,
+	get_list_columns::get_list_columns,
+	get_missing_commits_check::get_missing_commits_check,
 	get_string::{_get_string, get_string},
 	get_unsigned_integer::get_unsigned_integer,
 };
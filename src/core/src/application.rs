@@ -11,6 +11,7 @@ use todo_file::TodoFile;
 use view::View;
 
 use crate::{
+	diff,
 	events,
 	events::{KeyBindings, MetaEvent},
 	help::build_help,
@@ -79,6 +80,15 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 		let search_state = search_threads.state();
 		threads.push(Box::new(search_threads));
 
+		let diff_update_handler = Self::create_diff_update_handler(input_state.clone());
+		let diff_threads = diff::Thread::new(
+			repository.clone(),
+			diff::create_commit_diff_loader_options(&config),
+			diff_update_handler,
+		);
+		let diff_state = diff_threads.state();
+		threads.push(Box::new(diff_threads));
+
 		let process = Process::new(
 			initial_display_size,
 			todo_file,
@@ -86,6 +96,7 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 			input_state,
 			view_state,
 			search_state,
+			diff_state,
 			thread_statuses.clone(),
 		);
 		let process_threads = process::Thread::new(process.clone());
@@ -178,6 +189,10 @@ where ModuleProvider: module::ModuleProvider + Send + 'static
 	fn create_search_update_handler(input_state: events::State) -> impl Fn() + Send + Sync {
 		move || input_state.push_event(Event::MetaEvent(MetaEvent::SearchUpdate))
 	}
+
+	fn create_diff_update_handler(input_state: events::State) -> impl Fn() + Send + Sync {
+		move || input_state.push_event(Event::MetaEvent(MetaEvent::DiffUpdate))
+	}
 }
 
 #[cfg(all(unix, test))]
@@ -321,6 +336,19 @@ mod tests {
 		assert_eq!(input_state.read_event(), Event::MetaEvent(MetaEvent::SearchUpdate));
 	}
 
+	#[test]
+	#[serial_test::serial]
+	fn diff_update_handler_handles_update() {
+		let event_provider = create_event_reader(|| Ok(None));
+		let input_threads = events::Thread::new(event_provider);
+		let input_state = input_threads.state();
+		let update_handler =
+			Application::<TestModuleProvider<DefaultTestModule>>::create_diff_update_handler(input_state.clone());
+		update_handler();
+
+		assert_eq!(input_state.read_event(), Event::MetaEvent(MetaEvent::DiffUpdate));
+	}
+
 	#[test]
 	#[serial_test::serial]
 	fn run_until_finished_success() {
@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter};
+
+use regex::Regex;
+use todo_file::{Action, Line};
+
+#[derive(Debug)]
+pub(super) enum Target {
+	Hash(String),
+	Pattern(Regex),
+}
+
+impl Target {
+	fn parse(value: &str) -> Result<Self, String> {
+		if let Some(pattern) = value.strip_prefix('/').and_then(|v| v.strip_suffix('/')) {
+			Regex::new(pattern)
+				.map(Self::Pattern)
+				.map_err(|err| format!("Invalid pattern '{pattern}': {err}"))
+		}
+		else if !value.is_empty() && !value.contains(char::is_whitespace) {
+			Ok(Self::Hash(value.to_lowercase()))
+		}
+		else {
+			Err(format!("Invalid commit '{value}'"))
+		}
+	}
+
+	// abbreviated hashes match either way, since the todo file and the script can use different lengths
+	pub(super) fn matches(&self, line: &Line) -> bool {
+		if !is_commit_line(line) {
+			return false;
+		}
+		match *self {
+			Self::Hash(ref hash) => {
+				let line_hash = line.get_hash().to_lowercase();
+				line_hash.starts_with(hash.as_str()) || hash.starts_with(line_hash.as_str())
+			},
+			Self::Pattern(ref pattern) => pattern.is_match(line.get_content()),
+		}
+	}
+}
+
+impl Display for Target {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::Hash(ref hash) => write!(f, "{hash}"),
+			Self::Pattern(ref pattern) => write!(f, "/{pattern}/"),
+		}
+	}
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(super) enum Position {
+	After,
+	Before,
+}
+
+#[derive(Debug)]
+pub(super) enum Command {
+	SetAction(Action, Target),
+	Move(Target, Position, Target),
+	ExecAfterEach(String),
+}
+
+impl Command {
+	// parse a line of the script, blank lines and comments result in `None`
+	pub(super) fn parse(line: &str) -> Result<Option<Self>, String> {
+		let trimmed = line.trim();
+		if trimmed.is_empty() || trimmed.starts_with('#') {
+			return Ok(None);
+		}
+		let (name, untrimmed_argument) = trimmed.split_once(char::is_whitespace).unwrap_or((trimmed, ""));
+		let argument = untrimmed_argument.trim();
+
+		match name {
+			"pick" | "reword" | "edit" | "squash" | "fixup" | "drop" => {
+				let action = Action::try_from(name).map_err(|err| err.to_string())?;
+				Ok(Some(Self::SetAction(action, Target::parse(argument)?)))
+			},
+			"move" => {
+				let mut parts = argument.split_whitespace();
+				let (Some(hash), Some(position_name), Some(target), None) =
+					(parts.next(), parts.next(), parts.next(), parts.next())
+				else {
+					return Err(String::from("Expected 'move <hash> after <hash>' or 'move <hash> before <hash>'"));
+				};
+				let position = match position_name {
+					"after" => Position::After,
+					"before" => Position::Before,
+					_ => {
+						return Err(format!(
+							"Invalid move position '{position_name}', expected 'after' or 'before'"
+						));
+					},
+				};
+				Ok(Some(Self::Move(Target::parse(hash)?, position, Target::parse(target)?)))
+			},
+			"exec-after-each" => {
+				if argument.is_empty() {
+					Err(String::from("Missing command for 'exec-after-each'"))
+				}
+				else {
+					Ok(Some(Self::ExecAfterEach(String::from(argument))))
+				}
+			},
+			_ => Err(format!("Unknown command '{name}'")),
+		}
+	}
+}
+
+fn is_commit_line(line: &Line) -> bool {
+	match *line.get_action() {
+		Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => true,
+		Action::Break
+		| Action::Exec
+		| Action::Label
+		| Action::Merge
+		| Action::Noop
+		| Action::Reset
+		| Action::UpdateRef => false,
+	}
+}
+
+// the lines that result in a commit, which are the lines that `git rebase --exec` runs the command after
+pub(super) fn creates_commit(line: &Line) -> bool {
+	match *line.get_action() {
+		Action::Edit | Action::Fixup | Action::Merge | Action::Pick | Action::Reword | Action::Squash => true,
+		Action::Break
+		| Action::Drop
+		| Action::Exec
+		| Action::Label
+		| Action::Noop
+		| Action::Reset
+		| Action::UpdateRef => false,
+	}
+}
@@ -0,0 +1,131 @@
+mod command;
+#[cfg(test)]
+mod tests;
+
+use std::fs::read_to_string;
+
+use todo_file::{Action, EditContext, Line, TodoFile};
+
+use self::command::{creates_commit, Command, Position, Target};
+use crate::{
+	arguments::Args,
+	batch::{load_todo_file, validate_and_write_todo_file},
+	exit::Exit,
+	help::build_help,
+	module::ExitStatus,
+};
+
+pub(crate) fn run(args: &Args) -> Exit {
+	match apply(args) {
+		Ok(()) => Exit::from(ExitStatus::Good),
+		Err(exit) => exit,
+	}
+}
+
+fn apply(args: &Args) -> Result<(), Exit> {
+	let (Some(script_path), Some(filepath)) = (args.script_path().as_ref(), args.todo_file_path().as_ref())
+	else {
+		return Err(Exit::new(
+			ExitStatus::StateError,
+			build_help(Some(String::from("A script and a todo file path must be provided."))).as_str(),
+		));
+	};
+
+	let script = read_to_string(script_path).map_err(|err| {
+		Exit::new(
+			ExitStatus::FileReadError,
+			format!("Unable to read script file '{script_path}': {err}").as_str(),
+		)
+	})?;
+	let (repository, mut todo_file) = load_todo_file(filepath, true)?;
+
+	apply_script(&mut todo_file, script.as_str()).map_err(|err| Exit::new(ExitStatus::StateError, err.as_str()))?;
+
+	validate_and_write_todo_file(
+		&repository,
+		&todo_file,
+		"The todo file is not valid after applying the script",
+	)
+}
+
+pub(super) fn apply_script(todo_file: &mut TodoFile, script: &str) -> Result<(), String> {
+	for (index, line) in script.lines().enumerate() {
+		let command = Command::parse(line).map_err(|err| format!("Script line {}: {err}", index + 1))?;
+		if let Some(cmd) = command {
+			apply_command(todo_file, &cmd).map_err(|err| format!("Script line {}: {err}", index + 1))?;
+		}
+	}
+	Ok(())
+}
+
+fn apply_command(todo_file: &mut TodoFile, command: &Command) -> Result<(), String> {
+	match *command {
+		Command::SetAction(action, ref target) => {
+			let indexes = match *target {
+				Target::Hash(_) => vec![find_line(todo_file, target)?],
+				Target::Pattern(_) => find_lines(todo_file, target),
+			};
+			for index in indexes {
+				todo_file.update_range(index, index, &EditContext::new().action(action));
+			}
+		},
+		Command::Move(ref source, position, ref target) => {
+			let source_index = find_line(todo_file, source)?;
+			if source_index == find_line(todo_file, target)? {
+				return Err(format!("Cannot move '{source}' relative to itself"));
+			}
+			let line = todo_file
+				.get_line(source_index)
+				.cloned()
+				.ok_or_else(|| format!("No commit matches '{source}'"))?;
+			todo_file.remove_lines(source_index, source_index);
+			let target_index = find_line(todo_file, target)?;
+			todo_file.add_line(
+				match position {
+					Position::After => target_index + 1,
+					Position::Before => target_index,
+				},
+				line,
+			);
+		},
+		Command::ExecAfterEach(ref exec) => {
+			// like `git rebase --exec`, the command runs after each commit, following any fixups or squashes of it
+			let lines = todo_file.get_lines_owned();
+			let indexes = lines
+				.iter()
+				.enumerate()
+				.filter(|&(index, line)| {
+					// dropped commits and lines such as exec are skipped over, since they do not change the commit
+					let next_line = lines
+						.iter()
+						.skip(index + 1)
+						.find(|next| creates_commit(next) || *next.get_action() == Action::Reset);
+					creates_commit(line)
+						&& !next_line.is_some_and(|next| matches!(*next.get_action(), Action::Fixup | Action::Squash))
+				})
+				.map(|(index, _)| index)
+				.collect::<Vec<usize>>();
+			for index in indexes.into_iter().rev() {
+				todo_file.add_line(index + 1, Line::new_exec(exec.as_str()));
+			}
+		},
+	}
+	Ok(())
+}
+
+fn find_lines(todo_file: &TodoFile, target: &Target) -> Vec<usize> {
+	todo_file
+		.lines_iter()
+		.enumerate()
+		.filter(|&(_, line)| target.matches(line))
+		.map(|(index, _)| index)
+		.collect()
+}
+
+fn find_line(todo_file: &TodoFile, target: &Target) -> Result<usize, String> {
+	match *find_lines(todo_file, target).as_slice() {
+		[index] => Ok(index),
+		[] => Err(format!("No commit matches '{target}'")),
+		_ => Err(format!("More than one commit matches '{target}'")),
+	}
+}
@@ -0,0 +1,309 @@
+use std::{
+	ffi::OsString,
+	fs::{read_to_string, write},
+};
+
+use rstest::rstest;
+use tempfile::NamedTempFile;
+
+use super::*;
+use crate::testutil::{module_test, set_git_directory};
+
+fn assert_script(lines: &[&str], script: &str, expected: &[&str]) {
+	module_test(lines, &[], |mut test_context| {
+		let mut todo_file = test_context.take_todo_file();
+		apply_script(&mut todo_file, script).unwrap();
+		let actual = todo_file.lines_iter().map(Line::to_text).collect::<Vec<String>>();
+		assert_eq!(actual, expected);
+	});
+}
+
+fn assert_script_error(lines: &[&str], script: &str, expected: &str) {
+	module_test(lines, &[], |mut test_context| {
+		let mut todo_file = test_context.take_todo_file();
+		assert_eq!(apply_script(&mut todo_file, script).unwrap_err(), expected);
+	});
+}
+
+fn args(args: &[&str]) -> Args {
+	Args::try_from(args.iter().map(OsString::from).collect::<Vec<OsString>>()).unwrap()
+}
+
+#[rstest]
+#[case::pick("pick bbb", &["drop aaa c1", "pick bbb c2"])]
+#[case::reword("reword bbb", &["drop aaa c1", "reword bbb c2"])]
+#[case::edit("edit bbb", &["drop aaa c1", "edit bbb c2"])]
+#[case::squash("squash bbb", &["drop aaa c1", "squash bbb c2"])]
+#[case::fixup("fixup bbb", &["drop aaa c1", "fixup bbb c2"])]
+#[case::drop("drop bbb", &["drop aaa c1", "drop bbb c2"])]
+fn set_action(#[case] script: &str, #[case] expected: &[&str]) {
+	assert_script(&["drop aaa c1", "pick bbb c2"], script, expected);
+}
+
+#[test]
+fn set_action_abbreviated_hash() {
+	assert_script(&["pick aaaaaaa c1", "pick bbbbbbb c2"], "fixup BBB", &[
+		"pick aaaaaaa c1",
+		"fixup bbbbbbb c2",
+	]);
+}
+
+#[test]
+fn set_action_full_hash() {
+	assert_script(&["pick aaaaaaa c1", "pick bbbbbbb c2"], "fixup bbbbbbb0123456789", &[
+		"pick aaaaaaa c1",
+		"fixup bbbbbbb c2",
+	]);
+}
+
+#[test]
+fn set_action_pattern() {
+	assert_script(
+		&["pick aaa keep", "pick bbb WIP: one", "exec WIP", "pick ccc WIP: two"],
+		"drop /^WIP:/",
+		&["pick aaa keep", "drop bbb WIP: one", "exec WIP", "drop ccc WIP: two"],
+	);
+}
+
+#[test]
+fn set_action_pattern_no_match() {
+	assert_script(&["pick aaa c1"], "drop /nothing/", &["pick aaa c1"]);
+}
+
+#[test]
+fn set_action_missing_commit() {
+	assert_script_error(&["pick aaa c1"], "fixup bbb", "Script line 1: No commit matches 'bbb'");
+}
+
+#[test]
+fn set_action_ambiguous_commit() {
+	assert_script_error(
+		&["pick aaa1 c1", "pick aaa2 c2"],
+		"fixup aaa",
+		"Script line 1: More than one commit matches 'aaa'",
+	);
+}
+
+#[test]
+fn set_action_invalid_pattern() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		let mut todo_file = test_context.take_todo_file();
+		assert!(apply_script(&mut todo_file, "drop /(/")
+			.unwrap_err()
+			.starts_with("Script line 1: Invalid pattern '('"));
+	});
+}
+
+#[test]
+fn move_after() {
+	assert_script(&["pick aaa c1", "pick bbb c2", "pick ccc c3"], "move aaa after ccc", &[
+		"pick bbb c2",
+		"pick ccc c3",
+		"pick aaa c1",
+	]);
+}
+
+#[test]
+fn move_before() {
+	assert_script(&["pick aaa c1", "pick bbb c2", "pick ccc c3"], "move ccc before aaa", &[
+		"pick ccc c3",
+		"pick aaa c1",
+		"pick bbb c2",
+	]);
+}
+
+#[test]
+fn move_self() {
+	assert_script_error(
+		&["pick aaa c1", "pick bbb c2"],
+		"move aaa after aaa",
+		"Script line 1: Cannot move 'aaa' relative to itself",
+	);
+}
+
+#[test]
+fn move_missing_target() {
+	assert_script_error(
+		&["pick aaa c1", "pick bbb c2"],
+		"move aaa after ccc",
+		"Script line 1: No commit matches 'ccc'",
+	);
+}
+
+#[rstest]
+#[case::missing_target("move aaa after")]
+#[case::extra_argument("move aaa after bbb ccc")]
+fn move_invalid(#[case] script: &str) {
+	assert_script_error(
+		&["pick aaa c1", "pick bbb c2"],
+		script,
+		"Script line 1: Expected 'move <hash> after <hash>' or 'move <hash> before <hash>'",
+	);
+}
+
+#[test]
+fn move_invalid_position() {
+	assert_script_error(
+		&["pick aaa c1", "pick bbb c2"],
+		"move aaa under bbb",
+		"Script line 1: Invalid move position 'under', expected 'after' or 'before'",
+	);
+}
+
+#[test]
+fn exec_after_each() {
+	assert_script(
+		&[
+			"pick aaa c1",
+			"fixup bbb c2",
+			"drop ccc c3",
+			"exec echo foo",
+			"pick ddd c4",
+		],
+		"exec-after-each cargo test --workspace",
+		&[
+			"pick aaa c1",
+			"fixup bbb c2",
+			"exec cargo test --workspace",
+			"drop ccc c3",
+			"exec echo foo",
+			"pick ddd c4",
+			"exec cargo test --workspace",
+		],
+	);
+}
+
+#[test]
+fn exec_after_each_fixup_after_drop() {
+	assert_script(
+		&["pick aaa c1", "drop bbb c2", "fixup ccc c3"],
+		"exec-after-each make",
+		&["pick aaa c1", "drop bbb c2", "fixup ccc c3", "exec make"],
+	);
+}
+
+#[test]
+fn exec_after_each_merge() {
+	assert_script(
+		&[
+			"pick aaa c1",
+			"label feature",
+			"reset onto",
+			"merge -C bbb feature",
+			"pick ccc c3",
+		],
+		"exec-after-each make",
+		&[
+			"pick aaa c1",
+			"exec make",
+			"label feature",
+			"reset onto",
+			"merge -C bbb feature",
+			"exec make",
+			"pick ccc c3",
+			"exec make",
+		],
+	);
+}
+
+#[test]
+fn exec_after_each_missing_command() {
+	assert_script_error(
+		&["pick aaa c1"],
+		"exec-after-each",
+		"Script line 1: Missing command for 'exec-after-each'",
+	);
+}
+
+#[test]
+fn unknown_command() {
+	assert_script_error(&["pick aaa c1"], "\nsquish aaa", "Script line 2: Unknown command 'squish'");
+}
+
+#[test]
+fn multiple_commands_with_comments() {
+	assert_script(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+		"# combine the fix\n\nmove ccc after aaa\n  fixup ccc  \n",
+		&["pick aaa c1", "fixup ccc c3", "pick bbb c2"],
+	);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_success() {
+	_ = set_git_directory("fixtures/simple");
+	let todo_file = NamedTempFile::new().unwrap();
+	write(todo_file.path(), "pick aaa c1\npick bbb c2\n").unwrap();
+	let script = NamedTempFile::new().unwrap();
+	write(script.path(), "fixup bbb\n").unwrap();
+
+	let exit = run(&args(&[
+		"--apply",
+		script.path().to_str().unwrap(),
+		todo_file.path().to_str().unwrap(),
+	]));
+	assert_eq!(exit.get_status(), &ExitStatus::Good);
+	assert_eq!(read_to_string(todo_file.path()).unwrap(), "pick aaa c1\nfixup bbb c2\n");
+}
+
+#[test]
+#[serial_test::serial]
+fn run_missing_todo_file_path() {
+	_ = set_git_directory("fixtures/simple");
+	let exit = run(&args(&["--apply", "script"]));
+	assert_eq!(exit.get_status(), &ExitStatus::StateError);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_missing_script() {
+	_ = set_git_directory("fixtures/simple");
+	let exit = run(&args(&["--apply", "does-not-exist", "rebase-todo"]));
+	assert_eq!(exit.get_status(), &ExitStatus::FileReadError);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_script_error() {
+	_ = set_git_directory("fixtures/simple");
+	let todo_file = NamedTempFile::new().unwrap();
+	write(todo_file.path(), "pick aaa c1\n").unwrap();
+	let script = NamedTempFile::new().unwrap();
+	write(script.path(), "fixup zzz\n").unwrap();
+
+	let exit = run(&args(&[
+		"--apply",
+		script.path().to_str().unwrap(),
+		todo_file.path().to_str().unwrap(),
+	]));
+	assert_eq!(exit.get_status(), &ExitStatus::StateError);
+	assert_eq!(
+		exit.get_message().as_deref(),
+		Some("Script line 1: No commit matches 'zzz'")
+	);
+	assert_eq!(read_to_string(todo_file.path()).unwrap(), "pick aaa c1\n");
+}
+
+#[test]
+#[serial_test::serial]
+fn run_invalid_result() {
+	_ = set_git_directory("fixtures/simple");
+	let todo_file = NamedTempFile::new().unwrap();
+	write(todo_file.path(), "pick aaa c1\npick bbb c2\n").unwrap();
+	let script = NamedTempFile::new().unwrap();
+	write(script.path(), "fixup aaa\n").unwrap();
+
+	let exit = run(&args(&[
+		"--apply",
+		script.path().to_str().unwrap(),
+		todo_file.path().to_str().unwrap(),
+	]));
+	assert_eq!(exit.get_status(), &ExitStatus::StateError);
+	assert!(exit
+		.get_message()
+		.as_deref()
+		.unwrap()
+		.starts_with("The todo file is not valid after applying the script:\nLine 1: "));
+	assert_eq!(read_to_string(todo_file.path()).unwrap(), "pick aaa c1\npick bbb c2\n");
+}
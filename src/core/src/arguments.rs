@@ -6,8 +6,11 @@ use crate::{exit::Exit, module::ExitStatus};
 
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) enum Mode {
+	Apply,
 	Editor,
+	Export,
 	Help,
+	Import,
 	Version,
 	License,
 }
@@ -15,6 +18,9 @@ pub(crate) enum Mode {
 #[derive(Debug)]
 pub(crate) struct Args {
 	mode: Mode,
+	script_path: Option<String>,
+	json_path: Option<String>,
+	with_commits: bool,
 	todo_file_path: Option<String>,
 }
 
@@ -23,6 +29,18 @@ impl Args {
 		&self.mode
 	}
 
+	pub(crate) const fn script_path(&self) -> &Option<String> {
+		&self.script_path
+	}
+
+	pub(crate) const fn json_path(&self) -> &Option<String> {
+		&self.json_path
+	}
+
+	pub(crate) const fn with_commits(&self) -> bool {
+		self.with_commits
+	}
+
 	pub(crate) const fn todo_file_path(&self) -> &Option<String> {
 		&self.todo_file_path
 	}
@@ -34,6 +52,28 @@ impl TryFrom<Vec<OsString>> for Args {
 	fn try_from(args: Vec<OsString>) -> Result<Self, Self::Error> {
 		let mut pargs = Arguments::from_vec(args);
 
+		let script_path: Option<String> = pargs
+			.opt_value_from_str("--apply")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let export_path: Option<String> = pargs
+			.opt_value_from_str("--export")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let import_path: Option<String> = pargs
+			.opt_value_from_str("--import")
+			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
+		let with_commits = pargs.contains("--with-commits");
+
+		if [script_path.is_some(), export_path.is_some(), import_path.is_some()]
+			.iter()
+			.filter(|&&provided| provided)
+			.count() > 1
+		{
+			return Err(Exit::new(
+				ExitStatus::StateError,
+				"Only one of --apply, --export and --import can be used",
+			));
+		}
+
 		let mode = if pargs.contains(["-h", "--help"]) {
 			Mode::Help
 		}
@@ -43,6 +83,15 @@ impl TryFrom<Vec<OsString>> for Args {
 		else if pargs.contains("--license") {
 			Mode::License
 		}
+		else if script_path.is_some() {
+			Mode::Apply
+		}
+		else if export_path.is_some() {
+			Mode::Export
+		}
+		else if import_path.is_some() {
+			Mode::Import
+		}
 		else {
 			Mode::Editor
 		};
@@ -51,7 +100,13 @@ impl TryFrom<Vec<OsString>> for Args {
 			.opt_free_from_str()
 			.map_err(|err| Exit::new(ExitStatus::StateError, err.to_string().as_str()))?;
 
-		Ok(Self { mode, todo_file_path })
+		Ok(Self {
+			mode,
+			script_path,
+			json_path: export_path.or(import_path),
+			with_commits,
+			todo_file_path,
+		})
 	}
 }
 
@@ -86,6 +141,58 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn mode_apply() {
+		let args = Args::try_from(create_args(&["--apply", "script", "todofile"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Apply);
+		assert_eq!(args.script_path(), &Some(String::from("script")));
+		assert_eq!(args.todo_file_path(), &Some(String::from("todofile")));
+	}
+
+	#[test]
+	fn mode_apply_missing_script() {
+		_ = Args::try_from(create_args(&["--apply"])).unwrap_err();
+	}
+
+	#[test]
+	fn mode_export() {
+		let args = Args::try_from(create_args(&["--export", "todo.json", "todofile"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Export);
+		assert_eq!(args.json_path(), &Some(String::from("todo.json")));
+		assert!(!args.with_commits());
+		assert_eq!(args.todo_file_path(), &Some(String::from("todofile")));
+	}
+
+	#[test]
+	fn mode_export_with_commits() {
+		let args = Args::try_from(create_args(&["--export", "todo.json", "--with-commits", "todofile"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Export);
+		assert!(args.with_commits());
+		assert_eq!(args.todo_file_path(), &Some(String::from("todofile")));
+	}
+
+	#[test]
+	fn mode_import() {
+		let args = Args::try_from(create_args(&["--import", "todo.json", "todofile"])).unwrap();
+		assert_eq!(args.mode(), &Mode::Import);
+		assert_eq!(args.json_path(), &Some(String::from("todo.json")));
+		assert_eq!(args.todo_file_path(), &Some(String::from("todofile")));
+	}
+
+	#[test]
+	fn mode_export_and_import() {
+		let exit = Args::try_from(create_args(&["--export", "a.json", "--import", "b.json"])).unwrap_err();
+		assert_eq!(exit.get_status(), &ExitStatus::StateError);
+	}
+
+	#[test]
+	fn mode_help_with_apply() {
+		assert_eq!(
+			Args::try_from(create_args(&["--help", "--apply", "script"])).unwrap().mode(),
+			&Mode::Help
+		);
+	}
+
 	#[test]
 	fn todo_file_ok() {
 		let args = Args::try_from(create_args(&["todofile"])).unwrap();
@@ -0,0 +1,93 @@
+// shared loading and writing of the todo file, for the modes that change the todo file without the interface
+
+use config::Config;
+use git::Repository;
+use todo_file::TodoFile;
+
+use crate::{exit::Exit, module::ExitStatus, modules::validate_todo_file};
+
+pub(crate) fn load_todo_file(filepath: &str, load_lines: bool) -> Result<(Repository, TodoFile), Exit> {
+	let repository = Repository::open_from_env().map_err(|err| {
+		Exit::new(
+			ExitStatus::StateError,
+			format!("Unable to load Git repository: {err}").as_str(),
+		)
+	})?;
+	let config =
+		Config::try_from(&repository).map_err(|err| Exit::new(ExitStatus::ConfigError, format!("{err:#}").as_str()))?;
+	let mut todo_file = TodoFile::new(filepath, config.undo_limit, config.git.comment_char.as_str());
+	if load_lines {
+		todo_file
+			.load_file()
+			.map_err(|err| Exit::new(ExitStatus::FileReadError, err.to_string().as_str()))?;
+	}
+	Ok((repository, todo_file))
+}
+
+// the todo file is only written when it is valid, otherwise the issues are reported after `invalid_message`
+pub(crate) fn validate_and_write_todo_file(
+	repository: &Repository,
+	todo_file: &TodoFile,
+	invalid_message: &str,
+) -> Result<(), Exit> {
+	let issues = validate_todo_file(repository, todo_file);
+	if !issues.is_empty() {
+		let messages = issues
+			.iter()
+			.map(|&(index, ref issue)| format!("Line {}: {issue}", index + 1))
+			.collect::<Vec<String>>()
+			.join("\n");
+		return Err(Exit::new(
+			ExitStatus::StateError,
+			format!("{invalid_message}:\n{messages}").as_str(),
+		));
+	}
+
+	todo_file
+		.write_file()
+		.map_err(|err| Exit::new(ExitStatus::FileWriteError, err.to_string().as_str()))
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs::read_to_string;
+
+	use git::testutil::{head_id, with_temp_repository};
+	use tempfile::NamedTempFile;
+	use todo_file::Line;
+
+	use super::*;
+
+	#[test]
+	fn validate_and_write_todo_file_label_resolved_in_repository() {
+		with_temp_repository(|repository| {
+			let hash = head_id(&repository, "main").to_string();
+			let file = NamedTempFile::new().unwrap();
+			let mut todo_file = TodoFile::new(file.path().to_str().unwrap(), 1, "#");
+			todo_file.set_lines(vec![
+				Line::new(format!("reset {hash}").as_str()).unwrap(),
+				Line::new(format!("merge -C {hash} {hash}").as_str()).unwrap(),
+			]);
+			assert!(validate_and_write_todo_file(&repository, &todo_file, "Invalid").is_ok());
+			assert_eq!(
+				read_to_string(file.path()).unwrap(),
+				format!("reset {hash}\nmerge -C {hash} {hash}\n")
+			);
+		});
+	}
+
+	#[test]
+	fn validate_and_write_todo_file_undefined_label() {
+		with_temp_repository(|repository| {
+			let file = NamedTempFile::new().unwrap();
+			let mut todo_file = TodoFile::new(file.path().to_str().unwrap(), 1, "#");
+			todo_file.set_lines(vec![Line::new("reset missing").unwrap()]);
+			let exit = validate_and_write_todo_file(&repository, &todo_file, "Invalid").unwrap_err();
+			assert_eq!(exit.get_status(), &ExitStatus::StateError);
+			assert_eq!(
+				exit.get_message().as_deref(),
+				Some("Invalid:\nLine 1: The label `missing` is not defined")
+			);
+		});
+	}
+}
@@ -81,9 +81,9 @@ where T: Clone
 		&self.view_data
 	}
 
-	pub(crate) fn handle_event(&mut self, event: Event, view_state: &view::State) -> Option<&T> {
+	pub(crate) fn handle_event(&mut self, event: &Event, view_state: &view::State) -> Option<&T> {
 		if handle_view_data_scroll(event, view_state).is_none() {
-			if let Event::Key(key_event) = event {
+			if let Event::Key(key_event) = *event {
 				if let KeyCode::Char(c) = key_event.code {
 					if let Some(v) = self.map.get(&c) {
 						self.invalid_selection = false;
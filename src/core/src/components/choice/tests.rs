@@ -57,7 +57,7 @@ fn render_options_prompt() {
 fn valid_selection() {
 	with_view_state(|context| {
 		let mut module = Choice::new(create_choices());
-		let choice = module.handle_event(Event::from('b'), &context.state);
+		let choice = module.handle_event(&Event::from('b'), &context.state);
 		assert_eq!(choice.unwrap(), &TestAction::B);
 		assert_rendered_output!(
 			module.get_view_data(),
@@ -76,7 +76,7 @@ fn valid_selection() {
 fn invalid_selection_character() {
 	with_view_state(|context| {
 		let mut module = Choice::new(create_choices());
-		let choice = module.handle_event(Event::from('z'), &context.state);
+		let choice = module.handle_event(&Event::from('z'), &context.state);
 		assert!(choice.is_none());
 		assert_rendered_output!(
 			module.get_view_data(),
@@ -102,7 +102,7 @@ fn invalid_selection_character() {
 fn event_standard(#[case] event: Event) {
 	with_view_state(|context| {
 		let mut module = Choice::new(create_choices());
-		_ = module.handle_event(event, &context.state);
+		_ = module.handle_event(&event, &context.state);
 		assert!(!module.invalid_selection);
 	});
 }
@@ -2,7 +2,6 @@ mod confirmed;
 #[cfg(test)]
 mod tests;
 
-use captur::capture;
 pub(crate) use confirmed::Confirmed;
 use input::{InputOptions, KeyCode, KeyEvent, KeyModifiers};
 use lazy_static::lazy_static;
@@ -15,22 +14,26 @@ lazy_static! {
 }
 
 pub(crate) struct Confirm {
+	prompt: String,
 	view_data: ViewData,
 }
 
 impl Confirm {
 	pub(crate) fn new(prompt: &str, confirm_yes: &[String], confirm_no: &[String]) -> Self {
+		let confirm_prompt = format!("{prompt} ({}/{})? ", confirm_yes.join(","), confirm_no.join(","));
 		let view_data = ViewData::new(|updater| {
-			capture!(confirm_yes, confirm_no);
 			updater.set_show_title(true);
 			updater.set_retain_scroll_position(false);
-			updater.push_line(ViewLine::from(format!(
-				"{prompt} ({}/{})? ",
-				confirm_yes.join(","),
-				confirm_no.join(",")
-			)));
+			updater.push_line(ViewLine::from(confirm_prompt.as_str()));
 		});
-		Self { view_data }
+		Self {
+			prompt: confirm_prompt,
+			view_data,
+		}
+	}
+
+	pub(crate) fn get_prompt(&self) -> &str {
+		self.prompt.as_str()
 	}
 
 	pub(crate) fn get_view_data(&mut self) -> &ViewData {
@@ -61,8 +64,8 @@ impl Confirm {
 	}
 
 	#[allow(clippy::unused_self)]
-	pub(crate) const fn handle_event(&self, event: Event) -> Confirmed {
-		if let Event::MetaEvent(meta_event) = event {
+	pub(crate) const fn handle_event(&self, event: &Event) -> Confirmed {
+		if let Event::MetaEvent(meta_event) = *event {
 			match meta_event {
 				MetaEvent::Yes => Confirmed::Yes,
 				MetaEvent::No => Confirmed::No,
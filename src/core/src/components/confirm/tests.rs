@@ -71,14 +71,14 @@ fn read_event_not_char_event() {
 #[test]
 fn handle_event_yes() {
 	let module = Confirm::new("Prompt message", &[], &[]);
-	let confirmed = module.handle_event(Event::from(MetaEvent::Yes));
+	let confirmed = module.handle_event(&Event::from(MetaEvent::Yes));
 	assert_eq!(confirmed, Confirmed::Yes);
 }
 
 #[test]
 fn handle_event_no() {
 	let module = Confirm::new("Prompt message", &[], &[]);
-	let confirmed = module.handle_event(Event::from(MetaEvent::No));
+	let confirmed = module.handle_event(&Event::from(MetaEvent::No));
 	assert_eq!(confirmed, Confirmed::No);
 }
 
@@ -92,6 +92,6 @@ fn handle_event_no() {
 #[case::scroll_jump_up(Event::from(StandardEvent::ScrollJumpUp))]
 fn input_standard(#[case] event: Event) {
 	let module = Confirm::new("Prompt message", &[], &[]);
-	let confirmed = module.handle_event(event);
+	let confirmed = module.handle_event(&event);
 	assert_eq!(confirmed, Confirmed::Other);
 }
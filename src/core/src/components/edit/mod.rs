@@ -59,8 +59,8 @@ impl Edit {
 		self.build_view_data(|_| {}, |_| {})
 	}
 
-	pub(crate) fn handle_event(&mut self, event: Event) {
-		if event == FINISH_EVENT {
+	pub(crate) fn handle_event(&mut self, event: &Event) {
+		if *event == FINISH_EVENT {
 			self.finished = true;
 		}
 		else {
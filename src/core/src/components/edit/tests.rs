@@ -31,7 +31,7 @@ fn with_before_and_after_build() {
 fn edit_event() {
 	let mut module = Edit::new();
 	module.set_content("foobar");
-	module.handle_event(Event::from(KeyCode::Left));
+	module.handle_event(&Event::from(KeyCode::Left));
 	let view_data = module.get_view_data();
 
 	assert_rendered_output!(
@@ -49,7 +49,7 @@ fn edit_event() {
 fn finish_event() {
 	let mut module = Edit::new();
 	module.set_content("foobar");
-	module.handle_event(Event::from(KeyCode::Enter));
+	module.handle_event(&Event::from(KeyCode::Enter));
 	assert!(module.is_finished());
 }
 
@@ -84,9 +84,9 @@ impl Help {
 		(self.is_active() || event == Event::Standard(StandardEvent::Help)).then_some(event)
 	}
 
-	pub(crate) fn handle_event(&mut self, event: Event, view_state: &view::State) {
+	pub(crate) fn handle_event(&mut self, event: &Event, view_state: &view::State) {
 		let mut event_handler = || {
-			match event {
+			match *event {
 				Event::Key(_) | Event::Standard(StandardEvent::Help) => self.active = false,
 				_ => {},
 			}
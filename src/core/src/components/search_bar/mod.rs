@@ -56,18 +56,18 @@ impl SearchBar {
 		}
 	}
 
-	pub(crate) const fn read_event(&self, event: Event) -> Option<Event> {
+	pub(crate) fn read_event(&self, event: Event) -> Option<Event> {
 		match self.state {
 			State::Deactivated | State::Searching => None,
 			State::Editing => Some(event),
 		}
 	}
 
-	pub(crate) fn handle_event(&mut self, event: Event) -> SearchBarAction {
+	pub(crate) fn handle_event(&mut self, event: &Event) -> SearchBarAction {
 		if !self.state.is_active() {
 			return SearchBarAction::None;
 		}
-		match event {
+		match *event {
 			Event::Standard(StandardEvent::SearchNext) => {
 				SearchBarAction::Next(String::from(self.editable_line.get_content()))
 			},
@@ -101,7 +101,7 @@ fn read_event_editing() {
 fn handle_event_inactive() {
 	let mut search_bar = SearchBar::new();
 	search_bar.state = State::Deactivated;
-	assert_eq!(search_bar.handle_event(Event::from('a')), SearchBarAction::None);
+	assert_eq!(search_bar.handle_event(&Event::from('a')), SearchBarAction::None);
 }
 
 #[test]
@@ -110,7 +110,7 @@ fn handle_event_search_next() {
 	search_bar.start_search(Some("foo"));
 	let event = Event::from(StandardEvent::SearchNext);
 	assert_eq!(
-		search_bar.handle_event(event),
+		search_bar.handle_event(&event),
 		SearchBarAction::Next(String::from("foo"))
 	);
 }
@@ -121,7 +121,7 @@ fn handle_event_search_previous() {
 	search_bar.start_search(Some("foo"));
 	let event = Event::from(StandardEvent::SearchPrevious);
 	assert_eq!(
-		search_bar.handle_event(event),
+		search_bar.handle_event(&event),
 		SearchBarAction::Previous(String::from("foo"))
 	);
 }
@@ -132,7 +132,7 @@ fn handle_event_search_finish() {
 	search_bar.start_search(Some("foo"));
 	let event = Event::from(StandardEvent::SearchFinish);
 	assert_eq!(
-		search_bar.handle_event(event),
+		search_bar.handle_event(&event),
 		SearchBarAction::Start(String::from("foo"))
 	);
 	assert_eq!(search_bar.state, State::Searching);
@@ -145,7 +145,7 @@ fn handle_event_search_finish_with_enter() {
 	search_bar.start_search(Some("foo"));
 	let event = Event::from(KeyCode::Enter);
 	assert_eq!(
-		search_bar.handle_event(event),
+		search_bar.handle_event(&event),
 		SearchBarAction::Start(String::from("foo"))
 	);
 }
@@ -155,7 +155,7 @@ fn handle_event_search_cancel_with_esc() {
 	let mut search_bar = SearchBar::new();
 	search_bar.start_search(Some("foo"));
 	let event = Event::from(KeyCode::Esc);
-	assert_eq!(search_bar.handle_event(event), SearchBarAction::Cancel);
+	assert_eq!(search_bar.handle_event(&event), SearchBarAction::Cancel);
 }
 
 #[test]
@@ -163,7 +163,7 @@ fn handle_event_search_start_active() {
 	let mut search_bar = SearchBar::new();
 	search_bar.start_search(Some("foo"));
 	let event = Event::from(StandardEvent::SearchStart);
-	assert_eq!(search_bar.handle_event(event), SearchBarAction::Cancel);
+	assert_eq!(search_bar.handle_event(&event), SearchBarAction::Cancel);
 	assert_eq!(search_bar.state, State::Deactivated);
 }
 
@@ -172,7 +172,7 @@ fn handle_event_other_active_without_change() {
 	let mut search_bar = SearchBar::new();
 	search_bar.start_search(Some("foo"));
 	let event = Event::from(KeyCode::Null);
-	assert_eq!(search_bar.handle_event(event), SearchBarAction::None);
+	assert_eq!(search_bar.handle_event(&event), SearchBarAction::None);
 	assert_rendered_output!(
 		&create_view_data(&search_bar),
 		"{BODY}",
@@ -186,7 +186,7 @@ fn handle_event_other_active_with_change() {
 	search_bar.start_search(Some("foo"));
 	let event = Event::from('a');
 	assert_eq!(
-		search_bar.handle_event(event),
+		search_bar.handle_event(&event),
 		SearchBarAction::Update(String::from("fooa"))
 	);
 	assert_rendered_output!(
@@ -202,7 +202,7 @@ fn handle_event_other_inactive() {
 	search_bar.start_search(Some("foo"));
 	search_bar.state = State::Deactivated;
 	let event = Event::from('a');
-	assert_eq!(search_bar.handle_event(event), SearchBarAction::None);
+	assert_eq!(search_bar.handle_event(&event), SearchBarAction::None);
 	assert_rendered_output!(
 		&create_view_data(&search_bar),
 		"{BODY}",
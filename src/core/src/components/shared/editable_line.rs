@@ -91,11 +91,11 @@ impl EditableLine {
 		segments
 	}
 
-	pub(crate) fn handle_event(&mut self, event: Event) -> EditAction {
+	pub(crate) fn handle_event(&mut self, event: &Event) -> EditAction {
 		if self.read_only {
 			return EditAction::None;
 		}
-		match event {
+		match *event {
 			Event::Key(KeyEvent {
 				code: KeyCode::Backspace,
 				modifiers: KeyModifiers::NONE,
@@ -198,6 +198,28 @@ impl EditableLine {
 				self.cursor_position += 1;
 				EditAction::ContentUpdate
 			},
+			Event::Paste(ref content) => {
+				// the line cannot contain line breaks, so trailing line breaks are removed, and any others
+				// are replaced with a space
+				let pasted = content
+					.trim_end_matches(['\r', '\n'])
+					.split(['\r', '\n'])
+					.filter(|l| !l.is_empty())
+					.collect::<Vec<&str>>()
+					.join(" ");
+				if pasted.is_empty() {
+					return EditAction::None;
+				}
+				let start = UnicodeSegmentation::graphemes(self.content.as_str(), true)
+					.take(self.cursor_position)
+					.collect::<String>();
+				let end = UnicodeSegmentation::graphemes(self.content.as_str(), true)
+					.skip(self.cursor_position)
+					.collect::<String>();
+				self.content = format!("{start}{pasted}{end}");
+				self.cursor_position += UnicodeSegmentation::graphemes(pasted.as_str(), true).count();
+				EditAction::ContentUpdate
+			},
 			_ => EditAction::None,
 		}
 	}
@@ -218,7 +240,7 @@ mod tests {
 
 	fn handle_events(module: &mut EditableLine, events: &[Event]) {
 		for event in events {
-			_ = module.handle_event(*event);
+			_ = module.handle_event(event);
 		}
 	}
 
@@ -239,7 +261,7 @@ mod tests {
 	fn move_cursor_end() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("foobar");
-		_ = editable_line.handle_event(Event::from(KeyCode::Right));
+		_ = editable_line.handle_event(&Event::from(KeyCode::Right));
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -252,7 +274,7 @@ mod tests {
 	fn move_cursor_1_left() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("foobar");
-		_ = editable_line.handle_event(Event::from(KeyCode::Left));
+		_ = editable_line.handle_event(&Event::from(KeyCode::Left));
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -265,7 +287,7 @@ mod tests {
 	fn move_cursor_2_from_start() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("foobar");
-		handle_events(&mut editable_line, &[Event::from(KeyCode::Left); 2]);
+		handle_events(&mut editable_line, &vec![Event::from(KeyCode::Left); 2]);
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -278,7 +300,7 @@ mod tests {
 	fn move_cursor_1_from_start() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("foobar");
-		handle_events(&mut editable_line, &[Event::from(KeyCode::Left); 5]);
+		handle_events(&mut editable_line, &vec![Event::from(KeyCode::Left); 5]);
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -291,7 +313,7 @@ mod tests {
 	fn move_cursor_to_start() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("foobar");
-		handle_events(&mut editable_line, &[Event::from(KeyCode::Left); 6]);
+		handle_events(&mut editable_line, &vec![Event::from(KeyCode::Left); 6]);
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -304,7 +326,7 @@ mod tests {
 	fn move_cursor_to_home() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("foobar");
-		_ = editable_line.handle_event(Event::from(KeyCode::Home));
+		_ = editable_line.handle_event(&Event::from(KeyCode::Home));
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -370,7 +392,7 @@ mod tests {
 	fn move_cursor_attempt_past_start() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("foobar");
-		handle_events(&mut editable_line, &[Event::from(KeyCode::Left); 10]);
+		handle_events(&mut editable_line, &vec![Event::from(KeyCode::Left); 10]);
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -383,7 +405,7 @@ mod tests {
 	fn move_cursor_attempt_past_end() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("foobar");
-		handle_events(&mut editable_line, &[Event::from(KeyCode::Right); 10]);
+		handle_events(&mut editable_line, &vec![Event::from(KeyCode::Right); 10]);
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -396,7 +418,7 @@ mod tests {
 	fn multiple_width_unicode_single_width() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("a🗳b");
-		handle_events(&mut editable_line, &[Event::from(KeyCode::Left); 2]);
+		handle_events(&mut editable_line, &vec![Event::from(KeyCode::Left); 2]);
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -409,7 +431,7 @@ mod tests {
 	fn multiple_width_unicode_emoji() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("a😀b");
-		handle_events(&mut editable_line, &[Event::from(KeyCode::Left); 2]);
+		handle_events(&mut editable_line, &vec![Event::from(KeyCode::Left); 2]);
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -422,7 +444,7 @@ mod tests {
 	fn add_character_end() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
-		_ = editable_line.handle_event(Event::from('x'));
+		_ = editable_line.handle_event(&Event::from('x'));
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -485,7 +507,7 @@ mod tests {
 	fn add_character_uppercase() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
-		_ = editable_line.handle_event(Event::from(KeyCode::Char('X')));
+		_ = editable_line.handle_event(&Event::from(KeyCode::Char('X')));
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -498,7 +520,7 @@ mod tests {
 	fn backspace_at_end() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
-		_ = editable_line.handle_event(Event::from(KeyCode::Backspace));
+		_ = editable_line.handle_event(&Event::from(KeyCode::Backspace));
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -564,7 +586,7 @@ mod tests {
 	fn delete_at_end() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
-		_ = editable_line.handle_event(Event::from(KeyCode::Delete));
+		_ = editable_line.handle_event(&Event::from(KeyCode::Delete));
 		assert_rendered_output!(
 			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
 			view_data_from_editable_line!(&editable_line),
@@ -629,7 +651,7 @@ mod tests {
 	#[test]
 	fn ignore_other_input() {
 		let mut editable_line = EditableLine::new();
-		_ = editable_line.handle_event(Event::from(KeyCode::Null));
+		_ = editable_line.handle_event(&Event::from(KeyCode::Null));
 	}
 
 	#[test]
@@ -637,7 +659,7 @@ mod tests {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
 		editable_line.set_read_only(true);
-		_ = editable_line.handle_event(Event::from(KeyCode::Home));
+		_ = editable_line.handle_event(&Event::from(KeyCode::Home));
 		assert_eq!(editable_line.cursor_position(), 4);
 	}
 
@@ -671,7 +693,7 @@ mod tests {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
 		assert_eq!(
-			editable_line.handle_event(Event::from(KeyCode::Backspace)),
+			editable_line.handle_event(&Event::from(KeyCode::Backspace)),
 			EditAction::ContentUpdate
 		);
 	}
@@ -681,7 +703,7 @@ mod tests {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("");
 		assert_eq!(
-			editable_line.handle_event(Event::from(KeyCode::Backspace)),
+			editable_line.handle_event(&Event::from(KeyCode::Backspace)),
 			EditAction::None
 		);
 	}
@@ -692,7 +714,7 @@ mod tests {
 		editable_line.set_content("abcd");
 		editable_line.cursor_position = 0;
 		assert_eq!(
-			editable_line.handle_event(Event::from(KeyCode::Delete)),
+			editable_line.handle_event(&Event::from(KeyCode::Delete)),
 			EditAction::ContentUpdate
 		);
 	}
@@ -702,7 +724,7 @@ mod tests {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("");
 		assert_eq!(
-			editable_line.handle_event(Event::from(KeyCode::Delete)),
+			editable_line.handle_event(&Event::from(KeyCode::Delete)),
 			EditAction::None
 		);
 	}
@@ -712,7 +734,7 @@ mod tests {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
 		assert_eq!(
-			editable_line.handle_event(Event::from(KeyCode::Home)),
+			editable_line.handle_event(&Event::from(KeyCode::Home)),
 			EditAction::CursorMove
 		);
 	}
@@ -722,7 +744,7 @@ mod tests {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
 		editable_line.cursor_position = 0;
-		assert_eq!(editable_line.handle_event(Event::from(KeyCode::Home)), EditAction::None);
+		assert_eq!(editable_line.handle_event(&Event::from(KeyCode::Home)), EditAction::None);
 	}
 
 	#[test]
@@ -731,7 +753,7 @@ mod tests {
 		editable_line.set_content("abcd");
 		editable_line.cursor_position = 0;
 		assert_eq!(
-			editable_line.handle_event(Event::from(KeyCode::End)),
+			editable_line.handle_event(&Event::from(KeyCode::End)),
 			EditAction::CursorMove
 		);
 	}
@@ -740,7 +762,7 @@ mod tests {
 	fn handle_event_edit_action_end_without_cursor_change() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
-		assert_eq!(editable_line.handle_event(Event::from(KeyCode::End)), EditAction::None);
+		assert_eq!(editable_line.handle_event(&Event::from(KeyCode::End)), EditAction::None);
 	}
 
 	#[test]
@@ -749,7 +771,7 @@ mod tests {
 		editable_line.set_content("abcd");
 		editable_line.cursor_position = 0;
 		assert_eq!(
-			editable_line.handle_event(Event::from(KeyCode::Right)),
+			editable_line.handle_event(&Event::from(KeyCode::Right)),
 			EditAction::CursorMove
 		);
 	}
@@ -759,7 +781,7 @@ mod tests {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
 		assert_eq!(
-			editable_line.handle_event(Event::from(KeyCode::Right)),
+			editable_line.handle_event(&Event::from(KeyCode::Right)),
 			EditAction::None
 		);
 	}
@@ -769,7 +791,7 @@ mod tests {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
 		assert_eq!(
-			editable_line.handle_event(Event::from(KeyCode::Left)),
+			editable_line.handle_event(&Event::from(KeyCode::Left)),
 			EditAction::CursorMove
 		);
 	}
@@ -779,7 +801,7 @@ mod tests {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
 		editable_line.cursor_position = 0;
-		assert_eq!(editable_line.handle_event(Event::from(KeyCode::Left)), EditAction::None);
+		assert_eq!(editable_line.handle_event(&Event::from(KeyCode::Left)), EditAction::None);
 	}
 
 	#[test]
@@ -787,7 +809,7 @@ mod tests {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
 		assert_eq!(
-			editable_line.handle_event(Event::from(KeyCode::Char('a'))),
+			editable_line.handle_event(&Event::from(KeyCode::Char('a'))),
 			EditAction::ContentUpdate
 		);
 	}
@@ -796,6 +818,77 @@ mod tests {
 	fn handle_event_edit_action_other() {
 		let mut editable_line = EditableLine::new();
 		editable_line.set_content("abcd");
-		assert_eq!(editable_line.handle_event(Event::from(KeyCode::Esc)), EditAction::None);
+		assert_eq!(editable_line.handle_event(&Event::from(KeyCode::Esc)), EditAction::None);
+	}
+
+	#[test]
+	fn paste_at_cursor() {
+		let mut editable_line = EditableLine::new();
+		editable_line.set_content("abcd");
+		handle_events(&mut editable_line, &[
+			Event::from(KeyCode::Left),
+			Event::from(KeyCode::Left),
+			Event::Paste(String::from("xyz")),
+		]);
+		assert_rendered_output!(
+			Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
+			view_data_from_editable_line!(&editable_line),
+			"{BODY}",
+			"{Normal}abxyz{Normal,Underline}c{Normal}d"
+		);
+	}
+
+	#[test]
+	fn paste_with_trailing_line_break() {
+		let mut editable_line = EditableLine::new();
+		editable_line.set_content("abcd");
+		_ = editable_line.handle_event(&Event::Paste(String::from("xyz\r\n")));
+		assert_eq!(editable_line.get_content(), "abcdxyz");
+		assert_eq!(editable_line.cursor_position(), 7);
+	}
+
+	#[test]
+	fn paste_with_multiple_lines() {
+		let mut editable_line = EditableLine::new();
+		_ = editable_line.handle_event(&Event::Paste(String::from("git fetch\n\ngit rebase\r\ngit log\n")));
+		assert_eq!(editable_line.get_content(), "git fetch git rebase git log");
+	}
+
+	#[test]
+	fn paste_multiple_width_unicode() {
+		let mut editable_line = EditableLine::new();
+		editable_line.set_content("ab");
+		_ = editable_line.handle_event(&Event::Paste(String::from("😀🗳")));
+		assert_eq!(editable_line.cursor_position(), 4);
+	}
+
+	#[test]
+	fn handle_event_edit_action_paste() {
+		let mut editable_line = EditableLine::new();
+		assert_eq!(
+			editable_line.handle_event(&Event::Paste(String::from("abcd"))),
+			EditAction::ContentUpdate
+		);
+	}
+
+	#[test]
+	fn handle_event_edit_action_paste_only_line_breaks() {
+		let mut editable_line = EditableLine::new();
+		editable_line.set_content("abcd");
+		assert_eq!(
+			editable_line.handle_event(&Event::Paste(String::from("\n\r\n"))),
+			EditAction::None
+		);
+		assert_eq!(editable_line.get_content(), "abcd");
+	}
+
+	#[test]
+	fn handle_event_edit_action_paste_read_only() {
+		let mut editable_line = EditableLine::new();
+		editable_line.set_read_only(true);
+		assert_eq!(
+			editable_line.handle_event(&Event::Paste(String::from("abcd"))),
+			EditAction::None
+		);
 	}
 }
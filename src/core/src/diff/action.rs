@@ -0,0 +1,54 @@
+use std::{
+	fmt::{Debug, Formatter},
+	sync::Arc,
+};
+
+use parking_lot::Mutex;
+
+use crate::diff::{Interrupter, LoadedDiff};
+
+#[allow(clippy::exhaustive_enums)]
+pub(crate) enum Action {
+	Continue,
+	End,
+	Load(String, Arc<Mutex<Option<LoadedDiff>>>, Interrupter),
+	LoadRange(String, String, Arc<Mutex<Option<LoadedDiff>>>, Interrupter),
+}
+
+impl Debug for Action {
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::Continue => write!(f, "Continue"),
+			Self::End => write!(f, "End"),
+			Self::Load(ref hash, ..) => write!(f, "Load({hash})"),
+			Self::LoadRange(ref first_hash, ref last_hash, ..) => write!(f, "LoadRange({first_hash}..{last_hash})"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::cont(Action::Continue, "Continue")]
+	#[case::end(Action::End, "End")]
+	#[case::load(
+		Action::Load(String::from("abc"), Arc::new(Mutex::new(None)), Interrupter::new()),
+		"Load(abc)"
+	)]
+	#[case::load_range(
+		Action::LoadRange(
+			String::from("abc"),
+			String::from("def"),
+			Arc::new(Mutex::new(None)),
+			Interrupter::new()
+		),
+		"LoadRange(abc..def)"
+	)]
+	fn debug(#[case] action: Action, #[case] expected: &str) {
+		assert_eq!(format!("{action:?}"), expected);
+	}
+}
@@ -0,0 +1,52 @@
+use std::sync::{
+	atomic::{AtomicBool, Ordering},
+	Arc,
+};
+
+/// A flag shared with the diff thread, that stops the loading of a diff that is no longer needed.
+#[derive(Clone, Debug)]
+pub(crate) struct Interrupter {
+	interrupted: Arc<AtomicBool>,
+}
+
+impl Interrupter {
+	pub(crate) fn new() -> Self {
+		Self {
+			interrupted: Arc::new(AtomicBool::new(false)),
+		}
+	}
+
+	pub(crate) fn interrupt(&self) {
+		self.interrupted.store(true, Ordering::Release);
+	}
+
+	pub(crate) fn should_continue(&self) -> bool {
+		!self.interrupted.load(Ordering::Acquire)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn should_continue_before_interrupt() {
+		let interrupter = Interrupter::new();
+		assert!(interrupter.should_continue());
+	}
+
+	#[test]
+	fn should_continue_after_interrupt() {
+		let interrupter = Interrupter::new();
+		interrupter.interrupt();
+		assert!(!interrupter.should_continue());
+	}
+
+	#[test]
+	fn interrupt_shared_with_clone() {
+		let interrupter = Interrupter::new();
+		let thread_interrupter = interrupter.clone();
+		interrupter.interrupt();
+		assert!(!thread_interrupter.should_continue());
+	}
+}
@@ -0,0 +1,95 @@
+use git::{CommitDiff, FileStatus};
+
+/// The diffs of a commit loaded by the diff thread, or the error from loading them. While the diffs are loading, the
+/// file statuses of the first diff are added as they are loaded.
+#[derive(Debug)]
+pub(crate) struct LoadedDiff {
+	diffs: Option<Result<Vec<CommitDiff>, String>>,
+	file_statuses: Vec<FileStatus>,
+	hash: String,
+}
+
+impl LoadedDiff {
+	pub(crate) const fn new(hash: String, diffs: Result<Vec<CommitDiff>, String>) -> Self {
+		Self {
+			diffs: Some(diffs),
+			file_statuses: vec![],
+			hash,
+		}
+	}
+
+	pub(crate) const fn new_loading(hash: String) -> Self {
+		Self {
+			diffs: None,
+			file_statuses: vec![],
+			hash,
+		}
+	}
+
+	/// The hash that was requested, which may be an abbreviated hash.
+	pub(crate) fn hash(&self) -> &str {
+		self.hash.as_str()
+	}
+
+	/// The loaded diffs, or `None` while the diffs are still loading.
+	pub(crate) const fn diffs(&self) -> Option<&Result<Vec<CommitDiff>, String>> {
+		self.diffs.as_ref()
+	}
+
+	pub(crate) fn into_diffs(self) -> Option<Result<Vec<CommitDiff>, String>> {
+		self.diffs
+	}
+
+	/// The file statuses of the first diff that have been loaded so far.
+	pub(crate) fn file_statuses(&self) -> &[FileStatus] {
+		self.file_statuses.as_slice()
+	}
+
+	pub(crate) fn push_file_status(&mut self, file_status: FileStatus) {
+		self.file_statuses.push(file_status);
+	}
+
+	pub(crate) fn set_diffs(&mut self, diffs: Result<Vec<CommitDiff>, String>) {
+		self.file_statuses.clear();
+		self.diffs = Some(diffs);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use git::testutil::FileStatusBuilder;
+
+	use super::*;
+
+	fn create_file_status(path: &str) -> FileStatus {
+		FileStatusBuilder::new()
+			.source_path(path)
+			.destination_path(path)
+			.build()
+	}
+
+	#[test]
+	fn new() {
+		let loaded_diff = LoadedDiff::new(String::from("abc"), Err(String::from("error")));
+		assert_eq!(loaded_diff.hash(), "abc");
+		assert!(loaded_diff.diffs().is_some());
+	}
+
+	#[test]
+	fn loading() {
+		let mut loaded_diff = LoadedDiff::new_loading(String::from("abc"));
+		assert!(loaded_diff.diffs().is_none());
+		loaded_diff.push_file_status(create_file_status("a"));
+		loaded_diff.push_file_status(create_file_status("b"));
+		assert_eq!(loaded_diff.file_statuses().len(), 2);
+	}
+
+	#[test]
+	fn set_diffs() {
+		let mut loaded_diff = LoadedDiff::new_loading(String::from("abc"));
+		loaded_diff.push_file_status(create_file_status("a"));
+		loaded_diff.set_diffs(Ok(vec![]));
+		assert!(loaded_diff.diffs().is_some());
+		assert!(loaded_diff.file_statuses().is_empty());
+	}
+}
@@ -0,0 +1,31 @@
+mod action;
+mod interrupter;
+mod loaded_diff;
+mod state;
+mod thread;
+mod update_handler;
+
+use config::{Config, DiffIgnoreWhitespaceSetting};
+use git::CommitDiffLoaderOptions;
+
+pub(crate) use self::{
+	action::Action,
+	interrupter::Interrupter,
+	loaded_diff::LoadedDiff,
+	state::State,
+	thread::Thread,
+	update_handler::UpdateHandlerFn,
+};
+
+pub(crate) fn create_commit_diff_loader_options(config: &Config) -> CommitDiffLoaderOptions {
+	CommitDiffLoaderOptions::new()
+		.context_lines(config.git.diff_context)
+		.copies(config.git.diff_copies)
+		.ignore_whitespace(config.diff_ignore_whitespace == DiffIgnoreWhitespaceSetting::All)
+		.ignore_whitespace_change(config.diff_ignore_whitespace == DiffIgnoreWhitespaceSetting::Change)
+		.ignore_blank_lines(config.diff_ignore_blank_lines)
+		.interhunk_context(config.git.diff_interhunk_lines)
+		.line_limits(config.diff_file_line_limit, config.diff_total_line_limit)
+		.collapse_generated(config.diff_collapse_generated)
+		.renames(config.git.diff_renames, config.git.diff_rename_limit)
+}
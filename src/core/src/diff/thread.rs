@@ -0,0 +1,333 @@
+use std::{
+	sync::Arc,
+	thread::sleep,
+	time::{Duration, Instant},
+};
+
+use captur::capture;
+use git::{CommitDiffLoaderOptions, FileStatus, Repository};
+use runtime::{Installer, Threadable};
+
+use crate::diff::{Action, LoadedDiff, State, UpdateHandlerFn};
+
+pub(crate) const THREAD_NAME: &str = "diff";
+const MINIMUM_PAUSE_RATE: Duration = Duration::from_millis(50);
+const PROGRESS_UPDATE_RATE: Duration = Duration::from_millis(100);
+
+#[derive(Debug)]
+pub(crate) struct Thread<UpdateHandler: UpdateHandlerFn> {
+	commit_diff_loader_options: CommitDiffLoaderOptions,
+	diff_update_handler: Arc<UpdateHandler>,
+	repository: Repository,
+	state: State,
+}
+
+impl<UpdateHandler> Threadable for Thread<UpdateHandler>
+where UpdateHandler: UpdateHandlerFn + 'static
+{
+	#[inline]
+	fn install(&self, installer: &Installer) {
+		let state = self.state();
+		let update_handler = Arc::clone(&self.diff_update_handler);
+		let repository = self.repository.clone();
+		let commit_diff_loader_options = self.commit_diff_loader_options;
+
+		installer.spawn(THREAD_NAME, |notifier| {
+			move || {
+				capture!(notifier, state);
+				notifier.wait();
+				let mut time = Instant::now();
+
+				loop {
+					notifier.wait();
+					if state.is_ended() {
+						break;
+					}
+					while state.is_paused() {
+						sleep(time.saturating_duration_since(Instant::now()));
+						time += MINIMUM_PAUSE_RATE;
+					}
+
+					let msg = state.receive_update();
+					notifier.busy();
+					let (hash, range, output, interrupter) = match msg {
+						Action::Load(hash, output, interrupter) => (hash, None, output, interrupter),
+						Action::LoadRange(first_hash, last_hash, output, interrupter) => {
+							(
+								format!("{first_hash}..{last_hash}"),
+								Some((first_hash, last_hash)),
+								output,
+								interrupter,
+							)
+						},
+						Action::Continue => continue,
+						Action::End => break,
+					};
+					// a load that is replaced by a newer load is interrupted by the module that requested it
+					if !interrupter.should_continue() {
+						continue;
+					}
+
+					*output.lock() = Some(LoadedDiff::new_loading(hash.clone()));
+					let mut last_update = Instant::now();
+					let mut progress = |diff_index: usize, file_status: &FileStatus| {
+						if !interrupter.should_continue() {
+							return false;
+						}
+						// only the files of the first diff are shown while loading
+						if diff_index == 0 {
+							if let Some(loaded_diff) = output.lock().as_mut() {
+								loaded_diff.push_file_status(file_status.clone());
+							}
+							if last_update.elapsed() >= PROGRESS_UPDATE_RATE {
+								last_update = Instant::now();
+								update_handler();
+							}
+						}
+						true
+					};
+					let diffs = if let Some((first_hash, last_hash)) = range {
+						repository
+							.load_range_diff_with_progress(
+								first_hash.as_str(),
+								last_hash.as_str(),
+								&commit_diff_loader_options,
+								|file_status| progress(0, file_status),
+							)
+							.map(|diff| vec![diff])
+					}
+					else {
+						repository.load_commit_diffs_with_progress(hash.as_str(), &commit_diff_loader_options, progress)
+					};
+					// an interrupted load is left incomplete, since nothing is waiting on it
+					if !interrupter.should_continue() {
+						continue;
+					}
+					if let Some(loaded_diff) = output.lock().as_mut() {
+						loaded_diff.set_diffs(diffs.map_err(|err| format!("{:#}", anyhow::Error::from(err))));
+					}
+					update_handler();
+				}
+
+				notifier.request_end();
+				notifier.end();
+			}
+		});
+	}
+
+	#[inline]
+	fn pause(&self) {
+		self.state.pause();
+	}
+
+	#[inline]
+	fn resume(&self) {
+		self.state.resume();
+	}
+
+	#[inline]
+	fn end(&self) {
+		self.state.end();
+	}
+}
+
+impl<UpdateHandler> Thread<UpdateHandler>
+where UpdateHandler: UpdateHandlerFn
+{
+	pub(crate) fn new(
+		repository: Repository,
+		commit_diff_loader_options: CommitDiffLoaderOptions,
+		diff_update_handler: UpdateHandler,
+	) -> Self {
+		Self {
+			commit_diff_loader_options,
+			diff_update_handler: Arc::new(diff_update_handler),
+			repository,
+			state: State::new(),
+		}
+	}
+
+	pub(crate) fn state(&self) -> State {
+		self.state.clone()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::atomic::{AtomicUsize, Ordering};
+
+	use git::testutil::with_temp_repository;
+	use parking_lot::Mutex;
+	use runtime::{testutils::ThreadableTester, Status};
+
+	use super::*;
+	use crate::diff::Interrupter;
+
+	fn is_loaded(output: &Arc<Mutex<Option<LoadedDiff>>>) -> bool {
+		output
+			.lock()
+			.as_ref()
+			.is_some_and(|loaded_diff| loaded_diff.diffs().is_some())
+	}
+
+	fn with_thread<C>(callback: C)
+	where C: FnOnce(Thread<Box<dyn Fn() + Send + Sync>>, Arc<AtomicUsize>) {
+		with_temp_repository(|repository| {
+			let update_handler_calls = Arc::new(AtomicUsize::new(0));
+			let update_handler_calls_thread = Arc::clone(&update_handler_calls);
+			let update_handler: Box<dyn Fn() + Send + Sync> = Box::new(move || {
+				_ = update_handler_calls_thread.fetch_add(1, Ordering::Release);
+			});
+			let thread = Thread::new(repository, CommitDiffLoaderOptions::new(), update_handler);
+			callback(thread, update_handler_calls);
+		});
+	}
+
+	#[test]
+	fn set_pause_resume() {
+		with_thread(|thread, _| {
+			let state = thread.state();
+			let tester = ThreadableTester::new();
+			tester.start_threadable(&thread, THREAD_NAME);
+			tester.wait_for_status(&Status::Waiting);
+			thread.pause();
+			assert!(state.is_paused());
+			// give thread time to pause
+			sleep(Duration::from_secs(1));
+			state.send_update(Action::Continue);
+			thread.resume();
+			assert!(!state.is_paused());
+			state.end();
+			tester.wait_for_status(&Status::Ended);
+		});
+	}
+
+	#[test]
+	fn set_end() {
+		with_thread(|thread, _| {
+			let state = thread.state();
+			thread.end();
+			assert!(state.is_ended());
+		});
+	}
+
+	#[test]
+	fn thread_end_from_action() {
+		with_thread(|thread, _| {
+			let state = thread.state();
+			let tester = ThreadableTester::new();
+			tester.start_threadable(&thread, THREAD_NAME);
+			tester.wait_for_status(&Status::Waiting);
+			state.send_update(Action::End);
+			tester.wait_for_status(&Status::Ended);
+		});
+	}
+
+	#[test]
+	fn thread_load() {
+		with_thread(|thread, update_handler_calls| {
+			let state = thread.state();
+			let output = Arc::new(Mutex::new(None));
+			let tester = ThreadableTester::new();
+			tester.start_threadable(&thread, THREAD_NAME);
+			state.send_update(Action::Load(
+				String::from("HEAD"),
+				Arc::clone(&output),
+				Interrupter::new(),
+			));
+			while !is_loaded(&output) {
+				sleep(Duration::from_millis(10));
+			}
+			state.send_update(Action::End);
+			tester.wait_for_status(&Status::Ended);
+
+			// progress updates are rate limited, so only the final update is certain
+			assert!(update_handler_calls.load(Ordering::Acquire) >= 1);
+			let loaded_diff = output.lock();
+			let loaded = loaded_diff.as_ref().unwrap();
+			assert_eq!(loaded.hash(), "HEAD");
+			assert_eq!(loaded.diffs().unwrap().as_ref().unwrap().len(), 1);
+		});
+	}
+
+	#[test]
+	fn thread_load_range() {
+		with_thread(|thread, update_handler_calls| {
+			let state = thread.state();
+			let output = Arc::new(Mutex::new(None));
+			let tester = ThreadableTester::new();
+			tester.start_threadable(&thread, THREAD_NAME);
+			state.send_update(Action::LoadRange(
+				String::from("HEAD"),
+				String::from("HEAD"),
+				Arc::clone(&output),
+				Interrupter::new(),
+			));
+			while !is_loaded(&output) {
+				sleep(Duration::from_millis(10));
+			}
+			state.send_update(Action::End);
+			tester.wait_for_status(&Status::Ended);
+
+			assert!(update_handler_calls.load(Ordering::Acquire) >= 1);
+			let loaded_diff = output.lock();
+			let loaded = loaded_diff.as_ref().unwrap();
+			assert_eq!(loaded.hash(), "HEAD..HEAD");
+			assert_eq!(loaded.diffs().unwrap().as_ref().unwrap().len(), 1);
+		});
+	}
+
+	#[test]
+	fn thread_load_error() {
+		with_thread(|thread, update_handler_calls| {
+			let state = thread.state();
+			let output = Arc::new(Mutex::new(None));
+			let tester = ThreadableTester::new();
+			tester.start_threadable(&thread, THREAD_NAME);
+			state.send_update(Action::Load(
+				String::from("invalid"),
+				Arc::clone(&output),
+				Interrupter::new(),
+			));
+			while !is_loaded(&output) {
+				sleep(Duration::from_millis(10));
+			}
+			state.send_update(Action::End);
+			tester.wait_for_status(&Status::Ended);
+
+			assert_eq!(update_handler_calls.load(Ordering::Acquire), 1);
+			assert!(output.lock().as_ref().unwrap().diffs().unwrap().is_err());
+		});
+	}
+
+	#[test]
+	fn thread_load_interrupted() {
+		with_thread(|thread, update_handler_calls| {
+			let state = thread.state();
+			let interrupted_output = Arc::new(Mutex::new(None));
+			let output = Arc::new(Mutex::new(None));
+			let interrupter = Interrupter::new();
+			interrupter.interrupt();
+			state.send_update(Action::Load(
+				String::from("HEAD"),
+				Arc::clone(&interrupted_output),
+				interrupter,
+			));
+			state.send_update(Action::Load(
+				String::from("HEAD"),
+				Arc::clone(&output),
+				Interrupter::new(),
+			));
+			let tester = ThreadableTester::new();
+			tester.start_threadable(&thread, THREAD_NAME);
+			while !is_loaded(&output) {
+				sleep(Duration::from_millis(10));
+			}
+			state.send_update(Action::End);
+			tester.wait_for_status(&Status::Ended);
+
+			assert!(update_handler_calls.load(Ordering::Acquire) >= 1);
+			assert!(interrupted_output.lock().is_none());
+		});
+	}
+}
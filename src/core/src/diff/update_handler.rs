@@ -0,0 +1,4 @@
+/// Function for handling a completed diff load
+pub(crate) trait UpdateHandlerFn: Fn() + Sync + Send {}
+
+impl<FN: Fn() + Sync + Send> UpdateHandlerFn for FN {}
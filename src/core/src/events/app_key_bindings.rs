@@ -31,6 +31,14 @@ pub(crate) struct AppKeyBindings {
 	pub(crate) confirm_yes: Vec<Event>,
 	/// Key bindings for editing.
 	pub(crate) edit: Vec<Event>,
+	/// Key bindings for expanding a collapsed file in a diff.
+	pub(crate) expand_file: Vec<Event>,
+	/// Key bindings for folding or unfolding all files in a diff.
+	pub(crate) fold_all: Vec<Event>,
+	/// Key bindings for folding or unfolding a file in a diff.
+	pub(crate) fold_file: Vec<Event>,
+	/// Key bindings for folding or unfolding a hunk in a diff.
+	pub(crate) fold_hunk: Vec<Event>,
 	/// Key bindings for forcing an abort.
 	pub(crate) force_abort: Vec<Event>,
 	/// Key bindings for forcing a rebase.
@@ -53,22 +61,54 @@ pub(crate) struct AppKeyBindings {
 	pub(crate) move_selection_down: Vec<Event>,
 	/// Key bindings for moving the selection up.
 	pub(crate) move_selection_up: Vec<Event>,
+	/// Key bindings for moving the merge branch block down.
+	pub(crate) move_block_down: Vec<Event>,
+	/// Key bindings for moving the merge branch block up.
+	pub(crate) move_block_up: Vec<Event>,
+	/// Key bindings for moving the update-ref of a branch after the selected commit.
+	pub(crate) move_update_ref: Vec<Event>,
 	/// Key bindings for moving up.
 	pub(crate) move_up: Vec<Event>,
 	/// Key bindings for moving up a step.
 	pub(crate) move_up_step: Vec<Event>,
+	/// Key bindings for jumping to the next file in a diff.
+	pub(crate) next_file: Vec<Event>,
+	/// Key bindings for jumping to the next hunk in a diff.
+	pub(crate) next_hunk: Vec<Event>,
 	/// Key bindings for opening the external editor.
 	pub(crate) open_in_external_editor: Vec<Event>,
+	/// Key bindings for jumping to the previous file in a diff.
+	pub(crate) previous_file: Vec<Event>,
+	/// Key bindings for jumping to the previous hunk in a diff.
+	pub(crate) previous_hunk: Vec<Event>,
 	/// Key bindings for rebasing.
 	pub(crate) rebase: Vec<Event>,
 	/// Key bindings for removing a line.
 	pub(crate) remove_line: Vec<Event>,
+	/// Key bindings for running a command.
+	pub(crate) run_command: Vec<Event>,
+	/// Key bindings for selecting the merge branch block.
+	pub(crate) select_block: Vec<Event>,
 	/// Key bindings for showing a commit.
 	pub(crate) show_commit: Vec<Event>,
 	/// Key bindings for showing a diff.
 	pub(crate) show_diff: Vec<Event>,
+	/// Key bindings for showing the diff against the next parent of a merge commit.
+	pub(crate) show_next_parent: Vec<Event>,
+	/// Key bindings for showing the preview of squashing the selected commit with its fixup and squash commits.
+	pub(crate) show_squash_preview: Vec<Event>,
+	/// Key bindings for showing the changes made to the todo list.
+	pub(crate) show_todo_diff: Vec<Event>,
+	/// Key bindings for toggling the preview of the selected commit.
+	pub(crate) toggle_preview: Vec<Event>,
+	/// Key bindings for adding or removing the update-ref of a branch.
+	pub(crate) toggle_update_ref: Vec<Event>,
 	/// Key bindings for toggling visual mode.
 	pub(crate) toggle_visual_mode: Vec<Event>,
+	/// Key bindings for toggling the wrapping of long lines.
+	pub(crate) toggle_wrap: Vec<Event>,
+	/// Key bindings for managing the update-refs of branches.
+	pub(crate) update_refs: Vec<Event>,
 	/// Key bindings for the fixup specific action to toggle the c option.
 	pub(crate) fixup_keep_message: Vec<Event>,
 	/// Key biding for the fixup specific action to toggle the C option.
@@ -88,6 +128,10 @@ impl CustomKeybinding for AppKeyBindings {
 			action_reword: map_keybindings(&key_bindings.action_reword),
 			action_squash: map_keybindings(&key_bindings.action_squash),
 			edit: map_keybindings(&key_bindings.edit),
+			expand_file: map_keybindings(&key_bindings.expand_file),
+			fold_all: map_keybindings(&key_bindings.fold_all),
+			fold_file: map_keybindings(&key_bindings.fold_file),
+			fold_hunk: map_keybindings(&key_bindings.fold_hunk),
 			force_abort: map_keybindings(&key_bindings.force_abort),
 			force_rebase: map_keybindings(&key_bindings.force_rebase),
 			insert_line: map_keybindings(&key_bindings.insert_line),
@@ -99,14 +143,30 @@ impl CustomKeybinding for AppKeyBindings {
 			move_right: map_keybindings(&key_bindings.move_right),
 			move_selection_down: map_keybindings(&key_bindings.move_selection_down),
 			move_selection_up: map_keybindings(&key_bindings.move_selection_up),
+			move_block_down: map_keybindings(&key_bindings.move_block_down),
+			move_block_up: map_keybindings(&key_bindings.move_block_up),
+			move_update_ref: map_keybindings(&key_bindings.move_update_ref),
 			move_up: map_keybindings(&key_bindings.move_up),
 			move_up_step: map_keybindings(&key_bindings.move_up_step),
+			next_file: map_keybindings(&key_bindings.next_file),
+			next_hunk: map_keybindings(&key_bindings.next_hunk),
 			open_in_external_editor: map_keybindings(&key_bindings.open_in_external_editor),
+			previous_file: map_keybindings(&key_bindings.previous_file),
+			previous_hunk: map_keybindings(&key_bindings.previous_hunk),
 			rebase: map_keybindings(&key_bindings.rebase),
 			remove_line: map_keybindings(&key_bindings.remove_line),
+			run_command: map_keybindings(&key_bindings.run_command),
+			select_block: map_keybindings(&key_bindings.select_block),
 			show_commit: map_keybindings(&key_bindings.show_commit),
 			show_diff: map_keybindings(&key_bindings.show_diff),
+			show_next_parent: map_keybindings(&key_bindings.show_next_parent),
+			show_squash_preview: map_keybindings(&key_bindings.show_squash_preview),
+			show_todo_diff: map_keybindings(&key_bindings.show_todo_diff),
+			toggle_preview: map_keybindings(&key_bindings.toggle_preview),
+			toggle_update_ref: map_keybindings(&key_bindings.toggle_update_ref),
 			toggle_visual_mode: map_keybindings(&key_bindings.toggle_visual_mode),
+			toggle_wrap: map_keybindings(&key_bindings.toggle_wrap),
+			update_refs: map_keybindings(&key_bindings.update_refs),
 			confirm_yes: map_keybindings(&key_bindings.confirm_yes),
 			fixup_keep_message: map_keybindings(&key_bindings.fixup_keep_message),
 			fixup_keep_message_with_editor: map_keybindings(&key_bindings.fixup_keep_message_with_editor),
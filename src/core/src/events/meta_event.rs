@@ -44,18 +44,58 @@ pub(crate) enum MetaEvent {
 	Delete,
 	/// The edit meta event.
 	Edit,
+	/// The expand collapsed file meta event.
+	ExpandFile,
+	/// The fold all files meta event.
+	FoldAll,
+	/// The fold file meta event.
+	FoldFile,
+	/// The fold hunk meta event.
+	FoldHunk,
+	/// The next file meta event.
+	NextFile,
+	/// The next hunk meta event.
+	NextHunk,
 	/// The open in editor meta event.
 	OpenInEditor,
+	/// The previous file meta event.
+	PreviousFile,
+	/// The previous hunk meta event.
+	PreviousHunk,
+	/// The run command meta event.
+	RunCommand,
 	/// The show commit meta event.
 	ShowCommit,
 	/// The show diff meta event.
 	ShowDiff,
+	/// The show next parent meta event.
+	ShowNextParent,
+	/// The show squash preview meta event.
+	ShowSquashPreview,
+	/// The show todo diff meta event.
+	ShowTodoDiff,
 	/// The swap selection down meta event.
 	SwapSelectedDown,
 	/// The swap selection up meta event.
 	SwapSelectedUp,
+	/// The swap merge branch block down meta event.
+	SwapBlockDown,
+	/// The swap merge branch block up meta event.
+	SwapBlockUp,
+	/// The select merge branch block meta event.
+	SelectBlock,
 	/// The toggle visual mode meta event.
 	ToggleVisualMode,
+	/// The toggle commit preview meta event.
+	TogglePreview,
+	/// The toggle line wrapping meta event.
+	ToggleWrap,
+	/// The update-refs meta event.
+	UpdateRefs,
+	/// The toggle update-ref meta event.
+	ToggleUpdateRef,
+	/// The move update-ref meta event.
+	MoveUpdateRef,
 	/// The insert line meta event.
 	InsertLine,
 	/// Fixup specific action to toggle the c option.
@@ -72,6 +112,8 @@ pub(crate) enum MetaEvent {
 	ExternalCommandError,
 	/// Search was updated
 	SearchUpdate,
+	/// A commit diff was loaded
+	DiffUpdate,
 }
 
 impl input::CustomEvent for MetaEvent {}
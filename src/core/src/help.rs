@@ -5,12 +5,18 @@ Git Interactive Rebase Editor ({{VERSION}})
 Full feature terminal based sequence editor for git interactive rebase.
 
 USAGE:
-  interactive-rebase-tool [FLAGS] [REBASE-TODO-FILE]
+  interactive-rebase-tool [FLAGS] [OPTIONS] [REBASE-TODO-FILE]
 
 FLAGS:
   -v, --version       Prints versioning information
   -h, --help          Prints help information
   --license           Prints Open Source Software licensing
+  --with-commits      Includes the commit metadata of each line with --export
+
+OPTIONS:
+  --apply <SCRIPT>    Applies the commands in the script file to the todo file, without starting the editor
+  --export <JSON>     Writes the lines of the todo file to the JSON file, without starting the editor
+  --import <JSON>     Replaces the lines of the todo file with the lines in the JSON file, without starting the editor
 
 ARGS:
   <REBASE-TODO-FILE>  The path to the Git rebase todo file
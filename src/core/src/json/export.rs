@@ -0,0 +1,63 @@
+use git::{Commit, User};
+use serde_json::{json, Value};
+use todo_file::{Line, TodoFile};
+
+// the format of the exported JSON, incremented on any change that is not backwards compatible
+pub(super) const FORMAT_VERSION: u64 = 1;
+
+fn user_to_json(user: &User) -> Value {
+	json!({
+		"name": user.name(),
+		"email": user.email(),
+	})
+}
+
+fn commit_to_json(commit: &Commit) -> Value {
+	json!({
+		"hash": commit.hash(),
+		"summary": commit.summary(),
+		"message": commit.message(),
+		"author": user_to_json(commit.author()),
+		"authoredDate": commit.authored_date().as_ref().map(|date| date.to_rfc3339()),
+		"committer": commit.committer().as_ref().map(user_to_json),
+		"committedDate": commit.committed_date().to_rfc3339(),
+		"signature": commit.signature().map(|kind| kind.to_string()),
+		"trailers": commit
+			.trailers()
+			.iter()
+			.map(|trailer| json!({"key": trailer.key(), "value": trailer.value()}))
+			.collect::<Vec<Value>>(),
+	})
+}
+
+fn line_to_json<F: Fn(&str) -> Option<Commit>>(line: &Line, load_commit: Option<&F>) -> Value {
+	let mut value = json!({
+		"action": line.get_action().to_string(),
+		"hash": line.has_reference().then(|| line.get_hash()),
+		"content": line.get_content(),
+		"option": line.option(),
+	});
+	if let Some(load) = load_commit {
+		let commit = line
+			.has_reference()
+			.then(|| load(line.get_hash()))
+			.flatten()
+			.map(|c| commit_to_json(&c));
+		value["commit"] = commit.unwrap_or(Value::Null);
+	}
+	value
+}
+
+// when `load_commit` is provided, each line includes the metadata of its commit, or null if it has no commit
+pub(super) fn todo_file_to_json<F: Fn(&str) -> Option<Commit>>(
+	todo_file: &TodoFile,
+	load_commit: Option<&F>,
+) -> Value {
+	json!({
+		"version": FORMAT_VERSION,
+		"lines": todo_file
+			.lines_iter()
+			.map(|line| line_to_json(line, load_commit))
+			.collect::<Vec<Value>>(),
+	})
+}
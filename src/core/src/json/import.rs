@@ -0,0 +1,95 @@
+use serde_json::{Map, Value};
+use todo_file::{Action, Line};
+
+use super::export::FORMAT_VERSION;
+
+fn get_string<'value>(object: &'value Map<String, Value>, key: &str) -> Result<Option<&'value str>, String> {
+	let Some(value) = object.get(key).filter(|v| !v.is_null())
+	else {
+		return Ok(None);
+	};
+	let string = value
+		.as_str()
+		.ok_or_else(|| format!("The '{key}' field must be a string"))?;
+	// a line break would add lines to the todo file that are not part of the imported lines
+	if string.contains(['\n', '\r']) {
+		return Err(format!("The '{key}' field must not contain a line break"));
+	}
+	Ok(Some(string))
+}
+
+fn required_content(action: Action, content: &str) -> Result<&str, String> {
+	if content.is_empty() {
+		Err(format!("The '{action}' action requires content"))
+	}
+	else {
+		Ok(content)
+	}
+}
+
+fn line_from_json(value: &Value) -> Result<Line, String> {
+	let object = value.as_object().ok_or_else(|| String::from("Expected an object"))?;
+	let action_name = get_string(object, "action")?.ok_or_else(|| String::from("Missing the 'action' field"))?;
+	let action = Action::try_from(action_name).map_err(|err| err.to_string())?;
+	let hash = get_string(object, "hash")?.unwrap_or("");
+	let content = get_string(object, "content")?.unwrap_or("");
+	let option = get_string(object, "option")?;
+
+	if option.is_some() && action != Action::Fixup {
+		return Err(format!("The '{action}' action does not support an option"));
+	}
+
+	let mut line = match action {
+		Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
+			if hash.is_empty() {
+				return Err(format!("The '{action}' action requires a hash"));
+			}
+			if hash.contains(char::is_whitespace) {
+				return Err(String::from("The 'hash' field must not contain whitespace"));
+			}
+			Line::new_commit(action, hash, content).map_err(|err| err.to_string())?
+		},
+		Action::Exec => Line::new_exec(required_content(action, content)?),
+		Action::Label => Line::new_label(required_content(action, content)?),
+		Action::Merge => Line::new_merge(required_content(action, content)?),
+		Action::Reset => Line::new_reset(required_content(action, content)?),
+		Action::UpdateRef => Line::new_update_ref(required_content(action, content)?),
+		Action::Break => Line::new_break(),
+		Action::Noop => return Err(String::from("The 'noop' action cannot be imported")),
+	};
+
+	if let Some(opt) = option {
+		if opt != "-C" && opt != "-c" {
+			return Err(format!("Invalid option '{opt}', expected '-C' or '-c'"));
+		}
+		line.toggle_option(opt);
+	}
+
+	// the line is written to the todo file as text, so it must read back as the same line
+	if Line::new(line.to_text().as_str()).ok().as_ref() != Some(&line) {
+		return Err(format!(
+			"The line '{}' cannot be written to the todo file unchanged",
+			line.to_text()
+		));
+	}
+	Ok(line)
+}
+
+pub(super) fn lines_from_json(value: &Value) -> Result<Vec<Line>, String> {
+	let object = value
+		.as_object()
+		.ok_or_else(|| String::from("Expected a JSON object with a 'lines' field"))?;
+	match object.get("version") {
+		None => {},
+		Some(version) if version.as_u64().is_some_and(|v| (1..=FORMAT_VERSION).contains(&v)) => {},
+		Some(version) => return Err(format!("Unsupported format version '{version}'")),
+	}
+	object
+		.get("lines")
+		.and_then(Value::as_array)
+		.ok_or_else(|| String::from("Missing the 'lines' field, or it is not an array"))?
+		.iter()
+		.enumerate()
+		.map(|(index, line)| line_from_json(line).map_err(|err| format!("Line {}: {err}", index + 1)))
+		.collect()
+}
@@ -0,0 +1,93 @@
+mod export;
+mod import;
+#[cfg(test)]
+mod tests;
+
+use std::{
+	collections::HashMap,
+	fs::{read_to_string, write},
+};
+
+use git::Repository;
+use todo_file::{Line, TodoFile};
+
+use self::{export::todo_file_to_json, import::lines_from_json};
+use crate::{
+	arguments::Args,
+	batch::{load_todo_file, validate_and_write_todo_file},
+	exit::Exit,
+	help::build_help,
+	module::ExitStatus,
+};
+
+pub(crate) fn export(args: &Args) -> Exit {
+	match run_export(args) {
+		Ok(()) => Exit::from(ExitStatus::Good),
+		Err(exit) => exit,
+	}
+}
+
+pub(crate) fn import(args: &Args) -> Exit {
+	match run_import(args) {
+		Ok(()) => Exit::from(ExitStatus::Good),
+		Err(exit) => exit,
+	}
+}
+
+fn run_export(args: &Args) -> Result<(), Exit> {
+	let (json_path, repository, todo_file) = load(args, true)?;
+	let commits = if args.with_commits() {
+		repository.load_commits(
+			todo_file
+				.lines_iter()
+				.filter(|line| line.has_reference())
+				.map(Line::get_hash),
+		)
+	}
+	else {
+		HashMap::new()
+	};
+	let load_commit = |hash: &str| commits.get(hash).cloned().flatten();
+	let json = todo_file_to_json(&todo_file, args.with_commits().then_some(&load_commit));
+
+	write(json_path, format!("{json:#}\n")).map_err(|err| {
+		Exit::new(
+			ExitStatus::FileWriteError,
+			format!("Unable to write JSON file '{json_path}': {err}").as_str(),
+		)
+	})
+}
+
+fn run_import(args: &Args) -> Result<(), Exit> {
+	// an import replaces the lines of the todo file, so the existing lines are not loaded
+	let (json_path, repository, mut todo_file) = load(args, false)?;
+	let contents = read_to_string(json_path).map_err(|err| {
+		Exit::new(
+			ExitStatus::FileReadError,
+			format!("Unable to read JSON file '{json_path}': {err}").as_str(),
+		)
+	})?;
+	let json = serde_json::from_str(contents.as_str()).map_err(|err| {
+		Exit::new(
+			ExitStatus::StateError,
+			format!("Unable to parse JSON file '{json_path}': {err}").as_str(),
+		)
+	})?;
+	let lines = lines_from_json(&json).map_err(|err| Exit::new(ExitStatus::StateError, err.as_str()))?;
+
+	todo_file.set_lines(lines);
+	validate_and_write_todo_file(&repository, &todo_file, "The imported todo file is not valid")
+}
+
+fn load(args: &Args, load_lines: bool) -> Result<(&str, Repository, TodoFile), Exit> {
+	let (Some(json_path), Some(filepath)) = (args.json_path().as_ref(), args.todo_file_path().as_ref())
+	else {
+		return Err(Exit::new(
+			ExitStatus::StateError,
+			build_help(Some(String::from("A JSON file and a todo file path must be provided."))).as_str(),
+		));
+	};
+
+	let (repository, todo_file) = load_todo_file(filepath, load_lines)?;
+	Ok((json_path.as_str(), repository, todo_file))
+}
@@ -0,0 +1,347 @@
+use std::{
+	ffi::OsString,
+	fs::{read_to_string, write},
+};
+
+use git::{testutil::CommitBuilder, Commit, SignatureKind, Trailer, User};
+use rstest::rstest;
+use serde_json::{json, Value};
+use tempfile::NamedTempFile;
+use todo_file::Line;
+
+use super::*;
+use crate::testutil::{module_test, set_git_directory};
+
+fn args(args: &[&str]) -> Args {
+	Args::try_from(args.iter().map(OsString::from).collect::<Vec<OsString>>()).unwrap()
+}
+
+fn assert_export(lines: &[&str], expected: &Value) {
+	module_test(lines, &[], |mut test_context| {
+		let todo_file = test_context.take_todo_file();
+		assert_eq!(
+			&todo_file_to_json(&todo_file, None::<&fn(&str) -> Option<Commit>>),
+			expected
+		);
+	});
+}
+
+fn assert_import_error(json: &Value, expected: &str) {
+	assert_eq!(lines_from_json(json).unwrap_err(), expected);
+}
+
+#[test]
+fn export_lines() {
+	assert_export(
+		&[
+			"pick aaa c1",
+			"fixup -C bbb c2",
+			"exec echo foo",
+			"label onto",
+			"reset onto",
+			"merge -C ccc branch",
+			"update-ref refs/heads/main",
+			"break",
+		],
+		&json!({
+			"version": 1,
+			"lines": [
+				{"action": "pick", "hash": "aaa", "content": "c1", "option": null},
+				{"action": "fixup", "hash": "bbb", "content": "c2", "option": "-C"},
+				{"action": "exec", "hash": null, "content": "echo foo", "option": null},
+				{"action": "label", "hash": null, "content": "onto", "option": null},
+				{"action": "reset", "hash": null, "content": "onto", "option": null},
+				{"action": "merge", "hash": null, "content": "-C ccc branch", "option": null},
+				{"action": "update-ref", "hash": null, "content": "refs/heads/main", "option": null},
+				{"action": "break", "hash": null, "content": "", "option": null},
+			],
+		}),
+	);
+}
+
+#[test]
+fn export_empty() {
+	assert_export(&[], &json!({"version": 1, "lines": []}));
+}
+
+#[test]
+fn export_with_commits() {
+	module_test(&["pick aaa c1", "exec echo foo", "pick bbb c2"], &[], |mut test_context| {
+		let todo_file = test_context.take_todo_file();
+		let commit = CommitBuilder::new("aaa0000")
+			.summary("c1")
+			.message("c1\n\nSigned-off-by: Tim <tim@example.com>")
+			.author(User::new(Some("Tim"), Some("tim@example.com")))
+			.signature(SignatureKind::Ssh)
+			.trailers(&[Trailer::new("Signed-off-by", "Tim <tim@example.com>")])
+			.build();
+		let load_commit = |hash: &str| (hash == "aaa").then(|| commit.clone());
+		let committed_date = commit.committed_date().to_rfc3339();
+
+		assert_eq!(
+			todo_file_to_json(&todo_file, Some(&load_commit)),
+			json!({
+				"version": 1,
+				"lines": [
+					{
+						"action": "pick",
+						"hash": "aaa",
+						"content": "c1",
+						"option": null,
+						"commit": {
+							"hash": "aaa0000",
+							"summary": "c1",
+							"message": "c1\n\nSigned-off-by: Tim <tim@example.com>",
+							"author": {"name": "Tim", "email": "tim@example.com"},
+							"authoredDate": null,
+							"committer": null,
+							"committedDate": committed_date,
+							"signature": "SSH",
+							"trailers": [{"key": "Signed-off-by", "value": "Tim <tim@example.com>"}],
+						},
+					},
+					{"action": "exec", "hash": null, "content": "echo foo", "option": null, "commit": null},
+					{"action": "pick", "hash": "bbb", "content": "c2", "option": null, "commit": null},
+				],
+			})
+		);
+	});
+}
+
+#[test]
+fn import_lines() {
+	let lines = lines_from_json(&json!({
+		"version": 1,
+		"lines": [
+			{"action": "pick", "hash": "aaa", "content": "c1"},
+			{"action": "fixup", "hash": "bbb", "content": "c2", "option": "-c"},
+			{"action": "squash", "hash": "ccc"},
+			{"action": "exec", "content": "echo foo", "hash": null, "option": null},
+			{"action": "label", "content": "onto"},
+			{"action": "reset", "content": "onto"},
+			{"action": "merge", "content": "-C ddd branch"},
+			{"action": "update-ref", "content": "refs/heads/main"},
+			{"action": "break"},
+		],
+	}))
+	.unwrap();
+	assert_eq!(lines.iter().map(Line::to_text).collect::<Vec<String>>(), vec![
+		"pick aaa c1",
+		"fixup -c bbb c2",
+		"squash ccc ",
+		"exec echo foo",
+		"label onto",
+		"reset onto",
+		"merge -C ddd branch",
+		"update-ref refs/heads/main",
+		"break",
+	]);
+}
+
+#[test]
+fn import_without_version() {
+	let lines = lines_from_json(&json!({"lines": [{"action": "pick", "hash": "aaa", "content": "c1"}]})).unwrap();
+	assert_eq!(lines, vec![Line::new("pick aaa c1").unwrap()]);
+}
+
+#[test]
+fn export_import_round_trip() {
+	let lines = [
+		"pick aaa c1",
+		"fixup -C bbb c2",
+		"exec echo foo",
+		"label onto",
+		"merge -C ccc branch",
+		"break",
+	];
+	module_test(&lines, &[], |mut test_context| {
+		let todo_file = test_context.take_todo_file();
+		let json = todo_file_to_json(&todo_file, None::<&fn(&str) -> Option<Commit>>);
+		assert_eq!(lines_from_json(&json).unwrap(), todo_file.get_lines_owned());
+	});
+}
+
+#[rstest]
+#[case::not_object(json!([]), "Expected a JSON object with a 'lines' field")]
+#[case::missing_lines(json!({}), "Missing the 'lines' field, or it is not an array")]
+#[case::lines_not_array(json!({"lines": "pick aaa"}), "Missing the 'lines' field, or it is not an array")]
+#[case::unsupported_version(json!({"version": 2, "lines": []}), "Unsupported format version '2'")]
+#[case::invalid_version(json!({"version": "1", "lines": []}), "Unsupported format version '\"1\"'")]
+#[case::line_not_object(json!({"lines": ["pick aaa"]}), "Line 1: Expected an object")]
+#[case::missing_action(json!({"lines": [{"hash": "aaa"}]}), "Line 1: Missing the 'action' field")]
+#[case::invalid_action(json!({"lines": [{"action": "squish"}]}), "Line 1: The action `squish` is not valid")]
+#[case::action_not_string(json!({"lines": [{"action": 1}]}), "Line 1: The 'action' field must be a string")]
+#[case::missing_hash(
+	json!({"lines": [{"action": "pick", "hash": "aaa"}, {"action": "pick"}]}),
+	"Line 2: The 'pick' action requires a hash"
+)]
+#[case::missing_content(json!({"lines": [{"action": "exec"}]}), "Line 1: The 'exec' action requires content")]
+#[case::option_on_pick(
+	json!({"lines": [{"action": "pick", "hash": "aaa", "option": "-C"}]}),
+	"Line 1: The 'pick' action does not support an option"
+)]
+#[case::invalid_option(
+	json!({"lines": [{"action": "fixup", "hash": "aaa", "option": "-x"}]}),
+	"Line 1: Invalid option '-x', expected '-C' or '-c'"
+)]
+#[case::noop(json!({"lines": [{"action": "noop"}]}), "Line 1: The 'noop' action cannot be imported")]
+#[case::line_break_in_content(
+	json!({"lines": [{"action": "pick", "hash": "aaa", "content": "c1\nexec rm -rf ~"}]}),
+	"Line 1: The 'content' field must not contain a line break"
+)]
+#[case::carriage_return_in_content(
+	json!({"lines": [{"action": "exec", "content": "make\rexec rm -rf ~"}]}),
+	"Line 1: The 'content' field must not contain a line break"
+)]
+#[case::line_break_in_hash(
+	json!({"lines": [{"action": "pick", "hash": "aaa\nexec rm -rf ~"}]}),
+	"Line 1: The 'hash' field must not contain a line break"
+)]
+#[case::line_break_in_action(
+	json!({"lines": [{"action": "break\nexec rm -rf ~"}]}),
+	"Line 1: The 'action' field must not contain a line break"
+)]
+#[case::line_break_in_option(
+	json!({"lines": [{"action": "fixup", "hash": "aaa", "option": "-C\nexec rm -rf ~"}]}),
+	"Line 1: The 'option' field must not contain a line break"
+)]
+#[case::whitespace_in_hash(
+	json!({"lines": [{"action": "fixup", "hash": "-C aaa", "content": "c1"}]}),
+	"Line 1: The 'hash' field must not contain whitespace"
+)]
+#[case::hash_read_as_option(
+	json!({"lines": [{"action": "fixup", "hash": "-C", "content": "aaa c1"}]}),
+	"Line 1: The line 'fixup -C aaa c1' cannot be written to the todo file unchanged"
+)]
+#[case::leading_whitespace_in_content(
+	json!({"lines": [{"action": "label", "content": " onto"}]}),
+	"Line 1: The line 'label  onto' cannot be written to the todo file unchanged"
+)]
+fn import_error(#[case] json: Value, #[case] expected: &str) {
+	assert_import_error(&json, expected);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_export_and_import() {
+	_ = set_git_directory("fixtures/simple");
+	let todo_file = NamedTempFile::new().unwrap();
+	write(todo_file.path(), "pick aaa c1\nfixup bbb c2\n").unwrap();
+	let json_file = NamedTempFile::new().unwrap();
+
+	let export_exit = export(&args(&[
+		"--export",
+		json_file.path().to_str().unwrap(),
+		todo_file.path().to_str().unwrap(),
+	]));
+	assert_eq!(export_exit.get_status(), &ExitStatus::Good);
+	let exported: Value = serde_json::from_str(read_to_string(json_file.path()).unwrap().as_str()).unwrap();
+	assert_eq!(exported["lines"][1]["action"], json!("fixup"));
+	assert!(exported["lines"][0].get("commit").is_none());
+
+	write(todo_file.path(), "").unwrap();
+	let import_exit = import(&args(&[
+		"--import",
+		json_file.path().to_str().unwrap(),
+		todo_file.path().to_str().unwrap(),
+	]));
+	assert_eq!(import_exit.get_status(), &ExitStatus::Good);
+	assert_eq!(read_to_string(todo_file.path()).unwrap(), "pick aaa c1\nfixup bbb c2\n");
+}
+
+#[test]
+#[serial_test::serial]
+fn run_export_with_commits() {
+	_ = set_git_directory("fixtures/simple");
+	let todo_file = NamedTempFile::new().unwrap();
+	write(todo_file.path(), "pick aed0fd1db3e73c0e568677ae8903a11c5fbc5659 c1\npick aaa c2\n").unwrap();
+	let json_file = NamedTempFile::new().unwrap();
+
+	let exit = export(&args(&[
+		"--export",
+		json_file.path().to_str().unwrap(),
+		"--with-commits",
+		todo_file.path().to_str().unwrap(),
+	]));
+	assert_eq!(exit.get_status(), &ExitStatus::Good);
+	let exported: Value = serde_json::from_str(read_to_string(json_file.path()).unwrap().as_str()).unwrap();
+	assert_eq!(
+		exported["lines"][0]["commit"]["hash"],
+		json!("aed0fd1db3e73c0e568677ae8903a11c5fbc5659")
+	);
+	assert_eq!(exported["lines"][1]["commit"], Value::Null);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_missing_todo_file_path() {
+	_ = set_git_directory("fixtures/simple");
+	let exit = export(&args(&["--export", "todo.json"]));
+	assert_eq!(exit.get_status(), &ExitStatus::StateError);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_export_missing_todo_file() {
+	_ = set_git_directory("fixtures/simple");
+	let exit = export(&args(&["--export", "todo.json", "does-not-exist"]));
+	assert_eq!(exit.get_status(), &ExitStatus::FileReadError);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_import_missing_json_file() {
+	_ = set_git_directory("fixtures/simple");
+	let exit = import(&args(&["--import", "does-not-exist.json", "rebase-todo"]));
+	assert_eq!(exit.get_status(), &ExitStatus::FileReadError);
+}
+
+#[test]
+#[serial_test::serial]
+fn run_import_invalid_json() {
+	_ = set_git_directory("fixtures/simple");
+	let todo_file = NamedTempFile::new().unwrap();
+	write(todo_file.path(), "pick aaa c1\n").unwrap();
+	let json_file = NamedTempFile::new().unwrap();
+	write(json_file.path(), "{").unwrap();
+
+	let exit = import(&args(&[
+		"--import",
+		json_file.path().to_str().unwrap(),
+		todo_file.path().to_str().unwrap(),
+	]));
+	assert_eq!(exit.get_status(), &ExitStatus::StateError);
+	assert!(exit
+		.get_message()
+		.as_deref()
+		.unwrap()
+		.starts_with("Unable to parse JSON file"));
+	assert_eq!(read_to_string(todo_file.path()).unwrap(), "pick aaa c1\n");
+}
+
+#[test]
+#[serial_test::serial]
+fn run_import_invalid_result() {
+	_ = set_git_directory("fixtures/simple");
+	let todo_file = NamedTempFile::new().unwrap();
+	write(todo_file.path(), "pick aaa c1\n").unwrap();
+	let json_file = NamedTempFile::new().unwrap();
+	write(
+		json_file.path(),
+		r#"{"lines": [{"action": "fixup", "hash": "aaa"}, {"action": "pick", "hash": "bbb"}]}"#,
+	)
+	.unwrap();
+
+	let exit = import(&args(&[
+		"--import",
+		json_file.path().to_str().unwrap(),
+		todo_file.path().to_str().unwrap(),
+	]));
+	assert_eq!(exit.get_status(), &ExitStatus::StateError);
+	assert!(exit
+		.get_message()
+		.as_deref()
+		.unwrap()
+		.starts_with("The imported todo file is not valid:\nLine 1: "));
+	assert_eq!(read_to_string(todo_file.path()).unwrap(), "pick aaa c1\n");
+}
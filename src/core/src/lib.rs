@@ -134,12 +134,16 @@
 )]
 
 mod application;
+mod apply;
 mod arguments;
+mod batch;
 mod components;
+mod diff;
 mod editor;
 mod events;
 mod exit;
 mod help;
+mod json;
 mod license;
 mod module;
 mod modules;
@@ -169,7 +173,10 @@ pub fn run(os_args: Vec<OsString>) -> Exit {
 				Mode::Help => help::run(),
 				Mode::Version => version::run(),
 				Mode::License => license::run(),
+				Mode::Apply => apply::run(&args),
 				Mode::Editor => editor::run(&args),
+				Mode::Export => json::export(&args),
+				Mode::Import => json::import(&args),
 			}
 		},
 	}
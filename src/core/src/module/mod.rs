@@ -9,6 +9,7 @@ mod tests;
 use anyhow::Error;
 use input::InputOptions;
 use lazy_static::lazy_static;
+use todo_file::Line;
 use view::{RenderContext, ViewData};
 
 pub(crate) use self::{
@@ -41,6 +42,12 @@ pub(crate) trait Module: Send {
 		&DEFAULT_VIEW_DATA
 	}
 
+	/// Build the view data for a view that is split into two panes, the first rendered in place of the view data from
+	/// `build_view_data` and the second alongside it. Results in `None` when the view is not split.
+	fn build_split_view_data(&mut self, _render_context: &RenderContext) -> Option<(&ViewData, &ViewData)> {
+		None
+	}
+
 	fn input_options(&self) -> &InputOptions {
 		&DEFAULT_INPUT_OPTIONS
 	}
@@ -56,4 +63,15 @@ pub(crate) trait Module: Send {
 	fn handle_error(&mut self, _error: &Error) -> Results {
 		Results::new()
 	}
+
+	/// Handle a request to show the combined change of the commits from `first_hash` to `last_hash`, before the module
+	/// is activated.
+	fn handle_range(&mut self, _first_hash: &str, _last_hash: &str) -> Results {
+		Results::new()
+	}
+
+	/// Handle a request to show the result of squashing the commits of `lines`, before the module is activated.
+	fn handle_squash(&mut self, _lines: &[Line]) -> Results {
+		Results::new()
+	}
 }
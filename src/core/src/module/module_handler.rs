@@ -1,4 +1,5 @@
 use input::EventHandler;
+use todo_file::Line;
 use view::{RenderContext, ViewData};
 
 use super::State;
@@ -38,6 +39,16 @@ impl<ModuleProvider: crate::module::ModuleProvider> ModuleHandler<ModuleProvider
 			.build_view_data(render_context)
 	}
 
+	pub(crate) fn build_split_view_data(
+		&mut self,
+		state: State,
+		render_context: &RenderContext,
+	) -> Option<(&ViewData, &ViewData)> {
+		self.module_provider
+			.get_mut_module(state)
+			.build_split_view_data(render_context)
+	}
+
 	pub(crate) fn handle_event(
 		&mut self,
 		state: State,
@@ -53,7 +64,7 @@ impl<ModuleProvider: crate::module::ModuleProvider> ModuleHandler<ModuleProvider
 			});
 		(event != Event::None).then(|| {
 			let mut results = Results::new();
-			results.event(event);
+			results.event(event.clone());
 			results.append(
 				self.module_provider
 					.get_mut_module(state)
@@ -66,6 +77,16 @@ impl<ModuleProvider: crate::module::ModuleProvider> ModuleHandler<ModuleProvider
 	pub(crate) fn error(&mut self, state: State, error: &anyhow::Error) -> Results {
 		self.module_provider.get_mut_module(state).handle_error(error)
 	}
+
+	pub(crate) fn range(&mut self, state: State, first_hash: &str, last_hash: &str) -> Results {
+		self.module_provider
+			.get_mut_module(state)
+			.handle_range(first_hash, last_hash)
+	}
+
+	pub(crate) fn squash(&mut self, state: State, lines: &[Line]) -> Results {
+		self.module_provider.get_mut_module(state).handle_squash(lines)
+	}
 }
 
 #[cfg(test)]
@@ -126,6 +147,16 @@ mod tests {
 			self.trace.lock().push(error.to_string());
 			Results::new()
 		}
+
+		fn handle_range(&mut self, first_hash: &str, last_hash: &str) -> Results {
+			self.trace.lock().push(format!("Range {first_hash}..{last_hash}"));
+			Results::new()
+		}
+
+		fn handle_squash(&mut self, lines: &[Line]) -> Results {
+			self.trace.lock().push(format!("Squash {}", lines.len()));
+			Results::new()
+		}
 	}
 
 	#[test]
@@ -169,4 +200,38 @@ mod tests {
 			},
 		);
 	}
+
+	#[test]
+	fn range() {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::Standard(StandardEvent::Exit)],
+			|context| {
+				let test_module = TestModule::new();
+				let mut module_handler = ModuleHandler::new(
+					context.event_handler_context.event_handler,
+					TestModuleProvider::from(test_module.clone()),
+				);
+				_ = module_handler.range(State::ShowCommit, "aaa", "bbb");
+				assert_eq!(test_module.trace(), "Range aaa..bbb");
+			},
+		);
+	}
+
+	#[test]
+	fn squash() {
+		module_test(
+			&["pick aaa comment"],
+			&[Event::Standard(StandardEvent::Exit)],
+			|context| {
+				let test_module = TestModule::new();
+				let mut module_handler = ModuleHandler::new(
+					context.event_handler_context.event_handler,
+					TestModuleProvider::from(test_module.clone()),
+				);
+				_ = module_handler.squash(State::ShowCommit, &[Line::new("pick aaa comment").unwrap()]);
+				assert_eq!(test_module.trace(), "Squash 1");
+			},
+		);
+	}
 }
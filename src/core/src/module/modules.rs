@@ -8,7 +8,19 @@ use todo_file::TodoFile;
 use super::{Module, State};
 use crate::{
 	module::ModuleProvider,
-	modules::{ConfirmAbort, ConfirmRebase, Error, ExternalEditor, Insert, List, ShowCommit, WindowSizeError},
+	modules::{
+		ConfirmAbort,
+		ConfirmRebase,
+		Error,
+		ExternalEditor,
+		Insert,
+		List,
+		RunCommand,
+		ShowCommit,
+		TodoDiff,
+		UpdateRefs,
+		WindowSizeError,
+	},
 };
 
 pub(crate) struct Modules {
@@ -18,25 +30,35 @@ pub(crate) struct Modules {
 	external_editor: ExternalEditor,
 	insert: Insert,
 	list: List,
+	run_command: RunCommand,
 	show_commit: ShowCommit,
+	todo_diff: TodoDiff,
+	update_refs: UpdateRefs,
 	window_size_error: WindowSizeError,
 }
 
 impl ModuleProvider for Modules {
 	fn new(config: &Config, repository: Repository, todo_file: &Arc<Mutex<TodoFile>>) -> Self {
+		let rebase_state = repository.load_rebase_state();
+		// steps already applied by the rebase mean that the todo file is being edited with `git rebase --edit-todo`
+		let is_edit_todo = rebase_state.as_ref().is_some_and(|state| !state.done().is_empty());
 		Self {
 			error: Error::new(),
-			list: List::new(config, Arc::clone(todo_file)),
-			show_commit: ShowCommit::new(config, repository, Arc::clone(todo_file)),
+			list: List::new(config, repository.clone(), Arc::clone(todo_file), rebase_state.as_ref()),
+			show_commit: ShowCommit::new(config, repository.clone(), Arc::clone(todo_file)),
 			window_size_error: WindowSizeError::new(),
 			confirm_abort: ConfirmAbort::new(
 				&config.key_bindings.confirm_yes,
 				&config.key_bindings.confirm_no,
 				Arc::clone(todo_file),
+				is_edit_todo,
 			),
-			confirm_rebase: ConfirmRebase::new(&config.key_bindings.confirm_yes, &config.key_bindings.confirm_no),
-			external_editor: ExternalEditor::new(config.git.editor.as_str(), Arc::clone(todo_file)),
+			confirm_rebase: ConfirmRebase::new(config, repository.clone(), Arc::clone(todo_file)),
+			external_editor: ExternalEditor::new(config.git.editor.as_str(), Arc::clone(todo_file), is_edit_todo),
 			insert: Insert::new(Arc::clone(todo_file)),
+			run_command: RunCommand::new(Arc::clone(todo_file)),
+			todo_diff: TodoDiff::new(Arc::clone(todo_file)),
+			update_refs: UpdateRefs::new(config, repository, Arc::clone(todo_file)),
 		}
 	}
 
@@ -48,7 +70,10 @@ impl ModuleProvider for Modules {
 			State::ExternalEditor => &mut self.external_editor,
 			State::Insert => &mut self.insert,
 			State::List => &mut self.list,
+			State::RunCommand => &mut self.run_command,
 			State::ShowCommit => &mut self.show_commit,
+			State::TodoDiff => &mut self.todo_diff,
+			State::UpdateRefs => &mut self.update_refs,
 			State::WindowSizeError => &mut self.window_size_error,
 		}
 	}
@@ -61,7 +86,10 @@ impl ModuleProvider for Modules {
 			State::ExternalEditor => &self.external_editor,
 			State::Insert => &self.insert,
 			State::List => &self.list,
+			State::RunCommand => &self.run_command,
 			State::ShowCommit => &self.show_commit,
+			State::TodoDiff => &self.todo_diff,
+			State::UpdateRefs => &self.update_refs,
 			State::WindowSizeError => &self.window_size_error,
 		}
 	}
@@ -96,7 +124,10 @@ mod tests {
 			_ = modules.get_mut_module(State::ExternalEditor);
 			_ = modules.get_mut_module(State::Insert);
 			_ = modules.get_mut_module(State::List);
+			_ = modules.get_mut_module(State::RunCommand);
 			_ = modules.get_mut_module(State::ShowCommit);
+			_ = modules.get_mut_module(State::TodoDiff);
+			_ = modules.get_mut_module(State::UpdateRefs);
 			_ = modules.get_mut_module(State::WindowSizeError);
 		});
 	}
@@ -110,7 +141,10 @@ mod tests {
 			_ = modules.get_module(State::ExternalEditor);
 			_ = modules.get_module(State::Insert);
 			_ = modules.get_module(State::List);
+			_ = modules.get_module(State::RunCommand);
 			_ = modules.get_module(State::ShowCommit);
+			_ = modules.get_module(State::TodoDiff);
+			_ = modules.get_module(State::UpdateRefs);
 			_ = modules.get_module(State::WindowSizeError);
 		});
 	}
@@ -6,6 +6,9 @@ pub(crate) enum State {
 	ExternalEditor,
 	List,
 	Insert,
+	RunCommand,
 	ShowCommit,
+	TodoDiff,
+	UpdateRefs,
 	WindowSizeError,
 }
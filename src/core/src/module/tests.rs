@@ -57,3 +57,15 @@ fn default_trait_method_handle_error() {
 	let mut module = TestModule {};
 	assert!(module.handle_error(&anyhow!("Error")).artifact().is_none());
 }
+
+#[test]
+fn default_trait_method_handle_range() {
+	let mut module = TestModule {};
+	assert!(module.handle_range("aaa", "bbb").artifact().is_none());
+}
+
+#[test]
+fn default_trait_method_handle_squash() {
+	let mut module = TestModule {};
+	assert!(module.handle_squash(&[]).artifact().is_none());
+}
@@ -15,6 +15,7 @@ use crate::{
 pub(crate) struct ConfirmAbort {
 	dialog: Confirm,
 	todo_file: Arc<Mutex<TodoFile>>,
+	is_edit_todo: bool,
 }
 
 impl Module for ConfirmAbort {
@@ -31,11 +32,19 @@ impl Module for ConfirmAbort {
 	}
 
 	fn handle_event(&mut self, event: Event, _: &view::State) -> Results {
-		let confirmed = self.dialog.handle_event(event);
+		let confirmed = self.dialog.handle_event(&event);
 		let mut results = Results::new();
 		match confirmed {
 			Confirmed::Yes => {
-				self.todo_file.lock().set_lines(vec![]);
+				let mut todo_file = self.todo_file.lock();
+				// an empty todo file does not abort a rebase that is already in progress, but would instead drop all
+				// remaining steps, so only the changes made to the todo file are discarded
+				if self.is_edit_todo {
+					todo_file.restore_original_lines();
+				}
+				else {
+					todo_file.set_lines(vec![]);
+				}
 				results.exit_status(ExitStatus::Good);
 			},
 			Confirmed::No => {
@@ -48,10 +57,22 @@ impl Module for ConfirmAbort {
 }
 
 impl ConfirmAbort {
-	pub(crate) fn new(confirm_yes: &[String], confirm_no: &[String], todo_file: Arc<Mutex<TodoFile>>) -> Self {
+	pub(crate) fn new(
+		confirm_yes: &[String],
+		confirm_no: &[String],
+		todo_file: Arc<Mutex<TodoFile>>,
+		is_edit_todo: bool,
+	) -> Self {
+		let prompt = if is_edit_todo {
+			"Are you sure you want to discard the changes to the remaining steps"
+		}
+		else {
+			"Are you sure you want to abort"
+		};
 		Self {
-			dialog: Confirm::new("Are you sure you want to abort", confirm_yes, confirm_no),
+			dialog: Confirm::new(prompt, confirm_yes, confirm_no),
 			todo_file,
+			is_edit_todo,
 		}
 	}
 }
@@ -59,6 +80,7 @@ impl ConfirmAbort {
 #[cfg(test)]
 mod tests {
 	use input::KeyCode;
+	use todo_file::Line;
 	use view::assert_rendered_output;
 
 	use super::*;
@@ -69,6 +91,16 @@ mod tests {
 			&[String::from("y")],
 			&[String::from("n")],
 			Arc::new(Mutex::new(todo_file)),
+			false,
+		)
+	}
+
+	fn create_confirm_abort_edit_todo(todo_file: TodoFile) -> ConfirmAbort {
+		ConfirmAbort::new(
+			&[String::from("y")],
+			&[String::from("n")],
+			Arc::new(Mutex::new(todo_file)),
+			true,
 		)
 	}
 
@@ -103,6 +135,44 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn build_view_data_edit_todo() {
+		module_test(&["pick aaa comment"], &[], |mut test_context| {
+			let mut module = create_confirm_abort_edit_todo(test_context.take_todo_file());
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
+				view_data,
+				"{TITLE}",
+				"{BODY}",
+				"{Normal}Are you sure you want to discard the changes to the remaining steps (y/n)? "
+			);
+		});
+	}
+
+	#[test]
+	fn handle_event_yes_edit_todo() {
+		module_test(
+			&["pick aaa c1", "pick bbb c2"],
+			&[Event::from(MetaEvent::Yes)],
+			|mut test_context| {
+				let mut todo_file = test_context.take_todo_file();
+				_ = todo_file.swap_range_up(1, 1);
+				let mut module = create_confirm_abort_edit_todo(todo_file);
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::Yes)),
+					Artifact::ExitStatus(ExitStatus::Good)
+				);
+				let lines = module.todo_file.lock().get_lines_owned();
+				assert_eq!(lines, vec![
+					Line::new("pick aaa c1").unwrap(),
+					Line::new("pick bbb c2").unwrap()
+				]);
+			},
+		);
+	}
+
 	#[test]
 	fn handle_event_no() {
 		module_test(
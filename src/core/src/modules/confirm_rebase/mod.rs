@@ -1,20 +1,76 @@
-use input::InputOptions;
-use view::{RenderContext, ViewData};
+use std::sync::Arc;
+
+use captur::capture;
+use config::{Config, MissingCommitsCheckSetting};
+use display::DisplayColor;
+use git::Repository;
+use input::{InputOptions, KeyCode, KeyEvent, StandardEvent};
+use parking_lot::Mutex;
+use todo_file::{errors::ValidationError, Line, TodoFile};
+use view::{LineSegment, RenderContext, ViewData, ViewLine};
 
 use crate::{
 	components::confirm::{Confirm, Confirmed, INPUT_OPTIONS},
-	events::{Event, KeyBindings},
+	events::{Event, KeyBindings, MetaEvent},
 	module::{ExitStatus, Module, State},
+	modules::update_refs::find_rebase_branches,
 	process::Results,
+	util::handle_view_data_scroll,
 };
 
 pub(crate) struct ConfirmRebase {
+	blocked_prompt: String,
 	dialog: Confirm,
+	dropped_lines: Vec<Line>,
+	issues: Vec<(usize, ValidationError)>,
+	missing_commits_check: MissingCommitsCheckSetting,
+	missing_update_refs: Vec<(String, String)>,
+	removed_lines: Vec<Line>,
+	repository: Repository,
+	review_prompt: String,
+	selected_issue: usize,
+	todo_file: Arc<Mutex<TodoFile>>,
+	view_data: ViewData,
 }
 
 impl Module for ConfirmRebase {
+	fn activate(&mut self, _: State) -> Results {
+		self.issues = self.validate();
+		self.selected_issue = 0;
+		let todo_file = self.todo_file.lock();
+		self.dropped_lines = todo_file.get_dropped_lines().into_iter().cloned().collect();
+		self.removed_lines = if self.missing_commits_check == MissingCommitsCheckSetting::Ignore {
+			vec![]
+		}
+		else {
+			todo_file.get_removed_lines().into_iter().cloned().collect()
+		};
+		// failing to load the branches should not prevent a rebase, so these warnings are skipped on error
+		self.missing_update_refs = find_rebase_branches(&self.repository, &todo_file)
+			.unwrap_or_default()
+			.into_iter()
+			.filter(|branch| branch.update_ref_index.is_none())
+			.map(|branch| {
+				let commit = todo_file
+					.get_line(branch.commit_index)
+					.map(|line| format!(" {} {}", line.get_hash(), line.get_content()))
+					.unwrap_or_default();
+				(branch.short_name, commit)
+			})
+			.collect();
+		Results::new()
+	}
+
 	fn build_view_data(&mut self, _: &RenderContext) -> &ViewData {
-		self.dialog.get_view_data()
+		if !self.issues.is_empty() {
+			self.get_review_view_data()
+		}
+		else if self.has_warnings() {
+			self.get_warnings_view_data()
+		}
+		else {
+			self.dialog.get_view_data()
+		}
 	}
 
 	fn input_options(&self) -> &InputOptions {
@@ -22,12 +78,61 @@ impl Module for ConfirmRebase {
 	}
 
 	fn read_event(&self, event: Event, key_bindings: &KeyBindings) -> Event {
-		Confirm::read_event(event, key_bindings)
+		if key_bindings.custom.show_todo_diff.contains(&event) {
+			Event::from(MetaEvent::ShowTodoDiff)
+		}
+		else if !self.issues.is_empty() {
+			if key_bindings.custom.force_rebase.contains(&event) {
+				Event::from(MetaEvent::ForceRebase)
+			}
+			else if key_bindings.custom.confirm_yes.contains(&event) {
+				Event::from(MetaEvent::Yes)
+			}
+			else if key_bindings.custom.abort.contains(&event) {
+				Event::from(MetaEvent::Abort)
+			}
+			else {
+				event
+			}
+		}
+		else if self.is_blocked() {
+			if key_bindings.custom.abort.contains(&event) {
+				Event::from(MetaEvent::Abort)
+			}
+			else {
+				event
+			}
+		}
+		else {
+			Confirm::read_event(event, key_bindings)
+		}
 	}
 
-	fn handle_event(&mut self, event: Event, _: &view::State) -> Results {
-		let confirmed = self.dialog.handle_event(event);
+	fn handle_event(&mut self, event: Event, view_state: &view::State) -> Results {
+		if event == Event::from(MetaEvent::ShowTodoDiff) {
+			return Results::from(State::TodoDiff);
+		}
+
+		if !self.issues.is_empty() {
+			return self.handle_review_event(&event);
+		}
+
 		let mut results = Results::new();
+		if self.has_warnings() && handle_view_data_scroll(&event, view_state).is_some() {
+			return results;
+		}
+
+		if self.is_blocked() {
+			if matches!(
+				event,
+				Event::MetaEvent(MetaEvent::Abort) | Event::Key(KeyEvent { code: KeyCode::Esc, .. })
+			) {
+				results.state(State::List);
+			}
+			return results;
+		}
+
+		let confirmed = self.dialog.handle_event(&event);
 		match confirmed {
 			Confirmed::Yes => {
 				results.exit_status(ExitStatus::Good);
@@ -41,84 +146,664 @@ impl Module for ConfirmRebase {
 	}
 }
 
+// the issues of the todo file, where labels can also be references or commits in the repository, or labels from a
+// previous rebase
+pub(crate) fn validate_todo_file(repository: &Repository, todo_file: &TodoFile) -> Vec<(usize, ValidationError)> {
+	todo_file
+		.validate()
+		.into_iter()
+		.filter(|issue| {
+			if let ValidationError::UndefinedLabel(ref label) = issue.1 {
+				!repository.has_revision(label) && !repository.has_revision(format!("refs/rewritten/{label}").as_str())
+			}
+			else {
+				true
+			}
+		})
+		.collect()
+}
+
 impl ConfirmRebase {
-	pub(crate) fn new(confirm_yes: &[String], confirm_no: &[String]) -> Self {
+	pub(crate) fn new(config: &Config, repository: Repository, todo_file: Arc<Mutex<TodoFile>>) -> Self {
 		Self {
-			dialog: Confirm::new("Are you sure you want to rebase", confirm_yes, confirm_no),
+			blocked_prompt: format!("Press {} to return", config.key_bindings.abort.join(",")),
+			dialog: Confirm::new(
+				"Are you sure you want to rebase",
+				&config.key_bindings.confirm_yes,
+				&config.key_bindings.confirm_no,
+			),
+			dropped_lines: vec![],
+			issues: vec![],
+			missing_commits_check: config.git.missing_commits_check,
+			missing_update_refs: vec![],
+			removed_lines: vec![],
+			repository,
+			review_prompt: format!(
+				"Press {} to go to the selected line, {} to rebase anyway or {} to return",
+				config.key_bindings.confirm_yes.join(","),
+				config.key_bindings.force_rebase.join(","),
+				config.key_bindings.abort.join(",")
+			),
+			selected_issue: 0,
+			todo_file,
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+			}),
 		}
 	}
+
+	fn validate(&self) -> Vec<(usize, ValidationError)> {
+		validate_todo_file(&self.repository, &self.todo_file.lock())
+	}
+
+	fn has_warnings(&self) -> bool {
+		!self.dropped_lines.is_empty() || !self.removed_lines.is_empty() || !self.missing_update_refs.is_empty()
+	}
+
+	// with the missing commits check set to error, git will refuse to rebase with commits removed from the list
+	fn is_blocked(&self) -> bool {
+		self.missing_commits_check == MissingCommitsCheckSetting::Error && !self.removed_lines.is_empty()
+	}
+
+	fn get_warnings_view_data(&mut self) -> &ViewData {
+		let is_blocked = self.is_blocked();
+		let dropped_lines = &self.dropped_lines;
+		let removed_lines = &self.removed_lines;
+		let missing_update_refs = &self.missing_update_refs;
+		let blocked_prompt = self.blocked_prompt.as_str();
+		let confirm_prompt = self.dialog.get_prompt();
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			if !dropped_lines.is_empty() {
+				updater.push_line(ViewLine::from(LineSegment::new_with_color(
+					"The following commits will be dropped:",
+					DisplayColor::IndicatorColor,
+				)));
+				for line in dropped_lines {
+					updater.push_line(ViewLine::from(vec![
+						LineSegment::new("  "),
+						LineSegment::new_with_color(line.get_hash(), DisplayColor::ActionDrop),
+						LineSegment::new(format!(" {}", line.get_content()).as_str()),
+					]));
+				}
+				updater.push_line(ViewLine::new_empty_line());
+			}
+			if !removed_lines.is_empty() {
+				updater.push_line(ViewLine::from(LineSegment::new_with_color(
+					if is_blocked {
+						"The following commits were removed from the list, use drop to explicitly remove a commit:"
+					}
+					else {
+						"The following commits were removed from the list and will be dropped:"
+					},
+					DisplayColor::IndicatorColor,
+				)));
+				for line in removed_lines {
+					updater.push_line(ViewLine::from(vec![
+						LineSegment::new("  "),
+						LineSegment::new_with_color(line.get_hash(), DisplayColor::DiffRemoveColor),
+						LineSegment::new(format!(" {}", line.get_content()).as_str()),
+					]));
+				}
+				updater.push_line(ViewLine::new_empty_line());
+			}
+			if !missing_update_refs.is_empty() {
+				updater.push_line(ViewLine::from(LineSegment::new_with_color(
+					"The following branches point to commits in the rebase, but will not be updated:",
+					DisplayColor::IndicatorColor,
+				)));
+				for missing_update_ref in missing_update_refs {
+					updater.push_line(ViewLine::from(vec![
+						LineSegment::new("  "),
+						LineSegment::new_with_color(missing_update_ref.0.as_str(), DisplayColor::ActionUpdateRef),
+						LineSegment::new_with_color(missing_update_ref.1.as_str(), DisplayColor::DiffContextColor),
+					]));
+				}
+				updater.push_line(ViewLine::new_empty_line());
+			}
+			updater.push_trailing_line(ViewLine::from(if is_blocked { blocked_prompt } else { confirm_prompt }));
+		});
+		&self.view_data
+	}
+
+	fn get_review_view_data(&mut self) -> &ViewData {
+		let todo_file = self.todo_file.lock();
+		let issues = &self.issues;
+		let selected_issue = self.selected_issue;
+		let review_prompt = self.review_prompt.as_str();
+		self.view_data.update_view_data(|updater| {
+			capture!(todo_file);
+			updater.clear();
+			updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+				"The rebase todo file has the following problems:",
+				DisplayColor::IndicatorColor,
+			)));
+			updater.push_leading_line(ViewLine::new_empty_line());
+			for (i, &(index, ref error)) in issues.iter().enumerate() {
+				let line_text = todo_file.get_line(index).map(|line| line.to_text()).unwrap_or_default();
+				let mut view_line = ViewLine::from(vec![
+					LineSegment::new_with_color(format!("Line {}: ", index + 1).as_str(), DisplayColor::IndicatorColor),
+					LineSegment::new(format!("{error} ").as_str()),
+					LineSegment::new_with_color(format!("({line_text})").as_str(), DisplayColor::DiffContextColor),
+				]);
+				if i == selected_issue {
+					view_line = view_line.set_selected(true).set_padding(' ');
+				}
+				updater.push_line(view_line);
+			}
+			updater.push_trailing_line(ViewLine::new_empty_line());
+			updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+				review_prompt,
+				DisplayColor::IndicatorColor,
+			)));
+			updater.ensure_line_visible(selected_issue);
+		});
+		&self.view_data
+	}
+
+	fn handle_review_event(&mut self, event: &Event) -> Results {
+		let mut results = Results::new();
+		match *event {
+			Event::MetaEvent(MetaEvent::ForceRebase) => {
+				self.issues.clear();
+				if !self.has_warnings() {
+					results.exit_status(ExitStatus::Good);
+				}
+			},
+			Event::Standard(StandardEvent::ScrollUp) => {
+				self.selected_issue = self.selected_issue.saturating_sub(1);
+			},
+			Event::Standard(StandardEvent::ScrollDown) if self.selected_issue + 1 < self.issues.len() => {
+				self.selected_issue += 1;
+			},
+			Event::MetaEvent(MetaEvent::Yes)
+			| Event::Key(KeyEvent {
+				code: KeyCode::Enter, ..
+			}) => {
+				let (index, _) = self.issues[self.selected_issue];
+				_ = self.todo_file.lock().set_selected_line_index(index);
+				results.state(State::List);
+			},
+			Event::MetaEvent(MetaEvent::Abort) | Event::Key(KeyEvent { code: KeyCode::Esc, .. }) => {
+				results.state(State::List);
+			},
+			_ => {},
+		}
+		results
+	}
 }
 #[cfg(test)]
 mod tests {
+	use git::testutil::{create_branch, head_id, with_temp_repository};
 	use input::KeyCode;
+	use rstest::rstest;
 	use view::assert_rendered_output;
 
 	use super::*;
 	use crate::{assert_results, events::MetaEvent, process::Artifact, testutil::module_test};
 
-	fn create_confirm_rebase() -> ConfirmRebase {
-		ConfirmRebase::new(&[String::from("y")], &[String::from("n")])
+	fn create_confirm_rebase(repository: Repository, todo_file: TodoFile) -> ConfirmRebase {
+		ConfirmRebase::new(&Config::new(), repository, Arc::new(Mutex::new(todo_file)))
+	}
+
+	fn create_confirm_rebase_with_check(
+		repository: Repository,
+		todo_file: TodoFile,
+		missing_commits_check: MissingCommitsCheckSetting,
+	) -> ConfirmRebase {
+		let mut config = Config::new();
+		config.git.missing_commits_check = missing_commits_check;
+		ConfirmRebase::new(&config, repository, Arc::new(Mutex::new(todo_file)))
 	}
 
 	#[test]
 	fn build_view_data() {
-		module_test(&["pick aaa comment"], &[], |test_context| {
-			let mut module = create_confirm_rebase();
-			let view_data = test_context.build_view_data(&mut module);
-			assert_rendered_output!(
-				Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
-				view_data,
-				"{TITLE}",
-				"{BODY}",
-				"{Normal}Are you sure you want to rebase (y/n)? "
-			);
+		with_temp_repository(|repository| {
+			module_test(&["pick aaa comment"], &[], |mut test_context| {
+				let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					Options AssertRenderOptions::INCLUDE_TRAILING_WHITESPACE,
+					view_data,
+					"{TITLE}",
+					"{BODY}",
+					"{Normal}Are you sure you want to rebase (y/n)? "
+				);
+			});
 		});
 	}
 
 	#[test]
 	fn handle_event_yes() {
-		module_test(
-			&["pick aaa comment"],
-			&[Event::from(MetaEvent::Yes)],
-			|mut test_context| {
-				let mut module = create_confirm_rebase();
+		with_temp_repository(|repository| {
+			module_test(
+				&["pick aaa comment"],
+				&[Event::from(MetaEvent::Yes)],
+				|mut test_context| {
+					let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+					_ = test_context.activate(&mut module, State::List);
+					assert_results!(
+						test_context.handle_event(&mut module),
+						Artifact::Event(Event::from(MetaEvent::Yes)),
+						Artifact::ExitStatus(ExitStatus::Good)
+					);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn handle_event_no() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["pick aaa comment"],
+				&[Event::from(MetaEvent::No)],
+				|mut test_context| {
+					let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+					_ = test_context.activate(&mut module, State::List);
+					assert_results!(
+						test_context.handle_event(&mut module),
+						Artifact::Event(Event::from(MetaEvent::No)),
+						Artifact::ChangeState(State::List)
+					);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn handle_event_no_match_key() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["pick aaa comment"],
+				&[Event::from(KeyCode::Null)],
+				|mut test_context| {
+					let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+					_ = test_context.activate(&mut module, State::List);
+					assert_results!(
+						test_context.handle_event(&mut module),
+						Artifact::Event(Event::from(KeyCode::Null))
+					);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn build_view_data_with_issues() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["fixup aaa comment", "pick bbb comment", "reset feature"],
+				&[],
+				|mut test_context| {
+					let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+					_ = test_context.activate(&mut module, State::List);
+					let view_data = test_context.build_view_data(&mut module);
+					assert_rendered_output!(
+						Options AssertRenderOptions::EXCLUDE_STYLE,
+						view_data,
+						"{TITLE}",
+						"{LEADING}",
+						"The rebase todo file has the following problems:",
+						"",
+						"{BODY}",
+						"{Selected}Line 1: Cannot fixup without a previous commit (fixup aaa comment){Pad( )}",
+						"Line 3: The label `feature` is not defined (reset feature)",
+						"{TRAILING}",
+						"",
+						"Press y to go to the selected line, W to rebase anyway or q to return"
+					);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn undefined_label_resolved_in_repository() {
+		with_temp_repository(|repository| {
+			module_test(&["pick aaa comment", "reset main"], &[], |mut test_context| {
+				let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				assert!(module.issues.is_empty());
+			});
+		});
+	}
+
+	#[test]
+	fn review_select_issue_and_jump_to_line() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["fixup aaa comment", "pick bbb comment", "exec foo", "reset feature"],
+				&[
+					Event::from(StandardEvent::ScrollDown),
+					Event::from(StandardEvent::ScrollDown),
+					Event::from(KeyCode::Enter),
+				],
+				|mut test_context| {
+					let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+					_ = test_context.activate(&mut module, State::List);
+					_ = test_context.handle_event(&mut module);
+					_ = test_context.handle_event(&mut module);
+					assert_eq!(module.selected_issue, 1);
+					assert_results!(
+						test_context.handle_event(&mut module),
+						Artifact::Event(Event::from(KeyCode::Enter)),
+						Artifact::ChangeState(State::List)
+					);
+					assert_eq!(module.todo_file.lock().get_selected_line_index(), 3);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn review_select_previous_issue() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["fixup aaa comment", "reset feature"],
+				&[
+					Event::from(StandardEvent::ScrollDown),
+					Event::from(StandardEvent::ScrollUp),
+					Event::from(StandardEvent::ScrollUp),
+				],
+				|mut test_context| {
+					let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+					_ = test_context.activate(&mut module, State::List);
+					_ = test_context.handle_all_events(&mut module);
+					assert_eq!(module.selected_issue, 0);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn review_force_rebase() {
+		with_temp_repository(|repository| {
+			module_test(&["fixup aaa comment"], &[Event::from('W')], |mut test_context| {
+				let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
 				assert_results!(
 					test_context.handle_event(&mut module),
-					Artifact::Event(Event::from(MetaEvent::Yes)),
+					Artifact::Event(Event::from(MetaEvent::ForceRebase)),
 					Artifact::ExitStatus(ExitStatus::Good)
 				);
-			},
-		);
+			});
+		});
 	}
 
 	#[test]
-	fn handle_event_no() {
-		module_test(
-			&["pick aaa comment"],
-			&[Event::from(MetaEvent::No)],
-			|mut test_context| {
-				let mut module = create_confirm_rebase();
+	fn review_jump_to_line_with_confirm_key() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["pick bbb comment", "reset feature"],
+				&[Event::from('y')],
+				|mut test_context| {
+					let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+					_ = test_context.activate(&mut module, State::List);
+					assert_results!(
+						test_context.handle_event(&mut module),
+						Artifact::Event(Event::from(MetaEvent::Yes)),
+						Artifact::ChangeState(State::List)
+					);
+					assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn review_return_to_list_with_abort_key() {
+		with_temp_repository(|repository| {
+			module_test(&["fixup aaa comment"], &[Event::from('q')], |mut test_context| {
+				let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
 				assert_results!(
 					test_context.handle_event(&mut module),
-					Artifact::Event(Event::from(MetaEvent::No)),
+					Artifact::Event(Event::from(MetaEvent::Abort)),
 					Artifact::ChangeState(State::List)
 				);
-			},
-		);
+			});
+		});
 	}
 
 	#[test]
-	fn handle_event_no_match_key() {
-		module_test(
-			&["pick aaa comment"],
-			&[Event::from(KeyCode::Null)],
-			|mut test_context| {
-				let mut module = create_confirm_rebase();
+	fn review_return_to_list() {
+		with_temp_repository(|repository| {
+			module_test(&["fixup aaa comment"], &[Event::from(KeyCode::Esc)], |mut test_context| {
+				let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
 				assert_results!(
 					test_context.handle_event(&mut module),
-					Artifact::Event(Event::from(KeyCode::Null))
+					Artifact::Event(Event::from(KeyCode::Esc)),
+					Artifact::ChangeState(State::List)
 				);
-			},
-		);
+			});
+		});
+	}
+
+	#[test]
+	fn build_view_data_with_dropped_commits() {
+		with_temp_repository(|repository| {
+			module_test(&["pick aaa comment", "drop bbb other"], &[], |mut test_context| {
+				let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					view_data,
+					"{TITLE}",
+					"{BODY}",
+					"The following commits will be dropped:",
+					"  bbb other",
+					"",
+					"{TRAILING}",
+					"Are you sure you want to rebase (y/n)?"
+				);
+			});
+		});
+	}
+
+	#[test]
+	fn removed_commits_ignored() {
+		with_temp_repository(|repository| {
+			module_test(&["pick aaa comment", "pick bbb other"], &[], |mut test_context| {
+				let mut todo_file = test_context.take_todo_file();
+				todo_file.remove_lines(1, 1);
+				let mut module = create_confirm_rebase(repository, todo_file);
+				_ = test_context.activate(&mut module, State::List);
+				assert!(module.removed_lines.is_empty());
+			});
+		});
+	}
+
+	#[test]
+	fn build_view_data_with_removed_commits_warn() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["pick aaa comment", "pick bbb other", "drop ccc dropped"],
+				&[],
+				|mut test_context| {
+					let mut todo_file = test_context.take_todo_file();
+					todo_file.remove_lines(1, 1);
+					let mut module =
+						create_confirm_rebase_with_check(repository, todo_file, MissingCommitsCheckSetting::Warn);
+					_ = test_context.activate(&mut module, State::List);
+					let view_data = test_context.build_view_data(&mut module);
+					assert_rendered_output!(
+						Options AssertRenderOptions::EXCLUDE_STYLE,
+						view_data,
+						"{TITLE}",
+						"{BODY}",
+						"The following commits will be dropped:",
+						"  ccc dropped",
+						"",
+						"The following commits were removed from the list and will be dropped:",
+						"  bbb other",
+						"",
+						"{TRAILING}",
+						"Are you sure you want to rebase (y/n)?"
+					);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn removed_commits_warn_confirm() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["pick aaa comment", "pick bbb other"],
+				&[Event::from(MetaEvent::Yes)],
+				|mut test_context| {
+					let mut todo_file = test_context.take_todo_file();
+					todo_file.remove_lines(1, 1);
+					let mut module =
+						create_confirm_rebase_with_check(repository, todo_file, MissingCommitsCheckSetting::Warn);
+					_ = test_context.activate(&mut module, State::List);
+					assert_results!(
+						test_context.handle_event(&mut module),
+						Artifact::Event(Event::from(MetaEvent::Yes)),
+						Artifact::ExitStatus(ExitStatus::Good)
+					);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn build_view_data_with_removed_commits_error() {
+		with_temp_repository(|repository| {
+			module_test(&["pick aaa comment", "pick bbb other"], &[], |mut test_context| {
+				let mut todo_file = test_context.take_todo_file();
+				todo_file.remove_lines(1, 1);
+				let mut module =
+					create_confirm_rebase_with_check(repository, todo_file, MissingCommitsCheckSetting::Error);
+				_ = test_context.activate(&mut module, State::List);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					view_data,
+					"{TITLE}",
+					"{BODY}",
+					"The following commits were removed from the list, use drop to explicitly remove a commit:",
+					"  bbb other",
+					"",
+					"{TRAILING}",
+					"Press q to return"
+				);
+			});
+		});
+	}
+
+	#[test]
+	fn removed_commits_error_ignores_confirm() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["pick aaa comment", "pick bbb other"],
+				&[Event::from('y'), Event::from(KeyCode::Esc)],
+				|mut test_context| {
+					let mut todo_file = test_context.take_todo_file();
+					todo_file.remove_lines(1, 1);
+					let mut module =
+						create_confirm_rebase_with_check(repository, todo_file, MissingCommitsCheckSetting::Error);
+					_ = test_context.activate(&mut module, State::List);
+					assert_results!(
+						test_context.handle_event(&mut module),
+						Artifact::Event(Event::from('y'))
+					);
+					assert_results!(
+						test_context.handle_event(&mut module),
+						Artifact::Event(Event::from(KeyCode::Esc)),
+						Artifact::ChangeState(State::List)
+					);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn removed_commits_error_return_with_abort_key() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["pick aaa comment", "pick bbb other"],
+				&[Event::from('q')],
+				|mut test_context| {
+					let mut todo_file = test_context.take_todo_file();
+					todo_file.remove_lines(1, 1);
+					let mut module =
+						create_confirm_rebase_with_check(repository, todo_file, MissingCommitsCheckSetting::Error);
+					_ = test_context.activate(&mut module, State::List);
+					assert_results!(
+						test_context.handle_event(&mut module),
+						Artifact::Event(Event::from(MetaEvent::Abort)),
+						Artifact::ChangeState(State::List)
+					);
+				},
+			);
+		});
+	}
+
+	#[test]
+	fn review_force_rebase_with_dropped_commits() {
+		with_temp_repository(|repository| {
+			module_test(
+				&["fixup aaa comment", "drop bbb other"],
+				&[Event::from('W')],
+				|mut test_context| {
+					let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+					_ = test_context.activate(&mut module, State::List);
+					assert_results!(
+						test_context.handle_event(&mut module),
+						Artifact::Event(Event::from(MetaEvent::ForceRebase))
+					);
+					assert!(module.issues.is_empty());
+					assert!(module.has_warnings());
+				},
+			);
+		});
+	}
+
+	#[rstest]
+	#[case::confirm(&["pick aaa comment"])]
+	#[case::review(&["fixup aaa comment"])]
+	#[case::discarded(&["pick aaa comment", "drop bbb other"])]
+	fn show_todo_diff(#[case] lines: &[&str]) {
+		with_temp_repository(|repository| {
+			module_test(lines, &[Event::from('D')], |mut test_context| {
+				let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::ShowTodoDiff)),
+					Artifact::ChangeState(State::TodoDiff)
+				);
+			});
+		});
+	}
+
+	#[test]
+	fn build_view_data_with_missing_update_refs() {
+		with_temp_repository(|repository| {
+			create_branch(&repository, "feature", "refs/heads/main");
+			let hash = head_id(&repository, "feature").to_string();
+			let lines = [format!("pick {hash:.7} comment")];
+			module_test(&[lines[0].as_str()], &[], |mut test_context| {
+				let mut module = create_confirm_rebase(repository, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					view_data,
+					"{TITLE}",
+					"{BODY}",
+					"The following branches point to commits in the rebase, but will not be updated:",
+					format!("  feature {hash:.7} comment"),
+					"",
+					"{TRAILING}",
+					"Are you sure you want to rebase (y/n)?"
+				);
+			});
+		});
 	}
 }
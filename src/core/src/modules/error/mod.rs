@@ -36,7 +36,7 @@ impl Module for Error {
 
 	fn handle_event(&mut self, event: Event, view_state: &view::State) -> Results {
 		let mut results = Results::new();
-		if handle_view_data_scroll(event, view_state).is_none() {
+		if handle_view_data_scroll(&event, view_state).is_none() {
 			if let Event::Key(_) = event {
 				results.state(self.return_state);
 			}
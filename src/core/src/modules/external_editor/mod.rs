@@ -5,7 +5,7 @@ mod external_editor_state;
 #[cfg(all(unix, test))]
 mod tests;
 
-use std::sync::Arc;
+use std::{path::Path, sync::Arc};
 
 use anyhow::{anyhow, Result};
 use input::InputOptions;
@@ -26,11 +26,15 @@ lazy_static! {
 	static ref INPUT_OPTIONS: InputOptions = InputOptions::RESIZE;
 }
 
+/// Editors that are known to accept a `+<line>` argument to place the cursor on a line.
+const LINE_NUMBER_EDITORS: [&str; 5] = ["vi", "vim", "nvim", "emacs", "nano"];
+
 pub(crate) struct ExternalEditor {
 	editor: String,
 	empty_choice: Choice<Action>,
 	error_choice: Choice<Action>,
 	external_command: (String, Vec<String>),
+	is_edit_todo: bool,
 	lines: Vec<Line>,
 	state: ExternalEditorState,
 	todo_file: Arc<Mutex<TodoFile>>,
@@ -106,6 +110,15 @@ impl Module for ExternalEditor {
 								if todo_file.is_empty() || todo_file.is_noop() {
 									Some(ExternalEditorState::Empty)
 								}
+								// mid-rebase, the first line may already be in progress, so it must not be changed
+								else if self.is_edit_todo
+									&& todo_file.get_line(0).map(Line::to_text) != self.lines.first().map(Line::to_text)
+								{
+									Some(ExternalEditorState::Error(anyhow!(
+										"The first line of the rebase file may already be in progress, and cannot be \
+										 moved or changed"
+									)))
+								}
 								else {
 									results.state(State::List);
 									None
@@ -130,7 +143,7 @@ impl Module for ExternalEditor {
 				}
 			},
 			ExternalEditorState::Empty => {
-				let choice = self.empty_choice.handle_event(event, view_state);
+				let choice = self.empty_choice.handle_event(&event, view_state);
 				if let Some(action) = choice {
 					match *action {
 						Action::AbortRebase => results.exit_status(ExitStatus::Good),
@@ -143,7 +156,7 @@ impl Module for ExternalEditor {
 				}
 			},
 			ExternalEditorState::Error(_) => {
-				let choice = self.error_choice.handle_event(event, view_state);
+				let choice = self.error_choice.handle_event(&event, view_state);
 				if let Some(action) = choice {
 					match *action {
 						Action::AbortRebase => {
@@ -171,7 +184,7 @@ impl Module for ExternalEditor {
 }
 
 impl ExternalEditor {
-	pub(crate) fn new(editor: &str, todo_file: Arc<Mutex<TodoFile>>) -> Self {
+	pub(crate) fn new(editor: &str, todo_file: Arc<Mutex<TodoFile>>, is_edit_todo: bool) -> Self {
 		let view_data = ViewData::new(|updater| {
 			updater.set_show_title(true);
 		});
@@ -207,6 +220,7 @@ impl ExternalEditor {
 			empty_choice,
 			error_choice,
 			external_command: (String::new(), vec![]),
+			is_edit_todo,
 			lines: vec![],
 			state: ExternalEditorState::Active,
 			todo_file,
@@ -236,17 +250,6 @@ impl ExternalEditor {
 	}
 
 	fn get_command(&self) -> Result<(String, Vec<String>)> {
-		let mut parameters = tokenize(self.editor.as_str())
-			.map_or(Err(anyhow!("Invalid editor: \"{}\"", self.editor)), |args| {
-				if args.is_empty() {
-					Err(anyhow!("No editor configured"))
-				}
-				else {
-					Ok(args.into_iter())
-				}
-			})
-			.map_err(|e| anyhow!("Please see the git \"core.editor\" configuration for details").context(e))?;
-
 		let todo_file = self.todo_file.lock();
 		let filepath = todo_file.get_filepath().to_str().ok_or_else(|| {
 			anyhow!(
@@ -254,22 +257,50 @@ impl ExternalEditor {
 				todo_file.get_filepath().to_string_lossy()
 			)
 		})?;
-		let mut file_pattern_found = false;
-		let command = parameters.next().unwrap_or_else(|| String::from("false"));
-		let mut arguments = parameters
-			.map(|a| {
-				if a.as_str() == "%" {
-					file_pattern_found = true;
-					String::from(filepath)
-				}
-				else {
-					a
-				}
-			})
-			.collect::<Vec<String>>();
-		if !file_pattern_found {
+		get_editor_command(self.editor.as_str(), filepath, None)
+	}
+}
+
+/// Build the command and arguments to open `filepath` in `editor`, optionally with the cursor at `line_number`. The
+/// line number is only provided when the editor uses the file placement marker, or is known to support it.
+pub(crate) fn get_editor_command(
+	editor: &str,
+	filepath: &str,
+	line_number: Option<u32>,
+) -> Result<(String, Vec<String>)> {
+	let mut parameters = tokenize(editor)
+		.map_or(Err(anyhow!("Invalid editor: \"{}\"", editor)), |args| {
+			if args.is_empty() {
+				Err(anyhow!("No editor configured"))
+			}
+			else {
+				Ok(args.into_iter())
+			}
+		})
+		.map_err(|e| anyhow!("Please see the git \"core.editor\" configuration for details").context(e))?;
+
+	let mut file_pattern_found = false;
+	let command = parameters.next().unwrap_or_else(|| String::from("false"));
+	let supports_line_number = Path::new(command.as_str())
+		.file_name()
+		.and_then(|name| name.to_str())
+		.is_some_and(|name| LINE_NUMBER_EDITORS.contains(&name));
+	let mut arguments = vec![];
+	for argument in parameters {
+		if argument.as_str() == "%" {
+			file_pattern_found = true;
+			arguments.extend(line_number.map(|line| format!("+{line}")));
 			arguments.push(String::from(filepath));
 		}
-		Ok((command, arguments))
+		else {
+			arguments.push(argument);
+		}
+	}
+	if !file_pattern_found {
+		if supports_line_number {
+			arguments.extend(line_number.map(|line| format!("+{line}")));
+		}
+		arguments.push(String::from(filepath));
 	}
+	Ok((command, arguments))
 }
@@ -45,7 +45,7 @@ macro_rules! assert_external_editor_state_eq {
 }
 
 fn create_external_editor(editor: &str, todo_file: TodoFile) -> ExternalEditor {
-	ExternalEditor::new(editor, Arc::new(Mutex::new(todo_file)))
+	ExternalEditor::new(editor, Arc::new(Mutex::new(todo_file)), false)
 }
 
 #[test]
@@ -136,6 +136,53 @@ fn edit_success() {
 	);
 }
 
+#[test]
+fn edit_todo_in_progress_line_changed_error() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::ExternalCommandSuccess)],
+		|mut test_context| {
+			let mut module = ExternalEditor::new("editor", Arc::new(Mutex::new(test_context.take_todo_file())), true);
+			_ = test_context.activate(&mut module, State::List);
+			let mut todo_file = module.todo_file.lock();
+			_ = todo_file.swap_range_down(0, 0);
+			todo_file.write_file().unwrap();
+			drop(todo_file);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ExternalCommandSuccess))
+			);
+			assert_external_editor_state_eq!(
+				module.state,
+				ExternalEditorState::Error(anyhow!(
+					"The first line of the rebase file may already be in progress, and cannot be moved or changed"
+				))
+			);
+		},
+	);
+}
+
+#[test]
+fn edit_todo_in_progress_line_unchanged() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::ExternalCommandSuccess)],
+		|mut test_context| {
+			let mut module = ExternalEditor::new("editor", Arc::new(Mutex::new(test_context.take_todo_file())), true);
+			_ = test_context.activate(&mut module, State::List);
+			let mut todo_file = module.todo_file.lock();
+			todo_file.remove_lines(1, 1);
+			todo_file.write_file().unwrap();
+			drop(todo_file);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ExternalCommandSuccess)),
+				Artifact::ChangeState(State::List)
+			);
+		},
+	);
+}
+
 #[test]
 fn empty_edit_error() {
 	module_test(
@@ -411,3 +458,59 @@ fn error_undo_modifications_and_reedit() {
 		]);
 	});
 }
+
+#[test]
+fn get_editor_command_with_line_number() {
+	assert_eq!(
+		get_editor_command("vim -f", "/tmp/file", Some(42)).unwrap(),
+		(String::from("vim"), vec![
+			String::from("-f"),
+			String::from("+42"),
+			String::from("/tmp/file")
+		])
+	);
+}
+
+#[test]
+fn get_editor_command_with_line_number_and_editor_path() {
+	assert_eq!(
+		get_editor_command("/usr/bin/nano", "/tmp/file", Some(42)).unwrap(),
+		(String::from("/usr/bin/nano"), vec![
+			String::from("+42"),
+			String::from("/tmp/file")
+		])
+	);
+}
+
+#[test]
+fn get_editor_command_with_line_number_unsupported_editor() {
+	assert_eq!(
+		get_editor_command("code --wait", "/tmp/file", Some(42)).unwrap(),
+		(String::from("code"), vec![
+			String::from("--wait"),
+			String::from("/tmp/file")
+		])
+	);
+}
+
+#[test]
+fn get_editor_command_with_line_number_and_file_placement_marker() {
+	assert_eq!(
+		get_editor_command("editor % --wait", "/tmp/file", Some(42)).unwrap(),
+		(String::from("editor"), vec![
+			String::from("+42"),
+			String::from("/tmp/file"),
+			String::from("--wait")
+		])
+	);
+}
+
+#[test]
+fn get_editor_command_invalid_editor() {
+	assert_eq!(
+		get_editor_command("editor \"", "/tmp/file", None)
+			.unwrap_err()
+			.to_string(),
+		"Invalid editor: \"editor \"\""
+	);
+}
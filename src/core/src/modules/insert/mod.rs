@@ -68,7 +68,7 @@ impl Module for Insert {
 		let mut results = Results::new();
 		match self.state {
 			InsertState::Prompt => {
-				let choice = self.action_choices.handle_event(event, view_state);
+				let choice = self.action_choices.handle_event(&event, view_state);
 				if let Some(action) = choice {
 					if action == &LineType::Cancel {
 						results.state(State::List);
@@ -81,7 +81,7 @@ impl Module for Insert {
 				}
 			},
 			InsertState::Edit => {
-				self.edit.handle_event(event);
+				self.edit.handle_event(&event);
 				if self.edit.is_finished() {
 					let content = self.edit.get_content();
 					results.state(State::List);
@@ -89,6 +89,23 @@ fn edit_render_exec() {
 	);
 }
 
+#[test]
+fn edit_paste_exec() {
+	module_test(
+		&[],
+		&[
+			Event::from('e'),
+			Event::Paste(String::from("cargo test\n")),
+			Event::from(KeyCode::Enter),
+		],
+		|mut test_context| {
+			let mut module = create_insert(test_context.take_todo_file());
+			_ = test_context.handle_n_events(&mut module, 3);
+			assert_eq!(module.todo_file.lock().get_line(0).unwrap().to_text(), "exec cargo test");
+		},
+	);
+}
+
 #[test]
 fn edit_render_pick() {
 	module_test(
@@ -2,21 +2,33 @@
 mod tests;
 mod utils;
 
-use std::{cmp::min, sync::Arc};
+use std::{
+	cmp::min,
+	collections::{HashMap, HashSet},
+	iter,
+	sync::Arc,
+	time::{Duration, Instant},
+};
 
 use captur::capture;
-use config::Config;
+use config::{Config, ListColumn};
 use display::DisplayColor;
+use git::{Commit, RebaseState, Reference, Repository};
 use if_chain::if_chain;
-use input::{InputOptions, MouseEventKind, StandardEvent};
+use input::{InputOptions, MouseButton, MouseEvent, MouseEventKind, StandardEvent};
 use parking_lot::Mutex;
 use todo_file::{Action, EditContext, Line, Search, TodoFile};
 use view::{LineSegment, RenderContext, ViewData, ViewLine};
 
 use self::utils::{
+	get_done_line_segments,
 	get_list_normal_mode_help_lines,
 	get_list_visual_mode_help_lines,
+	get_rebase_onto_name,
+	get_rewritten_lines,
+	get_status_line_segments,
 	get_todo_line_segments,
+	is_commit_column,
 	TodoLineSegmentsOptions,
 };
 use crate::{
@@ -25,9 +37,13 @@ use crate::{
 		help::Help,
 		search_bar::{SearchBar, SearchBarAction},
 	},
+	diff::{Interrupter, LoadedDiff},
 	events::{Event, KeyBindings, MetaEvent},
 	module::{ExitStatus, Module, State},
-	modules::list::utils::get_line_action_maximum_width,
+	modules::{
+		list::utils::get_line_action_maximum_width,
+		show_commit::{create_view_builder, ViewBuilder},
+	},
 	process::Results,
 	select,
 };
@@ -38,6 +54,9 @@ const INPUT_OPTIONS: InputOptions = InputOptions::UNDO_REDO
 	.union(InputOptions::HELP)
 	.union(InputOptions::SEARCH);
 
+// the maximum time between two clicks on the same line for them to be considered a double click
+const DOUBLE_CLICK_DURATION: Duration = Duration::from_millis(500);
+
 #[derive(Debug, PartialEq, Eq)]
 enum ListState {
 	Normal,
@@ -53,25 +72,46 @@ enum CursorUpdate {
 	End,
 }
 
+#[allow(clippy::struct_excessive_bools)]
 pub(crate) struct List {
 	auto_select_next: bool,
+	columns: Vec<ListColumn>,
+	commits: HashMap<String, Option<Commit>>,
+	done_lines: Vec<Line>,
 	edit: Edit,
 	height: usize,
+	last_click: Option<(Instant, usize)>,
 	normal_mode_help: Help,
+	preview: bool,
+	preview_diff: Arc<Mutex<Option<LoadedDiff>>>,
+	preview_hash: Option<String>,
+	preview_interrupter: Interrupter,
+	preview_pending: bool,
+	preview_view_data: ViewData,
+	rebase_head_name: Option<String>,
+	rebase_onto: Option<String>,
+	rebase_onto_name: Option<String>,
+	references: Option<HashMap<String, Vec<Reference>>>,
+	repository: Repository,
 	search: Search,
 	search_bar: SearchBar,
 	selected_line_action: Option<Action>,
 	state: ListState,
+	status_bar: bool,
 	todo_file: Arc<Mutex<TodoFile>>,
 	view_data: ViewData,
+	view_builder: ViewBuilder,
 	visual_index_start: Option<usize>,
 	visual_mode_help: Help,
+	width: usize,
 }
 
 impl Module for List {
 	fn activate(&mut self, _: State) -> Results {
 		self.selected_line_action = self.todo_file.lock().get_selected_line().map(|line| *line.get_action());
-		Results::new()
+		let mut results = Results::new();
+		self.update_preview(&mut results);
+		results
 	}
 
 	fn build_view_data(&mut self, context: &RenderContext) -> &ViewData {
@@ -98,19 +138,34 @@ impl Module for List {
 		}
 	}
 
+	fn build_split_view_data(&mut self, context: &RenderContext) -> Option<(&ViewData, &ViewData)> {
+		if !self.preview
+			|| self.state == ListState::Edit
+			|| self.normal_mode_help.is_active()
+			|| self.visual_mode_help.is_active()
+		{
+			return None;
+		}
+		_ = self.update_list_view_data(&context.primary_pane());
+		self.update_preview_view_data(context);
+		Some((&self.view_data, &self.preview_view_data))
+	}
+
 	fn handle_event(&mut self, event: Event, view_state: &view::State) -> Results {
-		select!(
+		let mut results = select!(
 			default || {
 				match self.state {
-					ListState::Normal => self.handle_normal_mode_event(event, view_state),
-					ListState::Visual => self.handle_visual_mode_input(event, view_state),
-					ListState::Edit => self.handle_edit_mode_input(event),
+					ListState::Normal => self.handle_normal_mode_event(&event, view_state),
+					ListState::Visual => self.handle_visual_mode_input(&event, view_state),
+					ListState::Edit => self.handle_edit_mode_input(&event),
 				}
 			},
-			|| self.handle_normal_help_input(event, view_state),
-			|| self.handle_visual_help_input(event, view_state),
-			|| self.handle_search_input(event)
-		)
+			|| self.handle_normal_help_input(&event, view_state),
+			|| self.handle_visual_help_input(&event, view_state),
+			|| self.handle_search_input(event.clone())
+		);
+		self.update_preview(&mut results);
+		results
 	}
 
 	fn input_options(&self) -> &InputOptions {
@@ -126,34 +181,80 @@ impl Module for List {
 	fn read_event(&self, event: Event, key_bindings: &KeyBindings) -> Event {
 		select!(
 			default || self.read_event_default(event, key_bindings),
-			|| (self.state == ListState::Edit).then_some(event),
-			|| self.normal_mode_help.read_event(event),
-			|| self.visual_mode_help.read_event(event),
-			|| self.search_bar.read_event(event)
+			|| (self.state == ListState::Edit).then(|| event.clone()),
+			|| self.normal_mode_help.read_event(event.clone()),
+			|| self.visual_mode_help.read_event(event.clone()),
+			|| self.search_bar.read_event(event.clone())
 		)
 	}
 }
 
 impl List {
-	pub(crate) fn new(config: &Config, todo_file: Arc<Mutex<TodoFile>>) -> Self {
+	pub(crate) fn new(
+		config: &Config,
+		repository: Repository,
+		todo_file: Arc<Mutex<TodoFile>>,
+		rebase_state: Option<&RebaseState>,
+	) -> Self {
 		let view_data = ViewData::new(|updater| {
 			updater.set_show_title(true);
 			updater.set_show_help(true);
 		});
+		let rebase_onto = (config.list_columns.contains(&ListColumn::Signature) || config.status_bar)
+			.then(|| rebase_state.and_then(RebaseState::onto).map(String::from))
+			.flatten();
+		// the rebase context is read once, since it does not change while the todo file is edited
+		let rebase_onto_name = rebase_onto
+			.as_deref()
+			.filter(|_| config.status_bar)
+			.map(|onto| get_rebase_onto_name(onto, &repository.load_reference_map().unwrap_or_default()));
+		// the lines already applied by a rebase in progress, for when the todo file is edited mid-rebase
+		let done_lines = rebase_state
+			.map(|state| {
+				state
+					.done()
+					.iter()
+					.filter_map(|line| Line::new(line).ok())
+					.collect::<Vec<Line>>()
+			})
+			.unwrap_or_default();
+		let rebase_head_name = config
+			.status_bar
+			.then(|| rebase_state.and_then(RebaseState::head_name))
+			.flatten()
+			.map(|name| String::from(name.strip_prefix("refs/heads/").unwrap_or(name)));
 
 		Self {
 			auto_select_next: config.auto_select_next,
+			columns: config.list_columns.clone(),
+			commits: HashMap::new(),
+			done_lines,
 			edit: Edit::new(),
 			height: 0,
+			last_click: None,
 			normal_mode_help: Help::new_from_keybindings(&get_list_normal_mode_help_lines(&config.key_bindings)),
+			preview: config.commit_preview,
+			preview_diff: Arc::new(Mutex::new(None)),
+			preview_hash: None,
+			preview_interrupter: Interrupter::new(),
+			preview_pending: false,
+			preview_view_data: ViewData::new(|_| {}),
+			rebase_head_name,
+			rebase_onto,
+			rebase_onto_name,
+			references: None,
+			repository,
 			search: Search::new(),
 			search_bar: SearchBar::new(),
 			selected_line_action: None,
 			state: ListState::Normal,
+			status_bar: config.status_bar,
 			todo_file,
+			view_builder: create_view_builder(config),
 			view_data,
 			visual_index_start: None,
 			visual_mode_help: Help::new_from_keybindings(&get_list_visual_mode_help_lines(&config.key_bindings)),
+			width: 0,
 		}
 	}
 
@@ -203,10 +304,24 @@ impl List {
 		results.exit_status(ExitStatus::Good);
 	}
 
+	// when the todo file is edited mid-rebase, the first line may already be in progress, so it cannot be moved or
+	// changed, and no line can be moved before it
+	fn is_in_progress_line(&self, index: usize) -> bool {
+		index == 0 && !self.done_lines.is_empty()
+	}
+
+	// an undo or redo can change the history from before the rebase was in progress, which includes the first line
+	fn is_in_progress_line_changed(&self, todo_file: &TodoFile, previous_line: Option<&str>) -> bool {
+		!self.done_lines.is_empty() && todo_file.get_line(0).map(Line::to_text).as_deref() != previous_line
+	}
+
 	fn swap_selected_up(&mut self) {
 		let mut todo_file = self.todo_file.lock();
 		let start_index = todo_file.get_selected_line_index();
 		let end_index = self.visual_index_start.unwrap_or(start_index);
+		if self.is_in_progress_line(min(start_index, end_index).saturating_sub(1)) {
+			return;
+		}
 
 		let swapped = todo_file.swap_range_up(start_index, end_index);
 		drop(todo_file);
@@ -223,6 +338,9 @@ impl List {
 		let mut todo_file = self.todo_file.lock();
 		let start_index = todo_file.get_selected_line_index();
 		let end_index = self.visual_index_start.unwrap_or(start_index);
+		if self.is_in_progress_line(min(start_index, end_index)) {
+			return;
+		}
 
 		let swapped = todo_file.swap_range_down(start_index, end_index);
 		drop(todo_file);
@@ -235,10 +353,71 @@ impl List {
 		}
 	}
 
+	fn swap_block_up(&mut self) {
+		let mut todo_file = self.todo_file.lock();
+		let selected_index = todo_file.get_selected_line_index();
+		let block_start = todo_file.get_block_range(selected_index).map(|(start, _)| start);
+		// the block is swapped with the block, or line, above it, which must not hold the in-progress line
+		let previous_start = block_start
+			.and_then(|start| start.checked_sub(1))
+			.map(|index| todo_file.get_block_range(index).map_or(index, |(start, _)| start));
+		if block_start.is_some_and(|start| self.is_in_progress_line(start))
+			|| previous_start.is_some_and(|start| self.is_in_progress_line(start))
+		{
+			return;
+		}
+		let new_range = todo_file.swap_block_up(selected_index);
+		drop(todo_file);
+
+		if let (Some(start_index), Some(range)) = (block_start, new_range) {
+			self.update_block_selection(selected_index - start_index, range);
+		}
+	}
+
+	fn swap_block_down(&mut self) {
+		let mut todo_file = self.todo_file.lock();
+		let selected_index = todo_file.get_selected_line_index();
+		let block_start = todo_file.get_block_range(selected_index).map(|(start, _)| start);
+		if block_start.is_some_and(|start| self.is_in_progress_line(start)) {
+			return;
+		}
+		let new_range = todo_file.swap_block_down(selected_index);
+		drop(todo_file);
+
+		if let (Some(start_index), Some(range)) = (block_start, new_range) {
+			self.update_block_selection(selected_index - start_index, range);
+		}
+	}
+
+	fn update_block_selection(&mut self, cursor_offset: usize, (start_index, end_index): (usize, usize)) {
+		if self.state == ListState::Visual {
+			self.visual_index_start = Some(start_index);
+			_ = self.update_cursor(CursorUpdate::Set(end_index));
+		}
+		else {
+			_ = self.update_cursor(CursorUpdate::Set(start_index + cursor_offset));
+		}
+	}
+
+	fn select_block(&mut self) {
+		let todo_file = self.todo_file.lock();
+		let block_range = todo_file.get_block_range(todo_file.get_selected_line_index());
+		drop(todo_file);
+
+		if let Some((start_index, end_index)) = block_range {
+			self.state = ListState::Visual;
+			self.visual_index_start = Some(start_index);
+			_ = self.update_cursor(CursorUpdate::Set(end_index));
+		}
+	}
+
 	fn set_selected_line_action(&mut self, action: Action) {
 		let mut todo_file = self.todo_file.lock();
 		let start_index = todo_file.get_selected_line_index();
 		let end_index = self.visual_index_start.unwrap_or(start_index);
+		if self.is_in_progress_line(min(start_index, end_index)) {
+			return;
+		}
 
 		todo_file.update_range(start_index, end_index, &EditContext::new().action(action));
 		drop(todo_file);
@@ -250,7 +429,12 @@ impl List {
 
 	fn undo(&mut self) {
 		let mut todo_file = self.todo_file.lock();
-		let undo_result = todo_file.undo();
+		let previous_line = todo_file.get_line(0).map(Line::to_text);
+		let mut undo_result = todo_file.undo();
+		if undo_result.is_some() && self.is_in_progress_line_changed(&todo_file, previous_line.as_deref()) {
+			_ = todo_file.redo();
+			undo_result = None;
+		}
 		drop(todo_file);
 
 		if let Some((start_index, end_index)) = undo_result {
@@ -268,7 +452,12 @@ impl List {
 
 	fn redo(&mut self) {
 		let mut todo_file = self.todo_file.lock();
-		let redo_result = todo_file.redo();
+		let previous_line = todo_file.get_line(0).map(Line::to_text);
+		let mut redo_result = todo_file.redo();
+		if redo_result.is_some() && self.is_in_progress_line_changed(&todo_file, previous_line.as_deref()) {
+			_ = todo_file.undo();
+			redo_result = None;
+		}
 		drop(todo_file);
 
 		if let Some((start_index, end_index)) = redo_result {
@@ -288,6 +477,9 @@ impl List {
 		let mut todo_file = self.todo_file.lock();
 		let start_index = todo_file.get_selected_line_index();
 		let end_index = self.visual_index_start.unwrap_or(start_index);
+		if self.is_in_progress_line(min(start_index, end_index)) {
+			return;
+		}
 
 		todo_file.remove_lines(start_index, end_index);
 		drop(todo_file);
@@ -330,19 +522,113 @@ impl List {
 		}
 	}
 
-	fn resize(&mut self, height: u16) {
+	fn resize(&mut self, width: u16, height: u16) {
+		self.width = width as usize;
 		self.height = height as usize;
 	}
 
+	// the size of the list, which is the primary pane of the view when the preview is shown
+	fn list_pane_size(&self) -> RenderContext {
+		let context = RenderContext::new(self.width, self.height);
+		if self.preview { context.primary_pane() } else { context }
+	}
+
+	fn mouse(&mut self, mouse_event: MouseEvent, view_state: &view::State, results: &mut Results) {
+		// events on the preview pane, to the right of the list, are not for the list
+		if mouse_event.column as usize >= self.list_pane_size().width() {
+			return;
+		}
+		// the done lines, shown before the lines of the todo file, cannot be selected
+		if let Some(line_index) = view_state
+			.get_line_index_at_row(mouse_event.row as usize)
+			.and_then(|index| index.checked_sub(self.done_lines.len()))
+		{
+			match mouse_event.kind {
+				MouseEventKind::Down(MouseButton::Left) => {
+					let is_double_click = self
+						.last_click
+						.is_some_and(|(time, index)| index == line_index && time.elapsed() <= DOUBLE_CLICK_DURATION);
+					if self.state == ListState::Visual {
+						self.state = ListState::Normal;
+						self.visual_index_start = None;
+					}
+					_ = self.update_cursor(CursorUpdate::Set(line_index));
+					if is_double_click {
+						self.last_click = None;
+						self.show_commit(results);
+					}
+					else {
+						self.last_click = Some((Instant::now(), line_index));
+					}
+				},
+				MouseEventKind::Drag(MouseButton::Left) => {
+					let selected_line_index = self.todo_file.lock().get_selected_line_index();
+					if self.state == ListState::Normal && selected_line_index != line_index {
+						self.state = ListState::Visual;
+						self.visual_index_start = Some(selected_line_index);
+					}
+					_ = self.update_cursor(CursorUpdate::Set(line_index));
+				},
+				_ => {},
+			}
+		}
+	}
+
 	fn show_commit(&mut self, results: &mut Results) {
 		let todo_file = self.todo_file.lock();
 		if let Some(selected_line) = todo_file.get_selected_line() {
-			if selected_line.has_reference() {
+			if selected_line.has_reference() || selected_line.get_merge_commit().is_some() {
 				results.state(State::ShowCommit);
 			}
 		}
 	}
 
+	// the combined change of the selected commits, from the first to the last selected line with a commit
+	fn show_range(&self, results: &mut Results) {
+		let todo_file = self.todo_file.lock();
+		let selected_index = todo_file.get_selected_line_index();
+		let visual_index = self.visual_index_start.unwrap_or(selected_index);
+		let mut hashes = todo_file
+			.lines_iter()
+			.skip(selected_index.min(visual_index))
+			.take(selected_index.abs_diff(visual_index) + 1)
+			.filter(|line| line.has_reference())
+			.map(Line::get_hash);
+		if let Some(first_hash) = hashes.next() {
+			let last_hash = hashes.next_back().unwrap_or(first_hash);
+			results.show_range(first_hash, last_hash);
+		}
+	}
+
+	// the selected commit, with the fixup and squash commits that directly follow the commit
+	fn show_squash_preview(&self, results: &mut Results) {
+		let todo_file = self.todo_file.lock();
+		let selected_index = todo_file.get_selected_line_index();
+		let Some(selected_line) = todo_file.get_line(selected_index)
+		else {
+			return;
+		};
+		let is_squash_target = matches!(
+			*selected_line.get_action(),
+			Action::Pick | Action::Reword | Action::Edit
+		);
+		if !is_squash_target || !selected_line.has_reference() {
+			return;
+		}
+		let lines = iter::once(selected_line)
+			.chain(
+				todo_file
+					.lines_iter()
+					.skip(selected_index + 1)
+					.take_while(|line| matches!(*line.get_action(), Action::Fixup | Action::Squash)),
+			)
+			.cloned()
+			.collect::<Vec<Line>>();
+		if lines.len() > 1 {
+			results.show_squash(lines);
+		}
+	}
+
 	fn action_break(&mut self) {
 		let mut todo_file = self.todo_file.lock();
 		let selected_line_index = todo_file.get_selected_line_index();
@@ -359,6 +645,10 @@ impl List {
 			.get_line(selected_line_index)
 			.map_or(false, |line| line.get_action() == &Action::Break);
 
+		if selected_action_is_break && self.is_in_progress_line(selected_line_index) {
+			return;
+		}
+
 		let cursor_update = if selected_action_is_break {
 			todo_file.remove_lines(selected_line_index, selected_line_index);
 			CursorUpdate::Up(1)
@@ -373,10 +663,12 @@ impl List {
 		_ = self.update_cursor(cursor_update);
 	}
 
-	#[allow(clippy::unused_self)]
 	fn toggle_option(&mut self, option: &str) {
 		let mut todo_file = self.todo_file.lock();
 		let selected_line_index = todo_file.get_selected_line_index();
+		if self.is_in_progress_line(selected_line_index) {
+			return;
+		}
 		todo_file.update_range(
 			selected_line_index,
 			selected_line_index,
@@ -386,6 +678,9 @@ impl List {
 
 	fn edit(&mut self) {
 		let todo_file = self.todo_file.lock();
+		if self.is_in_progress_line(todo_file.get_selected_line_index()) {
+			return;
+		}
 		if let Some(selected_line) = todo_file.get_selected_line() {
 			if selected_line.is_editable() {
 				self.state = ListState::Edit;
@@ -400,6 +695,82 @@ impl List {
 		results.state(State::Insert);
 	}
 
+	fn toggle_preview(&mut self) {
+		self.preview = !self.preview;
+		self.preview_hash = None;
+		self.preview_view_data.update_view_data(|updater| updater.clear());
+	}
+
+	// request the diff of the selected commit when the selected commit has changed since the last request
+	fn update_preview(&mut self, results: &mut Results) {
+		if !self.preview {
+			return;
+		}
+		let hash = self.todo_file.lock().get_selected_line().and_then(|line| {
+			// merge lines reference the original merge commit with the -C or -c option
+			line.get_merge_commit()
+				.or_else(|| line.has_reference().then(|| line.get_hash()))
+				.map(String::from)
+		});
+		if hash == self.preview_hash {
+			return;
+		}
+		// the previously selected commit is no longer shown, so there is no need to finish loading it
+		self.preview_interrupter.interrupt();
+		self.preview_interrupter = Interrupter::new();
+		if let Some(h) = hash.as_deref() {
+			results.load_diff(h, &self.preview_diff, &self.preview_interrupter);
+		}
+		self.preview_hash = hash;
+		self.preview_view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+		});
+	}
+
+	fn update_preview_view_data(&mut self, context: &RenderContext) {
+		if !self.preview_pending && !self.preview_view_data.is_empty() {
+			return;
+		}
+		let loaded_diff = self.preview_diff.lock();
+		let loaded = self
+			.preview_hash
+			.as_deref()
+			.and_then(|hash| loaded_diff.as_ref().filter(|l| l.hash() == hash));
+		let has_hash = self.preview_hash.is_some();
+		let view_builder = &self.view_builder;
+		let is_full_width = context.is_full_width();
+		self.preview_pending = has_hash && loaded.and_then(LoadedDiff::diffs).is_none();
+
+		self.preview_view_data.update_view_data(|updater| {
+			updater.clear();
+			match loaded.and_then(LoadedDiff::diffs).map(Result::as_ref) {
+				Some(Ok(diffs)) => {
+					if let Some(diff) = diffs.first() {
+						view_builder.build_view_data_preview(updater, diff, diffs.len(), is_full_width);
+					}
+				},
+				Some(Err(err)) => {
+					updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+						format!("Unable to load commit: {err}").as_str(),
+						DisplayColor::IndicatorColor,
+					)));
+				},
+				None => {
+					updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+						if has_hash {
+							"Loading commit..."
+						}
+						else {
+							"No commit to preview"
+						},
+						DisplayColor::IndicatorColor,
+					)));
+				},
+			}
+		});
+	}
+
 	fn update_list_view_data(&mut self, context: &RenderContext) -> &ViewData {
 		let todo_file = self.todo_file.lock();
 		let is_visual_mode = self.state == ListState::Visual;
@@ -410,18 +781,81 @@ impl List {
 		let search_results_current = self.search.current_result_selected();
 		let search_term = self.search_bar.search_value();
 		let search_index = self.search.current_match();
+		let search_status = search_term.filter(|_| search_view_line.is_none()).map(|s_term| {
+			if_chain! {
+				if let Some(s_total) = search_results_total;
+				if let Some(s_index) = search_results_current;
+				if s_total != 0;
+				then {
+					format!("[{s_term}]: {}/{s_total}", s_index + 1)
+				}
+				else {
+					format!("[{s_term}]: No Results")
+				}
+			}
+		});
+		let status_line = self.status_bar.then(|| {
+			ViewLine::from(get_status_line_segments(
+				self.rebase_head_name.as_deref(),
+				self.rebase_onto_name.as_deref(),
+				&todo_file,
+				self.done_lines.len(),
+				search_status.as_deref(),
+			))
+		});
+
+		// the commits of the lines are loaded together, only for lines that were not loaded before, and only when a
+		// column shows commit information
+		if self.columns.iter().any(|column| is_commit_column(*column)) {
+			let new_hashes: HashSet<&str> = todo_file
+				.lines_iter()
+				.filter(|line| line.has_reference() && !self.commits.contains_key(line.get_hash()))
+				.map(Line::get_hash)
+				.collect();
+			if !new_hashes.is_empty() {
+				self.commits.extend(self.repository.load_commits(new_hashes));
+			}
+		}
+		// the references of the repository are loaded once, and only when the refs column is shown
+		if self.columns.contains(&ListColumn::Refs) && self.references.is_none() {
+			self.references = Some(self.repository.load_reference_map().unwrap_or_default());
+		}
+		let commits = &self.commits;
+		let rewritten_lines = if self.columns.contains(&ListColumn::Signature) {
+			get_rewritten_lines(&todo_file, commits, self.rebase_onto.as_deref())
+		}
+		else {
+			vec![]
+		};
+		let references = self.references.as_ref();
+		let columns = self.columns.as_slice();
+		let done_lines = self.done_lines.as_slice();
 
 		self.view_data.update_view_data(|updater| {
 			capture!(todo_file);
 			updater.clear();
+			let maximum_action_width = get_line_action_maximum_width(&todo_file, done_lines);
+			for line in done_lines {
+				updater.push_line(ViewLine::from(get_done_line_segments(
+					line,
+					context.is_full_width(),
+					maximum_action_width,
+				)));
+			}
 			if todo_file.is_empty() {
-				updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+				let empty_line = ViewLine::from(LineSegment::new_with_color(
 					"Rebase todo file is empty",
 					DisplayColor::IndicatorColor,
-				)));
+				));
+				// mid-rebase, the message follows the done lines, rather than being held above them
+				if done_lines.is_empty() {
+					updater.push_leading_line(empty_line);
+				}
+				else {
+					updater.push_line(empty_line);
+				}
 			}
 			else {
-				let maximum_action_width = get_line_action_maximum_width(&todo_file);
 				for (index, line) in todo_file.lines_iter().enumerate() {
 					let selected_line = is_visual_mode
 						&& ((visual_index <= selected_index && index >= visual_index && index <= selected_index)
@@ -439,8 +873,24 @@ impl List {
 					if search_index.map_or(false, |v| v == index) {
 						todo_line_segment_options.insert(TodoLineSegmentsOptions::SEARCH_LINE);
 					}
+					if rewritten_lines.get(index).copied().unwrap_or(false) {
+						todo_line_segment_options.insert(TodoLineSegmentsOptions::REWRITTEN);
+					}
+					let commit = commits.get(line.get_hash()).and_then(Option::as_ref);
+					let line_references = references
+						.zip(commit)
+						.and_then(|(r, c)| r.get(c.hash()))
+						.map_or(&[][..], Vec::as_slice);
 					let mut view_line = ViewLine::new_with_pinned_segments(
-						get_todo_line_segments(line, search_term, todo_line_segment_options, maximum_action_width),
+						get_todo_line_segments(
+							line,
+							commit,
+							line_references,
+							search_term,
+							todo_line_segment_options,
+							columns,
+							maximum_action_width,
+						),
 						if line.has_reference() { 2 } else { 3 },
 					)
 					.set_selected(selected_index == index || selected_line);
@@ -454,27 +904,21 @@ impl List {
 				if let Some(search) = search_view_line {
 					updater.push_trailing_line(search);
 				}
-				else if let Some(s_term) = search_term {
-					let mut search_line_segments = vec![];
-					search_line_segments.push(LineSegment::new(format!("[{s_term}]: ").as_str()));
-					if_chain! {
-						if let Some(s_total) = search_results_total;
-						if let Some(s_index) = search_results_current;
-						if s_total != 0;
-						then {
-							search_line_segments.push(LineSegment::new(format!("{}/{s_total}", s_index + 1).as_str()));
-						}
-						else {
-							search_line_segments.push(LineSegment::new("No Results"));
-						}
+				// with the status bar shown, the search results are part of the status line
+				else if status_line.is_none() {
+					if let Some(search) = search_status {
+						updater.push_trailing_line(ViewLine::from(search.as_str()));
 					}
-					updater.push_trailing_line(ViewLine::from(search_line_segments));
 				}
 			}
+			if let Some(status) = status_line {
+				updater.push_trailing_line(status);
+			}
+			// the done lines are shown before the lines of the todo file
 			if visual_index != selected_index {
-				updater.ensure_line_visible(visual_index);
+				updater.ensure_line_visible(visual_index + done_lines.len());
 			}
-			updater.ensure_line_visible(selected_index);
+			updater.ensure_line_visible(selected_index + done_lines.len());
 		});
 		&self.view_data
 	}
@@ -535,13 +979,21 @@ impl List {
 			e if key_bindings.custom.move_right.contains(&e) => Event::from(MetaEvent::MoveCursorRight),
 			e if key_bindings.custom.move_selection_down.contains(&e) => Event::from(MetaEvent::SwapSelectedDown),
 			e if key_bindings.custom.move_selection_up.contains(&e) => Event::from(MetaEvent::SwapSelectedUp),
+			e if key_bindings.custom.move_block_down.contains(&e) => Event::from(MetaEvent::SwapBlockDown),
+			e if key_bindings.custom.move_block_up.contains(&e) => Event::from(MetaEvent::SwapBlockUp),
 			e if key_bindings.custom.move_up.contains(&e) => Event::from(MetaEvent::MoveCursorUp),
 			e if key_bindings.custom.move_up_step.contains(&e) => Event::from(MetaEvent::MoveCursorPageUp),
 			e if key_bindings.custom.open_in_external_editor.contains(&e) => Event::from(MetaEvent::OpenInEditor),
 			e if key_bindings.custom.rebase.contains(&e) => Event::from(MetaEvent::Rebase),
 			e if key_bindings.custom.remove_line.contains(&e) => Event::from(MetaEvent::Delete),
+			e if key_bindings.custom.run_command.contains(&e) => Event::from(MetaEvent::RunCommand),
+			e if key_bindings.custom.select_block.contains(&e) => Event::from(MetaEvent::SelectBlock),
 			e if key_bindings.custom.show_commit.contains(&e) => Event::from(MetaEvent::ShowCommit),
+			e if key_bindings.custom.show_squash_preview.contains(&e) => Event::from(MetaEvent::ShowSquashPreview),
+			e if key_bindings.custom.show_todo_diff.contains(&e) => Event::from(MetaEvent::ShowTodoDiff),
+			e if key_bindings.custom.toggle_preview.contains(&e) => Event::from(MetaEvent::TogglePreview),
 			e if key_bindings.custom.toggle_visual_mode.contains(&e) => Event::from(MetaEvent::ToggleVisualMode),
+			e if key_bindings.custom.update_refs.contains(&e) => Event::from(MetaEvent::UpdateRefs),
 			Event::Mouse(mouse_event) => {
 				match mouse_event.kind {
 					MouseEventKind::ScrollDown => Event::from(MetaEvent::MoveCursorDown),
@@ -553,14 +1005,14 @@ impl List {
 		}
 	}
 
-	fn handle_normal_help_input(&mut self, event: Event, view_state: &view::State) -> Option<Results> {
+	fn handle_normal_help_input(&mut self, event: &Event, view_state: &view::State) -> Option<Results> {
 		self.normal_mode_help.is_active().then(|| {
 			self.normal_mode_help.handle_event(event, view_state);
 			Results::new()
 		})
 	}
 
-	fn handle_visual_help_input(&mut self, event: Event, view_state: &view::State) -> Option<Results> {
+	fn handle_visual_help_input(&mut self, event: &Event, view_state: &view::State) -> Option<Results> {
 		self.visual_mode_help.is_active().then(|| {
 			self.visual_mode_help.handle_event(event, view_state);
 			Results::new()
@@ -570,7 +1022,7 @@ impl List {
 	fn handle_search_input(&mut self, event: Event) -> Option<Results> {
 		if self.search_bar.is_active() {
 			let todo_file = self.todo_file.lock();
-			match self.search_bar.handle_event(event) {
+			match self.search_bar.handle_event(&event) {
 				SearchBarAction::Start(term) => {
 					if term.is_empty() {
 						self.search.cancel();
@@ -599,9 +1051,9 @@ impl List {
 	}
 
 	#[allow(clippy::integer_division)]
-	fn handle_common_list_input(&mut self, event: Event, view_state: &view::State) -> Option<Results> {
+	fn handle_common_list_input(&mut self, event: &Event, view_state: &view::State) -> Option<Results> {
 		let mut results = Results::new();
-		match event {
+		match *event {
 			Event::MetaEvent(meta_event) => {
 				match meta_event {
 					MetaEvent::Abort => self.abort(&mut results),
@@ -625,10 +1077,10 @@ impl List {
 					},
 					MetaEvent::MoveCursorLeft => self.move_cursor_left(view_state),
 					MetaEvent::MoveCursorPageDown => {
-						_ = self.update_cursor(CursorUpdate::Down(self.height / 2));
+						_ = self.update_cursor(CursorUpdate::Down(self.list_pane_size().height() / 2));
 					},
 					MetaEvent::MoveCursorPageUp => {
-						_ = self.update_cursor(CursorUpdate::Up(self.height / 2));
+						_ = self.update_cursor(CursorUpdate::Up(self.list_pane_size().height() / 2));
 					},
 					MetaEvent::MoveCursorRight => self.move_cursor_right(view_state),
 					MetaEvent::MoveCursorUp => {
@@ -638,6 +1090,11 @@ impl List {
 					MetaEvent::Rebase => self.rebase(&mut results),
 					MetaEvent::SwapSelectedDown => self.swap_selected_down(),
 					MetaEvent::SwapSelectedUp => self.swap_selected_up(),
+					MetaEvent::SwapBlockDown => self.swap_block_down(),
+					MetaEvent::SwapBlockUp => self.swap_block_up(),
+					MetaEvent::SelectBlock => self.select_block(),
+					MetaEvent::ShowTodoDiff => results.state(State::TodoDiff),
+					MetaEvent::TogglePreview => self.toggle_preview(),
 					MetaEvent::ToggleVisualMode => self.toggle_visual_mode(),
 					_ => return None,
 				}
@@ -651,25 +1108,29 @@ impl List {
 					_ => return None,
 				}
 			},
-			Event::Resize(_, height) => self.resize(height),
+			Event::Resize(width, height) => self.resize(width, height),
+			Event::Mouse(mouse_event) => self.mouse(mouse_event, view_state, &mut results),
 			_ => {},
 		}
 
 		Some(results)
 	}
 
-	fn handle_normal_mode_event(&mut self, event: Event, view_state: &view::State) -> Results {
+	fn handle_normal_mode_event(&mut self, event: &Event, view_state: &view::State) -> Results {
 		if let Some(results) = self.handle_common_list_input(event, view_state) {
 			results
 		}
 		else {
 			let mut results = Results::new();
-			if let Event::MetaEvent(meta_event) = event {
+			if let Event::MetaEvent(meta_event) = *event {
 				match meta_event {
 					MetaEvent::ActionBreak => self.action_break(),
 					MetaEvent::Edit => self.edit(),
 					MetaEvent::InsertLine => self.insert_line(&mut results),
+					MetaEvent::RunCommand => results.state(State::RunCommand),
 					MetaEvent::ShowCommit => self.show_commit(&mut results),
+					MetaEvent::ShowSquashPreview => self.show_squash_preview(&mut results),
+					MetaEvent::UpdateRefs => results.state(State::UpdateRefs),
 					MetaEvent::FixupKeepMessage => self.toggle_option("-C"),
 					MetaEvent::FixupKeepMessageWithEditor => self.toggle_option("-c"),
 					_ => {},
@@ -679,12 +1140,20 @@ impl List {
 		}
 	}
 
-	fn handle_visual_mode_input(&mut self, event: Event, view_state: &view::State) -> Results {
-		self.handle_common_list_input(event, view_state)
-			.unwrap_or_else(Results::new)
+	fn handle_visual_mode_input(&mut self, event: &Event, view_state: &view::State) -> Results {
+		if let Some(results) = self.handle_common_list_input(event, view_state) {
+			results
+		}
+		else {
+			let mut results = Results::new();
+			if let Event::MetaEvent(MetaEvent::ShowCommit) = *event {
+				self.show_range(&mut results);
+			}
+			results
+		}
 	}
 
-	fn handle_edit_mode_input(&mut self, event: Event) -> Results {
+	fn handle_edit_mode_input(&mut self, event: &Event) -> Results {
 		self.edit.handle_event(event);
 		if self.edit.is_finished() {
 			let mut todo_file = self.todo_file.lock();
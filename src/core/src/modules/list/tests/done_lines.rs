@@ -0,0 +1,251 @@
+use ::input::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rstest::rstest;
+use view::assert_rendered_output;
+
+use super::*;
+use crate::testutil::module_test;
+
+fn create_done_lines() -> Vec<Line> {
+	vec![
+		Line::new("pick 11111111 done 1").unwrap(),
+		Line::new("fixup -c 22222222 done 2").unwrap(),
+		Line::new("exec echo 'foo'").unwrap(),
+	]
+}
+
+#[test]
+fn render_full() {
+	module_test(&["pick aaaaaaaa comment 1"], &[], |mut test_context| {
+		let mut module = create_list(&Config::new(), test_context.take_todo_file());
+		module.done_lines = create_done_lines();
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Normal,Dimmed}   pick     11111111 done 1",
+			"{Normal,Dimmed}   fixup -c 22222222 done 2",
+			"{Normal,Dimmed}   exec     echo 'foo'",
+			"{Selected}{Normal} > {ActionPick}pick     {Normal}aaaaaaaa comment 1{Pad( )}"
+		);
+	});
+}
+
+#[test]
+fn render_compact() {
+	module_test(&["pick aaaaaaaa comment 1"], &[], |mut test_context| {
+		test_context.render_context.update(30, 300);
+		let mut module = create_list(&Config::new(), test_context.take_todo_file());
+		module.done_lines = create_done_lines();
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Normal,Dimmed} p 111 done 1",
+			"{Normal,Dimmed} f*222 done 2",
+			"{Normal,Dimmed} x echo 'foo'",
+			"{Selected}{Normal}>{ActionPick}p {Normal}aaa comment 1{Pad( )}"
+		);
+	});
+}
+
+#[test]
+fn render_empty_todo_file() {
+	module_test(&[], &[], |mut test_context| {
+		let mut module = create_list(&Config::new(), test_context.take_todo_file());
+		module.done_lines = create_done_lines();
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Normal,Dimmed}   pick     11111111 done 1",
+			"{Normal,Dimmed}   fixup -c 22222222 done 2",
+			"{Normal,Dimmed}   exec     echo 'foo'",
+			"{IndicatorColor}Rebase todo file is empty"
+		);
+	});
+}
+
+#[test]
+fn click_selects_todo_line_after_done_lines() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2"],
+		&[Event::Mouse(MouseEvent {
+			kind: MouseEventKind::Down(MouseButton::Left),
+			column: 0,
+			row: 5,
+			modifiers: KeyModifiers::empty(),
+		})],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			module.done_lines = create_done_lines();
+			test_context.view_context.state.resize(300, 120);
+			module.resize(300, 120);
+			let view_data = test_context.build_view_data(&mut module);
+			test_context.view_context.state.render(view_data);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn click_done_line_ignored() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2"],
+		&[Event::Mouse(MouseEvent {
+			kind: MouseEventKind::Down(MouseButton::Left),
+			column: 0,
+			row: 2,
+			modifiers: KeyModifiers::empty(),
+		})],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			module.done_lines = create_done_lines();
+			test_context.view_context.state.resize(300, 120);
+			module.resize(300, 120);
+			let view_data = test_context.build_view_data(&mut module);
+			test_context.view_context.state.render(view_data);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 0);
+		},
+	);
+}
+
+#[rstest]
+#[case::swap_up_before(&[MetaEvent::MoveCursorDown, MetaEvent::SwapSelectedUp])]
+#[case::swap_down(&[MetaEvent::SwapSelectedDown])]
+#[case::visual_swap_up_before(&[
+	MetaEvent::MoveCursorDown,
+	MetaEvent::ToggleVisualMode,
+	MetaEvent::MoveCursorDown,
+	MetaEvent::SwapSelectedUp
+])]
+#[case::action(&[MetaEvent::ActionDrop])]
+#[case::delete(&[MetaEvent::Delete])]
+fn in_progress_line_not_changed(#[case] meta_events: &[MetaEvent]) {
+	let events = meta_events
+		.iter()
+		.map(|&meta_event| Event::from(meta_event))
+		.collect::<Vec<Event>>();
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		events.as_slice(),
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			module.done_lines = create_done_lines();
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_lines_owned(), vec![
+				Line::new("pick aaa c1").unwrap(),
+				Line::new("pick bbb c2").unwrap()
+			]);
+		},
+	);
+}
+
+#[test]
+fn in_progress_break_not_removed() {
+	module_test(
+		&["break", "pick aaa c1"],
+		&[Event::from(MetaEvent::ActionBreak)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			module.done_lines = create_done_lines();
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_lines_owned(), vec![
+				Line::new("break").unwrap(),
+				Line::new("pick aaa c1").unwrap()
+			]);
+		},
+	);
+}
+
+#[test]
+fn in_progress_line_not_edited() {
+	module_test(
+		&["exec foo", "pick aaa c1"],
+		&[Event::from(MetaEvent::Edit)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			module.done_lines = create_done_lines();
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+fn undo_in_progress_line_change_refused() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::ActionDrop), Event::from(StandardEvent::Undo)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_event(&mut module);
+			module.done_lines = create_done_lines();
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_line(0).unwrap().to_text(), "drop aaa c1");
+		},
+	);
+}
+
+#[test]
+fn redo_in_progress_line_change_refused() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(StandardEvent::Redo)],
+		|mut test_context| {
+			let mut todo_file = test_context.take_todo_file();
+			_ = todo_file.swap_range_down(0, 0);
+			_ = todo_file.undo();
+			let mut module = create_list(&Config::new(), todo_file);
+			module.done_lines = create_done_lines();
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_line(0).unwrap().to_text(), "pick aaa c1");
+		},
+	);
+}
+
+#[test]
+fn undo_after_in_progress_line() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionDrop),
+			Event::from(StandardEvent::Undo),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			module.done_lines = create_done_lines();
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_line(1).unwrap().to_text(), "pick bbb c2");
+		},
+	);
+}
+
+#[test]
+fn line_after_in_progress_line_changed() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ActionDrop),
+			Event::from(MetaEvent::SwapSelectedDown),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			module.done_lines = create_done_lines();
+			_ = test_context.handle_all_events(&mut module);
+			let lines = module
+				.todo_file
+				.lock()
+				.lines_iter()
+				.map(Line::to_text)
+				.collect::<Vec<String>>();
+			assert_eq!(lines, vec!["pick aaa c1", "pick ccc c3", "drop bbb c2"]);
+		},
+	);
+}
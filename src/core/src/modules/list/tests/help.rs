@@ -35,7 +35,16 @@ fn normal_mode_help() {
 				"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
 				"{IndicatorColor} j       {Normal,Dimmed}|{Normal}Move selected lines down",
 				"{IndicatorColor} k       {Normal,Dimmed}|{Normal}Move selected lines up",
+				"{IndicatorColor} J       {Normal,Dimmed}|{Normal}Move merge branch block down",
+				"{IndicatorColor} K       {Normal,Dimmed}|{Normal}Move merge branch block up",
+				"{IndicatorColor} B       {Normal,Dimmed}|{Normal}Select merge branch block",
 				"{IndicatorColor} c       {Normal,Dimmed}|{Normal}Show commit information",
+				"{IndicatorColor} S       {Normal,Dimmed}|{Normal}Preview squashing the commit with its fixups and \
+				 squashes",
+				"{IndicatorColor} R       {Normal,Dimmed}|{Normal}Manage the update-refs of branches",
+				"{IndicatorColor} x       {Normal,Dimmed}|{Normal}Run exec line or command",
+				"{IndicatorColor} D       {Normal,Dimmed}|{Normal}Show changes made to the todo list",
+				"{IndicatorColor} P       {Normal,Dimmed}|{Normal}Toggle the preview of the selected commit",
 				"{IndicatorColor} b       {Normal,Dimmed}|{Normal}Toggle break action",
 				"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Set selected commits to be picked",
 				"{IndicatorColor} r       {Normal,Dimmed}|{Normal}Set selected commits to be reworded",
@@ -102,6 +111,12 @@ fn visual_mode_help() {
 				"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
 				"{IndicatorColor} j       {Normal,Dimmed}|{Normal}Move selected lines down",
 				"{IndicatorColor} k       {Normal,Dimmed}|{Normal}Move selected lines up",
+				"{IndicatorColor} J       {Normal,Dimmed}|{Normal}Move merge branch block down",
+				"{IndicatorColor} K       {Normal,Dimmed}|{Normal}Move merge branch block up",
+				"{IndicatorColor} B       {Normal,Dimmed}|{Normal}Select merge branch block",
+				"{IndicatorColor} c       {Normal,Dimmed}|{Normal}Show the combined changes of the selected commits",
+				"{IndicatorColor} D       {Normal,Dimmed}|{Normal}Show changes made to the todo list",
+				"{IndicatorColor} P       {Normal,Dimmed}|{Normal}Toggle the preview of the selected commit",
 				"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Set selected commits to be picked",
 				"{IndicatorColor} r       {Normal,Dimmed}|{Normal}Set selected commits to be reworded",
 				"{IndicatorColor} e       {Normal,Dimmed}|{Normal}Set selected commits to be edited",
@@ -0,0 +1,346 @@
+use chrono::{Local, TimeZone};
+use git::testutil::{
+	commit_id_from_ref,
+	create_branch,
+	create_commit,
+	create_reference,
+	create_tag,
+	with_temp_repository,
+	CommitBuilder,
+	CreateCommitOptions,
+};
+use rstest::rstest;
+use view::assert_rendered_output;
+
+use super::*;
+use crate::{
+	modules::list::utils::{format_age, get_rewritten_lines},
+	testutil::module_test,
+};
+
+const DAY: i64 = 60 * 60 * 24;
+
+fn create_config(columns: &[ListColumn]) -> Config {
+	let mut config = Config::new();
+	config.list_columns = columns.to_vec();
+	config
+}
+
+fn with_commit<C>(callback: C)
+where C: FnOnce(Repository, String, i64) {
+	with_temp_repository(|repository| {
+		let commit_time = Local::now().timestamp() - DAY * 3 - 100;
+		create_commit(
+			&repository,
+			Some(CreateCommitOptions::new().author("Jane Doe").commit_time(commit_time)),
+		);
+		let hash = commit_id_from_ref(&repository, "refs/heads/main").to_string();
+		callback(repository, hash, commit_time);
+	});
+}
+
+#[test]
+fn full_width_columns() {
+	with_commit(|repository, hash, commit_time| {
+		let line = format!("pick {hash} comment");
+		module_test(&[line.as_str(), "exec echo foo"], &[], |mut test_context| {
+			let config = create_config(&[
+				ListColumn::Action,
+				ListColumn::Hash,
+				ListColumn::Author,
+				ListColumn::Date,
+				ListColumn::Age,
+				ListColumn::Refs,
+				ListColumn::Subject,
+			]);
+			let mut module = List::new(
+				&config,
+				repository,
+				Arc::new(Mutex::new(test_context.take_todo_file())),
+				None,
+			);
+			let date = Local.timestamp_opt(commit_time, 0).unwrap().format("%Y-%m-%d %H:%M");
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Options AssertRenderOptions::EXCLUDE_STYLE,
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				format!(
+					"{{Selected}} > pick {} Jane Doe        {date} 3 days ago     (main) comment{{Pad( )}}",
+					hash.get(0..8).unwrap()
+				),
+				"   exec echo foo"
+			);
+		});
+	});
+}
+
+#[test]
+fn compact_width_columns() {
+	with_commit(|repository, hash, commit_time| {
+		let line = format!("pick {hash} comment");
+		module_test(&[line.as_str()], &[], |mut test_context| {
+			test_context.render_context.update(30, 300);
+			let config = create_config(&[
+				ListColumn::Action,
+				ListColumn::Hash,
+				ListColumn::Author,
+				ListColumn::Date,
+				ListColumn::Age,
+				ListColumn::Subject,
+			]);
+			let mut module = List::new(
+				&config,
+				repository,
+				Arc::new(Mutex::new(test_context.take_todo_file())),
+				None,
+			);
+			let date = Local.timestamp_opt(commit_time, 0).unwrap().format("%y-%m-%d");
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Options AssertRenderOptions::EXCLUDE_STYLE,
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				format!("{{Selected}}>p {} JD  {date} 3d   comment{{Pad( )}}", hash.get(0..3).unwrap())
+			);
+		});
+	});
+}
+
+#[test]
+fn column_styles() {
+	with_commit(|repository, hash, _| {
+		let line = format!("pick {hash} comment");
+		module_test(&["break", line.as_str()], &[], |mut test_context| {
+			let config = create_config(&[
+				ListColumn::Action,
+				ListColumn::Author,
+				ListColumn::Age,
+				ListColumn::Refs,
+				ListColumn::Subject,
+			]);
+			let mut module = List::new(
+				&config,
+				repository,
+				Arc::new(Mutex::new(test_context.take_todo_file())),
+				None,
+			);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionBreak}break {Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick  {Normal}Jane Doe        {Normal,Dimmed}3 days ago     \
+				 {Normal}({RefBranchColor}main{Normal}) comment"
+			);
+		});
+	});
+}
+
+#[test]
+fn refs_column_decorations() {
+	with_commit(|repository, hash, _| {
+		create_branch(&repository, "feature", "refs/heads/main");
+		create_reference(&repository, "refs/remotes/origin/main", "refs/heads/main");
+		create_tag(&repository, "v1.0.0", "refs/heads/main");
+		let line = format!("pick {hash} comment");
+		module_test(&["break", line.as_str()], &[], |mut test_context| {
+			let config = create_config(&[ListColumn::Action, ListColumn::Refs, ListColumn::Subject]);
+			let mut module = List::new(
+				&config,
+				repository,
+				Arc::new(Mutex::new(test_context.take_todo_file())),
+				None,
+			);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{Selected}{Normal} > {ActionBreak}break {Normal}{Pad( )}",
+				"{Normal}   {ActionPick}pick  {Normal}({RefBranchColor}feature{Normal}, {RefBranchColor}main{Normal}, \
+				 {RefRemoteColor}origin/main{Normal}, {RefTagColor}tag: v1.0.0{Normal}) comment"
+			);
+		});
+	});
+}
+
+#[test]
+fn references_loaded_once() {
+	module_test(&["pick aaaaaaaa comment"], &[], |mut test_context| {
+		let config = create_config(&[ListColumn::Refs, ListColumn::Subject]);
+		let mut module = create_list(&config, test_context.take_todo_file());
+		assert!(module.references.is_none());
+		_ = test_context.build_view_data(&mut module);
+		assert!(module.references.is_some());
+	});
+}
+
+#[test]
+fn references_not_loaded_without_refs_column() {
+	module_test(&["pick aaaaaaaa comment"], &[], |mut test_context| {
+		let config = create_config(&[ListColumn::Author, ListColumn::Subject]);
+		let mut module = create_list(&config, test_context.take_todo_file());
+		_ = test_context.build_view_data(&mut module);
+		assert!(module.references.is_none());
+	});
+}
+
+#[test]
+fn signature_column() {
+	with_temp_repository(|repository| {
+		let signature = "-----BEGIN SSH SIGNATURE-----\n-----END SSH SIGNATURE-----";
+		create_commit(&repository, Some(CreateCommitOptions::new().signature(signature)));
+		let first = commit_id_from_ref(&repository, "refs/heads/main").to_string();
+		create_commit(&repository, None);
+		let second = commit_id_from_ref(&repository, "refs/heads/main").to_string();
+		create_commit(&repository, Some(CreateCommitOptions::new().signature(signature)));
+		let third = commit_id_from_ref(&repository, "refs/heads/main").to_string();
+		let lines = [
+			format!("pick {first} first"),
+			format!("reword {second} second"),
+			format!("pick {third} third"),
+		];
+		module_test(
+			&[lines[0].as_str(), lines[1].as_str(), lines[2].as_str(), "exec echo foo"],
+			&[],
+			|mut test_context| {
+				let config = create_config(&[ListColumn::Signature, ListColumn::Subject]);
+				let mut module = List::new(
+					&config,
+					repository,
+					Arc::new(Mutex::new(test_context.take_todo_file())),
+					None,
+				);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					view_data,
+					"{TITLE}{HELP}",
+					"{BODY}",
+					"{Selected}{Normal} > {DiffAddColor}S {Normal}first{Pad( )}",
+					"{Normal}     second",
+					"{Normal}   {DiffRemoveColor}! {Normal}third",
+					"{Normal}   echo foo"
+				);
+			},
+		);
+	});
+}
+
+fn create_rewritten_commits() -> HashMap<String, Option<Commit>> {
+	let mut commits = HashMap::new();
+	for (hash, parent) in [("aaa", "000"), ("bbb", "aaa"), ("ccc", "bbb")] {
+		_ = commits.insert(
+			String::from(hash),
+			Some(CommitBuilder::new(hash).parent_hash(parent).build()),
+		);
+	}
+	_ = commits.insert(String::from("eee"), None);
+	commits
+}
+
+#[rstest]
+#[case::unchanged(&["pick aaa c", "pick bbb c", "pick ccc c"], None, &[false, false, false])]
+#[case::onto_parent(&["pick aaa c", "pick bbb c", "pick ccc c"], Some("000"), &[false, false, false])]
+#[case::onto_other(&["pick aaa c", "pick bbb c", "pick ccc c"], Some("fff"), &[true, true, true])]
+#[case::reordered(&["pick bbb c", "pick aaa c", "pick ccc c"], None, &[false, true, true])]
+#[case::reword(&["pick aaa c", "reword bbb c", "pick ccc c"], None, &[false, true, true])]
+#[case::edit(&["pick aaa c", "edit bbb c", "pick ccc c"], None, &[false, false, false])]
+#[case::fixup(&["pick aaa c", "pick bbb c", "fixup ccc c"], None, &[false, true, true])]
+#[case::squash(&["pick aaa c", "squash bbb c", "pick ccc c"], None, &[true, true, true])]
+#[case::drop(&["pick aaa c", "drop bbb c", "pick ccc c"], None, &[false, false, true])]
+#[case::exec(&["pick aaa c", "exec echo foo", "pick bbb c"], None, &[false, false, false])]
+#[case::reset(&["pick aaa c", "reset onto", "pick bbb c"], None, &[false, false, true])]
+#[case::missing_commit(&["pick eee c", "pick aaa c"], None, &[true, true])]
+fn rewritten_lines(#[case] lines: &[&str], #[case] onto: Option<&str>, #[case] expected: &[bool]) {
+	module_test(lines, &[], |mut test_context| {
+		let todo_file = test_context.take_todo_file();
+		assert_eq!(
+			get_rewritten_lines(&todo_file, &create_rewritten_commits(), onto),
+			expected
+		);
+	});
+}
+
+#[test]
+fn custom_column_order() {
+	module_test(&["pick aaaaaaaa comment"], &[], |mut test_context| {
+		let config = create_config(&[ListColumn::Subject, ListColumn::Hash]);
+		let mut module = create_list(&config, test_context.take_todo_file());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Options AssertRenderOptions::EXCLUDE_STYLE,
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{Selected} > commentaaaaaaaa {Pad( )}"
+		);
+	});
+}
+
+#[test]
+fn missing_commit() {
+	module_test(&["pick aaaaaaaa comment"], &[], |mut test_context| {
+		let config = create_config(&[
+			ListColumn::Hash,
+			ListColumn::Author,
+			ListColumn::Date,
+			ListColumn::Age,
+			ListColumn::Refs,
+			ListColumn::Subject,
+		]);
+		let mut module = create_list(&config, test_context.take_todo_file());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			Options AssertRenderOptions::EXCLUDE_STYLE,
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			format!("{{Selected}} > aaaaaaaa {:16}{:17}{:15}comment{{Pad( )}}", "", "", "")
+		);
+	});
+}
+
+#[test]
+fn commits_loaded_once() {
+	module_test(&["pick aaaaaaaa comment"], &[], |mut test_context| {
+		let config = create_config(&[ListColumn::Author, ListColumn::Subject]);
+		let mut module = create_list(&config, test_context.take_todo_file());
+		_ = test_context.build_view_data(&mut module);
+		assert_eq!(module.commits.len(), 1);
+		assert!(module.commits.get("aaaaaaaa").is_some_and(Option::is_none));
+		_ = test_context.build_view_data(&mut module);
+		assert_eq!(module.commits.len(), 1);
+	});
+}
+
+#[test]
+fn commits_not_loaded_without_commit_columns() {
+	module_test(&["pick aaaaaaaa comment"], &[], |mut test_context| {
+		let mut module = create_list(&Config::new(), test_context.take_todo_file());
+		_ = test_context.build_view_data(&mut module);
+		assert!(module.commits.is_empty());
+	});
+}
+
+#[rstest]
+#[case::now(30, "now", "now")]
+#[case::minute(60, "1 minute ago", "1m")]
+#[case::minutes(60 * 59, "59 minutes ago", "59m")]
+#[case::hour(60 * 60, "1 hour ago", "1h")]
+#[case::hours(60 * 60 * 23, "23 hours ago", "23h")]
+#[case::day(DAY, "1 day ago", "1d")]
+#[case::days(DAY * 6, "6 days ago", "6d")]
+#[case::week(DAY * 7, "1 week ago", "1w")]
+#[case::weeks(DAY * 29, "4 weeks ago", "4w")]
+#[case::month(DAY * 30, "1 month ago", "1mo")]
+#[case::months(DAY * 364, "12 months ago", "12mo")]
+#[case::year(DAY * 365, "1 year ago", "1y")]
+#[case::years(DAY * 365 * 3, "3 years ago", "3y")]
+fn age(#[case] seconds: i64, #[case] full: &str, #[case] compact: &str) {
+	assert_eq!(format_age(seconds, true), full);
+	assert_eq!(format_age(seconds, false), compact);
+}
@@ -0,0 +1,122 @@
+use view::assert_rendered_output;
+
+use super::*;
+use crate::testutil::module_test;
+
+const MERGE_BLOCKS: [&str; 9] = [
+	"label onto",
+	"reset onto",
+	"pick aaa c1",
+	"label branch-a",
+	"reset onto",
+	"pick bbb c2",
+	"label branch-b",
+	"reset onto",
+	"merge -C ccc branch-a",
+];
+
+#[test]
+fn select_block() {
+	module_test(
+		&MERGE_BLOCKS,
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::SelectBlock),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::Visual);
+			assert_eq!(module.visual_index_start, Some(1));
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 3);
+		},
+	);
+}
+
+#[test]
+fn select_block_outside_block() {
+	module_test(&MERGE_BLOCKS, &[Event::from(MetaEvent::SelectBlock)], |mut test_context| {
+		let mut module = create_list(&Config::new(), test_context.take_todo_file());
+		_ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.state, ListState::Normal);
+		assert_eq!(module.visual_index_start, None);
+	});
+}
+
+#[test]
+fn normal_mode_swap_block_up() {
+	module_test(
+		&MERGE_BLOCKS,
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::SwapBlockUp),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Options AssertRenderOptions::EXCLUDE_STYLE,
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"   label onto",
+				"   reset onto",
+				"{Selected} > pick  bbb      c2{Pad( )}",
+				"   label branch-b",
+				"   reset onto",
+				"   pick  aaa      c1",
+				"   label branch-a",
+				"   reset onto",
+				"   merge -C ccc branch-a"
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_swap_block_down() {
+	module_test(
+		&MERGE_BLOCKS,
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::SelectBlock),
+			Event::from(MetaEvent::SwapBlockDown),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Options AssertRenderOptions::EXCLUDE_STYLE,
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"   label onto",
+				"   reset onto",
+				"   pick  bbb      c2",
+				"   label branch-b",
+				"{Selected} > reset onto{Pad( )}",
+				"{Selected} > pick  aaa      c1{Pad( )}",
+				"{Selected} > label branch-a{Pad( )}",
+				"   reset onto",
+				"   merge -C ccc branch-a"
+			);
+		},
+	);
+}
+
+#[test]
+fn swap_block_outside_block() {
+	module_test(&MERGE_BLOCKS, &[Event::from(MetaEvent::SwapBlockDown)], |mut test_context| {
+		let mut module = create_list(&Config::new(), test_context.take_todo_file());
+		_ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.todo_file.lock().get_line(1).unwrap().get_content(), "onto");
+		assert_eq!(module.todo_file.lock().get_selected_line_index(), 0);
+	});
+}
@@ -1,27 +1,47 @@
 mod abort_and_rebase;
 mod change_action;
+mod done_lines;
 mod edit_mode;
 mod external_editor;
 mod help;
 mod insert_line;
+mod list_columns;
+mod merge_block;
+mod mouse;
 mod movement;
 mod normal_mode;
+mod preview;
 mod read_event;
 mod remove_lines;
 mod render;
+mod run_command;
 mod search;
 mod show_commit;
+mod squash_preview;
+mod status_bar;
 mod swap_lines;
+mod todo_diff;
 mod toggle_break;
 mod toggle_option;
 mod undo_redo;
 mod visual_mode;
 
+use std::path::Path;
+
 use super::*;
 use crate::testutil::module_test;
 
 pub(crate) fn create_list(config: &Config, todo_file: TodoFile) -> List {
-	List::new(config, Arc::new(Mutex::new(todo_file)))
+	let repository = Repository::open_from_path(
+		&Path::new(env!("CARGO_MANIFEST_DIR"))
+			.join("..")
+			.join("..")
+			.join("test")
+			.join("fixtures")
+			.join("simple"),
+	)
+	.unwrap();
+	List::new(config, repository, Arc::new(Mutex::new(todo_file)), None)
 }
 
 #[test]
@@ -29,6 +49,7 @@ fn resize() {
 	module_test(&["pick aaa c1"], &[Event::Resize(100, 200)], |mut test_context| {
 		let mut module = create_list(&Config::new(), test_context.take_todo_file());
 		_ = test_context.handle_all_events(&mut module);
+		assert_eq!(module.width, 100);
 		assert_eq!(module.height, 200);
 	});
 }
@@ -0,0 +1,210 @@
+use ::input::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use view::assert_rendered_output;
+
+use super::*;
+use crate::{
+	assert_results,
+	process::Artifact,
+	testutil::{module_test, ModuleTestContext},
+};
+
+fn create_mouse_event(kind: MouseEventKind, row: u16) -> Event {
+	create_mouse_event_at_column(kind, 0, row)
+}
+
+fn create_mouse_event_at_column(kind: MouseEventKind, column: u16, row: u16) -> Event {
+	Event::Mouse(MouseEvent {
+		kind,
+		column,
+		row,
+		modifiers: KeyModifiers::empty(),
+	})
+}
+
+fn render(test_context: &ModuleTestContext, module: &mut List) {
+	test_context.view_context.state.resize(300, 120);
+	module.resize(300, 120);
+	let view_data = test_context.build_view_data(module);
+	test_context.view_context.state.render(view_data);
+}
+
+#[test]
+fn click_selects_line() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[create_mouse_event(MouseEventKind::Down(MouseButton::Left), 3)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Options AssertRenderOptions::EXCLUDE_STYLE,
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"   pick aaa      c1",
+				"   pick aaa      c2",
+				"{Selected} > pick aaa      c3{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn click_title() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			create_mouse_event(MouseEventKind::Down(MouseButton::Left), 0),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn click_right_button() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[create_mouse_event(MouseEventKind::Down(MouseButton::Right), 3)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 0);
+		},
+	);
+}
+
+#[test]
+fn click_ends_visual_mode() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			create_mouse_event(MouseEventKind::Down(MouseButton::Left), 2),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+			assert_eq!(module.visual_index_start, None);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn drag_selects_range() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
+		&[
+			create_mouse_event(MouseEventKind::Down(MouseButton::Left), 2),
+			create_mouse_event(MouseEventKind::Drag(MouseButton::Left), 3),
+			create_mouse_event(MouseEventKind::Drag(MouseButton::Left), 4),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Options AssertRenderOptions::EXCLUDE_STYLE,
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"   pick aaa      c1",
+				"{Selected} > pick aaa      c2{Pad( )}",
+				"{Selected} > pick aaa      c3{Pad( )}",
+				"{Selected} > pick aaa      c4{Pad( )}"
+			);
+		},
+	);
+}
+
+#[test]
+fn drag_within_line() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			create_mouse_event(MouseEventKind::Down(MouseButton::Left), 2),
+			create_mouse_event(MouseEventKind::Drag(MouseButton::Left), 2),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			render(&test_context, &mut module);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(module.state, ListState::Normal);
+		},
+	);
+}
+
+#[test]
+fn double_click_shows_commit() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2"],
+		&[
+			create_mouse_event(MouseEventKind::Down(MouseButton::Left), 2),
+			create_mouse_event(MouseEventKind::Down(MouseButton::Left), 2),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			render(&test_context, &mut module);
+			_ = test_context.handle_event(&mut module);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(create_mouse_event(MouseEventKind::Down(MouseButton::Left), 2)),
+				Artifact::ChangeState(State::ShowCommit)
+			);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+		},
+	);
+}
+
+#[test]
+fn click_different_lines() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2"],
+		&[
+			create_mouse_event(MouseEventKind::Down(MouseButton::Left), 1),
+			create_mouse_event(MouseEventKind::Down(MouseButton::Left), 2),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			render(&test_context, &mut module);
+			_ = test_context.handle_event(&mut module);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(create_mouse_event(MouseEventKind::Down(MouseButton::Left), 2))
+			);
+		},
+	);
+}
+
+#[test]
+fn click_on_preview_pane_ignored() {
+	module_test(
+		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
+		&[
+			create_mouse_event_at_column(MouseEventKind::Down(MouseButton::Left), 149, 3),
+			create_mouse_event_at_column(MouseEventKind::Down(MouseButton::Left), 148, 2),
+		],
+		|mut test_context| {
+			let mut config = Config::new();
+			config.commit_preview = true;
+			let mut module = create_list(&config, test_context.take_todo_file());
+			render(&test_context, &mut module);
+			_ = test_context.handle_event(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 0);
+			_ = test_context.handle_event(&mut module);
+			assert_eq!(module.todo_file.lock().get_selected_line_index(), 1);
+		},
+	);
+}
@@ -30,7 +30,7 @@ fn move_down_1() {
 fn move_down_view_end() {
 	module_test(
 		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
-		&[Event::from(MetaEvent::MoveCursorDown); 2],
+		&vec![Event::from(MetaEvent::MoveCursorDown); 2],
 		|mut test_context| {
 			let mut module = create_list(&Config::new(), test_context.take_todo_file());
 			_ = test_context.handle_all_events(&mut module);
@@ -52,7 +52,7 @@ fn move_down_view_end() {
 fn move_down_past_end() {
 	module_test(
 		&["pick aaa c1", "pick aaa c2", "pick aaa c3"],
-		&[Event::from(MetaEvent::MoveCursorDown); 3],
+		&vec![Event::from(MetaEvent::MoveCursorDown); 3],
 		|mut test_context| {
 			let mut module = create_list(&Config::new(), test_context.take_todo_file());
 			_ = test_context.handle_all_events(&mut module);
@@ -152,7 +152,7 @@ fn move_up_attempt_above_top() {
 fn move_down_attempt_below_bottom() {
 	module_test(
 		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[Event::from(MetaEvent::MoveCursorDown); 4],
+		&vec![Event::from(MetaEvent::MoveCursorDown); 4],
 		|mut test_context| {
 			let mut module = create_list(&Config::new(), test_context.take_todo_file());
 			_ = test_context.handle_all_events(&mut module);
@@ -334,7 +334,7 @@ fn move_end() {
 fn move_page_down_past_bottom() {
 	module_test(
 		&["pick aaa c1", "pick aaa c2", "pick aaa c3", "pick aaa c4"],
-		&[Event::from(MetaEvent::MoveCursorPageDown); 3],
+		&vec![Event::from(MetaEvent::MoveCursorPageDown); 3],
 		|mut test_context| {
 			let mut module = create_list(&Config::new(), test_context.take_todo_file());
 			module.height = 4;
@@ -0,0 +1,199 @@
+use view::assert_rendered_output;
+
+use super::*;
+use crate::{assert_results, process::Artifact, testutil::module_test};
+
+fn create_preview_config() -> Config {
+	let mut config = Config::new();
+	config.commit_preview = true;
+	config
+}
+
+fn load_diff_artifact(hash: &str) -> Artifact {
+	Artifact::LoadDiff(String::from(hash), Arc::new(Mutex::new(None)), Interrupter::new())
+}
+
+#[test]
+fn toggle_preview_on() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::TogglePreview)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::TogglePreview)),
+				load_diff_artifact("aaa")
+			);
+			assert!(module.preview);
+		},
+	);
+}
+
+#[test]
+fn toggle_preview_off() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(MetaEvent::TogglePreview)],
+		|mut test_context| {
+			let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::TogglePreview))
+			);
+			assert!(!module.preview);
+			assert!(module.build_split_view_data(&test_context.render_context).is_none());
+		},
+	);
+}
+
+#[test]
+fn activate_loads_selected_commit() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+		assert_results!(
+			test_context.activate(&mut module, State::List),
+			load_diff_artifact("aaa")
+		);
+	});
+}
+
+#[test]
+fn cursor_move_loads_selected_commit() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::MoveCursorDown)],
+		|mut test_context| {
+			let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::MoveCursorDown)),
+				load_diff_artifact("bbb")
+			);
+		},
+	);
+}
+
+#[test]
+fn cursor_move_interrupts_previous_load() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::MoveCursorDown)],
+		|mut test_context| {
+			let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			let previous_interrupter = module.preview_interrupter.clone();
+			_ = test_context.handle_event(&mut module);
+			assert!(!previous_interrupter.should_continue());
+			assert!(module.preview_interrupter.should_continue());
+		},
+	);
+}
+
+#[test]
+fn unchanged_selection_does_not_reload() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::MoveCursorUp)],
+		|mut test_context| {
+			let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::MoveCursorUp))
+			);
+		},
+	);
+}
+
+#[test]
+fn merge_with_commit_loads_merge_commit() {
+	module_test(&["merge -C aaa label"], &[], |mut test_context| {
+		let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+		assert_results!(
+			test_context.activate(&mut module, State::List),
+			load_diff_artifact("aaa")
+		);
+	});
+}
+
+#[test]
+fn no_commit_to_preview() {
+	module_test(&["merge label"], &[], |mut test_context| {
+		let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+		assert_results!(test_context.activate(&mut module, State::List));
+		let (_, preview_view_data) = module.build_split_view_data(&test_context.render_context).unwrap();
+		assert_rendered_output!(preview_view_data, "{LEADING}", "{IndicatorColor}No commit to preview");
+	});
+}
+
+#[test]
+fn loading_commit() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+		_ = test_context.activate(&mut module, State::List);
+		let (_, preview_view_data) = module.build_split_view_data(&test_context.render_context).unwrap();
+		assert_rendered_output!(preview_view_data, "{LEADING}", "{IndicatorColor}Loading commit...");
+		assert!(module.preview_pending);
+	});
+}
+
+#[test]
+fn loading_commit_in_progress() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+		_ = test_context.activate(&mut module, State::List);
+		*module.preview_diff.lock() = Some(LoadedDiff::new_loading(String::from("aaa")));
+		let (_, preview_view_data) = module.build_split_view_data(&test_context.render_context).unwrap();
+		assert_rendered_output!(preview_view_data, "{LEADING}", "{IndicatorColor}Loading commit...");
+		assert!(module.preview_pending);
+	});
+}
+
+#[test]
+fn loaded_diff_for_other_commit_is_ignored() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+		_ = test_context.activate(&mut module, State::List);
+		*module.preview_diff.lock() = Some(LoadedDiff::new(String::from("bbb"), Err(String::from("error"))));
+		let (_, preview_view_data) = module.build_split_view_data(&test_context.render_context).unwrap();
+		assert_rendered_output!(preview_view_data, "{LEADING}", "{IndicatorColor}Loading commit...");
+	});
+}
+
+#[test]
+fn load_error() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+		_ = test_context.activate(&mut module, State::List);
+		_ = module.build_split_view_data(&test_context.render_context);
+		*module.preview_diff.lock() = Some(LoadedDiff::new(String::from("aaa"), Err(String::from("error"))));
+		let (_, preview_view_data) = module.build_split_view_data(&test_context.render_context).unwrap();
+		assert_rendered_output!(preview_view_data, "{LEADING}", "{IndicatorColor}Unable to load commit: error");
+		assert!(!module.preview_pending);
+	});
+}
+
+#[test]
+fn no_split_in_edit_mode() {
+	module_test(&["exec foo"], &[Event::from(MetaEvent::Edit)], |mut test_context| {
+		let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+		_ = test_context.handle_all_events(&mut module);
+		assert!(module.build_split_view_data(&test_context.render_context).is_none());
+	});
+}
+
+#[test]
+fn no_split_with_help() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(StandardEvent::Help)],
+		|mut test_context| {
+			let mut module = create_list(&create_preview_config(), test_context.take_todo_file());
+			_ = test_context.handle_all_events(&mut module);
+			assert!(module.build_split_view_data(&test_context.render_context).is_none());
+		},
+	);
+}
@@ -55,9 +55,17 @@ fn search() {
 #[case::insertline('I', MetaEvent::InsertLine)]
 #[case::swapselecteddown('j', MetaEvent::SwapSelectedDown)]
 #[case::swapselectedup('k', MetaEvent::SwapSelectedUp)]
+#[case::swapblockdown('J', MetaEvent::SwapBlockDown)]
+#[case::swapblockup('K', MetaEvent::SwapBlockUp)]
+#[case::selectblock('B', MetaEvent::SelectBlock)]
 #[case::openineditor('!', MetaEvent::OpenInEditor)]
 #[case::rebase('w', MetaEvent::Rebase)]
 #[case::showcommit('c', MetaEvent::ShowCommit)]
+#[case::showsquashpreview('S', MetaEvent::ShowSquashPreview)]
+#[case::showtododiff('D', MetaEvent::ShowTodoDiff)]
+#[case::updaterefs('R', MetaEvent::UpdateRefs)]
+#[case::runcommand('x', MetaEvent::RunCommand)]
+#[case::togglepreview('P', MetaEvent::TogglePreview)]
 #[case::togglevisualmode('v', MetaEvent::ToggleVisualMode)]
 fn default_events_single_char(#[case] binding: char, #[case] expected: MetaEvent) {
 	read_event_test(Event::from(binding), |mut context| {
@@ -146,7 +154,7 @@ fn mouse_other() {
 		row: 0,
 		modifiers: KeyModifiers::empty(),
 	});
-	read_event_test(mouse_event, |mut context| {
+	read_event_test(mouse_event.clone(), |mut context| {
 		let mut module = create_list(&Config::new(), context.take_todo_file());
 		assert_eq!(context.read_event(&module), mouse_event);
 	});
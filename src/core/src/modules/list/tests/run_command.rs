@@ -0,0 +1,37 @@
+use super::*;
+use crate::{assert_results, process::Artifact, testutil::module_test};
+
+#[test]
+fn normal_mode_run_command() {
+	module_test(
+		&["exec echo foo"],
+		&[Event::from(MetaEvent::RunCommand)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::RunCommand)),
+				Artifact::ChangeState(State::RunCommand)
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_run_command() {
+	module_test(
+		&["exec echo foo"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::RunCommand),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_event(&mut module);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::RunCommand))
+			);
+		},
+	);
+}
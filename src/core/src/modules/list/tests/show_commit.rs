@@ -17,6 +17,33 @@ fn when_hash_available() {
 	);
 }
 
+#[test]
+fn when_merge_with_commit() {
+	module_test(
+		&["merge -C aaa label"],
+		&[Event::from(MetaEvent::ShowCommit)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ShowCommit)),
+				Artifact::ChangeState(State::ShowCommit)
+			);
+		},
+	);
+}
+
+#[test]
+fn do_not_when_merge_without_commit() {
+	module_test(&["merge label"], &[Event::from(MetaEvent::ShowCommit)], |mut test_context| {
+		let mut module = create_list(&Config::new(), test_context.take_todo_file());
+		assert_results!(
+			test_context.handle_event(&mut module),
+			Artifact::Event(Event::from(MetaEvent::ShowCommit))
+		);
+	});
+}
+
 #[test]
 fn when_no_selected_line() {
 	module_test(&[], &[Event::from(MetaEvent::ShowCommit)], |mut test_context| {
@@ -42,3 +69,109 @@ fn do_not_when_hash_not_available() {
 		},
 	);
 }
+
+#[test]
+fn visual_mode_range() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ShowCommit),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_n_events(&mut module, 3);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ShowCommit)),
+				Artifact::ShowRange(String::from("aaa"), String::from("ccc"))
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_range_selected_upwards() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorUp),
+			Event::from(MetaEvent::ShowCommit),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_n_events(&mut module, 3);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ShowCommit)),
+				Artifact::ShowRange(String::from("aaa"), String::from("bbb"))
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_range_skips_lines_without_commit() {
+	module_test(
+		&["exec echo foo", "pick aaa c1", "break", "pick bbb c2", "label foo"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorEnd),
+			Event::from(MetaEvent::ShowCommit),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_n_events(&mut module, 2);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ShowCommit)),
+				Artifact::ShowRange(String::from("aaa"), String::from("bbb"))
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_range_single_commit() {
+	module_test(
+		&["pick aaa c1", "break"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ShowCommit),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_n_events(&mut module, 2);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ShowCommit)),
+				Artifact::ShowRange(String::from("aaa"), String::from("aaa"))
+			);
+		},
+	);
+}
+
+#[test]
+fn visual_mode_range_without_commits() {
+	module_test(
+		&["exec echo foo", "break"],
+		&[
+			Event::from(MetaEvent::ToggleVisualMode),
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ShowCommit),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_n_events(&mut module, 2);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ShowCommit))
+			);
+		},
+	);
+}
@@ -0,0 +1,93 @@
+use super::*;
+use crate::{assert_results, process::Artifact, testutil::module_test};
+
+#[test]
+fn with_fixup_and_squash_lines() {
+	module_test(
+		&[
+			"pick aaa c1",
+			"fixup bbb c2",
+			"squash ccc c3",
+			"fixup -C ddd c4",
+			"pick eee c5",
+		],
+		&[Event::from(MetaEvent::ShowSquashPreview)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ShowSquashPreview)),
+				Artifact::ShowSquash(vec![
+					Line::new("pick aaa c1").unwrap(),
+					Line::new("fixup bbb c2").unwrap(),
+					Line::new("squash ccc c3").unwrap(),
+					Line::new("fixup -C ddd c4").unwrap(),
+				])
+			);
+		},
+	);
+}
+
+#[test]
+fn from_reword_line() {
+	module_test(
+		&["reword aaa c1", "fixup bbb c2"],
+		&[Event::from(MetaEvent::ShowSquashPreview)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ShowSquashPreview)),
+				Artifact::ShowSquash(vec![
+					Line::new("reword aaa c1").unwrap(),
+					Line::new("fixup bbb c2").unwrap(),
+				])
+			);
+		},
+	);
+}
+
+#[test]
+fn do_not_without_following_fixup() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2", "fixup ccc c3"],
+		&[Event::from(MetaEvent::ShowSquashPreview)],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ShowSquashPreview))
+			);
+		},
+	);
+}
+
+#[test]
+fn do_not_from_fixup_line() {
+	module_test(
+		&["pick aaa c1", "fixup bbb c2", "fixup ccc c3"],
+		&[
+			Event::from(MetaEvent::MoveCursorDown),
+			Event::from(MetaEvent::ShowSquashPreview),
+		],
+		|mut test_context| {
+			let mut module = create_list(&Config::new(), test_context.take_todo_file());
+			_ = test_context.handle_event(&mut module);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ShowSquashPreview))
+			);
+		},
+	);
+}
+
+#[test]
+fn do_not_when_no_selected_line() {
+	module_test(&[], &[Event::from(MetaEvent::ShowSquashPreview)], |mut test_context| {
+		let mut module = create_list(&Config::new(), test_context.take_todo_file());
+		assert_results!(
+			test_context.handle_event(&mut module),
+			Artifact::Event(Event::from(MetaEvent::ShowSquashPreview))
+		);
+	});
+}
@@ -0,0 +1,219 @@
+use git::{testutil::ReferenceBuilder, ReferenceKind};
+use view::assert_rendered_output;
+
+use super::*;
+use crate::testutil::module_test;
+
+fn create_status_bar_config() -> Config {
+	let mut config = Config::new();
+	config.status_bar = true;
+	config
+}
+
+// the list of a rebase of the master branch, since the rebase state is only read from a rebase in progress
+fn create_status_bar_list(todo_file: TodoFile) -> List {
+	let mut module = create_list(&create_status_bar_config(), todo_file);
+	module.rebase_head_name = Some(String::from("master"));
+	module
+}
+
+#[test]
+fn rebase_context_and_action_counts() {
+	module_test(
+		&["pick aaa c1", "fixup bbb c2", "drop ccc c3", "pick ddd c4", "exec foo"],
+		&[],
+		|mut test_context| {
+			let mut module = create_status_bar_list(test_context.take_todo_file());
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{{Any(5)}}",
+				"{TRAILING}",
+				"{Normal}Rebasing {RefBranchColor}master{Normal} | 2 pick, 1 fixup, 1 drop, 1 exec"
+			);
+		},
+	);
+}
+
+#[test]
+fn rebase_onto() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		let mut module = create_status_bar_list(test_context.take_todo_file());
+		module.rebase_onto_name = Some(String::from("main"));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{{Any(1)}}",
+			"{TRAILING}",
+			"{Normal}Rebasing {RefBranchColor}master{Normal} onto {RefBranchColor}main{Normal} | 1 pick"
+		);
+	});
+}
+
+#[test]
+fn without_rebase_context() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		let mut module = create_list(&create_status_bar_config(), test_context.take_todo_file());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{{Any(1)}}",
+			"{TRAILING}",
+			"{Normal}1 pick"
+		);
+	});
+}
+
+#[test]
+fn modified() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::ActionDrop)],
+		|mut test_context| {
+			let mut module = create_list(&create_status_bar_config(), test_context.take_todo_file());
+			_ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{{Any(2)}}",
+				"{TRAILING}",
+				"{Normal}1 pick, 1 drop | {IndicatorColor}modified"
+			);
+		},
+	);
+}
+
+#[test]
+fn modified_undone() {
+	module_test(
+		&["pick aaa c1", "pick bbb c2"],
+		&[Event::from(MetaEvent::ActionDrop), Event::from(StandardEvent::Undo)],
+		|mut test_context| {
+			let mut module = create_list(&create_status_bar_config(), test_context.take_todo_file());
+			_ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{{Any(2)}}",
+				"{TRAILING}",
+				"{Normal}2 pick"
+			);
+		},
+	);
+}
+
+#[test]
+fn search_results() {
+	module_test(
+		&["pick aaa x1", "pick bbb x2"],
+		&[
+			Event::from(StandardEvent::SearchStart),
+			Event::from('x'),
+			Event::from(StandardEvent::SearchFinish),
+		],
+		|mut test_context| {
+			let mut module = create_list(&create_status_bar_config(), test_context.take_todo_file());
+			_ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{{Any(2)}}",
+				"{TRAILING}",
+				"{Normal}2 pick | [x]: 1/2"
+			);
+		},
+	);
+}
+
+#[test]
+fn search_editing() {
+	module_test(
+		&["pick aaa x1"],
+		&[Event::from(StandardEvent::SearchStart), Event::from('x')],
+		|mut test_context| {
+			let mut module = create_list(&create_status_bar_config(), test_context.take_todo_file());
+			_ = test_context.handle_all_events(&mut module);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}{HELP}",
+				"{BODY}",
+				"{{Any(1)}}",
+				"{TRAILING}",
+				"{Normal}/x{Normal,Underline}",
+				"{Normal}1 pick"
+			);
+		},
+	);
+}
+
+#[test]
+fn empty_todo_file() {
+	module_test(&[], &[], |mut test_context| {
+		let mut module = create_status_bar_list(test_context.take_todo_file());
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{LEADING}",
+			"{IndicatorColor}Rebase todo file is empty",
+			"{TRAILING}",
+			"{Normal}Rebasing {RefBranchColor}master"
+		);
+	});
+}
+
+#[test]
+fn rebase_onto_name_from_branch() {
+	let mut references = HashMap::new();
+	_ = references.insert(String::from("0123456789abcdef"), vec![
+		ReferenceBuilder::new("0123456789abcdef").build(),
+	]);
+	assert_eq!(get_rebase_onto_name("0123456789abcdef", &references), "main");
+}
+
+#[test]
+fn rebase_onto_name_prefers_branch() {
+	let mut tag = ReferenceBuilder::new("0123456789abcdef");
+	_ = tag.shorthand("v1.0.0").kind(ReferenceKind::Tag);
+	let mut references = HashMap::new();
+	_ = references.insert(String::from("0123456789abcdef"), vec![
+		tag.build(),
+		ReferenceBuilder::new("0123456789abcdef").build(),
+	]);
+	assert_eq!(get_rebase_onto_name("0123456789abcdef", &references), "main");
+}
+
+#[test]
+fn rebase_onto_name_without_reference() {
+	assert_eq!(get_rebase_onto_name("0123456789abcdef", &HashMap::new()), "01234567");
+}
+
+#[test]
+fn done_count() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		let mut module = create_status_bar_list(test_context.take_todo_file());
+		module.done_lines = vec![Line::new("pick bbb c2").unwrap(), Line::new("pick ccc c3").unwrap()];
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}{HELP}",
+			"{BODY}",
+			"{{Any(3)}}",
+			"{TRAILING}",
+			"{Normal}Rebasing {RefBranchColor}master{Normal} | 2 done, 1 pick"
+		);
+	});
+}
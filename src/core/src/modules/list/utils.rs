@@ -1,8 +1,10 @@
-use std::cmp;
+use std::{cmp, collections::HashMap};
 
 use bitflags::bitflags;
-use config::KeyBindings;
+use chrono::{DateTime, Local};
+use config::{KeyBindings, ListColumn};
 use display::DisplayColor;
+use git::{Commit, Reference, ReferenceKind};
 use todo_file::{Action, Line, TodoFile};
 use view::LineSegment;
 
@@ -82,11 +84,56 @@ fn build_help_lines(key_bindings: &KeyBindings, selector: HelpLinesSelector) ->
 			"Move selected lines up",
 			HelpLinesSelector::Common,
 		),
+		(
+			&key_bindings.move_block_down,
+			"Move merge branch block down",
+			HelpLinesSelector::Common,
+		),
+		(
+			&key_bindings.move_block_up,
+			"Move merge branch block up",
+			HelpLinesSelector::Common,
+		),
+		(
+			&key_bindings.select_block,
+			"Select merge branch block",
+			HelpLinesSelector::Common,
+		),
 		(
 			&key_bindings.show_commit,
 			"Show commit information",
 			HelpLinesSelector::Normal,
 		),
+		(
+			&key_bindings.show_commit,
+			"Show the combined changes of the selected commits",
+			HelpLinesSelector::Visual,
+		),
+		(
+			&key_bindings.show_squash_preview,
+			"Preview squashing the commit with its fixups and squashes",
+			HelpLinesSelector::Normal,
+		),
+		(
+			&key_bindings.update_refs,
+			"Manage the update-refs of branches",
+			HelpLinesSelector::Normal,
+		),
+		(
+			&key_bindings.run_command,
+			"Run exec line or command",
+			HelpLinesSelector::Normal,
+		),
+		(
+			&key_bindings.show_todo_diff,
+			"Show changes made to the todo list",
+			HelpLinesSelector::Common,
+		),
+		(
+			&key_bindings.toggle_preview,
+			"Toggle the preview of the selected commit",
+			HelpLinesSelector::Common,
+		),
 		(
 			&key_bindings.action_break,
 			"Toggle break action",
@@ -196,10 +243,11 @@ const fn get_action_color(action: Action) -> DisplayColor {
 	}
 }
 
-pub(super) fn get_line_action_maximum_width(todo_file: &TodoFile) -> usize {
+// the widest action of the done lines and the lines of the todo file
+pub(super) fn get_line_action_maximum_width(todo_file: &TodoFile, done_lines: &[Line]) -> usize {
 	let mut max_width = 0;
 
-	for line in todo_file.lines_iter() {
+	for line in done_lines.iter().chain(todo_file.lines_iter()) {
 		let action_length = match line.get_action() {
 			// allow these to overflow their bounds
 			&Action::Exec | &Action::UpdateRef => 0,
@@ -229,15 +277,205 @@ bitflags! {
 		const SELECTED = 0b0000_0010;
 		const FULL_WIDTH = 0b0000_0100;
 		const SEARCH_LINE = 0b0000_1000;
+		const REWRITTEN = 0b0001_0000;
 	}
 }
 
+// the widths of the commit metadata columns, in full and compact width
+const AUTHOR_WIDTH: (usize, usize) = (15, 3);
+const DATE_WIDTH: (usize, usize) = (16, 8);
+const AGE_WIDTH: (usize, usize) = (14, 4);
+
+const fn column_width(widths: (usize, usize), is_full_width: bool) -> usize {
+	if is_full_width { widths.0 } else { widths.1 }
+}
+
+pub(super) const fn is_commit_column(column: ListColumn) -> bool {
+	matches!(
+		column,
+		ListColumn::Author | ListColumn::Date | ListColumn::Age | ListColumn::Refs | ListColumn::Signature
+	)
+}
+
+// Follows the todo list the way the rebase will, to find the lines with commits that are rewritten, and so lose any
+// signature. A picked commit is kept as is when its parent is the commit the rebase has reached, which starts at the
+// commit the rebase is onto, or the parent of the first commit when that is unknown. Once a commit is rewritten, every
+// following commit has a new parent, and is rewritten too.
+pub(super) fn get_rewritten_lines(
+	todo_file: &TodoFile,
+	commits: &HashMap<String, Option<Commit>>,
+	onto: Option<&str>,
+) -> Vec<bool> {
+	let get_commit = |line: &Line| commits.get(line.get_hash()).and_then(Option::as_ref);
+	let mut rewritten = vec![];
+	let mut head = onto.map(String::from).or_else(|| {
+		todo_file
+			.lines_iter()
+			.find(|line| matches!(*line.get_action(), Action::Pick | Action::Edit))
+			.and_then(get_commit)
+			.and_then(|commit| commit.parent_hash().map(String::from))
+	});
+	let mut last_commit_index = None;
+
+	for (index, line) in todo_file.lines_iter().enumerate() {
+		let is_rewritten = match *line.get_action() {
+			Action::Pick | Action::Edit => {
+				let commit = get_commit(line);
+				last_commit_index = Some(index);
+				if head.is_some() && commit.and_then(Commit::parent_hash) == head.as_deref() {
+					head = commit.map(|c| String::from(c.hash()));
+					false
+				}
+				else {
+					head = None;
+					true
+				}
+			},
+			Action::Reword => {
+				last_commit_index = Some(index);
+				head = None;
+				true
+			},
+			// the commit is combined with the previous commit, so both are rewritten
+			Action::Fixup | Action::Squash => {
+				if let Some(last_commit) = last_commit_index.and_then(|i| rewritten.get_mut(i)) {
+					*last_commit = true;
+				}
+				head = None;
+				true
+			},
+			Action::Merge | Action::Reset => {
+				head = None;
+				false
+			},
+			Action::Break | Action::Drop | Action::Exec | Action::Label | Action::Noop | Action::UpdateRef => false,
+		};
+		rewritten.push(is_rewritten);
+	}
+	rewritten
+}
+
+fn get_signature_column(commit: Option<&Commit>, is_rewritten: bool) -> LineSegment {
+	match commit.and_then(Commit::signature) {
+		Some(_) if is_rewritten => LineSegment::new_with_color("! ", DisplayColor::DiffRemoveColor),
+		Some(_) => LineSegment::new_with_color("S ", DisplayColor::DiffAddColor),
+		None => LineSegment::new("  "),
+	}
+}
+
+fn get_author_column(commit: Option<&Commit>, is_full_width: bool) -> String {
+	let name = commit.and_then(|c| c.author().name()).unwrap_or("");
+	let author = if is_full_width {
+		String::from(name)
+	}
+	else {
+		name.split_whitespace().filter_map(|part| part.chars().next()).collect()
+	};
+	let width = column_width(AUTHOR_WIDTH, is_full_width);
+	format!("{:width$} ", author.chars().take(width).collect::<String>())
+}
+
+fn get_date_column(commit: Option<&Commit>, is_full_width: bool) -> String {
+	let date = commit.map_or_else(String::new, |c| {
+		c.committed_date()
+			.format(if is_full_width { "%Y-%m-%d %H:%M" } else { "%y-%m-%d" })
+			.to_string()
+	});
+	format!("{:width$} ", date, width = column_width(DATE_WIDTH, is_full_width))
+}
+
+fn get_age_column(commit: Option<&Commit>, now: DateTime<Local>, is_full_width: bool) -> String {
+	let age = commit.map_or_else(String::new, |c| {
+		format_age((now - *c.committed_date()).num_seconds(), is_full_width)
+	});
+	format!("{:width$} ", age, width = column_width(AGE_WIDTH, is_full_width))
+}
+
+#[allow(clippy::integer_division)]
+pub(super) fn format_age(seconds: i64, is_full_width: bool) -> String {
+	const MINUTE: i64 = 60;
+	const HOUR: i64 = MINUTE * 60;
+	const DAY: i64 = HOUR * 24;
+	const WEEK: i64 = DAY * 7;
+	const MONTH: i64 = DAY * 30;
+	const YEAR: i64 = DAY * 365;
+
+	let (value, unit, abbreviation) = match seconds {
+		s if s < MINUTE => return String::from("now"),
+		s if s < HOUR => (s / MINUTE, "minute", "m"),
+		s if s < DAY => (s / HOUR, "hour", "h"),
+		s if s < WEEK => (s / DAY, "day", "d"),
+		s if s < MONTH => (s / WEEK, "week", "w"),
+		s if s < YEAR => (s / MONTH, "month", "mo"),
+		s => (s / YEAR, "year", "y"),
+	};
+
+	if is_full_width {
+		format!("{value} {unit}{} ago", if value == 1 { "" } else { "s" })
+	}
+	else {
+		format!("{value}{abbreviation}")
+	}
+}
+
+fn get_reference_segment(reference: &Reference) -> LineSegment {
+	match reference.kind() {
+		ReferenceKind::Branch => LineSegment::new_with_color(reference.shortname(), DisplayColor::RefBranchColor),
+		ReferenceKind::Remote => LineSegment::new_with_color(reference.shortname(), DisplayColor::RefRemoteColor),
+		ReferenceKind::Tag => {
+			LineSegment::new_with_color(
+				format!("tag: {}", reference.shortname()).as_str(),
+				DisplayColor::RefTagColor,
+			)
+		},
+		ReferenceKind::Note | ReferenceKind::Other => LineSegment::new(reference.shortname()),
+	}
+}
+
+// a line that was already applied by the rebase, which is shown dimmed, and cannot be selected or changed
+pub(super) fn get_done_line_segments(
+	line: &Line,
+	is_full_width: bool,
+	maximum_action_width: usize,
+) -> Vec<LineSegment> {
+	let action = line.get_action();
+	let mut text = if is_full_width {
+		let action_name = line
+			.option()
+			.map_or_else(|| action.to_string(), |opt| format!("{action} {opt}"));
+		format!("   {action_name:maximum_action_width$} ")
+	}
+	else {
+		format!(
+			" {:1}{}",
+			action.to_abbreviation(),
+			if line.option().is_some() { "*" } else { " " }
+		)
+	};
+	if line.has_reference() {
+		let hash_width = if is_full_width { 8 } else { 3 };
+		let hash = line.get_hash();
+		text.push_str(format!("{:hash_width$} ", hash.get(0..hash_width).unwrap_or(hash)).as_str());
+	}
+	text.push_str(line.get_content());
+	vec![LineSegment::new_with_color_and_style(
+		text.as_str(),
+		DisplayColor::Normal,
+		true,
+		false,
+		false,
+	)]
+}
+
 // safe slice, as it is only on the hash, which is hexadecimal
-#[allow(clippy::string_slice)]
+#[allow(clippy::string_slice, clippy::too_many_lines)]
 pub(super) fn get_todo_line_segments(
 	line: &Line,
+	commit: Option<&Commit>,
+	references: &[Reference],
 	search_term: Option<&str>,
 	options: TodoLineSegmentsOptions,
+	columns: &[ListColumn],
 	maximum_action_width: usize,
 ) -> Vec<LineSegment> {
 	let mut segments: Vec<LineSegment> = vec![];
@@ -246,8 +484,19 @@ pub(super) fn get_todo_line_segments(
 	let selected = options.contains(TodoLineSegmentsOptions::SELECTED);
 	let is_full_width = options.contains(TodoLineSegmentsOptions::FULL_WIDTH);
 	let is_search_index = options.contains(TodoLineSegmentsOptions::SEARCH_LINE);
+	let is_rewritten = options.contains(TodoLineSegmentsOptions::REWRITTEN);
 
 	let action = line.get_action();
+	let is_commit_line = match *action {
+		Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => true,
+		Action::Exec
+		| Action::Label
+		| Action::Reset
+		| Action::Merge
+		| Action::Break
+		| Action::Noop
+		| Action::UpdateRef => false,
+	};
 
 	let indicator = if is_cursor_line || selected {
 		if is_full_width { " > " } else { ">" }
@@ -267,83 +516,200 @@ pub(super) fn get_todo_line_segments(
 		false,
 	));
 
-	let action_name = if is_full_width {
-		if let Some(opt) = line.option() {
-			format!("{:maximum_action_width$} ", format!("{action} {opt}"))
-		}
-		else {
-			format!("{:maximum_action_width$} ", action.to_string())
-		}
-	}
-	else {
-		format!(
-			"{:1}{}",
-			action.to_abbreviation(),
-			if line.option().is_some() { "*" } else { " " }
-		)
-	};
-
-	segments.push(LineSegment::new_with_color(
-		action_name.as_str(),
-		get_action_color(*action),
-	));
-
-	// render hash
-	match *action {
-		Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
-			let action_width = if is_full_width { 8 } else { 3 };
-			let max_index = cmp::min(line.get_hash().len(), action_width);
-			let search_match = search_term.map_or(false, |term| line.get_hash().starts_with(term));
-
-			segments.push(LineSegment::new_with_color_and_style(
-				format!(
-					"{:width$}",
-					line.get_hash()[0..max_index].to_string(), // safe slice, ascii only
-					width = action_width
-				)
-				.as_str(),
-				if search_match {
-					DisplayColor::IndicatorColor
+	for column in columns {
+		match *column {
+			ListColumn::Action => {
+				let action_name = if is_full_width {
+					if let Some(opt) = line.option() {
+						format!("{:maximum_action_width$} ", format!("{action} {opt}"))
+					}
+					else {
+						format!("{:maximum_action_width$} ", action.to_string())
+					}
 				}
 				else {
-					DisplayColor::Normal
-				},
-				false,
-				search_match && is_search_index,
-				false,
-			));
-			segments.push(LineSegment::new(" "));
-		},
-		Action::Exec
-		| Action::Label
-		| Action::Reset
-		| Action::Merge
-		| Action::Break
-		| Action::Noop
-		| Action::UpdateRef => {},
-	}
+					format!(
+						"{:1}{}",
+						action.to_abbreviation(),
+						if line.option().is_some() { "*" } else { " " }
+					)
+				};
+
+				segments.push(LineSegment::new_with_color(
+					action_name.as_str(),
+					get_action_color(*action),
+				));
+			},
+			ListColumn::Hash if is_commit_line => {
+				let action_width = if is_full_width { 8 } else { 3 };
+				let max_index = cmp::min(line.get_hash().len(), action_width);
+				let search_match = search_term.is_some_and(|term| line.get_hash().starts_with(term));
 
-	let content = line.get_content();
-	if !content.is_empty() {
-		if let Some(term) = search_term {
-			let mut split_iter = content.split(term);
-			segments.push(LineSegment::new(split_iter.next().unwrap()));
-			for split in split_iter {
 				segments.push(LineSegment::new_with_color_and_style(
-					term,
-					DisplayColor::IndicatorColor,
+					format!(
+						"{:width$}",
+						line.get_hash()[0..max_index].to_string(), // safe slice, ascii only
+						width = action_width
+					)
+					.as_str(),
+					if search_match {
+						DisplayColor::IndicatorColor
+					}
+					else {
+						DisplayColor::Normal
+					},
+					false,
+					search_match && is_search_index,
+					false,
+				));
+				segments.push(LineSegment::new(" "));
+			},
+			ListColumn::Author if is_commit_line => {
+				segments.push(LineSegment::new(get_author_column(commit, is_full_width).as_str()));
+			},
+			ListColumn::Date if is_commit_line => {
+				segments.push(LineSegment::new_with_color_and_style(
+					get_date_column(commit, is_full_width).as_str(),
+					DisplayColor::Normal,
+					true,
 					false,
-					is_search_index,
 					false,
 				));
-				if !split.is_empty() {
-					segments.push(LineSegment::new(split));
+			},
+			ListColumn::Age if is_commit_line => {
+				segments.push(LineSegment::new_with_color_and_style(
+					get_age_column(commit, Local::now(), is_full_width).as_str(),
+					DisplayColor::Normal,
+					true,
+					false,
+					false,
+				));
+			},
+			ListColumn::Refs if is_commit_line && !references.is_empty() => {
+				segments.push(LineSegment::new("("));
+				for (index, reference) in references.iter().enumerate() {
+					if index > 0 {
+						segments.push(LineSegment::new(", "));
+					}
+					segments.push(get_reference_segment(reference));
 				}
-			}
+				segments.push(LineSegment::new(") "));
+			},
+			ListColumn::Signature if is_commit_line => {
+				segments.push(get_signature_column(commit, is_rewritten));
+			},
+			ListColumn::Subject => {
+				let content = line.get_content();
+				if !content.is_empty() {
+					if let Some(term) = search_term {
+						let mut split_iter = content.split(term);
+						segments.push(LineSegment::new(split_iter.next().unwrap()));
+						for split in split_iter {
+							segments.push(LineSegment::new_with_color_and_style(
+								term,
+								DisplayColor::IndicatorColor,
+								false,
+								is_search_index,
+								false,
+							));
+							if !split.is_empty() {
+								segments.push(LineSegment::new(split));
+							}
+						}
+					}
+					else {
+						segments.push(LineSegment::new(content));
+					}
+				}
+			},
+			_ => {},
+		}
+	}
+	segments
+}
+
+// the order that the action counts are shown in the status line
+const STATUS_ACTIONS: [Action; 12] = [
+	Action::Pick,
+	Action::Reword,
+	Action::Edit,
+	Action::Squash,
+	Action::Fixup,
+	Action::Drop,
+	Action::Exec,
+	Action::Break,
+	Action::Label,
+	Action::Reset,
+	Action::Merge,
+	Action::UpdateRef,
+];
+
+// A branch pointing at the commit the rebase is onto reads better than a hash, so it is used when there is one.
+pub(super) fn get_rebase_onto_name(onto: &str, references: &HashMap<String, Vec<Reference>>) -> String {
+	let onto_references = references.get(onto).map_or(&[][..], Vec::as_slice);
+	onto_references
+		.iter()
+		.find(|reference| reference.kind() == ReferenceKind::Branch)
+		.or_else(|| onto_references.first())
+		.map_or_else(
+			|| onto.chars().take(8).collect(),
+			|reference| String::from(reference.shortname()),
+		)
+}
+
+pub(super) fn get_status_line_segments(
+	head_name: Option<&str>,
+	onto_name: Option<&str>,
+	todo_file: &TodoFile,
+	done_count: usize,
+	search_status: Option<&str>,
+) -> Vec<LineSegment> {
+	let mut parts = vec![];
+
+	if let Some(head) = head_name {
+		let mut rebase_segments = vec![
+			LineSegment::new("Rebasing "),
+			LineSegment::new_with_color(head, DisplayColor::RefBranchColor),
+		];
+		if let Some(onto) = onto_name {
+			rebase_segments.push(LineSegment::new(" onto "));
+			rebase_segments.push(LineSegment::new_with_color(onto, DisplayColor::RefBranchColor));
 		}
-		else {
-			segments.push(LineSegment::new(content));
+		parts.push(rebase_segments);
+	}
+
+	let action_counts = (done_count > 0)
+		.then(|| format!("{done_count} done"))
+		.into_iter()
+		.chain(STATUS_ACTIONS.iter().filter_map(|action| {
+			let count = todo_file
+				.lines_iter()
+				.filter(|line| line.get_action() == action)
+				.count();
+			(count > 0).then(|| format!("{count} {action}"))
+		}))
+		.collect::<Vec<String>>();
+	if !action_counts.is_empty() {
+		parts.push(vec![LineSegment::new(action_counts.join(", ").as_str())]);
+	}
+
+	if todo_file.is_modified() {
+		parts.push(vec![LineSegment::new_with_color(
+			"modified",
+			DisplayColor::IndicatorColor,
+		)]);
+	}
+
+	if let Some(search) = search_status {
+		parts.push(vec![LineSegment::new(search)]);
+	}
+
+	let mut segments = vec![];
+	for (index, part) in parts.into_iter().enumerate() {
+		if index > 0 {
+			segments.push(LineSegment::new(" | "));
 		}
+		segments.extend(part);
 	}
 	segments
 }
@@ -4,16 +4,22 @@ mod error;
 mod external_editor;
 mod insert;
 mod list;
+mod run_command;
 mod show_commit;
+mod todo_diff;
+mod update_refs;
 mod window_size_error;
 
 pub(crate) use self::{
 	confirm_abort::ConfirmAbort,
-	confirm_rebase::ConfirmRebase,
+	confirm_rebase::{validate_todo_file, ConfirmRebase},
 	error::Error,
 	external_editor::ExternalEditor,
 	insert::Insert,
 	list::List,
+	run_command::RunCommand,
 	show_commit::ShowCommit,
+	todo_diff::TodoDiff,
+	update_refs::UpdateRefs,
 	window_size_error::WindowSizeError,
 };
@@ -0,0 +1,201 @@
+mod run_command_state;
+
+#[cfg(all(unix, test))]
+mod tests;
+
+use std::sync::Arc;
+
+use display::DisplayColor;
+use input::InputOptions;
+use parking_lot::Mutex;
+use todo_file::{Action, TodoFile};
+use view::{LineSegment, RenderContext, ViewData, ViewDataUpdater, ViewLine};
+
+use self::run_command_state::RunCommandState;
+use crate::{
+	components::edit::{Edit, INPUT_OPTIONS as EDIT_INPUT_OPTIONS},
+	events::{Event, MetaEvent},
+	module::{Module, State},
+	process::{CommandOutput, Results},
+	util::handle_view_data_scroll,
+};
+
+// TODO Remove `union` call when bitflags/bitflags#180 is resolved
+const OUTPUT_INPUT_OPTIONS: InputOptions = InputOptions::RESIZE.union(InputOptions::MOVEMENT);
+
+pub(crate) struct RunCommand {
+	command: String,
+	edit: Edit,
+	output: Arc<Mutex<Option<CommandOutput>>>,
+	state: RunCommandState,
+	todo_file: Arc<Mutex<TodoFile>>,
+	view_data: ViewData,
+}
+
+impl Module for RunCommand {
+	fn activate(&mut self, previous_state: State) -> Results {
+		// a resize that was too small should return to the output that was being shown
+		if previous_state == State::WindowSizeError {
+			return Results::new();
+		}
+		self.state = RunCommandState::Edit;
+		self.edit.reset();
+		let todo_file = self.todo_file.lock();
+		if let Some(line) = todo_file.get_selected_line() {
+			if line.get_action() == &Action::Exec {
+				self.edit.set_content(line.get_content());
+			}
+		}
+		Results::new()
+	}
+
+	fn build_view_data(&mut self, _: &RenderContext) -> &ViewData {
+		match self.state {
+			RunCommandState::Edit => {
+				self.edit.build_view_data(
+					|updater: &mut ViewDataUpdater<'_>| {
+						updater.push_leading_line(ViewLine::from(vec![LineSegment::new_with_color(
+							"Enter a command to run in the working tree. Empty content cancels running the command.",
+							DisplayColor::IndicatorColor,
+						)]));
+						updater.push_leading_line(ViewLine::new_empty_line());
+					},
+					|_| {},
+				)
+			},
+			RunCommandState::Running | RunCommandState::Output => &self.view_data,
+		}
+	}
+
+	fn input_options(&self) -> &InputOptions {
+		match self.state {
+			RunCommandState::Edit | RunCommandState::Running => &EDIT_INPUT_OPTIONS,
+			RunCommandState::Output => &OUTPUT_INPUT_OPTIONS,
+		}
+	}
+
+	fn handle_event(&mut self, event: Event, view_state: &view::State) -> Results {
+		let mut results = Results::new();
+		match self.state {
+			RunCommandState::Edit => {
+				self.edit.handle_event(&event);
+				if self.edit.is_finished() {
+					let content = String::from(self.edit.get_content());
+					if content.is_empty() {
+						results.state(State::List);
+					}
+					else {
+						self.run(&mut results, content);
+					}
+				}
+			},
+			RunCommandState::Running => {
+				if let Event::MetaEvent(MetaEvent::ExternalCommandSuccess | MetaEvent::ExternalCommandError) = event {
+					let command_output = self.output.lock().take();
+					if let Some(output) = command_output {
+						self.build_output(&output);
+						self.state = RunCommandState::Output;
+					}
+					else {
+						results.state(State::List);
+					}
+				}
+			},
+			RunCommandState::Output => {
+				if handle_view_data_scroll(&event, view_state).is_none() {
+					if let Event::Key(_) = event {
+						results.state(State::List);
+					}
+				}
+			},
+		}
+		results
+	}
+}
+
+impl RunCommand {
+	pub(crate) fn new(todo_file: Arc<Mutex<TodoFile>>) -> Self {
+		let mut edit = Edit::new();
+		edit.set_label("$ ");
+
+		Self {
+			command: String::new(),
+			edit,
+			output: Arc::new(Mutex::new(None)),
+			state: RunCommandState::Edit,
+			todo_file,
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+			}),
+		}
+	}
+
+	fn run(&mut self, results: &mut Results, command: String) {
+		self.state = RunCommandState::Running;
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+			updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+				format!("Running {command}").as_str(),
+				DisplayColor::IndicatorColor,
+			)));
+		});
+		let (shell, arguments) = Self::get_shell_command(command.as_str());
+		self.command = command;
+		results.captured_command(shell, arguments, &self.output);
+	}
+
+	#[cfg(not(windows))]
+	fn get_shell_command(command: &str) -> (String, Vec<String>) {
+		(String::from("sh"), vec![String::from("-c"), String::from(command)])
+	}
+
+	#[cfg(windows)]
+	fn get_shell_command(command: &str) -> (String, Vec<String>) {
+		(String::from("cmd"), vec![String::from("/C"), String::from(command)])
+	}
+
+	fn build_output(&mut self, output: &CommandOutput) {
+		let command = self.command.as_str();
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+			updater.push_leading_line(ViewLine::from(vec![
+				LineSegment::new_with_color("$ ", DisplayColor::IndicatorColor),
+				LineSegment::new(command),
+			]));
+			updater.push_leading_line(match output.status() {
+				Some(0) => ViewLine::from(LineSegment::new_with_color("Exit status: 0", DisplayColor::DiffAddColor)),
+				Some(code) => {
+					ViewLine::from(LineSegment::new_with_color(
+						format!("Exit status: {code}").as_str(),
+						DisplayColor::DiffRemoveColor,
+					))
+				},
+				None => {
+					ViewLine::from(LineSegment::new_with_color(
+						"Terminated by signal",
+						DisplayColor::DiffRemoveColor,
+					))
+				},
+			});
+			updater.push_leading_line(ViewLine::new_empty_line());
+
+			if output.stdout().is_empty() && output.stderr().is_empty() {
+				updater.push_line(ViewLine::from(LineSegment::new_with_color(
+					"No output",
+					DisplayColor::IndicatorColor,
+				)));
+			}
+			for line in output.stdout().lines() {
+				updater.push_line(ViewLine::from(line));
+			}
+			for line in output.stderr().lines() {
+				updater.push_line(ViewLine::from(LineSegment::new_with_color(
+					line,
+					DisplayColor::DiffRemoveColor,
+				)));
+			}
+		});
+	}
+}
@@ -0,0 +1,6 @@
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum RunCommandState {
+	Edit,
+	Running,
+	Output,
+}
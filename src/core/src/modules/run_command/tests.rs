@@ -0,0 +1,288 @@
+use input::{KeyCode, StandardEvent};
+use view::assert_rendered_output;
+
+use super::*;
+use crate::{
+	assert_results,
+	process::Artifact,
+	testutil::{module_test, ModuleTestContext},
+};
+
+fn create_run_command(todo_file: TodoFile) -> RunCommand {
+	RunCommand::new(Arc::new(Mutex::new(todo_file)))
+}
+
+fn run_with_output(test_context: &mut ModuleTestContext, module: &mut RunCommand, output: CommandOutput) {
+	_ = test_context.activate(module, State::List);
+	_ = test_context.handle_event(module);
+	*module.output.lock() = Some(output);
+	_ = test_context.handle_event(module);
+}
+
+#[test]
+fn activate_with_exec_line() {
+	module_test(&["exec echo foo"], &[], |mut test_context| {
+		let mut module = create_run_command(test_context.take_todo_file());
+		assert_results!(test_context.activate(&mut module, State::List));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Enter a command to run in the working tree. Empty content cancels running the command.",
+			"",
+			"{BODY}",
+			"{Normal,Dimmed}$ {Normal}echo foo{Normal,Underline}",
+			"{TRAILING}",
+			"{IndicatorColor}Enter to finish"
+		);
+	});
+}
+
+#[test]
+fn activate_without_exec_line() {
+	module_test(&["pick aaa c1"], &[], |mut test_context| {
+		let mut module = create_run_command(test_context.take_todo_file());
+		assert_results!(test_context.activate(&mut module, State::List));
+		let view_data = test_context.build_view_data(&mut module);
+		assert_rendered_output!(
+			view_data,
+			"{TITLE}",
+			"{LEADING}",
+			"{IndicatorColor}Enter a command to run in the working tree. Empty content cancels running the command.",
+			"",
+			"{BODY}",
+			"{Normal,Dimmed}$ {Normal,Underline}",
+			"{TRAILING}",
+			"{IndicatorColor}Enter to finish"
+		);
+	});
+}
+
+#[test]
+fn edit_empty_cancels() {
+	module_test(
+		&["pick aaa c1"],
+		&[Event::from(KeyCode::Enter)],
+		|mut test_context| {
+			let mut module = create_run_command(test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(KeyCode::Enter)),
+				Artifact::ChangeState(State::List)
+			);
+		},
+	);
+}
+
+#[test]
+fn edit_runs_exec_line() {
+	module_test(
+		&["exec echo foo"],
+		&[Event::from(KeyCode::Enter)],
+		|mut test_context| {
+			let mut module = create_run_command(test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(KeyCode::Enter)),
+				Artifact::CapturedCommand(
+					(String::from("sh"), vec![String::from("-c"), String::from("echo foo")]),
+					Arc::new(Mutex::new(None))
+				)
+			);
+			assert_eq!(module.state, RunCommandState::Running);
+		},
+	);
+}
+
+#[test]
+fn edit_runs_ad_hoc_command() {
+	module_test(
+		&["pick aaa c1"],
+		&[
+			Event::from('l'),
+			Event::from('s'),
+			Event::from(KeyCode::Enter),
+		],
+		|mut test_context| {
+			let mut module = create_run_command(test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			_ = test_context.handle_n_events(&mut module, 2);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(KeyCode::Enter)),
+				Artifact::CapturedCommand(
+					(String::from("sh"), vec![String::from("-c"), String::from("ls")]),
+					Arc::new(Mutex::new(None))
+				)
+			);
+		},
+	);
+}
+
+#[test]
+fn render_output_success() {
+	module_test(
+		&["exec echo foo"],
+		&[
+			Event::from(KeyCode::Enter),
+			Event::from(MetaEvent::ExternalCommandSuccess),
+		],
+		|mut test_context| {
+			let mut module = create_run_command(test_context.take_todo_file());
+			run_with_output(
+				&mut test_context,
+				&mut module,
+				CommandOutput::new(Some(0), "foo\nbar\n", "warning\n"),
+			);
+			assert_eq!(module.state, RunCommandState::Output);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}$ {Normal}echo foo",
+				"{DiffAddColor}Exit status: 0",
+				"",
+				"{BODY}",
+				"{Normal}foo",
+				"{Normal}bar",
+				"{DiffRemoveColor}warning"
+			);
+		},
+	);
+}
+
+#[test]
+fn render_output_failure() {
+	module_test(
+		&["exec false"],
+		&[
+			Event::from(KeyCode::Enter),
+			Event::from(MetaEvent::ExternalCommandError),
+		],
+		|mut test_context| {
+			let mut module = create_run_command(test_context.take_todo_file());
+			run_with_output(&mut test_context, &mut module, CommandOutput::new(Some(1), "", ""));
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}$ {Normal}false",
+				"{DiffRemoveColor}Exit status: 1",
+				"",
+				"{BODY}",
+				"{IndicatorColor}No output"
+			);
+		},
+	);
+}
+
+#[test]
+fn render_output_terminated() {
+	module_test(
+		&["exec sleep 100"],
+		&[
+			Event::from(KeyCode::Enter),
+			Event::from(MetaEvent::ExternalCommandError),
+		],
+		|mut test_context| {
+			let mut module = create_run_command(test_context.take_todo_file());
+			run_with_output(&mut test_context, &mut module, CommandOutput::new(None, "", ""));
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"{IndicatorColor}$ {Normal}sleep 100",
+				"{DiffRemoveColor}Terminated by signal",
+				"",
+				"{BODY}",
+				"{IndicatorColor}No output"
+			);
+		},
+	);
+}
+
+#[test]
+fn running_without_output_returns_to_list() {
+	module_test(
+		&["exec echo foo"],
+		&[
+			Event::from(KeyCode::Enter),
+			Event::from(MetaEvent::ExternalCommandError),
+		],
+		|mut test_context| {
+			let mut module = create_run_command(test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			_ = test_context.handle_event(&mut module);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(MetaEvent::ExternalCommandError)),
+				Artifact::ChangeState(State::List)
+			);
+		},
+	);
+}
+
+#[test]
+fn output_scroll() {
+	module_test(
+		&["exec echo foo"],
+		&[
+			Event::from(KeyCode::Enter),
+			Event::from(MetaEvent::ExternalCommandSuccess),
+			Event::from(StandardEvent::ScrollDown),
+		],
+		|mut test_context| {
+			let mut module = create_run_command(test_context.take_todo_file());
+			run_with_output(&mut test_context, &mut module, CommandOutput::new(Some(0), "foo\n", ""));
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(StandardEvent::ScrollDown))
+			);
+			test_context.view_context.assert_render_action(&["ScrollDown"]);
+		},
+	);
+}
+
+#[test]
+fn output_key_returns_to_list() {
+	module_test(
+		&["exec echo foo"],
+		&[
+			Event::from(KeyCode::Enter),
+			Event::from(MetaEvent::ExternalCommandSuccess),
+			Event::from('q'),
+		],
+		|mut test_context| {
+			let mut module = create_run_command(test_context.take_todo_file());
+			run_with_output(&mut test_context, &mut module, CommandOutput::new(Some(0), "foo\n", ""));
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from('q')),
+				Artifact::ChangeState(State::List)
+			);
+		},
+	);
+}
+
+#[test]
+fn activate_from_window_size_error_keeps_output() {
+	module_test(
+		&["exec echo foo"],
+		&[
+			Event::from(KeyCode::Enter),
+			Event::from(MetaEvent::ExternalCommandSuccess),
+		],
+		|mut test_context| {
+			let mut module = create_run_command(test_context.take_todo_file());
+			run_with_output(&mut test_context, &mut module, CommandOutput::new(Some(0), "foo\n", ""));
+			assert_results!(test_context.activate(&mut module, State::WindowSizeError));
+			assert_eq!(module.state, RunCommandState::Output);
+		},
+	);
+}
@@ -0,0 +1,119 @@
+use std::collections::HashSet;
+
+// the files and hunks of a diff that are folded, referenced by their index in the diff
+#[derive(Debug, Default)]
+pub(crate) struct DiffFolds {
+	files: HashSet<usize>,
+	hunks: HashSet<(usize, usize)>,
+}
+
+impl DiffFolds {
+	pub(crate) fn new() -> Self {
+		Self::default()
+	}
+
+	pub(super) fn clear(&mut self) {
+		self.files.clear();
+		self.hunks.clear();
+	}
+
+	pub(super) fn is_file_folded(&self, file_index: usize) -> bool {
+		self.files.contains(&file_index)
+	}
+
+	pub(super) fn is_hunk_folded(&self, file_index: usize, hunk_index: usize) -> bool {
+		self.hunks.contains(&(file_index, hunk_index))
+	}
+
+	pub(super) fn toggle_file(&mut self, file_index: usize) {
+		if !self.files.remove(&file_index) {
+			_ = self.files.insert(file_index);
+		}
+	}
+
+	pub(super) fn unfold_file(&mut self, file_index: usize) {
+		_ = self.files.remove(&file_index);
+	}
+
+	pub(super) fn toggle_hunk(&mut self, file_index: usize, hunk_index: usize) {
+		if !self.hunks.remove(&(file_index, hunk_index)) {
+			_ = self.hunks.insert((file_index, hunk_index));
+		}
+	}
+
+	// fold every file, or unfold everything when every file is already folded
+	pub(super) fn toggle_all(&mut self, file_count: usize) {
+		if (0..file_count).all(|file_index| self.is_file_folded(file_index)) {
+			self.clear();
+		}
+		else {
+			self.files.extend(0..file_count);
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn toggle_file() {
+		let mut folds = DiffFolds::new();
+		folds.toggle_file(1);
+		assert!(folds.is_file_folded(1));
+		assert!(!folds.is_file_folded(0));
+		folds.toggle_file(1);
+		assert!(!folds.is_file_folded(1));
+	}
+
+	#[test]
+	fn unfold_file() {
+		let mut folds = DiffFolds::new();
+		folds.toggle_file(1);
+		folds.unfold_file(1);
+		assert!(!folds.is_file_folded(1));
+		folds.unfold_file(1);
+		assert!(!folds.is_file_folded(1));
+	}
+
+	#[test]
+	fn toggle_hunk() {
+		let mut folds = DiffFolds::new();
+		folds.toggle_hunk(1, 2);
+		assert!(folds.is_hunk_folded(1, 2));
+		assert!(!folds.is_hunk_folded(2, 1));
+		folds.toggle_hunk(1, 2);
+		assert!(!folds.is_hunk_folded(1, 2));
+	}
+
+	#[test]
+	fn toggle_all_folds_every_file() {
+		let mut folds = DiffFolds::new();
+		folds.toggle_file(1);
+		folds.toggle_all(3);
+		assert!(folds.is_file_folded(0));
+		assert!(folds.is_file_folded(1));
+		assert!(folds.is_file_folded(2));
+	}
+
+	#[test]
+	fn toggle_all_unfolds_everything_when_all_folded() {
+		let mut folds = DiffFolds::new();
+		folds.toggle_hunk(0, 0);
+		folds.toggle_all(2);
+		folds.toggle_all(2);
+		assert!(!folds.is_file_folded(0));
+		assert!(!folds.is_file_folded(1));
+		assert!(!folds.is_hunk_folded(0, 0));
+	}
+
+	#[test]
+	fn clear() {
+		let mut folds = DiffFolds::new();
+		folds.toggle_file(0);
+		folds.toggle_hunk(1, 0);
+		folds.clear();
+		assert!(!folds.is_file_folded(0));
+		assert!(!folds.is_hunk_folded(1, 0));
+	}
+}
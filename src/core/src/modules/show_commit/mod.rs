@@ -1,3 +1,4 @@
+mod diff_folds;
 mod show_commit_state;
 mod util;
 mod view_builder;
@@ -5,26 +6,31 @@ mod view_builder;
 #[cfg(test)]
 mod tests;
 
-use std::sync::Arc;
+use std::{io::Write, path::Path, sync::Arc};
 
-use anyhow::{anyhow, Error};
+use anyhow::{anyhow, Result};
 use captur::capture;
-use config::{Config, DiffIgnoreWhitespaceSetting, DiffShowWhitespaceSetting};
-use git::{CommitDiff, CommitDiffLoaderOptions, Repository};
-use input::{InputOptions, StandardEvent};
+use config::{Config, DiffShowWhitespaceSetting};
+use git::{CollapseReason, Commit, CommitDiff, CommitDiffLoaderOptions, Repository};
+use input::{InputOptions, MouseButton, MouseEvent, MouseEventKind, StandardEvent};
 use parking_lot::Mutex;
-use todo_file::TodoFile;
+use tempfile::{Builder, NamedTempFile};
+use todo_file::{Line, TodoFile};
 use view::{RenderContext, ViewData};
 
+pub(crate) use self::view_builder::ViewBuilder;
 use self::{
+	diff_folds::DiffFolds,
 	show_commit_state::ShowCommitState,
-	util::get_show_commit_help_lines,
-	view_builder::{ViewBuilder, ViewBuilderOptions},
+	util::{get_show_commit_help_lines, get_squash_message},
+	view_builder::ViewBuilderOptions,
 };
 use crate::{
-	components::help::Help,
+	components::{help::Help, spin_indicator::SpinIndicator},
+	diff::{create_commit_diff_loader_options, Interrupter, LoadedDiff},
 	events::{Event, KeyBindings, MetaEvent},
 	module::{Module, State},
+	modules::external_editor::get_editor_command,
 	process::Results,
 	select,
 	util::handle_view_data_scroll,
@@ -37,49 +43,61 @@ const INPUT_OPTIONS: InputOptions = InputOptions::UNDO_REDO
 
 pub(crate) struct ShowCommit {
 	commit_diff_loader_options: CommitDiffLoaderOptions,
-	diff: Option<CommitDiff>,
+	diff_index: usize,
+	diff_load: Arc<Mutex<Option<LoadedDiff>>>,
 	diff_view_data: ViewData,
+	diffs: Vec<CommitDiff>,
+	editor: String,
+	editor_file: Option<NamedTempFile>,
+	folds: DiffFolds,
 	help: Help,
+	interrupter: Interrupter,
+	loading_hash: Option<String>,
+	loading_view_data: ViewData,
 	overview_view_data: ViewData,
+	range: Option<(String, String)>,
 	repository: Repository,
+	requested_range: Option<(String, String)>,
+	requested_squash: Option<Vec<Line>>,
+	spin_indicator: SpinIndicator,
+	squash_message: Option<String>,
 	state: ShowCommitState,
 	todo_file: Arc<Mutex<TodoFile>>,
 	view_builder: ViewBuilder,
+	wrap: bool,
 }
 
 impl Module for ShowCommit {
 	fn activate(&mut self, _: State) -> Results {
 		let mut results = Results::new();
-		if let Some(selected_line) = self.todo_file.lock().get_selected_line() {
+		if let Some((first_hash, last_hash)) = self.requested_range.take() {
+			let squash_lines = self.requested_squash.take();
+			self.activate_range(first_hash, last_hash, squash_lines.as_deref(), &mut results);
+			return results;
+		}
+		// merge lines reference the original merge commit with the -C or -c option
+		let selected_hash =
+			self.todo_file.lock().get_selected_line().map(|selected_line| {
+				String::from(selected_line.get_merge_commit().unwrap_or(selected_line.get_hash()))
+			});
+		if let Some(hash) = selected_hash.as_deref() {
 			// skip loading commit data if the currently loaded commit has not changed, this retains
 			// position after returning to the list view or help
-			if let Some(diff) = self.diff.as_ref() {
-				if diff.commit().hash() == selected_line.get_hash() {
+			if let Some(diff) = self.diffs.first() {
+				if self.range.is_none() && diff.commit().hash() == hash {
 					return results;
 				}
 			}
-			self.overview_view_data.update_view_data(|updater| {
-				updater.clear();
-				updater.reset_scroll_position();
-			});
-
-			self.diff_view_data.update_view_data(|updater| {
-				updater.clear();
-				updater.reset_scroll_position();
-			});
-
-			let new_diff = self
-				.repository
-				.load_commit_diff(selected_line.get_hash(), &self.commit_diff_loader_options);
-
-			match new_diff {
-				Ok(diff) => {
-					self.diff = Some(diff);
-				},
-				Err(e) => {
-					results.error_with_return(Error::from(e), State::List);
-				},
+			// a load that was not interrupted by leaving the view is still in progress
+			if self.loading_hash.as_deref() == Some(hash) && self.interrupter.should_continue() {
+				return results;
 			}
+			if self.range.take().is_some() {
+				self.squash_message = None;
+				self.state = ShowCommitState::Overview;
+			}
+			self.start_load(hash);
+			results.load_diff(hash, &self.diff_load, &self.interrupter);
 		}
 		else {
 			results.error_with_return(anyhow!("No valid commit to show"), State::List);
@@ -92,9 +110,16 @@ impl Module for ShowCommit {
 			return self.help.get_view_data();
 		}
 
-		let diff = self.diff.as_ref().unwrap(); // will only fail on programmer error
+		let Some(diff) = self.diffs.get(self.diff_index)
+		else {
+			return self.build_loading_view_data(context);
+		};
+		let diff_index = self.diff_index;
+		let parent_count = self.parent_count();
 		let state = &self.state;
+		let folds = &self.folds;
 		let view_builder = &self.view_builder;
+		let squash_message = self.squash_message.as_deref();
 		let is_full_width = context.is_full_width();
 
 		match *state {
@@ -102,16 +127,43 @@ impl Module for ShowCommit {
 				if self.overview_view_data.is_empty() {
 					self.overview_view_data.update_view_data(|updater| {
 						capture!(view_builder, diff);
-						view_builder.build_view_data_for_overview(updater, diff, is_full_width);
+						if let Some(message) = squash_message {
+							ViewBuilder::build_view_data_for_squash_overview(updater, diff, message, is_full_width);
+						}
+						else {
+							view_builder.build_view_data_for_overview(
+								updater,
+								diff,
+								diff_index,
+								parent_count,
+								is_full_width,
+							);
+						}
 					});
 				}
 				&self.overview_view_data
 			},
+			ShowCommitState::Diff if self.range.is_some() => {
+				if self.diff_view_data.is_empty() {
+					self.diff_view_data.update_view_data(|updater| {
+						capture!(view_builder, diff, folds);
+						view_builder.build_view_data_range_diff(updater, diff, folds, is_full_width);
+					});
+				}
+				&self.diff_view_data
+			},
 			ShowCommitState::Diff => {
 				if self.diff_view_data.is_empty() {
 					self.diff_view_data.update_view_data(|updater| {
-						capture!(view_builder, diff);
-						view_builder.build_view_data_diff(updater, diff, is_full_width);
+						capture!(view_builder, diff, folds);
+						view_builder.build_view_data_diff(
+							updater,
+							diff,
+							folds,
+							diff_index,
+							parent_count,
+							is_full_width,
+						);
 					});
 				}
 				&self.diff_view_data
@@ -126,46 +178,133 @@ impl Module for ShowCommit {
 	fn read_event(&self, event: Event, key_bindings: &KeyBindings) -> Event {
 		select!(
 			default || {
-				key_bindings
-					.custom
-					.show_diff
-					.contains(&event)
-					.then(|| Event::from(MetaEvent::ShowDiff))
-					.unwrap_or(event)
+				match event {
+					e if key_bindings.custom.show_diff.contains(&e) => Event::from(MetaEvent::ShowDiff),
+					e if key_bindings.custom.show_next_parent.contains(&e) => Event::from(MetaEvent::ShowNextParent),
+					e if key_bindings.custom.toggle_wrap.contains(&e) => Event::from(MetaEvent::ToggleWrap),
+					e if self.state == ShowCommitState::Diff => Self::read_diff_event(e, key_bindings),
+					_ => event,
+				}
 			},
-			|| { self.help.read_event(event) }
+			|| { self.help.read_event(event.clone()) }
 		)
 	}
 
-	fn handle_event(&mut self, event: Event, view_state: &view::State) -> Results {
-		if self.help.is_active() {
-			self.help.handle_event(event, view_state);
-			return Results::new();
+	fn handle_range(&mut self, first_hash: &str, last_hash: &str) -> Results {
+		self.requested_range = Some((String::from(first_hash), String::from(last_hash)));
+		Results::new()
+	}
+
+	fn handle_squash(&mut self, lines: &[Line]) -> Results {
+		if let (Some(first_line), Some(last_line)) = (lines.first(), lines.last()) {
+			self.requested_range = Some((String::from(first_line.get_hash()), String::from(last_line.get_hash())));
+			self.requested_squash = Some(lines.to_vec());
 		}
+		Results::new()
+	}
 
+	fn handle_event(&mut self, event: Event, view_state: &view::State) -> Results {
 		let mut results = Results::new();
+		if event == Event::MetaEvent(MetaEvent::DiffUpdate) {
+			self.update_loaded_diffs(&mut results);
+			return results;
+		}
 
+		if self.help.is_active() {
+			self.help.handle_event(&event, view_state);
+			return results;
+		}
+
+		let has_overview = self.has_overview();
 		let active_view_data = match self.state {
 			ShowCommitState::Overview => &mut self.overview_view_data,
 			ShowCommitState::Diff => &mut self.diff_view_data,
 		};
 
-		if handle_view_data_scroll(event, view_state).is_none() {
+		if handle_view_data_scroll(&event, view_state).is_none() {
 			match event {
-				Event::MetaEvent(meta_event) if meta_event == MetaEvent::ShowDiff => {
+				// a range only has a diff, unless the range is a squash
+				Event::MetaEvent(meta_event)
+					if meta_event == MetaEvent::ShowDiff && !self.diffs.is_empty() && has_overview =>
+				{
 					active_view_data.update_view_data(|updater| updater.clear());
 					self.state = match self.state {
 						ShowCommitState::Overview => ShowCommitState::Diff,
 						ShowCommitState::Diff => ShowCommitState::Overview,
 					}
 				},
+				Event::MetaEvent(meta_event) if meta_event == MetaEvent::ShowNextParent && self.diffs.len() > 1 => {
+					self.diff_index = if self.diff_index + 1 < self.diffs.len() {
+						self.diff_index + 1
+					}
+					else {
+						0
+					};
+					self.folds.clear();
+					self.overview_view_data.update_view_data(|updater| {
+						updater.clear();
+						updater.reset_scroll_position();
+					});
+					self.diff_view_data.update_view_data(|updater| {
+						updater.clear();
+						updater.reset_scroll_position();
+					});
+				},
+				Event::MetaEvent(MetaEvent::ToggleWrap) => {
+					self.wrap = !self.wrap;
+					let wrap = self.wrap;
+					self.overview_view_data.update_view_data(|updater| updater.set_wrap(wrap));
+					self.diff_view_data.update_view_data(|updater| updater.set_wrap(wrap));
+				},
+				Event::Mouse(MouseEvent {
+					kind: MouseEventKind::Down(MouseButton::Left),
+					row,
+					..
+				}) if self.state == ShowCommitState::Overview && !self.diffs.is_empty() => {
+					let diff = &self.diffs[self.diff_index];
+					let file_line_start = self.squash_message.as_deref().map_or_else(
+						|| ViewBuilder::get_overview_file_line_start(diff),
+						ViewBuilder::get_squash_overview_file_line_start,
+					);
+					if let Some(file_index) = view_state
+						.get_line_index_at_row(row as usize)
+						.and_then(|line_index| line_index.checked_sub(file_line_start))
+						.filter(|file_index| *file_index < diff.file_statuses().len())
+					{
+						self.overview_view_data.update_view_data(|updater| updater.clear());
+						self.diff_view_data.update_view_data(|updater| updater.clear());
+						self.state = ShowCommitState::Diff;
+						self.folds.unfold_file(file_index);
+						view_state.scroll_to(ViewBuilder::get_diff_file_line_index(diff, &self.folds, file_index));
+					}
+				},
+				Event::MetaEvent(meta_event) if meta_event == MetaEvent::OpenInEditor => {
+					self.open_in_editor(view_state, &mut results);
+				},
+				Event::MetaEvent(MetaEvent::ExpandFile) => {
+					self.expand_file(view_state, &mut results);
+				},
+				Event::MetaEvent(MetaEvent::FoldAll) => self.fold_all(view_state),
+				Event::MetaEvent(MetaEvent::FoldFile) => self.fold_file(view_state),
+				Event::MetaEvent(MetaEvent::FoldHunk) => self.fold_hunk(view_state),
+				Event::MetaEvent(MetaEvent::NextFile) => self.jump_to_file(view_state, true),
+				Event::MetaEvent(MetaEvent::PreviousFile) => self.jump_to_file(view_state, false),
+				Event::MetaEvent(MetaEvent::NextHunk) => self.jump_to_hunk(view_state, true),
+				Event::MetaEvent(MetaEvent::PreviousHunk) => self.jump_to_hunk(view_state, false),
+				Event::MetaEvent(MetaEvent::ExternalCommandSuccess | MetaEvent::ExternalCommandError) => {
+					self.editor_file = None;
+				},
 				Event::Standard(standard_event) if standard_event == StandardEvent::Help => self.help.set_active(),
 				Event::Key(_) => {
 					active_view_data.update_view_data(|updater| updater.clear());
-					if self.state == ShowCommitState::Diff {
+					if self.state == ShowCommitState::Diff && has_overview {
 						self.state = ShowCommitState::Overview;
 					}
 					else {
+						// the commit is no longer needed once the view is left
+						if self.loading_hash.is_some() {
+							self.interrupter.interrupt();
+						}
 						results.state(State::List);
 					}
 				},
@@ -177,6 +316,19 @@ impl Module for ShowCommit {
 	}
 }
 
+pub(crate) fn create_view_builder(config: &Config) -> ViewBuilder {
+	ViewBuilder::new(ViewBuilderOptions::new(
+		config.diff_tab_width as usize,
+		config.diff_tab_symbol.as_str(),
+		config.diff_space_symbol.as_str(),
+		config.diff_show_whitespace == DiffShowWhitespaceSetting::Both
+			|| config.diff_show_whitespace == DiffShowWhitespaceSetting::Leading,
+		config.diff_show_whitespace == DiffShowWhitespaceSetting::Both
+			|| config.diff_show_whitespace == DiffShowWhitespaceSetting::Trailing,
+		config.key_bindings.expand_file.first().map_or("", String::as_str),
+	))
+}
+
 impl ShowCommit {
 	pub(crate) fn new(config: &Config, repository: Repository, todo_file: Arc<Mutex<TodoFile>>) -> Self {
 		let overview_view_data = ViewData::new(|updater| {
@@ -187,35 +339,365 @@ impl ShowCommit {
 			updater.set_show_title(true);
 			updater.set_show_help(true);
 		});
-		let view_builder_options = ViewBuilderOptions::new(
-			config.diff_tab_width as usize,
-			config.diff_tab_symbol.as_str(),
-			config.diff_space_symbol.as_str(),
-			config.diff_show_whitespace == DiffShowWhitespaceSetting::Both
-				|| config.diff_show_whitespace == DiffShowWhitespaceSetting::Leading,
-			config.diff_show_whitespace == DiffShowWhitespaceSetting::Both
-				|| config.diff_show_whitespace == DiffShowWhitespaceSetting::Trailing,
-		);
-
-		let commit_diff_loader_options = CommitDiffLoaderOptions::new()
-			.context_lines(config.git.diff_context)
-			.copies(config.git.diff_copies)
-			.ignore_whitespace(config.diff_ignore_whitespace == DiffIgnoreWhitespaceSetting::All)
-			.ignore_whitespace_change(config.diff_ignore_whitespace == DiffIgnoreWhitespaceSetting::Change)
-			.ignore_blank_lines(config.diff_ignore_blank_lines)
-			.interhunk_context(config.git.diff_interhunk_lines)
-			.renames(config.git.diff_renames, config.git.diff_rename_limit);
-
 		Self {
-			commit_diff_loader_options,
-			diff: None,
+			commit_diff_loader_options: create_commit_diff_loader_options(config),
+			diff_index: 0,
+			diff_load: Arc::new(Mutex::new(None)),
 			diff_view_data,
+			diffs: vec![],
+			editor: config.git.editor.clone(),
+			editor_file: None,
+			folds: DiffFolds::new(),
 			help: Help::new_from_keybindings(&get_show_commit_help_lines(&config.key_bindings)),
+			interrupter: Interrupter::new(),
+			loading_hash: None,
+			loading_view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+				updater.set_show_help(true);
+			}),
 			overview_view_data,
+			range: None,
 			repository,
+			requested_range: None,
+			requested_squash: None,
+			spin_indicator: SpinIndicator::new(),
+			squash_message: None,
 			state: ShowCommitState::Overview,
 			todo_file,
-			view_builder: ViewBuilder::new(view_builder_options),
+			view_builder: create_view_builder(config),
+			wrap: false,
+		}
+	}
+
+	// the range is always shown as a diff, retaining the position when the same range was already loaded
+	// a squash of the range starts on the overview of the resulting commit message
+	fn activate_range(
+		&mut self,
+		first_hash: String,
+		last_hash: String,
+		squash_lines: Option<&[Line]>,
+		results: &mut Results,
+	) {
+		let squash_message = match squash_lines.map(|lines| self.load_squash_message(lines)).transpose() {
+			Ok(message) => message,
+			Err(err) => {
+				results.error_with_return(err, State::List);
+				return;
+			},
+		};
+		self.state = if squash_message.is_some() {
+			ShowCommitState::Overview
+		}
+		else {
+			ShowCommitState::Diff
+		};
+		if self
+			.range
+			.as_ref()
+			.is_some_and(|&(ref first, ref last)| *first == first_hash && *last == last_hash)
+			&& self.squash_message == squash_message
+			&& (!self.diffs.is_empty() || (self.loading_hash.is_some() && self.interrupter.should_continue()))
+		{
+			return;
+		}
+		self.start_load(format!("{first_hash}..{last_hash}").as_str());
+		results.load_range_diff(&first_hash, &last_hash, &self.diff_load, &self.interrupter);
+		self.range = Some((first_hash, last_hash));
+		self.squash_message = squash_message;
+	}
+
+	fn load_squash_message(&self, lines: &[Line]) -> Result<String> {
+		let commits = lines
+			.iter()
+			.map(|line| self.repository.load_commit(line.get_hash()))
+			.collect::<Result<Vec<Commit>, _>>()?;
+		// the diff is loaded from the parent of the first commit to the last commit, so any commit between the commits
+		// of the lines would be included in the diff
+		if commits
+			.iter()
+			.zip(commits.iter().skip(1))
+			.any(|(commit, next_commit)| next_commit.parent_hash() != Some(commit.hash()))
+		{
+			return Err(anyhow!(
+				"Cannot preview the squash, the fixup and squash commits must directly follow the commit in the \
+				 history"
+			));
+		}
+		let lines_with_messages = lines
+			.iter()
+			.zip(commits.iter().map(|commit| commit.message().unwrap_or_default()))
+			.collect::<Vec<(&Line, &str)>>();
+		Ok(get_squash_message(&lines_with_messages))
+	}
+
+	// the commit overview is not available for a range, unless the range is a squash
+	const fn has_overview(&self) -> bool {
+		self.range.is_none() || self.squash_message.is_some()
+	}
+
+	// any previous load is interrupted, and the views are reset for the newly loading diff
+	fn start_load(&mut self, loading_hash: &str) {
+		self.overview_view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+		});
+
+		self.diff_view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+		});
+
+		self.diff_index = 0;
+		self.diffs.clear();
+		self.folds.clear();
+		self.diff_load = Arc::new(Mutex::new(None));
+		self.interrupter.interrupt();
+		self.interrupter = Interrupter::new();
+		self.loading_hash = Some(String::from(loading_hash));
+	}
+
+	// the diffs are loaded by the diff thread, and are moved into the module once the load is complete
+	fn update_loaded_diffs(&mut self, results: &mut Results) {
+		let loaded_diffs = {
+			let mut diff_load = self.diff_load.lock();
+			if self.loading_hash.is_none() || diff_load.as_ref().and_then(LoadedDiff::diffs).is_none() {
+				return;
+			}
+			diff_load.take().and_then(LoadedDiff::into_diffs)
+		};
+		self.loading_hash = None;
+		self.loading_view_data.update_view_data(|updater| updater.clear());
+		match loaded_diffs {
+			Some(Ok(mut diffs)) => {
+				if let Some(combined_diff) = CommitDiff::new_combined(&diffs) {
+					diffs.push(combined_diff);
+				}
+				self.diff_index = 0;
+				self.diffs = diffs;
+				self.folds.clear();
+			},
+			Some(Err(err)) => results.error_with_return(anyhow!(err), State::List),
+			None => {},
+		}
+	}
+
+	fn build_loading_view_data(&mut self, context: &RenderContext) -> &ViewData {
+		self.spin_indicator.refresh();
+		let diff_load = self.diff_load.lock();
+		let file_statuses = diff_load.as_ref().map_or(&[][..], LoadedDiff::file_statuses);
+		let hash = self.loading_hash.as_deref().unwrap_or_default();
+		let indicator = self.spin_indicator.indicator();
+		let is_full_width = context.is_full_width();
+		self.loading_view_data.update_view_data(|updater| {
+			updater.clear();
+			ViewBuilder::build_view_data_loading(updater, hash, indicator.as_str(), file_statuses, is_full_width);
+		});
+		&self.loading_view_data
+	}
+
+	fn open_in_editor(&mut self, view_state: &view::State, results: &mut Results) {
+		let Some(diff) = self.diffs.get(self.diff_index)
+		else {
+			return;
+		};
+		if let Some((status, delta)) =
+			ViewBuilder::get_diff_delta_at_line(diff, &self.folds, view_state.get_top_line_index())
+		{
+			let editor_file = self.create_editor_file(diff.commit().hash(), status.destination_path());
+			match editor_file.and_then(|file| {
+				let filepath = file
+					.path()
+					.to_str()
+					.ok_or_else(|| anyhow!("The file path {} is invalid", file.path().to_string_lossy()))?;
+				let command = get_editor_command(self.editor.as_str(), filepath, Some(delta.new_lines_start()))?;
+				Ok((file, command))
+			}) {
+				Ok((file, (command, arguments))) => {
+					self.editor_file = Some(file);
+					results.external_command(command, arguments);
+				},
+				Err(err) => results.error(err),
+			}
+		}
+	}
+
+	// the collapsed file at or after the top of the view is loaded for every parent, so the combined diff of a merge
+	// commit can include the file
+	fn expand_file(&mut self, view_state: &view::State, results: &mut Results) {
+		let Some(shown_diff) = self.diffs.get(self.diff_index)
+		else {
+			return;
+		};
+		let Some((file_index, destination_path)) =
+			ViewBuilder::get_diff_file_at_line(shown_diff, &self.folds, view_state.get_top_line_index())
+				.and_then(|top_file_index| {
+					shown_diff
+						.file_statuses()
+						.iter()
+						.enumerate()
+						.skip(top_file_index)
+						.find(|&(_, status)| status.collapse_reason().is_some_and(CollapseReason::is_expandable))
+				})
+				.map(|(section_index, status)| (section_index, status.destination_path().to_path_buf()))
+		else {
+			return;
+		};
+
+		let parent_count = self.parent_count();
+		for diff in self.diffs.iter_mut().take(parent_count) {
+			let Some(status) = diff
+				.file_statuses()
+				.iter()
+				.find(|status| status.destination_path() == destination_path && status.collapse_reason().is_some())
+			else {
+				continue;
+			};
+			let loaded_status = self.repository.load_file_diff(
+				diff.commit().hash(),
+				diff.parent().as_ref().map(Commit::hash),
+				status,
+				&self.commit_diff_loader_options,
+			);
+			match loaded_status {
+				Ok(file_status) => diff.replace_file_status(file_status),
+				Err(err) => {
+					results.error(anyhow!(err));
+					return;
+				},
+			}
+		}
+
+		if self.diffs.len() > 1 {
+			drop(self.diffs.pop());
+			if let Some(combined_diff) = CommitDiff::new_combined(&self.diffs) {
+				self.diffs.push(combined_diff);
+			}
+		}
+		self.folds.unfold_file(file_index);
+		self.diff_view_data.update_view_data(|updater| updater.clear());
+	}
+
+	// the file at the top of the view is folded, or unfolded, and the view is scrolled to the header of the file
+	fn fold_file(&mut self, view_state: &view::State) {
+		let Some(diff) = self.diffs.get(self.diff_index)
+		else {
+			return;
+		};
+		if let Some(file_index) = ViewBuilder::get_diff_file_at_line(diff, &self.folds, view_state.get_top_line_index())
+		{
+			self.folds.toggle_file(file_index);
+			view_state.scroll_to(ViewBuilder::get_diff_file_line_index(diff, &self.folds, file_index));
+			self.diff_view_data.update_view_data(|updater| updater.clear());
+		}
+	}
+
+	// the hunk at the top of the view is folded, or unfolded, and the view is scrolled to the header of the hunk
+	fn fold_hunk(&mut self, view_state: &view::State) {
+		let Some(diff) = self.diffs.get(self.diff_index)
+		else {
+			return;
+		};
+		if let Some((toggled_file_index, toggled_hunk_index)) =
+			ViewBuilder::get_diff_hunk_at_line(diff, &self.folds, view_state.get_top_line_index())
+		{
+			self.folds.toggle_hunk(toggled_file_index, toggled_hunk_index);
+			if let Some(&(_, _, line_index)) = ViewBuilder::get_diff_hunk_line_indexes(diff, &self.folds).iter().find(
+				|&&(file_index, hunk_index, _)| file_index == toggled_file_index && hunk_index == toggled_hunk_index,
+			) {
+				view_state.scroll_to(line_index);
+			}
+			self.diff_view_data.update_view_data(|updater| updater.clear());
+		}
+	}
+
+	// every file is folded, or everything is unfolded, keeping the file at the top of the view in place
+	fn fold_all(&mut self, view_state: &view::State) {
+		let Some(diff) = self.diffs.get(self.diff_index)
+		else {
+			return;
+		};
+		let top_file_index = ViewBuilder::get_diff_file_at_line(diff, &self.folds, view_state.get_top_line_index());
+		self.folds.toggle_all(diff.file_statuses().len());
+		if let Some(file_index) = top_file_index {
+			view_state.scroll_to(ViewBuilder::get_diff_file_line_index(diff, &self.folds, file_index));
+		}
+		self.diff_view_data.update_view_data(|updater| updater.clear());
+	}
+
+	fn jump_to_file(&self, view_state: &view::State, next: bool) {
+		let Some(diff) = self.diffs.get(self.diff_index)
+		else {
+			return;
+		};
+		let top_line_index = view_state.get_top_line_index();
+		let mut file_line_indexes = ViewBuilder::get_diff_file_line_indexes(diff, &self.folds).into_iter();
+		let line_index = if next {
+			file_line_indexes.find(|line_index| *line_index > top_line_index)
+		}
+		else {
+			file_line_indexes.rev().find(|line_index| *line_index < top_line_index)
+		};
+		if let Some(index) = line_index {
+			view_state.scroll_to(index);
+		}
+	}
+
+	fn jump_to_hunk(&self, view_state: &view::State, next: bool) {
+		let Some(diff) = self.diffs.get(self.diff_index)
+		else {
+			return;
+		};
+		let top_line_index = view_state.get_top_line_index();
+		let mut hunk_line_indexes = ViewBuilder::get_diff_hunk_line_indexes(diff, &self.folds)
+			.into_iter()
+			.map(|(_, _, line_index)| line_index);
+		let line_index = if next {
+			hunk_line_indexes.find(|line_index| *line_index > top_line_index)
+		}
+		else {
+			hunk_line_indexes.rev().find(|line_index| *line_index < top_line_index)
+		};
+		if let Some(index) = line_index {
+			view_state.scroll_to(index);
+		}
+	}
+
+	// the key bindings that only apply to the diff
+	fn read_diff_event(event: Event, key_bindings: &KeyBindings) -> Event {
+		match event {
+			e if key_bindings.custom.open_in_external_editor.contains(&e) => Event::from(MetaEvent::OpenInEditor),
+			e if key_bindings.custom.expand_file.contains(&e) => Event::from(MetaEvent::ExpandFile),
+			e if key_bindings.custom.fold_all.contains(&e) => Event::from(MetaEvent::FoldAll),
+			e if key_bindings.custom.fold_file.contains(&e) => Event::from(MetaEvent::FoldFile),
+			e if key_bindings.custom.fold_hunk.contains(&e) => Event::from(MetaEvent::FoldHunk),
+			e if key_bindings.custom.next_file.contains(&e) => Event::from(MetaEvent::NextFile),
+			e if key_bindings.custom.next_hunk.contains(&e) => Event::from(MetaEvent::NextHunk),
+			e if key_bindings.custom.previous_file.contains(&e) => Event::from(MetaEvent::PreviousFile),
+			e if key_bindings.custom.previous_hunk.contains(&e) => Event::from(MetaEvent::PreviousHunk),
+			_ => event,
+		}
+	}
+
+	// the file contents at the commit are written to a temporary file, that is kept until the editor exits
+	fn create_editor_file(&self, hash: &str, path: &Path) -> Result<NamedTempFile> {
+		let contents = self.repository.load_file_from_commit(hash, path)?;
+		let file_name = path.file_name().map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+		let mut file = Builder::new()
+			.prefix("girt-")
+			.suffix(format!("-{file_name}").as_str())
+			.tempfile()?;
+		file.write_all(contents.as_slice())?;
+		file.flush()?;
+		Ok(file)
+	}
+
+	// the combined diff is included with the diffs when the commit has more than one parent
+	fn parent_count(&self) -> usize {
+		if self.diffs.len() > 1 {
+			self.diffs.len() - 1
+		}
+		else {
+			self.diffs.len()
 		}
 	}
 }
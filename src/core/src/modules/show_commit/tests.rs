@@ -1,47 +1,163 @@
+use std::fs::{read_to_string, write};
+
 use anyhow::anyhow;
 use git::{
-	testutil::{head_id, with_temp_repository, CommitBuilder, CommitDiffBuilder, FileStatusBuilder},
+	testutil::{
+		add_path_to_index,
+		create_commit,
+		head_id,
+		repo_path,
+		with_temp_repository,
+		CommitBuilder,
+		CommitDiffBuilder,
+		CreateCommitOptions,
+		FileStatusBuilder,
+	},
+	CollapseReason,
 	Delta,
 	DiffLine,
 	FileMode,
 	Origin,
+	SignatureKind,
 	Status,
+	Trailer,
 	User,
 };
-use input::StandardEvent;
+use input::{KeyModifiers, MouseButton, MouseEvent, MouseEventKind, StandardEvent};
 use rstest::rstest;
 use view::{assert_rendered_output, render_line, ViewLine};
 
 use super::*;
-use crate::{assert_results, process::Artifact, testutil::module_test};
+use crate::{
+	assert_results,
+	process::Artifact,
+	testutil::{module_test, ModuleTestContext},
+};
 
 fn create_show_commit(config: &Config, repository: Repository, todo_file: TodoFile) -> ShowCommit {
 	ShowCommit::new(config, repository, Arc::new(Mutex::new(todo_file)))
 }
 
+// the diff thread does not run in these tests, so the load it would do is done in its place
+fn load_diffs(module: &ShowCommit) {
+	let hash = module.loading_hash.clone().unwrap();
+	let diffs = module
+		.repository
+		.load_commit_diffs(hash.as_str(), &module.commit_diff_loader_options)
+		.map_err(|err| format!("{:#}", anyhow::Error::from(err)));
+	*module.diff_load.lock() = Some(LoadedDiff::new(hash, diffs));
+}
+
+// the range is loaded in place of the diff thread, the same as `load_diffs`
+fn load_range_diffs(module: &ShowCommit) {
+	let (first_hash, last_hash) = module.range.clone().unwrap();
+	let diffs = module
+		.repository
+		.load_range_diff(
+			first_hash.as_str(),
+			last_hash.as_str(),
+			&module.commit_diff_loader_options,
+		)
+		.map(|diff| vec![diff])
+		.map_err(|err| format!("{:#}", anyhow::Error::from(err)));
+	*module.diff_load.lock() = Some(LoadedDiff::new(format!("{first_hash}..{last_hash}"), diffs));
+}
+
 #[test]
 fn load_commit_during_activate() {
+	with_temp_repository(|repo| {
+		let oid = head_id(&repo, "main");
+		let line = format!("pick {oid} comment1");
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::DiffUpdate)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				assert_results!(
+					test_context.activate(&mut module, State::List),
+					Artifact::LoadDiff(oid.to_string(), Arc::new(Mutex::new(None)), Interrupter::new())
+				);
+				assert!(module.diffs.is_empty());
+				load_diffs(&module);
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::DiffUpdate))
+				);
+				assert!(!module.diffs.is_empty());
+				assert!(module.loading_hash.is_none());
+			},
+		);
+	});
+}
+
+#[test]
+fn load_merge_commit_during_activate() {
+	with_temp_repository(|repo| {
+		let oid = head_id(&repo, "main");
+		let line = format!("merge -C {oid} label");
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::DiffUpdate)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				load_diffs(&module);
+				_ = test_context.handle_event(&mut module);
+				assert_eq!(module.diffs.len(), 1);
+				assert_eq!(module.diffs[0].commit().hash(), oid.to_string());
+			},
+		);
+	});
+}
+
+#[test]
+fn cached_commit_in_activate() {
+	with_temp_repository(|repo| {
+		let oid = head_id(&repo, "main");
+		let line = format!("pick {oid} comment1");
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::DiffUpdate)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				load_diffs(&module);
+				_ = test_context.handle_event(&mut module);
+				assert_results!(test_context.activate(&mut module, State::List));
+			},
+		);
+	});
+}
+
+#[test]
+fn loading_commit_in_activate() {
 	with_temp_repository(|repo| {
 		let oid = head_id(&repo, "main");
 		let line = format!("pick {oid} comment1");
 		module_test(&[line.as_str()], &[], |mut test_context| {
 			let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			let interrupter = module.interrupter.clone();
 			assert_results!(test_context.activate(&mut module, State::List));
-			assert!(module.diff.is_some());
+			assert!(interrupter.should_continue());
 		});
 	});
 }
 
 #[test]
-fn cached_commit_in_activate() {
+fn interrupted_commit_load_restarted_in_activate() {
 	with_temp_repository(|repo| {
 		let oid = head_id(&repo, "main");
 		let line = format!("pick {oid} comment1");
 		module_test(&[line.as_str()], &[], |mut test_context| {
 			let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-			// would be nice to be able to test that a second call to load_commit_diff did not happen here
-			assert_results!(test_context.activate(&mut module, State::List));
-			assert_results!(test_context.activate(&mut module, State::List));
+			_ = test_context.activate(&mut module, State::List);
+			module.interrupter.interrupt();
+			assert_results!(
+				test_context.activate(&mut module, State::List),
+				Artifact::LoadDiff(oid.to_string(), Arc::new(Mutex::new(None)), Interrupter::new())
+			);
+			assert!(module.interrupter.should_continue());
 		});
 	});
 }
@@ -60,24 +176,122 @@ fn no_selected_line_in_activate() {
 }
 
 #[test]
-fn activate_error() {
+fn load_error() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick aaaaaaaaaa comment1"],
+			&[Event::from(MetaEvent::DiffUpdate)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				load_diffs(&module);
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::DiffUpdate)),
+					Artifact::Error(
+						anyhow!(
+							"Could not load commit: revspec 'aaaaaaaaaa' not found; class=Reference (4); code=NotFound \
+							 (-3)"
+						),
+						Some(State::List)
+					)
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn diff_update_while_loading() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick aaaaaaaaaa comment1"],
+			&[Event::from(MetaEvent::DiffUpdate)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				*module.diff_load.lock() = Some(LoadedDiff::new_loading(String::from("aaaaaaaaaa")));
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::DiffUpdate))
+				);
+				assert!(module.loading_hash.is_some());
+			},
+		);
+	});
+}
+
+#[test]
+fn render_loading() {
+	with_temp_repository(|repo| {
+		module_test(&["pick aaaaaaaaaa comment1"], &[], |mut test_context| {
+			let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{IndicatorColor}Loading commit: {Normal}aaaaaaaaaa (-)"
+			);
+		});
+	});
+}
+
+#[test]
+fn render_loading_with_loaded_files() {
 	with_temp_repository(|repo| {
 		module_test(&["pick aaaaaaaaaa comment1"], &[], |mut test_context| {
 			let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-			assert_results!(
-				test_context.activate(&mut module, State::List),
-				Artifact::Error(
-					anyhow!(
-						"Could not load commit: revspec 'aaaaaaaaaa' not found; class=Reference (4); code=NotFound \
-						 (-3)"
-					),
-					Some(State::List)
-				)
+			_ = test_context.activate(&mut module, State::List);
+			let mut loaded_diff = LoadedDiff::new_loading(String::from("aaaaaaaaaa"));
+			loaded_diff.push_file_status(
+				FileStatusBuilder::new()
+					.source_path("file.1")
+					.destination_path("file.1")
+					.status(Status::Added)
+					.build(),
+			);
+			loaded_diff.push_file_status(
+				FileStatusBuilder::new()
+					.source_path("file.2")
+					.destination_path("file.2")
+					.status(Status::Modified)
+					.build(),
+			);
+			*module.diff_load.lock() = Some(loaded_diff);
+			assert_rendered_output!(
+				test_context.build_view_data(&mut module),
+				"{TITLE}{HELP}",
+				"{LEADING}",
+				"{IndicatorColor}Loading commit: {Normal}aaaaaaaaaa (-)",
+				"{BODY}",
+				"{DiffAddColor}   added: file.1",
+				"{DiffChangeColor}modified: file.2"
 			);
 		});
 	});
 }
 
+#[test]
+fn leaving_interrupts_load() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick aaaaaaaaaa comment1"],
+			&[Event::from('a')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from('a')),
+					Artifact::ChangeState(State::List)
+				);
+				assert!(!module.interrupter.should_continue());
+			},
+		);
+	});
+}
+
 #[test]
 fn render_overview_minimal_commit() {
 	with_temp_repository(|repo| {
@@ -89,7 +303,7 @@ fn render_overview_minimal_commit() {
 				let commit_date = commit.committed_date().format("%c %z").to_string();
 				let diff = CommitDiffBuilder::new(commit).build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					"{TITLE}{HELP}",
@@ -118,7 +332,7 @@ fn render_overview_minimal_commit_compact() {
 				let commit_date = commit.committed_date().format("%c %z").to_string();
 				let diff = CommitDiffBuilder::new(commit).build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					"{TITLE}{HELP}",
@@ -148,7 +362,7 @@ fn render_overview_with_author() {
 				)
 				.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -175,7 +389,7 @@ fn render_overview_with_author_compact() {
 				)
 				.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -201,7 +415,7 @@ fn render_overview_with_committer() {
 				)
 				.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -228,7 +442,7 @@ fn render_overview_with_committer_compact() {
 				)
 				.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -254,7 +468,7 @@ fn render_overview_with_commit_summary() {
 				)
 				.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -280,7 +494,7 @@ fn render_overview_with_commit_body() {
 				)
 				.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -307,13 +521,132 @@ fn render_overview_with_commit_summary_and_body() {
 				)
 				.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 5),
+					"{Normal}Commit title",
+					"{Normal}",
+					"{Normal}Commit body",
+					render_line!(AnyLine 2)
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn render_overview_with_signature() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				let diff = CommitDiffBuilder::new(
+					CommitBuilder::new("0123456789abcdef0123456789abcdef")
+						.signature(SignatureKind::Gpg)
+						.build(),
+				)
+				.build();
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![diff];
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 5),
+					"{IndicatorColor}Signature: {Normal}GPG",
+					render_line!(AnyLine 2)
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn render_overview_with_signature_compact() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				test_context.render_context.update(30, 300);
+				let diff = CommitDiffBuilder::new(
+					CommitBuilder::new("0123456789abcdef0123456789abcdef")
+						.signature(SignatureKind::Ssh)
+						.build(),
+				)
+				.build();
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![diff];
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 5),
+					"{IndicatorColor}S: {Normal}SSH",
+					render_line!(AnyLine 2)
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn render_overview_with_x509_signature() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				let diff = CommitDiffBuilder::new(
+					CommitBuilder::new("0123456789abcdef0123456789abcdef")
+						.signature(SignatureKind::X509)
+						.build(),
+				)
+				.build();
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![diff];
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 5),
+					"{IndicatorColor}Signature: {Normal}X.509",
+					render_line!(AnyLine 2)
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn render_overview_with_trailers() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				let diff = CommitDiffBuilder::new(
+					CommitBuilder::new("0123456789abcdef0123456789abcdef")
+						.summary("Commit title")
+						.message(
+							"Commit body\n\nSigned-off-by: Jane Doe <jane.doe@example.com>\nReviewed-by: John Doe \
+							 <john.doe@example.com>\n",
+						)
+						.trailers(&[
+							Trailer::new("Signed-off-by", "Jane Doe <jane.doe@example.com>"),
+							Trailer::new("Reviewed-by", "John Doe <john.doe@example.com>"),
+						])
+						.build(),
+				)
+				.build();
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
 					"{Normal}Commit title",
 					"{Normal}",
 					"{Normal}Commit body",
+					"{Normal}",
+					"{IndicatorColor}Trailers:",
+					"{IndicatorColor}Signed-off-by: {Normal}Jane Doe <jane.doe@example.com>",
+					"{IndicatorColor}Reviewed-by: {Normal}John Doe <john.doe@example.com>",
 					render_line!(AnyLine 2)
 				);
 			},
@@ -369,7 +702,7 @@ fn render_overview_with_file_stats() {
 					])
 					.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 6),
@@ -438,7 +771,7 @@ fn render_overview_with_file_stats_compact() {
 					])
 					.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 6),
@@ -467,7 +800,7 @@ fn render_overview_single_file_changed() {
 					.number_files_changed(1)
 					.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 6),
@@ -490,7 +823,7 @@ fn render_overview_more_than_one_file_changed() {
 					.number_files_changed(2)
 					.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -514,7 +847,7 @@ fn render_overview_single_insertion() {
 					.number_insertions(1)
 					.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -538,7 +871,7 @@ fn render_overview_more_than_one_insertion() {
 					.number_insertions(2)
 					.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -562,7 +895,7 @@ fn render_overview_single_deletion() {
 					.number_deletions(1)
 					.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -586,7 +919,7 @@ fn render_overview_more_than_one_deletion() {
 					.number_deletions(2)
 					.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
 					render_line!(AnyLine 5),
@@ -611,7 +944,7 @@ fn render_diff_minimal_commit() {
 				let diff =
 					CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build()).build();
 				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -641,7 +974,7 @@ fn render_diff_minimal_commit_compact() {
 				let diff =
 					CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build()).build();
 				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -705,7 +1038,7 @@ fn render_diff_basic_file_stats() {
 					])
 					.build();
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -757,7 +1090,7 @@ fn render_diff_end_new_line_missing() {
 					])
 					.build();
 				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -801,7 +1134,7 @@ fn render_diff_add_line() {
 					])
 					.build();
 				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -844,7 +1177,7 @@ fn render_diff_delete_line() {
 					])
 					.build();
 				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -890,7 +1223,7 @@ fn render_diff_context_add_remove_lines() {
 					])
 					.build();
 				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -959,7 +1292,7 @@ fn render_diff_show_both_whitespace() {
 					])
 					.build();
 				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -1000,7 +1333,7 @@ fn render_diff_show_leading_whitespace() {
 					])
 					.build();
 				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -1041,7 +1374,7 @@ fn render_diff_show_no_whitespace() {
 					])
 					.build();
 				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -1084,7 +1417,7 @@ fn render_diff_show_whitespace_all_spaces() {
 					])
 					.build();
 				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
-				module.diff = Some(diff);
+				module.diffs = vec![diff];
 				module.state = ShowCommitState::Diff;
 				assert_rendered_output!(
 					test_context.build_view_data(&mut module),
@@ -1104,6 +1437,9 @@ fn handle_event_toggle_diff_to_overview() {
 			&[Event::from(MetaEvent::ShowDiff)],
 			|mut test_context| {
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![
+					CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build()).build(),
+				];
 				module
 					.diff_view_data
 					.update_view_data(|updater| updater.push_line(ViewLine::from("foo")));
@@ -1127,6 +1463,9 @@ fn handle_event_toggle_overview_to_diff() {
 			&[Event::from('d')],
 			|mut test_context| {
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![
+					CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build()).build(),
+				];
 				module
 					.overview_view_data
 					.update_view_data(|updater| updater.push_line(ViewLine::from("foo")));
@@ -1143,112 +1482,1288 @@ fn handle_event_toggle_overview_to_diff() {
 }
 
 #[test]
-fn handle_event_resize() {
+fn handle_event_toggle_overview_to_diff_while_loading() {
 	with_temp_repository(|repo| {
 		module_test(
 			&["pick 0123456789abcdef0123456789abcdef c1"],
-			&[Event::Resize(100, 100)],
+			&[Event::from('d')],
 			|mut test_context| {
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.state = ShowCommitState::Overview;
 				assert_results!(
 					test_context.handle_event(&mut module),
-					Artifact::Event(Event::Resize(100, 100))
+					Artifact::Event(Event::from(MetaEvent::ShowDiff))
 				);
+				assert_eq!(module.state, ShowCommitState::Overview);
 			},
 		);
 	});
 }
 
-#[test]
-fn render_help() {
+fn create_merge_diffs() -> Vec<CommitDiff> {
+	let diffs = vec![
+		CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+			.parent(CommitBuilder::new("aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa").build())
+			.build(),
+		CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+			.parent(CommitBuilder::new("bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb").build())
+			.build(),
+	];
+	let combined = CommitDiff::new_combined(&diffs).unwrap();
+	diffs.into_iter().chain([combined]).collect()
+}
+
+#[rstest]
+#[case::first_parent(0, "1 of 2 (aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa)")]
+#[case::second_parent(1, "2 of 2 (bbbbbbbbbbbbbbbbbbbbbbbbbbbbbbbb)")]
+#[case::combined(2, "combined diff of 2 parents")]
+fn render_overview_merge_commit(#[case] diff_index: usize, #[case] expected: &str) {
 	with_temp_repository(|repo| {
 		module_test(
-			&["pick aaa c1"],
-			&[Event::from(StandardEvent::Help)],
+			&["merge -C 0123456789abcdef0123456789abcdef label"],
+			&[],
 			|mut test_context| {
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				_ = test_context.handle_all_events(&mut module);
+				module.diffs = create_merge_diffs();
+				module.diff_index = diff_index;
 				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
 					test_context.build_view_data(&mut module),
-					"{TITLE}",
+					"{TITLE}{HELP}",
 					"{LEADING}",
-					"{Normal,Underline} Key      Action{Pad( )}",
+					"Commit: 0123456789abcdef0123456789abcdef",
+					format!("Parent: {expected}").as_str(),
 					"{BODY}",
-					"{IndicatorColor} Up      {Normal,Dimmed}|{Normal}Scroll up",
-					"{IndicatorColor} Down    {Normal,Dimmed}|{Normal}Scroll down",
-					"{IndicatorColor} PageUp  {Normal,Dimmed}|{Normal}Scroll up half a page",
-					"{IndicatorColor} PageDown{Normal,Dimmed}|{Normal}Scroll down half a page",
-					"{IndicatorColor} Home    {Normal,Dimmed}|{Normal}Scroll to the top",
-					"{IndicatorColor} End     {Normal,Dimmed}|{Normal}Scroll to the bottom",
-					"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll right",
-					"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll left",
-					"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Show full diff",
-					"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
-					"{TRAILING}",
-					"{IndicatorColor}Press any key to close"
+					render_line!(AnyLine 3)
 				);
 			},
 		);
 	});
 }
 
-#[test]
-fn handle_help_event_show() {
+#[rstest]
+#[case::first_parent(0, "1/2 aaaaaaaa")]
+#[case::combined(2, "combined")]
+fn render_diff_merge_commit_compact(#[case] diff_index: usize, #[case] expected: &str) {
 	with_temp_repository(|repo| {
 		module_test(
-			&["pick aaa c1"],
-			&[Event::from(StandardEvent::Help)],
+			&["merge -C 0123456789abcdef0123456789abcdef label"],
+			&[],
 			|mut test_context| {
+				test_context.render_context.update(30, 300);
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				_ = test_context.handle_all_events(&mut module);
-				assert!(module.help.is_active());
+				module.diffs = create_merge_diffs();
+				module.diff_index = diff_index;
+				module.state = ShowCommitState::Diff;
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					test_context.build_view_data(&mut module),
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"01234567",
+					expected,
+					"0 / 0 / 0",
+					"{BODY}",
+					render_line!(AnyLine 1)
+				);
 			},
 		);
 	});
 }
+
+#[test]
+fn handle_event_show_next_parent() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["merge -C 0123456789abcdef0123456789abcdef label"],
+			&[Event::from('p'), Event::from('p'), Event::from('p')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = create_merge_diffs();
+				module
+					.overview_view_data
+					.update_view_data(|updater| updater.push_line(ViewLine::from("foo")));
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::ShowNextParent))
+				);
+				assert_eq!(module.diff_index, 1);
+				assert!(module.overview_view_data.is_empty());
+				module.folds.toggle_file(0);
+				_ = test_context.handle_event(&mut module);
+				assert_eq!(module.diff_index, 2);
+				assert!(!module.folds.is_file_folded(0));
+				_ = test_context.handle_event(&mut module);
+				assert_eq!(module.diff_index, 0);
+				assert_eq!(module.state, ShowCommitState::Overview);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_show_next_parent_single_parent() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('p')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef").build()).build()];
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::ShowNextParent))
+				);
+				assert_eq!(module.diff_index, 0);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_toggle_wrap() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('w'), Event::from('w')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef").build()).build()];
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::ToggleWrap))
+				);
+				assert!(module.wrap);
+				assert_eq!(module.state, ShowCommitState::Overview);
+				_ = test_context.handle_event(&mut module);
+				assert!(!module.wrap);
+			},
+		);
+	});
+}
+
+fn create_file_status_with_delta(path: &str) -> git::FileStatus {
+	let mut delta = Delta::new("@@ -1,1 +1,1 @@", 1, 1, 1, 1);
+	delta.add_line(DiffLine::new(Origin::Deletion, "old", Some(1), None, false));
+	delta.add_line(DiffLine::new(Origin::Addition, "new", None, Some(1), false));
+	FileStatusBuilder::new()
+		.source_path(path)
+		.destination_path(path)
+		.status(Status::Modified)
+		.push_delta(delta)
+		.build()
+}
+
+fn create_diff_with_files() -> CommitDiff {
+	CommitDiffBuilder::new(
+		CommitBuilder::new("0123456789abcdef0123456789abcdef")
+			.summary("Summary")
+			.build(),
+	)
+	.file_statuses(vec![
+		create_file_status_with_delta("file.1"),
+		create_file_status_with_delta("file.2"),
+	])
+	.number_files_changed(2)
+	.build()
+}
+
+fn create_mouse_click(row: u16) -> Event {
+	Event::Mouse(MouseEvent {
+		kind: MouseEventKind::Down(MouseButton::Left),
+		column: 0,
+		row,
+		modifiers: KeyModifiers::empty(),
+	})
+}
+
+fn render(test_context: &ModuleTestContext, module: &mut ShowCommit) {
+	test_context.view_context.state.resize(300, 120);
+	let view_data = test_context.build_view_data(module);
+	test_context.view_context.state.render(view_data);
+}
+
+#[test]
+fn handle_event_click_overview_file() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[create_mouse_click(7)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_files()];
+				render(&test_context, &mut module);
+				assert_results!(test_context.handle_event(&mut module), Artifact::Event(create_mouse_click(7)));
+				assert!(module.overview_view_data.is_empty());
+				assert_eq!(module.state, ShowCommitState::Diff);
+				test_context.view_context.assert_render_action(&["ScrollTo(8)"]);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_click_overview_outside_files() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[create_mouse_click(0), create_mouse_click(5), create_mouse_click(8)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_files()];
+				render(&test_context, &mut module);
+				_ = test_context.handle_all_events(&mut module);
+				assert_eq!(module.state, ShowCommitState::Overview);
+				test_context.view_context.assert_render_action(&[]);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_click_diff() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[create_mouse_click(7)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_files()];
+				module.state = ShowCommitState::Diff;
+				render(&test_context, &mut module);
+				_ = test_context.handle_all_events(&mut module);
+				assert_eq!(module.state, ShowCommitState::Diff);
+				test_context.view_context.assert_render_action(&[]);
+			},
+		);
+	});
+}
+
+#[test]
+fn overview_file_line_start_matches_render() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[],
+			|mut test_context| {
+				let diff = create_diff_with_files();
+				assert_eq!(ViewBuilder::get_overview_file_line_start(&diff), 4);
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![diff];
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 8),
+					"modified: file.1",
+					"modified: file.2"
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn overview_file_line_start_matches_render_with_signature_and_trailers() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[],
+			|mut test_context| {
+				let diff = CommitDiffBuilder::new(
+					CommitBuilder::new("0123456789abcdef0123456789abcdef")
+						.summary("Summary")
+						.message("Summary\n\nSigned-off-by: Jane Doe <jane.doe@example.com>\n")
+						.signature(SignatureKind::Gpg)
+						.trailers(&[Trailer::new("Signed-off-by", "Jane Doe <jane.doe@example.com>")])
+						.build(),
+				)
+				.file_statuses(vec![
+					create_file_status_with_delta("file.1"),
+					create_file_status_with_delta("file.2"),
+				])
+				.number_files_changed(2)
+				.build();
+				assert_eq!(ViewBuilder::get_overview_file_line_start(&diff), 10);
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![diff];
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 14),
+					"modified: file.1",
+					"modified: file.2"
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn diff_file_line_index_matches_render() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[],
+			|mut test_context| {
+				let diff = create_diff_with_files();
+				assert_eq!(ViewBuilder::get_diff_file_line_index(&diff, &DiffFolds::new(), 0), 1);
+				assert_eq!(ViewBuilder::get_diff_file_line_index(&diff, &DiffFolds::new(), 1), 8);
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![diff];
+				module.state = ShowCommitState::Diff;
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 6),
+					"modified: file.1",
+					render_line!(AnyLine 6),
+					"modified: file.2",
+					render_line!(AnyLine 5)
+				);
+			},
+		);
+	});
+}
+
+fn create_diff_for_editor(hash: &str, path: &str, new_lines_start: u32) -> CommitDiff {
+	let mut delta = Delta::new("@@ -1,1 +1,1 @@", 1, new_lines_start, 1, 1);
+	delta.add_line(DiffLine::new(Origin::Addition, "new", None, Some(new_lines_start), false));
+	CommitDiffBuilder::new(CommitBuilder::new(hash).build())
+		.file_statuses(vec![
+			FileStatusBuilder::new()
+				.source_path(path)
+				.destination_path(path)
+				.status(Status::Modified)
+				.push_delta(delta)
+				.build(),
+		])
+		.build()
+}
+
+#[test]
+fn read_event_open_in_editor_overview() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('!')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				assert_eq!(test_context.read_event(&module), Event::from('!'));
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_open_in_editor() {
+	with_temp_repository(|repo| {
+		let root = repo_path(&repo).parent().unwrap().to_path_buf();
+		write(root.join("file.txt"), "line 1\nline 2\n").unwrap();
+		add_path_to_index(&repo, Path::new("file.txt"));
+		create_commit(&repo, None);
+		let hash = head_id(&repo, "main").to_string();
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[
+				Event::from('!'),
+				Event::from(MetaEvent::ExternalCommandSuccess),
+			],
+			|mut test_context| {
+				let mut config = Config::new();
+				config.git.editor = String::from("vim");
+				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_for_editor(hash.as_str(), "file.txt", 2)];
+				module.state = ShowCommitState::Diff;
+				let results = test_context.handle_event(&mut module);
+				let editor_file_path = module.editor_file.as_ref().unwrap().path().to_path_buf();
+				assert_results!(
+					results,
+					Artifact::Event(Event::from(MetaEvent::OpenInEditor)),
+					Artifact::ExternalCommand((String::from("vim"), vec![
+						String::from("+2"),
+						String::from(editor_file_path.to_str().unwrap())
+					]))
+				);
+				assert!(editor_file_path.to_string_lossy().ends_with("-file.txt"));
+				assert_eq!(
+					read_to_string(editor_file_path.as_path()).unwrap(),
+					"line 1\nline 2\n"
+				);
+				_ = test_context.handle_event(&mut module);
+				assert!(module.editor_file.is_none());
+				assert!(!editor_file_path.exists());
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_open_in_editor_file_not_in_commit() {
+	with_temp_repository(|repo| {
+		let hash = head_id(&repo, "main").to_string();
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('!')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_for_editor(hash.as_str(), "deleted.txt", 0)];
+				module.state = ShowCommitState::Diff;
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::OpenInEditor)),
+					Artifact::Error(
+						anyhow!(
+							"Could not load file from commit: the path 'deleted.txt' does not exist in the given tree; \
+							 class=Tree (14); code=NotFound (-3)"
+						),
+						None
+					)
+				);
+				assert!(module.editor_file.is_none());
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_open_in_editor_without_hunks() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('!')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef").build()).build()];
+				module.state = ShowCommitState::Diff;
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::OpenInEditor))
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn diff_delta_at_line() {
+	let diff = create_diff_with_files();
+	let delta_file = |line_index: usize| {
+		ViewBuilder::get_diff_delta_at_line(&diff, &DiffFolds::new(), line_index)
+			.map(|(status, _)| String::from(status.destination_path().to_str().unwrap()))
+	};
+	assert_eq!(delta_file(0), Some(String::from("file.1")));
+	assert_eq!(delta_file(6), Some(String::from("file.1")));
+	assert_eq!(delta_file(7), Some(String::from("file.2")));
+	assert_eq!(delta_file(13), Some(String::from("file.2")));
+	assert_eq!(delta_file(14), None);
+}
+
+#[test]
+fn handle_event_resize() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::Resize(100, 100)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::Resize(100, 100))
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn render_help() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick aaa c1"],
+			&[Event::from(StandardEvent::Help)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				_ = test_context.handle_all_events(&mut module);
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					"{TITLE}",
+					"{LEADING}",
+					"{Normal,Underline} Key      Action{Pad( )}",
+					"{BODY}",
+					"{IndicatorColor} Up      {Normal,Dimmed}|{Normal}Scroll up",
+					"{IndicatorColor} Down    {Normal,Dimmed}|{Normal}Scroll down",
+					"{IndicatorColor} PageUp  {Normal,Dimmed}|{Normal}Scroll up half a page",
+					"{IndicatorColor} PageDown{Normal,Dimmed}|{Normal}Scroll down half a page",
+					"{IndicatorColor} Home    {Normal,Dimmed}|{Normal}Scroll to the top",
+					"{IndicatorColor} End     {Normal,Dimmed}|{Normal}Scroll to the bottom",
+					"{IndicatorColor} Right   {Normal,Dimmed}|{Normal}Scroll right",
+					"{IndicatorColor} Left    {Normal,Dimmed}|{Normal}Scroll left",
+					"{IndicatorColor} d       {Normal,Dimmed}|{Normal}Show full diff",
+					"{IndicatorColor} w       {Normal,Dimmed}|{Normal}Toggle wrapping of long lines",
+					"{IndicatorColor} p       {Normal,Dimmed}|{Normal}Show diff against next parent of merge",
+					"{IndicatorColor} !       {Normal,Dimmed}|{Normal}Open file at top hunk in external editor",
+					"{IndicatorColor} x       {Normal,Dimmed}|{Normal}Expand collapsed file at top of diff",
+					"{IndicatorColor} z       {Normal,Dimmed}|{Normal}Fold or unfold hunk at top of diff",
+					"{IndicatorColor} Z       {Normal,Dimmed}|{Normal}Fold or unfold file at top of diff",
+					"{IndicatorColor} F       {Normal,Dimmed}|{Normal}Fold or unfold all files",
+					"{IndicatorColor} ]       {Normal,Dimmed}|{Normal}Jump to next hunk",
+					"{IndicatorColor} [       {Normal,Dimmed}|{Normal}Jump to previous hunk",
+					"{IndicatorColor} }       {Normal,Dimmed}|{Normal}Jump to next file",
+					"{IndicatorColor} {       {Normal,Dimmed}|{Normal}Jump to previous file",
+					"{IndicatorColor} ?       {Normal,Dimmed}|{Normal}Show help",
+					"{TRAILING}",
+					"{IndicatorColor}Press any key to close"
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_help_event_show() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick aaa c1"],
+			&[Event::from(StandardEvent::Help)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				_ = test_context.handle_all_events(&mut module);
+				assert!(module.help.is_active());
+			},
+		);
+	});
+}
+#[test]
+fn handle_help_event_hide() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick aaa c1"],
+			&[Event::from(StandardEvent::Help), Event::from('?')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				_ = test_context.handle_all_events(&mut module);
+				assert!(!module.help.is_active());
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_other_key_from_diff() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('a')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.state = ShowCommitState::Diff;
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from('a'))
+				);
+				assert_eq!(module.state, ShowCommitState::Overview);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_other_key_from_overview() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('a')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.state = ShowCommitState::Overview;
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from('a')),
+					Artifact::ChangeState(State::List)
+				);
+			},
+		);
+	});
+}
+
+#[rstest]
+#[case::scroll_left(StandardEvent::ScrollLeft)]
+#[case::scroll_right(StandardEvent::ScrollRight)]
+#[case::scroll_down(StandardEvent::ScrollDown)]
+#[case::scroll_up(StandardEvent::ScrollUp)]
+#[case::scroll_jump_down(StandardEvent::ScrollJumpDown)]
+#[case::scroll_jump_up(StandardEvent::ScrollJumpUp)]
+fn scroll_events(#[case] event: StandardEvent) {
+	with_temp_repository(|repo| {
+		module_test(&[], &[Event::from(event)], |mut test_context| {
+			let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(event))
+			);
+		});
+	});
+}
+
+fn create_diff_with_collapsed_files() -> CommitDiff {
+	CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+		.file_statuses(vec![
+			FileStatusBuilder::new()
+				.source_path("large.txt")
+				.destination_path("large.txt")
+				.status(Status::Modified)
+				.collapsed(CollapseReason::TooLarge, 1234)
+				.build(),
+			FileStatusBuilder::new()
+				.source_path("generated.txt")
+				.destination_path("generated.txt")
+				.status(Status::Modified)
+				.collapsed(CollapseReason::Generated, 3)
+				.build(),
+			FileStatusBuilder::new()
+				.source_path("image.png")
+				.destination_path("image.png")
+				.status(Status::Added)
+				.collapsed(CollapseReason::Binary, 0)
+				.build(),
+		])
+		.build()
+}
+
+#[test]
+fn render_diff_collapsed_files() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_collapsed_files()];
+				module.state = ShowCommitState::Diff;
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"{{Any(2)}}",
+					"{BODY}",
+					"{Normal}{Pad(―)}",
+					"{DiffChangeColor}modified: large.txt",
+					"",
+					"{IndicatorColor}1,234 lines hidden, press x to expand",
+					"{Normal}{Pad(―)}",
+					"{DiffChangeColor}modified: generated.txt",
+					"",
+					"{IndicatorColor}Generated file, 3 lines hidden, press x to expand",
+					"{Normal}{Pad(―)}",
+					"{DiffAddColor}   added: image.png",
+					"",
+					"{IndicatorColor}Binary file not shown"
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_click_overview_file_after_collapsed_file() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[create_mouse_click(6)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_collapsed_files()];
+				render(&test_context, &mut module);
+				_ = test_context.handle_event(&mut module);
+				assert_eq!(module.state, ShowCommitState::Diff);
+				test_context.view_context.assert_render_action(&["ScrollTo(5)"]);
+			},
+		);
+	});
+}
+
+#[test]
+fn read_event_expand_file_overview() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('x')],
+			|mut test_context| {
+				let module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				assert_eq!(test_context.read_event(&module), Event::from('x'));
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_expand_file() {
+	with_temp_repository(|repo| {
+		let root = repo_path(&repo).parent().unwrap().to_path_buf();
+		write(root.join("a.txt"), "line 1\nline 2\nline 3\n").unwrap();
+		add_path_to_index(&repo, Path::new("a.txt"));
+		create_commit(&repo, None);
+		let line = format!("pick {} c1", head_id(&repo, "main"));
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::DiffUpdate), Event::from('x')],
+			|mut test_context| {
+				let mut config = Config::new();
+				config.diff_file_line_limit = 2;
+				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
+				_ = test_context.activate(&mut module, State::List);
+				load_diffs(&module);
+				_ = test_context.handle_event(&mut module);
+				assert_eq!(
+					module.diffs[0].file_statuses()[0].collapse_reason(),
+					Some(CollapseReason::TooLarge)
+				);
+				module.state = ShowCommitState::Diff;
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::ExpandFile))
+				);
+				let status = &module.diffs[0].file_statuses()[0];
+				assert_eq!(status.collapse_reason(), None);
+				assert_eq!(status.deltas()[0].lines().len(), 3);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_expand_file_binary() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('x')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![
+					CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+						.file_statuses(vec![
+							FileStatusBuilder::new()
+								.source_path("image.png")
+								.destination_path("image.png")
+								.collapsed(CollapseReason::Binary, 0)
+								.build(),
+						])
+						.build(),
+				];
+				module.state = ShowCommitState::Diff;
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::ExpandFile))
+				);
+				assert_eq!(
+					module.diffs[0].file_statuses()[0].collapse_reason(),
+					Some(CollapseReason::Binary)
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_expand_file_error() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('x')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_collapsed_files()];
+				module.state = ShowCommitState::Diff;
+				let results = test_context.handle_event(&mut module);
+				assert_results!(
+					results,
+					Artifact::Event(Event::from(MetaEvent::ExpandFile)),
+					Artifact::Error(
+						anyhow!(
+							"Could not load commit: revspec '0123456789abcdef0123456789abcdef' not found; \
+							 class=Reference (4); code=NotFound (-3)"
+						),
+						None
+					)
+				);
+			},
+		);
+	});
+}
+
+fn create_diff_with_three_files() -> CommitDiff {
+	CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+		.file_statuses(vec![
+			create_file_status_with_delta("file.1"),
+			create_file_status_with_delta("file.2"),
+			create_file_status_with_delta("file.3"),
+		])
+		.number_files_changed(3)
+		.build()
+}
+
+fn render_scrolled_to(test_context: &ModuleTestContext, module: &mut ShowCommit, line_index: usize) {
+	test_context.view_context.state.resize(300, 6);
+	let view_data = test_context.build_view_data(module);
+	test_context.view_context.state.scroll_to(line_index);
+	test_context.view_context.state.render(view_data);
+	assert_eq!(test_context.view_context.state.get_top_line_index(), line_index);
+}
+
+#[rstest]
+#[case::fold_all('F', MetaEvent::FoldAll)]
+#[case::fold_file('Z', MetaEvent::FoldFile)]
+#[case::fold_hunk('z', MetaEvent::FoldHunk)]
+#[case::next_file('}', MetaEvent::NextFile)]
+#[case::next_hunk(']', MetaEvent::NextHunk)]
+#[case::previous_file('{', MetaEvent::PreviousFile)]
+#[case::previous_hunk('[', MetaEvent::PreviousHunk)]
+fn read_event_fold_and_jump(#[case] key: char, #[case] meta_event: MetaEvent) {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from(key), Event::from(key)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				assert_eq!(test_context.read_event(&module), Event::from(key));
+				module.state = ShowCommitState::Diff;
+				assert_eq!(test_context.read_event(&module), Event::from(meta_event));
+			},
+		);
+	});
+}
+
+#[test]
+fn render_diff_folded_file() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_files()];
+				module.state = ShowCommitState::Diff;
+				module.folds.toggle_file(0);
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 6),
+					"modified: file.1 (folded)",
+					render_line!(AnyLine 1),
+					"modified: file.2",
+					render_line!(AnyLine 5)
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn render_diff_folded_hunk() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_files()];
+				module.state = ShowCommitState::Diff;
+				module.folds.toggle_hunk(1, 0);
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 13),
+					"modified: file.2",
+					"",
+					"@@ -1,1 +1,1 @@  (2 lines folded)"
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn diff_line_indexes_with_folds() {
+	let diff = create_diff_with_three_files();
+	let mut folds = DiffFolds::new();
+	assert_eq!(ViewBuilder::get_diff_hunk_line_indexes(&diff, &folds), vec![
+		(0, 0, 3),
+		(1, 0, 10),
+		(2, 0, 17)
+	]);
+	assert_eq!(ViewBuilder::get_diff_file_line_indexes(&diff, &folds), vec![1, 8, 15]);
+	folds.toggle_file(0);
+	folds.toggle_hunk(1, 0);
+	assert_eq!(ViewBuilder::get_diff_file_line_index(&diff, &folds, 1), 3);
+	assert_eq!(ViewBuilder::get_diff_file_line_index(&diff, &folds, 2), 7);
+	assert_eq!(ViewBuilder::get_diff_file_line_indexes(&diff, &folds), vec![1, 3, 7]);
+	assert_eq!(ViewBuilder::get_diff_hunk_line_indexes(&diff, &folds), vec![
+		(1, 0, 5),
+		(2, 0, 9)
+	]);
+	assert_eq!(ViewBuilder::get_diff_file_at_line(&diff, &folds, 1), Some(0));
+	assert_eq!(ViewBuilder::get_diff_file_at_line(&diff, &folds, 6), Some(1));
+	assert_eq!(ViewBuilder::get_diff_file_at_line(&diff, &folds, 7), Some(2));
+	assert_eq!(ViewBuilder::get_diff_hunk_at_line(&diff, &folds, 1), Some((1, 0)));
+	assert_eq!(ViewBuilder::get_diff_hunk_at_line(&diff, &folds, 7), Some((2, 0)));
+	assert_eq!(ViewBuilder::get_diff_hunk_at_line(&diff, &folds, 13), None);
+}
+
+#[test]
+fn handle_event_fold_file() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('Z'), Event::from('Z')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_three_files()];
+				module.state = ShowCommitState::Diff;
+				render_scrolled_to(&test_context, &mut module, 11);
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::FoldFile))
+				);
+				assert!(module.folds.is_file_folded(1));
+				assert!(module.diff_view_data.is_empty());
+				test_context.view_context.assert_render_action(&["ScrollTo(8)"]);
+				render_scrolled_to(&test_context, &mut module, 8);
+				_ = test_context.handle_event(&mut module);
+				assert!(!module.folds.is_file_folded(1));
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_fold_hunk() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('z')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_three_files()];
+				module.state = ShowCommitState::Diff;
+				render_scrolled_to(&test_context, &mut module, 12);
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::FoldHunk))
+				);
+				assert!(module.folds.is_hunk_folded(1, 0));
+				assert!(module.diff_view_data.is_empty());
+				test_context.view_context.assert_render_action(&["ScrollTo(10)"]);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_fold_all() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from('F'), Event::from('F')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_three_files()];
+				module.state = ShowCommitState::Diff;
+				render_scrolled_to(&test_context, &mut module, 11);
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from(MetaEvent::FoldAll))
+				);
+				assert!(module.folds.is_file_folded(0));
+				assert!(module.folds.is_file_folded(1));
+				assert!(module.folds.is_file_folded(2));
+				test_context.view_context.assert_render_action(&["ScrollTo(3)"]);
+				_ = test_context.handle_event(&mut module);
+				assert!(!module.folds.is_file_folded(0));
+				assert!(!module.folds.is_file_folded(1));
+				assert!(!module.folds.is_file_folded(2));
+			},
+		);
+	});
+}
+
+#[rstest]
+#[case::next_file_from_first_file('}', 3, 8)]
+#[case::next_file_from_file_header('}', 8, 15)]
+#[case::previous_file_from_hunk('{', 10, 8)]
+#[case::previous_file_from_file_header('{', 8, 1)]
+#[case::next_hunk_from_first_hunk(']', 3, 10)]
+#[case::next_hunk_from_file_header(']', 8, 10)]
+#[case::previous_hunk_from_hunk('[', 10, 3)]
+#[case::previous_hunk_from_hunk_lines('[', 12, 10)]
+fn handle_event_jump(#[case] key: char, #[case] top_line_index: usize, #[case] expected_line_index: usize) {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from(key)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_three_files()];
+				module.state = ShowCommitState::Diff;
+				render_scrolled_to(&test_context, &mut module, top_line_index);
+				_ = test_context.handle_event(&mut module);
+				let action = format!("ScrollTo({expected_line_index})");
+				test_context.view_context.assert_render_action(&[action.as_str()]);
+			},
+		);
+	});
+}
+
+#[rstest]
+#[case::next_file('}', 15)]
+#[case::next_hunk(']', 17)]
+fn handle_event_jump_at_end(#[case] key: char, #[case] top_line_index: usize) {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from(key)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_three_files()];
+				module.state = ShowCommitState::Diff;
+				render_scrolled_to(&test_context, &mut module, top_line_index);
+				_ = test_context.handle_event(&mut module);
+				test_context.view_context.assert_render_action(&[]);
+			},
+		);
+	});
+}
+
 #[test]
-fn handle_help_event_hide() {
+fn handle_event_next_hunk_skips_folded_file() {
 	with_temp_repository(|repo| {
 		module_test(
-			&["pick aaa c1"],
-			&[Event::from(StandardEvent::Help), Event::from('?')],
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from(']')],
 			|mut test_context| {
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				_ = test_context.handle_all_events(&mut module);
-				assert!(!module.help.is_active());
+				module.diffs = vec![create_diff_with_three_files()];
+				module.state = ShowCommitState::Diff;
+				module.folds.toggle_file(1);
+				render_scrolled_to(&test_context, &mut module, 3);
+				_ = test_context.handle_event(&mut module);
+				test_context.view_context.assert_render_action(&["ScrollTo(12)"]);
 			},
 		);
 	});
 }
 
 #[test]
-fn handle_event_other_key_from_diff() {
+fn handle_event_click_overview_folded_file() {
 	with_temp_repository(|repo| {
 		module_test(
 			&["pick 0123456789abcdef0123456789abcdef c1"],
-			&[Event::from('a')],
+			&[create_mouse_click(7)],
 			|mut test_context| {
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.state = ShowCommitState::Diff;
+				module.diffs = vec![create_diff_with_files()];
+				module.folds.toggle_file(0);
+				module.folds.toggle_file(1);
+				render(&test_context, &mut module);
+				_ = test_context.handle_event(&mut module);
+				assert!(module.folds.is_file_folded(0));
+				assert!(!module.folds.is_file_folded(1));
+				test_context.view_context.assert_render_action(&["ScrollTo(3)"]);
+			},
+		);
+	});
+}
+
+#[test]
+fn load_range_during_activate() {
+	with_temp_repository(|repo| {
+		let oid = head_id(&repo, "main").to_string();
+		let line = format!("pick {oid} comment1");
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::DiffUpdate)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				assert_results!(module.handle_range(oid.as_str(), oid.as_str()));
 				assert_results!(
-					test_context.handle_event(&mut module),
-					Artifact::Event(Event::from('a'))
+					test_context.activate(&mut module, State::List),
+					Artifact::LoadRangeDiff(oid.clone(), oid.clone(), Arc::new(Mutex::new(None)), Interrupter::new())
+				);
+				assert_eq!(module.state, ShowCommitState::Diff);
+				assert_eq!(module.loading_hash, Some(format!("{oid}..{oid}")));
+				load_range_diffs(&module);
+				_ = test_context.handle_event(&mut module);
+				assert_eq!(module.diffs.len(), 1);
+				assert_eq!(module.diffs[0].commit().hash(), oid);
+				assert!(module.loading_hash.is_none());
+			},
+		);
+	});
+}
+
+#[test]
+fn cached_range_in_activate() {
+	with_temp_repository(|repo| {
+		let oid = head_id(&repo, "main").to_string();
+		let line = format!("pick {oid} comment1");
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::DiffUpdate)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				_ = module.handle_range(oid.as_str(), oid.as_str());
+				_ = test_context.activate(&mut module, State::List);
+				load_range_diffs(&module);
+				_ = test_context.handle_event(&mut module);
+				_ = module.handle_range(oid.as_str(), oid.as_str());
+				assert_results!(test_context.activate(&mut module, State::List));
+			},
+		);
+	});
+}
+
+#[test]
+fn activate_after_range_loads_selected_commit() {
+	with_temp_repository(|repo| {
+		let oid = head_id(&repo, "main").to_string();
+		let line = format!("pick {oid} comment1");
+		module_test(
+			&[line.as_str()],
+			&[Event::from(MetaEvent::DiffUpdate)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				_ = module.handle_range(oid.as_str(), oid.as_str());
+				_ = test_context.activate(&mut module, State::List);
+				load_range_diffs(&module);
+				_ = test_context.handle_event(&mut module);
+				assert_results!(
+					test_context.activate(&mut module, State::List),
+					Artifact::LoadDiff(oid.clone(), Arc::new(Mutex::new(None)), Interrupter::new())
 				);
+				assert!(module.range.is_none());
 				assert_eq!(module.state, ShowCommitState::Overview);
 			},
 		);
 	});
 }
 
+fn create_range_diff() -> CommitDiff {
+	CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build())
+		.parent(CommitBuilder::new("abcdef0123456789abcdef0123456789").build())
+		.build()
+}
+
 #[test]
-fn handle_event_other_key_from_overview() {
+fn render_range_diff() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				let mut config = Config::new();
+				config.diff_show_whitespace = DiffShowWhitespaceSetting::None;
+				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
+				module.diffs = vec![create_range_diff()];
+				module.range = Some((String::from("a"), String::from("b")));
+				module.state = ShowCommitState::Diff;
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"{IndicatorColor}Range: {Normal}abcdef0123456789abcdef0123456789..0123456789abcdef0123456789abcdef",
+					"{IndicatorColor}0{Normal} files with {DiffAddColor}0{Normal} insertions and \
+					 {DiffRemoveColor}0{Normal} deletions",
+					"{BODY}",
+					"{Normal}{Pad(―)}"
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn render_range_diff_compact() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				test_context.render_context.update(30, 300);
+				let mut config = Config::new();
+				config.diff_show_whitespace = DiffShowWhitespaceSetting::None;
+				let mut module = create_show_commit(&config, repo, test_context.take_todo_file());
+				module.diffs = vec![create_range_diff()];
+				module.range = Some((String::from("a"), String::from("b")));
+				module.state = ShowCommitState::Diff;
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"{Normal}abcdef01..01234567",
+					"{IndicatorColor}0{Normal} / {DiffAddColor}0{Normal} / {DiffRemoveColor}0",
+					"{BODY}",
+					"{Normal}{Pad(―)}"
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn render_range_diff_from_root() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![
+					CommitDiffBuilder::new(CommitBuilder::new("0123456789abcdef0123456789abcdef").build()).build(),
+				];
+				module.range = Some((String::from("a"), String::from("b")));
+				module.state = ShowCommitState::Diff;
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					test_context.build_view_data(&mut module),
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"Range: (root)..0123456789abcdef0123456789abcdef",
+					"0 files with 0 insertions and 0 deletions",
+					"{BODY}",
+					"{Pad(―)}"
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_show_diff_in_range() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from(MetaEvent::ShowDiff)],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_range_diff()];
+				module.range = Some((String::from("a"), String::from("b")));
+				module.state = ShowCommitState::Diff;
+				_ = test_context.handle_event(&mut module);
+				assert_eq!(module.state, ShowCommitState::Diff);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_other_key_from_range_diff() {
 	with_temp_repository(|repo| {
 		module_test(
 			&["pick 0123456789abcdef0123456789abcdef c1"],
 			&[Event::from('a')],
 			|mut test_context| {
 				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
-				module.state = ShowCommitState::Overview;
+				module.range = Some((String::from("a"), String::from("b")));
+				module.state = ShowCommitState::Diff;
 				assert_results!(
 					test_context.handle_event(&mut module),
 					Artifact::Event(Event::from('a')),
@@ -1259,21 +2774,207 @@ fn handle_event_other_key_from_overview() {
 	});
 }
 
-#[rstest]
-#[case::scroll_left(StandardEvent::ScrollLeft)]
-#[case::scroll_right(StandardEvent::ScrollRight)]
-#[case::scroll_down(StandardEvent::ScrollDown)]
-#[case::scroll_up(StandardEvent::ScrollUp)]
-#[case::scroll_jump_down(StandardEvent::ScrollJumpDown)]
-#[case::scroll_jump_up(StandardEvent::ScrollJumpUp)]
-fn scroll_events(#[case] event: StandardEvent) {
+fn create_squash_commits(repo: &Repository) -> (String, String) {
+	create_commit(repo, Some(CreateCommitOptions::new().message("first\n\nfirst body\n")));
+	let first_hash = head_id(repo, "main").to_string();
+	create_commit(repo, Some(CreateCommitOptions::new().message("second\n")));
+	let last_hash = head_id(repo, "main").to_string();
+	(first_hash, last_hash)
+}
+
+#[test]
+fn load_squash_during_activate() {
 	with_temp_repository(|repo| {
-		module_test(&[], &[Event::from(event)], |mut test_context| {
+		let (first_hash, last_hash) = create_squash_commits(&repo);
+		let lines = [
+			Line::new(format!("pick {first_hash} c1").as_str()).unwrap(),
+			Line::new(format!("squash {last_hash} c2").as_str()).unwrap(),
+		];
+		module_test(&["pick aaa c1"], &[], |mut test_context| {
 			let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+			assert_results!(module.handle_squash(&lines));
 			assert_results!(
-				test_context.handle_event(&mut module),
-				Artifact::Event(Event::from(event))
+				test_context.activate(&mut module, State::List),
+				Artifact::LoadRangeDiff(
+					first_hash.clone(),
+					last_hash.clone(),
+					Arc::new(Mutex::new(None)),
+					Interrupter::new()
+				)
+			);
+			assert_eq!(module.state, ShowCommitState::Overview);
+			assert_eq!(module.squash_message.as_deref(), Some("first\n\nfirst body\n\nsecond"));
+		});
+	});
+}
+
+#[test]
+fn load_squash_with_fixup_during_activate() {
+	with_temp_repository(|repo| {
+		let (first_hash, last_hash) = create_squash_commits(&repo);
+		let lines = [
+			Line::new(format!("pick {first_hash} c1").as_str()).unwrap(),
+			Line::new(format!("fixup {last_hash} c2").as_str()).unwrap(),
+		];
+		module_test(&["pick aaa c1"], &[], |mut test_context| {
+			let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+			_ = module.handle_squash(&lines);
+			_ = test_context.activate(&mut module, State::List);
+			assert_eq!(module.squash_message.as_deref(), Some("first\n\nfirst body"));
+		});
+	});
+}
+
+#[test]
+fn load_squash_commit_error() {
+	with_temp_repository(|repo| {
+		let lines = [
+			Line::new("pick aaaaaaaaaa c1").unwrap(),
+			Line::new("fixup bbbbbbbbbb c2").unwrap(),
+		];
+		module_test(&["pick aaa c1"], &[], |mut test_context| {
+			let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+			_ = module.handle_squash(&lines);
+			assert_results!(
+				test_context.activate(&mut module, State::List),
+				Artifact::Error(
+					anyhow!(
+						"Could not load commit: revspec 'aaaaaaaaaa' not found; class=Reference (4); code=NotFound \
+						 (-3)"
+					),
+					Some(State::List)
+				)
 			);
+			assert!(module.range.is_none());
+		});
+	});
+}
+
+#[test]
+fn load_squash_not_consecutive_error() {
+	with_temp_repository(|repo| {
+		let (first_hash, _) = create_squash_commits(&repo);
+		create_commit(&repo, Some(CreateCommitOptions::new().message("third\n")));
+		let last_hash = head_id(&repo, "main").to_string();
+		let lines = [
+			Line::new(format!("pick {first_hash} c1").as_str()).unwrap(),
+			Line::new(format!("fixup {last_hash} c3").as_str()).unwrap(),
+		];
+		module_test(&["pick aaa c1"], &[], |mut test_context| {
+			let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+			_ = module.handle_squash(&lines);
+			assert_results!(
+				test_context.activate(&mut module, State::List),
+				Artifact::Error(
+					anyhow!(
+						"Cannot preview the squash, the fixup and squash commits must directly follow the commit in \
+						 the history"
+					),
+					Some(State::List)
+				)
+			);
+			assert!(module.range.is_none());
+		});
+	});
+}
+
+#[test]
+fn render_squash_overview() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef comment1"],
+			&[],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_range_diff()];
+				module.range = Some((String::from("a"), String::from("b")));
+				module.squash_message = Some(String::from("title\n\nbody"));
+				module.state = ShowCommitState::Overview;
+				assert_rendered_output!(
+					test_context.build_view_data(&mut module),
+					"{TITLE}{HELP}",
+					"{LEADING}",
+					"{IndicatorColor}Squash: \
+					 {Normal}abcdef0123456789abcdef0123456789..0123456789abcdef0123456789abcdef",
+					"{BODY}",
+					"{Normal}title",
+					"{Normal}",
+					"{Normal}body",
+					"{Normal}",
+					"{IndicatorColor}0{Normal} files with {DiffAddColor}0{Normal} insertions and \
+					 {DiffRemoveColor}0{Normal} deletions"
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn squash_overview_file_line_start_matches_render() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[],
+			|mut test_context| {
+				assert_eq!(ViewBuilder::get_squash_overview_file_line_start("title\n\nbody"), 5);
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_diff_with_files()];
+				module.range = Some((String::from("a"), String::from("b")));
+				module.squash_message = Some(String::from("title\n\nbody"));
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					test_context.build_view_data(&mut module),
+					render_line!(AnyLine 9),
+					"modified: file.1",
+					"modified: file.2"
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn handle_event_toggle_squash_overview_and_diff() {
+	with_temp_repository(|repo| {
+		module_test(
+			&["pick 0123456789abcdef0123456789abcdef c1"],
+			&[Event::from(MetaEvent::ShowDiff), Event::from('a'), Event::from('a')],
+			|mut test_context| {
+				let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+				module.diffs = vec![create_range_diff()];
+				module.range = Some((String::from("a"), String::from("b")));
+				module.squash_message = Some(String::from("title"));
+				module.state = ShowCommitState::Overview;
+				_ = test_context.handle_event(&mut module);
+				assert_eq!(module.state, ShowCommitState::Diff);
+				_ = test_context.handle_event(&mut module);
+				assert_eq!(module.state, ShowCommitState::Overview);
+				assert_results!(
+					test_context.handle_event(&mut module),
+					Artifact::Event(Event::from('a')),
+					Artifact::ChangeState(State::List)
+				);
+			},
+		);
+	});
+}
+
+#[test]
+fn activate_after_squash_loads_selected_commit() {
+	with_temp_repository(|repo| {
+		let (first_hash, last_hash) = create_squash_commits(&repo);
+		let lines = [
+			Line::new(format!("pick {first_hash} c1").as_str()).unwrap(),
+			Line::new(format!("squash {last_hash} c2").as_str()).unwrap(),
+		];
+		let line = format!("pick {last_hash} c2");
+		module_test(&[line.as_str()], &[], |mut test_context| {
+			let mut module = create_show_commit(&Config::new(), repo, test_context.take_todo_file());
+			_ = module.handle_squash(&lines);
+			_ = test_context.activate(&mut module, State::List);
+			_ = test_context.activate(&mut module, State::List);
+			assert!(module.range.is_none());
+			assert!(module.squash_message.is_none());
 		});
 	});
 }
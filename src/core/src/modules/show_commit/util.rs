@@ -2,8 +2,9 @@ use std::path::Path;
 
 use config::KeyBindings;
 use display::DisplayColor;
-use git::{CommitDiff, Status};
+use git::{Commit, CommitDiff, Status};
 use num_format::{Locale, ToFormattedString};
+use todo_file::{Action, Line};
 use unicode_segmentation::UnicodeSegmentation;
 use view::{LineSegment, ViewLine};
 
@@ -27,6 +28,38 @@ pub(super) fn get_show_commit_help_lines(key_bindings: &KeyBindings) -> Vec<(Vec
 		(key_bindings.scroll_right.clone(), String::from("Scroll right")),
 		(key_bindings.scroll_left.clone(), String::from("Scroll left")),
 		(key_bindings.show_diff.clone(), String::from("Show full diff")),
+		(key_bindings.toggle_wrap.clone(), String::from("Toggle wrapping of long lines")),
+		(
+			key_bindings.show_next_parent.clone(),
+			String::from("Show diff against next parent of merge"),
+		),
+		(
+			key_bindings.open_in_external_editor.clone(),
+			String::from("Open file at top hunk in external editor"),
+		),
+		(
+			key_bindings.expand_file.clone(),
+			String::from("Expand collapsed file at top of diff"),
+		),
+		(
+			key_bindings.fold_hunk.clone(),
+			String::from("Fold or unfold hunk at top of diff"),
+		),
+		(
+			key_bindings.fold_file.clone(),
+			String::from("Fold or unfold file at top of diff"),
+		),
+		(key_bindings.fold_all.clone(), String::from("Fold or unfold all files")),
+		(key_bindings.next_hunk.clone(), String::from("Jump to next hunk")),
+		(
+			key_bindings.previous_hunk.clone(),
+			String::from("Jump to previous hunk"),
+		),
+		(key_bindings.next_file.clone(), String::from("Jump to next file")),
+		(
+			key_bindings.previous_file.clone(),
+			String::from("Jump to previous file"),
+		),
 		(key_bindings.help.clone(), String::from("Show help")),
 	]
 }
@@ -176,12 +209,77 @@ pub(super) fn get_partition_index_on_whitespace_for_line(line: &str) -> (usize,
 	(start_partition_index, end_partition_index)
 }
 
+// the message of the commit, without the last paragraph when it holds the trailers, since those are shown separately
+pub(super) fn get_message_without_trailers(commit: &Commit) -> Option<&str> {
+	let message = commit.message()?;
+	if commit.trailers().is_empty() {
+		return Some(message);
+	}
+	let trimmed_message = message.trim_end();
+	Some(
+		trimmed_message
+			.rsplit_once("\n\n")
+			.map_or(trimmed_message, |(body, _)| body.trim_end()),
+	)
+}
+
+// the message of the commit that results from squashing the commits of the lines, where the message of a fixup is
+// dropped, unless the fixup has the -C or -c option, in which case its message replaces the preceding messages
+pub(super) fn get_squash_message(lines_with_messages: &[(&Line, &str)]) -> String {
+	let mut messages = vec![];
+	for &(line, message) in lines_with_messages {
+		if line.get_action() != &Action::Fixup {
+			messages.push(message.trim_end());
+		}
+		else if line.option().is_some() {
+			messages.clear();
+			messages.push(message.trim_end());
+		}
+	}
+	messages.join("\n\n")
+}
+
 #[cfg(test)]
 mod tests {
+	use git::{testutil::CommitBuilder, Trailer};
 	use rstest::rstest;
 
 	use super::*;
 
+	#[rstest]
+	#[case::without_trailers("Title\n\nBody\n", false, "Title\n\nBody\n")]
+	#[case::with_trailers("Title\n\nBody\n\nSigned-off-by: Name\n", true, "Title\n\nBody")]
+	#[case::title_and_trailers("Title\n\nSigned-off-by: Name\n", true, "Title")]
+	fn get_message_without_trailers_cases(#[case] message: &str, #[case] has_trailers: bool, #[case] expected: &str) {
+		let trailers = if has_trailers {
+			vec![Trailer::new("Signed-off-by", "Name")]
+		}
+		else {
+			vec![]
+		};
+		let commit = CommitBuilder::new("0123456789abcdef")
+			.message(message)
+			.trailers(trailers.as_slice())
+			.build();
+		assert_eq!(get_message_without_trailers(&commit), Some(expected));
+	}
+
+	#[rstest]
+	#[case::pick_only(&["pick aaa c1"], "first")]
+	#[case::fixup_dropped(&["pick aaa c1", "fixup bbb c2"], "first")]
+	#[case::squash_appended(&["pick aaa c1", "squash bbb c2"], "first\n\nsecond")]
+	#[case::fixup_and_squash(&["pick aaa c1", "fixup bbb c2", "squash ccc c3"], "first\n\nthird")]
+	#[case::fixup_keep_message(&["pick aaa c1", "squash bbb c2", "fixup -C ccc c3"], "third")]
+	#[case::fixup_keep_message_with_editor(&["pick aaa c1", "fixup -c bbb c2", "squash ccc c3"], "second\n\nthird")]
+	fn get_squash_message_cases(#[case] lines: &[&str], #[case] expected: &str) {
+		let lines = lines.iter().map(|line| Line::new(line).unwrap()).collect::<Vec<Line>>();
+		let lines_with_messages = lines
+			.iter()
+			.zip(["first\n", "second\n", "third\n"])
+			.collect::<Vec<(&Line, &str)>>();
+		assert_eq!(get_squash_message(&lines_with_messages), expected);
+	}
+
 	#[rstest]
 	#[case::empty_string("", 0, 0)]
 	#[case::single_character("a", 0, 1)]
@@ -1,12 +1,22 @@
 use display::DisplayColor;
-use git::{Commit, CommitDiff, DiffLine, Origin};
+use git::{CollapseReason, Commit, CommitDiff, Delta, DiffLine, FileStatus, Origin};
+use num_format::{Locale, ToFormattedString};
 use view::{LineSegment, ViewDataUpdater, ViewLine};
 
-use super::util::{get_files_changed_summary, get_partition_index_on_whitespace_for_line, get_stat_item_segments};
+use super::{
+	diff_folds::DiffFolds,
+	util::{
+		get_files_changed_summary,
+		get_message_without_trailers,
+		get_partition_index_on_whitespace_for_line,
+		get_stat_item_segments,
+	},
+};
 
 const PADDING_CHARACTER: char = '\u{2015}'; // '―'
 
-pub(super) struct ViewBuilderOptions {
+pub(crate) struct ViewBuilderOptions {
+	expand_file_key: String,
 	space_character: String,
 	tab_character: String,
 	tab_width: usize,
@@ -21,8 +31,10 @@ impl ViewBuilderOptions {
 		space_character: &str,
 		show_leading_whitespace: bool,
 		show_trailing_whitespace: bool,
+		expand_file_key: &str,
 	) -> Self {
 		Self {
+			expand_file_key: String::from(expand_file_key),
 			space_character: String::from(space_character),
 			tab_character: String::from(tab_character),
 			tab_width,
@@ -32,7 +44,8 @@ impl ViewBuilderOptions {
 	}
 }
 
-pub(super) struct ViewBuilder {
+pub(crate) struct ViewBuilder {
+	expand_file_key: String,
 	invisible_tab_string: String,
 	visible_tab_string: String,
 	visible_space_string: String,
@@ -43,6 +56,7 @@ pub(super) struct ViewBuilder {
 impl ViewBuilder {
 	pub(crate) fn new(options: ViewBuilderOptions) -> Self {
 		Self {
+			expand_file_key: options.expand_file_key,
 			invisible_tab_string: " ".repeat(options.tab_width),
 			visible_tab_string: format!("{0:width$}", options.tab_character, width = options.tab_width),
 			visible_space_string: options.space_character,
@@ -84,15 +98,101 @@ impl ViewBuilder {
 		ViewLine::from(segments)
 	}
 
+	// safe slice, as it is only on the hash, which is hexadecimal
+	#[allow(clippy::string_slice)]
+	fn build_leading_parent_summary(
+		diff: &CommitDiff,
+		diff_index: usize,
+		parent_count: usize,
+		is_full_width: bool,
+	) -> ViewLine {
+		let mut segments = vec![];
+		if is_full_width {
+			segments.push(LineSegment::new_with_color("Parent: ", DisplayColor::IndicatorColor));
+		}
+		segments.push(LineSegment::new(
+			match diff.parent().as_ref() {
+				Some(parent) => {
+					let hash = parent.hash();
+					if is_full_width {
+						format!("{} of {parent_count} ({hash})", diff_index + 1)
+					}
+					else {
+						let max_index = hash.len().min(8);
+						format!("{}/{parent_count} {}", diff_index + 1, &hash[0..max_index])
+					}
+				},
+				None => {
+					if is_full_width {
+						format!("combined diff of {parent_count} parents")
+					}
+					else {
+						String::from("combined")
+					}
+				},
+			}
+			.as_str(),
+		));
+		ViewLine::from(segments)
+	}
+
+	// safe slice, as it is only on the hash, which is hexadecimal
+	#[allow(clippy::string_slice)]
+	fn build_leading_range_summary(label: &str, diff: &CommitDiff, is_full_width: bool) -> ViewLine {
+		let format_hash = |hash: &str| {
+			if is_full_width {
+				String::from(hash)
+			}
+			else {
+				String::from(&hash[0..hash.len().min(8)])
+			}
+		};
+		let mut segments = vec![];
+		if is_full_width {
+			segments.push(LineSegment::new_with_color(label, DisplayColor::IndicatorColor));
+		}
+		segments.push(LineSegment::new(
+			format!(
+				"{}..{}",
+				diff.parent()
+					.as_ref()
+					.map_or_else(|| String::from("(root)"), |parent| format_hash(parent.hash())),
+				format_hash(diff.commit().hash())
+			)
+			.as_str(),
+		));
+		ViewLine::from(segments)
+	}
+
+	fn build_leading_lines(
+		updater: &mut ViewDataUpdater<'_>,
+		diff: &CommitDiff,
+		diff_index: usize,
+		parent_count: usize,
+		is_full_width: bool,
+	) {
+		updater.push_leading_line(Self::build_leading_summary(diff.commit(), is_full_width));
+		if parent_count > 1 {
+			updater.push_leading_line(Self::build_leading_parent_summary(
+				diff,
+				diff_index,
+				parent_count,
+				is_full_width,
+			));
+		}
+	}
+
 	#[allow(clippy::unused_self)]
-	pub(super) fn build_view_data_for_overview(
+	pub(crate) fn build_view_data_for_overview(
 		&self,
 		updater: &mut ViewDataUpdater<'_>,
 		diff: &CommitDiff,
+		diff_index: usize,
+		parent_count: usize,
 		is_full_width: bool,
 	) {
 		let commit = diff.commit();
-		updater.push_leading_line(Self::build_leading_summary(commit, is_full_width));
+		Self::build_leading_lines(updater, diff, diff_index, parent_count, is_full_width);
 		// TODO handle authored date
 		updater.push_line(ViewLine::from(vec![
 			LineSegment::new_with_color(
@@ -122,12 +222,22 @@ impl ViewBuilder {
 			]));
 		}
 
+		if let Some(kind) = commit.signature() {
+			updater.push_line(ViewLine::from(vec![
+				LineSegment::new_with_color(
+					if is_full_width { "Signature: " } else { "S: " },
+					DisplayColor::IndicatorColor,
+				),
+				LineSegment::new(kind.to_string().as_str()),
+			]));
+		}
+
 		if let Some(summary) = commit.summary() {
 			updater.push_lines(summary);
 			updater.push_line(ViewLine::from(""));
 		}
 
-		if let Some(message) = commit.message() {
+		if let Some(message) = get_message_without_trailers(commit) {
 			updater.push_lines(message);
 			updater.push_line(ViewLine::from(""));
 		}
@@ -136,6 +246,20 @@ impl ViewBuilder {
 			updater.push_line(ViewLine::from(""));
 		}
 
+		if !commit.trailers().is_empty() {
+			updater.push_line(ViewLine::from(LineSegment::new_with_color(
+				"Trailers:",
+				DisplayColor::IndicatorColor,
+			)));
+			for trailer in commit.trailers() {
+				updater.push_line(ViewLine::from(vec![
+					LineSegment::new_with_color(format!("{}: ", trailer.key()).as_str(), DisplayColor::IndicatorColor),
+					LineSegment::new(trailer.value()),
+				]));
+			}
+			updater.push_line(ViewLine::from(""));
+		}
+
 		updater.push_line(get_files_changed_summary(diff, is_full_width));
 		for status in diff.file_statuses() {
 			updater.push_line(ViewLine::from(get_stat_item_segments(
@@ -147,6 +271,62 @@ impl ViewBuilder {
 		}
 	}
 
+	// the message of the commit that results from a squash, and the files changed by the squashed commits
+	pub(crate) fn build_view_data_for_squash_overview(
+		updater: &mut ViewDataUpdater<'_>,
+		diff: &CommitDiff,
+		message: &str,
+		is_full_width: bool,
+	) {
+		updater.push_leading_line(Self::build_leading_range_summary("Squash: ", diff, is_full_width));
+		updater.push_lines(message);
+		updater.push_line(ViewLine::from(""));
+		updater.push_line(get_files_changed_summary(diff, is_full_width));
+		for status in diff.file_statuses() {
+			updater.push_line(ViewLine::from(get_stat_item_segments(
+				status.status(),
+				status.destination_path(),
+				status.source_path(),
+				is_full_width,
+			)));
+		}
+	}
+
+	// the index of the line of the first file in the squash overview, this must match the lines from
+	// `build_view_data_for_squash_overview`
+	pub(super) fn get_squash_overview_file_line_start(message: &str) -> usize {
+		message.lines().count() + 2
+	}
+
+	// the index of the line of the first file in the overview, this must match the lines from
+	// `build_view_data_for_overview`
+	pub(super) fn get_overview_file_line_start(diff: &CommitDiff) -> usize {
+		let commit = diff.commit();
+		let mut line_index = 1; // date
+		if commit.author().is_some() {
+			line_index += 1;
+		}
+		if commit.committer().is_some() {
+			line_index += 1;
+		}
+		if commit.signature().is_some() {
+			line_index += 1;
+		}
+		if let Some(summary) = commit.summary() {
+			line_index += summary.lines().count() + 1;
+		}
+		if let Some(message) = get_message_without_trailers(commit) {
+			line_index += message.lines().count() + 1;
+		}
+		if commit.summary().is_none() && commit.message().is_none() {
+			line_index += 1;
+		}
+		if !commit.trailers().is_empty() {
+			line_index += commit.trailers().len() + 2;
+		}
+		line_index + 1 // files changed summary
+	}
+
 	fn build_diff_line_line_segment(content: &str, origin: Origin) -> LineSegment {
 		LineSegment::new_with_color(content, match origin {
 			Origin::Addition => DisplayColor::DiffAddColor,
@@ -224,30 +404,111 @@ impl ViewBuilder {
 		line_segments
 	}
 
+	// the line shown in place of the hunks of a file that has not had its lines loaded
+	fn build_collapsed_file_line(&self, reason: CollapseReason, hidden_lines: usize) -> ViewLine {
+		let hidden = format!("{} lines hidden", hidden_lines.to_formatted_string(&Locale::en));
+		let content = match reason {
+			CollapseReason::Binary => String::from("Binary file not shown"),
+			CollapseReason::Generated => format!("Generated file, {hidden}, press {} to expand", self.expand_file_key),
+			CollapseReason::TooLarge => format!("{hidden}, press {} to expand", self.expand_file_key),
+		};
+		ViewLine::from(LineSegment::new_with_color(
+			content.as_str(),
+			DisplayColor::IndicatorColor,
+		))
+	}
+
 	pub(super) fn build_view_data_diff(
 		&self,
 		updater: &mut ViewDataUpdater<'_>,
 		diff: &CommitDiff,
+		folds: &DiffFolds,
+		diff_index: usize,
+		parent_count: usize,
 		is_full_width: bool,
 	) {
-		updater.push_leading_line(Self::build_leading_summary(diff.commit(), is_full_width));
+		Self::build_leading_lines(updater, diff, diff_index, parent_count, is_full_width);
 		updater.push_leading_line(get_files_changed_summary(diff, is_full_width));
-		updater.push_line(ViewLine::new_empty_line().set_padding(PADDING_CHARACTER));
+		self.build_diff_lines(updater, diff, folds);
+	}
 
-		let file_statuses = diff.file_statuses();
-		for (s_i, status) in file_statuses.iter().enumerate() {
+	// the combined change of a range of commits, from the parent of the first commit to the last commit
+	pub(super) fn build_view_data_range_diff(
+		&self,
+		updater: &mut ViewDataUpdater<'_>,
+		diff: &CommitDiff,
+		folds: &DiffFolds,
+		is_full_width: bool,
+	) {
+		updater.push_leading_line(Self::build_leading_range_summary("Range: ", diff, is_full_width));
+		updater.push_leading_line(get_files_changed_summary(diff, is_full_width));
+		self.build_diff_lines(updater, diff, folds);
+	}
+
+	// the files of the commit that have loaded so far, shown while the rest of the commit is loading
+	pub(super) fn build_view_data_loading(
+		updater: &mut ViewDataUpdater<'_>,
+		hash: &str,
+		indicator: &str,
+		file_statuses: &[FileStatus],
+		is_full_width: bool,
+	) {
+		updater.push_leading_line(ViewLine::from(vec![
+			LineSegment::new_with_color(
+				if is_full_width { "Loading commit: " } else { "Loading: " },
+				DisplayColor::IndicatorColor,
+			),
+			LineSegment::new(format!("{hash} {indicator}").as_str()),
+		]));
+		for status in file_statuses {
 			updater.push_line(ViewLine::from(get_stat_item_segments(
 				status.status(),
 				status.destination_path(),
 				status.source_path(),
-				true,
+				is_full_width,
 			)));
+		}
+	}
+
+	// the overview of the commit, followed by the diff, for a preview of the commit
+	pub(crate) fn build_view_data_preview(
+		&self,
+		updater: &mut ViewDataUpdater<'_>,
+		diff: &CommitDiff,
+		parent_count: usize,
+		is_full_width: bool,
+	) {
+		self.build_view_data_for_overview(updater, diff, 0, parent_count, is_full_width);
+		self.build_diff_lines(updater, diff, &DiffFolds::new());
+	}
+
+	fn build_diff_lines(&self, updater: &mut ViewDataUpdater<'_>, diff: &CommitDiff, folds: &DiffFolds) {
+		updater.push_line(ViewLine::new_empty_line().set_padding(PADDING_CHARACTER));
+
+		let file_statuses = diff.file_statuses();
+		for (s_i, status) in file_statuses.iter().enumerate() {
+			let mut file_header_segments =
+				get_stat_item_segments(status.status(), status.destination_path(), status.source_path(), true);
+			if folds.is_file_folded(s_i) {
+				file_header_segments.push(LineSegment::new_with_color(" (folded)", DisplayColor::IndicatorColor));
+				updater.push_line(ViewLine::from(file_header_segments));
+				if s_i + 1 != file_statuses.len() {
+					updater.push_line(ViewLine::new_empty_line().set_padding(PADDING_CHARACTER));
+				}
+				continue;
+			}
+			updater.push_line(ViewLine::from(file_header_segments));
+
+			if let Some(reason) = status.collapse_reason() {
+				updater.push_line(ViewLine::new_empty_line());
+				updater.push_line(self.build_collapsed_file_line(reason, status.hidden_lines()));
+			}
 
 			let old_largest_line_number_length = status.last_old_line_number().to_string().len();
 			let new_largest_line_number_length = status.last_new_line_number().to_string().len();
-			for delta in status.deltas() {
+			for (d_i, delta) in status.deltas().iter().enumerate() {
 				updater.push_line(ViewLine::new_empty_line());
-				updater.push_line(ViewLine::from(vec![
+				let mut hunk_header_segments = vec![
 					LineSegment::new_with_color_and_style("@@", DisplayColor::Normal, true, false, false),
 					LineSegment::new_with_color(
 						format!(
@@ -265,7 +526,20 @@ impl ViewBuilder {
 						format!(" {}", delta.context()).as_str(),
 						DisplayColor::DiffContextColor,
 					),
-				]));
+				];
+				if folds.is_hunk_folded(s_i, d_i) {
+					hunk_header_segments.push(LineSegment::new_with_color(
+						format!(
+							" ({} lines folded)",
+							delta.lines().len().to_formatted_string(&Locale::en)
+						)
+						.as_str(),
+						DisplayColor::IndicatorColor,
+					));
+					updater.push_line(ViewLine::from(hunk_header_segments));
+					continue;
+				}
+				updater.push_line(ViewLine::from(hunk_header_segments));
 				updater.push_line(ViewLine::new_pinned(vec![]).set_padding_with_color_and_style(
 					PADDING_CHARACTER,
 					DisplayColor::Normal,
@@ -298,4 +572,131 @@ impl ViewBuilder {
 			}
 		}
 	}
+
+	// the number of lines of a hunk, a folded hunk only shows the hunk header
+	fn get_diff_hunk_line_count(folds: &DiffFolds, file_index: usize, hunk_index: usize, delta: &Delta) -> usize {
+		if folds.is_hunk_folded(file_index, hunk_index) {
+			2
+		}
+		else {
+			3 + delta.lines().len()
+		}
+	}
+
+	// the number of lines after the file header for the hunks of a file, or the line shown for a collapsed file
+	fn get_diff_file_body_line_count(folds: &DiffFolds, file_index: usize, status: &FileStatus) -> usize {
+		if folds.is_file_folded(file_index) {
+			0
+		}
+		else if status.collapse_reason().is_some() {
+			2
+		}
+		else {
+			status
+				.deltas()
+				.iter()
+				.enumerate()
+				.map(|(hunk_index, delta)| Self::get_diff_hunk_line_count(folds, file_index, hunk_index, delta))
+				.sum::<usize>()
+		}
+	}
+
+	// the index of the line of the file header in the diff, this must match the lines from `build_view_data_diff`
+	pub(super) fn get_diff_file_line_index(diff: &CommitDiff, folds: &DiffFolds, file_index: usize) -> usize {
+		diff.file_statuses()
+			.iter()
+			.enumerate()
+			.take(file_index)
+			.fold(1, |line_index, (s_i, status)| {
+				// the file header, the lines of the file and the padding line that separates the files
+				line_index + 2 + Self::get_diff_file_body_line_count(folds, s_i, status)
+			})
+	}
+
+	// the index of the line of the file header of each file in the diff, this must match the lines from
+	// `build_view_data_diff`
+	pub(super) fn get_diff_file_line_indexes(diff: &CommitDiff, folds: &DiffFolds) -> Vec<usize> {
+		let mut file_line_indexes = vec![];
+		let mut file_start = 1;
+		for (file_index, status) in diff.file_statuses().iter().enumerate() {
+			file_line_indexes.push(file_start);
+			file_start += 2 + Self::get_diff_file_body_line_count(folds, file_index, status);
+		}
+		file_line_indexes
+	}
+
+	// the index of the file that contains the line in the diff, this must match the lines from `build_view_data_diff`
+	pub(super) fn get_diff_file_at_line(diff: &CommitDiff, folds: &DiffFolds, line_index: usize) -> Option<usize> {
+		let mut file_end = 1;
+		for (file_index, status) in diff.file_statuses().iter().enumerate() {
+			file_end += 2 + Self::get_diff_file_body_line_count(folds, file_index, status);
+			if file_end > line_index {
+				return Some(file_index);
+			}
+		}
+		None
+	}
+
+	// the index of the hunk header line of each hunk that is not within a folded file, with the index of the file and
+	// hunk, this must match the lines from `build_view_data_diff`
+	pub(super) fn get_diff_hunk_line_indexes(diff: &CommitDiff, folds: &DiffFolds) -> Vec<(usize, usize, usize)> {
+		let mut hunk_line_indexes = vec![];
+		let mut hunk_start = 1;
+		for (file_index, status) in diff.file_statuses().iter().enumerate() {
+			hunk_start += 1;
+			if folds.is_file_folded(file_index) {
+				hunk_start += 1;
+				continue;
+			}
+			if status.collapse_reason().is_some() {
+				hunk_start += Self::get_diff_file_body_line_count(folds, file_index, status);
+			}
+			for (hunk_index, delta) in status.deltas().iter().enumerate() {
+				// the hunk header follows the empty line that separates the hunks
+				hunk_line_indexes.push((file_index, hunk_index, hunk_start + 1));
+				hunk_start += Self::get_diff_hunk_line_count(folds, file_index, hunk_index, delta);
+			}
+			hunk_start += 1;
+		}
+		hunk_line_indexes
+	}
+
+	// the index of the file and hunk that contains the line in the diff, or the first hunk after the line, this must
+	// match the lines from `build_view_data_diff`
+	pub(super) fn get_diff_hunk_at_line(
+		diff: &CommitDiff,
+		folds: &DiffFolds,
+		line_index: usize,
+	) -> Option<(usize, usize)> {
+		let mut hunk_end = 1;
+		for (file_index, status) in diff.file_statuses().iter().enumerate() {
+			hunk_end += 1;
+			if folds.is_file_folded(file_index) {
+				hunk_end += 1;
+				continue;
+			}
+			if status.collapse_reason().is_some() {
+				hunk_end += Self::get_diff_file_body_line_count(folds, file_index, status);
+			}
+			for (hunk_index, delta) in status.deltas().iter().enumerate() {
+				hunk_end += Self::get_diff_hunk_line_count(folds, file_index, hunk_index, delta);
+				if hunk_end > line_index {
+					return Some((file_index, hunk_index));
+				}
+			}
+			hunk_end += 1;
+		}
+		None
+	}
+
+	// the file and hunk that contains the line in the diff, or the first hunk after the line
+	pub(super) fn get_diff_delta_at_line<'diff>(
+		diff: &'diff CommitDiff,
+		folds: &DiffFolds,
+		line_index: usize,
+	) -> Option<(&'diff FileStatus, &'diff Delta)> {
+		let (file_index, hunk_index) = Self::get_diff_hunk_at_line(diff, folds, line_index)?;
+		let status = diff.file_statuses().get(file_index)?;
+		Some((status, status.deltas().get(hunk_index)?))
+	}
 }
@@ -0,0 +1,275 @@
+use std::sync::Arc;
+
+use display::DisplayColor;
+use input::InputOptions;
+use parking_lot::Mutex;
+use todo_file::{LineDiff, TodoFile};
+use view::{LineSegment, RenderContext, ViewData, ViewDataUpdater, ViewLine};
+
+use crate::{
+	events::Event,
+	module::{Module, State},
+	process::Results,
+	util::handle_view_data_scroll,
+};
+
+// TODO Remove `union` call when bitflags/bitflags#180 is resolved
+const INPUT_OPTIONS: InputOptions = InputOptions::RESIZE.union(InputOptions::MOVEMENT);
+
+pub(crate) struct TodoDiff {
+	return_state: State,
+	todo_file: Arc<Mutex<TodoFile>>,
+	view_data: ViewData,
+}
+
+impl Module for TodoDiff {
+	fn activate(&mut self, previous_state: State) -> Results {
+		// returning from an error should not change where this module returns to
+		if previous_state != State::Error && previous_state != State::WindowSizeError {
+			self.return_state = previous_state;
+		}
+		let diff = self.todo_file.lock().diff_original();
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			updater.reset_scroll_position();
+			Self::build_diff(updater, &diff);
+		});
+		Results::new()
+	}
+
+	fn build_view_data(&mut self, _: &RenderContext) -> &ViewData {
+		&self.view_data
+	}
+
+	fn input_options(&self) -> &InputOptions {
+		&INPUT_OPTIONS
+	}
+
+	fn handle_event(&mut self, event: Event, view_state: &view::State) -> Results {
+		let mut results = Results::new();
+		if handle_view_data_scroll(&event, view_state).is_none() {
+			if let Event::Key(_) = event {
+				results.state(self.return_state);
+			}
+		}
+		results
+	}
+}
+
+impl TodoDiff {
+	pub(crate) fn new(todo_file: Arc<Mutex<TodoFile>>) -> Self {
+		Self {
+			return_state: State::List,
+			todo_file,
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+			}),
+		}
+	}
+
+	fn build_diff(updater: &mut ViewDataUpdater<'_>, diff: &[LineDiff]) {
+		let mut changed = 0;
+		let mut moved = 0;
+		let mut inserted = 0;
+		let mut removed = 0;
+
+		for line_diff in diff {
+			updater.push_line(match *line_diff {
+				LineDiff::Unchanged(ref line) => {
+					ViewLine::from(LineSegment::new_with_color(
+						format!("  {}", line.to_text()).as_str(),
+						DisplayColor::DiffContextColor,
+					))
+				},
+				LineDiff::Moved(ref line) => {
+					moved += 1;
+					ViewLine::from(vec![
+						LineSegment::new_with_color(
+							format!("> {}", line.to_text()).as_str(),
+							DisplayColor::DiffChangeColor,
+						),
+						LineSegment::new_with_color(" (moved)", DisplayColor::IndicatorColor),
+					])
+				},
+				LineDiff::Changed {
+					ref original,
+					ref line,
+					moved: is_moved,
+				} => {
+					// a line that was changed and moved is only counted once, as changed
+					changed += 1;
+					ViewLine::from(vec![
+						LineSegment::new_with_color(
+							format!("~ {}", line.to_text()).as_str(),
+							DisplayColor::DiffChangeColor,
+						),
+						LineSegment::new_with_color(
+							format!(
+								" ({}was: {})",
+								if is_moved { "moved, " } else { "" },
+								original.to_text()
+							)
+							.as_str(),
+							DisplayColor::IndicatorColor,
+						),
+					])
+				},
+				LineDiff::Inserted(ref line) => {
+					inserted += 1;
+					ViewLine::from(LineSegment::new_with_color(
+						format!("+ {}", line.to_text()).as_str(),
+						DisplayColor::DiffAddColor,
+					))
+				},
+				LineDiff::Removed(ref line) => {
+					removed += 1;
+					ViewLine::from(LineSegment::new_with_color(
+						format!("- {}", line.to_text()).as_str(),
+						DisplayColor::DiffRemoveColor,
+					))
+				},
+				_ => ViewLine::new_empty_line(),
+			});
+		}
+
+		updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+			if changed + moved + inserted + removed == 0 {
+				String::from("No changes have been made to the todo list")
+			}
+			else {
+				format!("{changed} changed, {moved} moved, {inserted} inserted, {removed} removed")
+			}
+			.as_str(),
+			DisplayColor::IndicatorColor,
+		)));
+		updater.push_leading_line(ViewLine::new_empty_line());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use todo_file::{Action, EditContext, Line};
+	use view::assert_rendered_output;
+
+	use super::*;
+	use crate::{assert_results, process::Artifact, testutil::module_test};
+
+	fn create_todo_diff(todo_file: TodoFile) -> TodoDiff {
+		TodoDiff::new(Arc::new(Mutex::new(todo_file)))
+	}
+
+	#[test]
+	fn build_view_data_no_changes() {
+		module_test(&["pick aaa c1", "pick bbb c2"], &[], |mut test_context| {
+			let mut module = create_todo_diff(test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::List);
+			let view_data = test_context.build_view_data(&mut module);
+			assert_rendered_output!(
+				Options AssertRenderOptions::EXCLUDE_STYLE,
+				view_data,
+				"{TITLE}",
+				"{LEADING}",
+				"No changes have been made to the todo list",
+				"",
+				"{BODY}",
+				"  pick aaa c1",
+				"  pick bbb c2"
+			);
+		});
+	}
+
+	#[test]
+	fn build_view_data_with_changes() {
+		module_test(
+			&[
+				"pick aaa c1",
+				"pick bbb c2",
+				"pick ccc c3",
+				"pick ddd c4",
+				"pick eee c5",
+			],
+			&[],
+			|mut test_context| {
+				let mut todo_file = test_context.take_todo_file();
+				todo_file.remove_lines(1, 1);
+				todo_file.add_line(0, Line::new_break());
+				_ = todo_file.swap_range_down(2, 2);
+				todo_file.update_range(4, 4, &EditContext::new().action(Action::Fixup));
+				let mut module = create_todo_diff(todo_file);
+				_ = test_context.activate(&mut module, State::List);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"1 changed, 1 moved, 1 inserted, 1 removed",
+					"",
+					"{BODY}",
+					"+ break",
+					"  pick aaa c1",
+					"> pick ddd c4 (moved)",
+					"- pick bbb c2",
+					"  pick ccc c3",
+					"~ fixup eee c5 (was: pick eee c5)"
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn build_view_data_changed_and_moved_counted_once() {
+		module_test(
+			&["pick aaa c1", "pick bbb c2", "pick ccc c3"],
+			&[],
+			|mut test_context| {
+				let mut todo_file = test_context.take_todo_file();
+				_ = todo_file.swap_range_up(2, 2);
+				_ = todo_file.swap_range_up(1, 1);
+				todo_file.update_range(0, 0, &EditContext::new().action(Action::Reword));
+				let mut module = create_todo_diff(todo_file);
+				_ = test_context.activate(&mut module, State::List);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"1 changed, 0 moved, 0 inserted, 0 removed",
+					"",
+					"{BODY}",
+					"~ reword ccc c3 (moved, was: pick ccc c3)",
+					"  pick aaa c1",
+					"  pick bbb c2"
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn handle_event_returns_to_previous_state() {
+		module_test(&["pick aaa c1"], &[Event::from('q')], |mut test_context| {
+			let mut module = create_todo_diff(test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::ConfirmRebase);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from('q')),
+				Artifact::ChangeState(State::ConfirmRebase)
+			);
+		});
+	}
+
+	#[test]
+	fn activate_after_error_keeps_return_state() {
+		module_test(&["pick aaa c1"], &[Event::from('q')], |mut test_context| {
+			let mut module = create_todo_diff(test_context.take_todo_file());
+			_ = test_context.activate(&mut module, State::ConfirmRebase);
+			_ = test_context.activate(&mut module, State::WindowSizeError);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from('q')),
+				Artifact::ChangeState(State::ConfirmRebase)
+			);
+		});
+	}
+}
@@ -0,0 +1,438 @@
+mod rebase_branch;
+
+use std::sync::Arc;
+
+use anyhow::Error;
+use config::Config;
+use display::DisplayColor;
+use git::Repository;
+use input::{InputOptions, KeyCode, KeyEvent, StandardEvent};
+use parking_lot::Mutex;
+use todo_file::{Line, TodoFile};
+use view::{LineSegment, RenderContext, ViewData, ViewLine};
+
+pub(crate) use self::rebase_branch::{find_rebase_branches, RebaseBranch};
+use self::rebase_branch::{find_commit_group_end, find_preceding_commit, find_update_ref_index};
+use crate::{
+	events::{Event, KeyBindings, MetaEvent},
+	module::{Module, State},
+	process::Results,
+};
+
+// TODO Remove `union` call when bitflags/bitflags#180 is resolved
+const INPUT_OPTIONS: InputOptions = InputOptions::RESIZE.union(InputOptions::MOVEMENT);
+
+pub(crate) struct UpdateRefs {
+	branches: Vec<RebaseBranch>,
+	prompt: String,
+	repository: Repository,
+	selected_branch: usize,
+	todo_file: Arc<Mutex<TodoFile>>,
+	view_data: ViewData,
+}
+
+impl Module for UpdateRefs {
+	fn activate(&mut self, _: State) -> Results {
+		let mut results = Results::new();
+		let todo_file = self.todo_file.lock();
+		match find_rebase_branches(&self.repository, &todo_file) {
+			Ok(branches) => {
+				let selected_line_index = todo_file.get_selected_line_index();
+				self.selected_branch = branches
+					.iter()
+					.position(|branch| branch.commit_index == selected_line_index)
+					.unwrap_or(0);
+				self.branches = branches;
+			},
+			Err(err) => {
+				results.error_with_return(Error::from(err), State::List);
+			},
+		}
+		results
+	}
+
+	fn build_view_data(&mut self, _: &RenderContext) -> &ViewData {
+		let todo_file = self.todo_file.lock();
+		let branches = &self.branches;
+		let selected_branch = self.selected_branch;
+		let selected_line_index = todo_file.get_selected_line_index();
+		let prompt = self.prompt.as_str();
+		self.view_data.update_view_data(|updater| {
+			updater.clear();
+			if branches.is_empty() {
+				updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+					"No branches point to commits in the rebase",
+					DisplayColor::IndicatorColor,
+				)));
+				updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+					"Press Esc to return",
+					DisplayColor::IndicatorColor,
+				)));
+				return;
+			}
+
+			updater.push_leading_line(ViewLine::from(LineSegment::new_with_color(
+				"Branches pointing to commits in the rebase:",
+				DisplayColor::IndicatorColor,
+			)));
+			updater.push_leading_line(ViewLine::new_empty_line());
+			for (i, branch) in branches.iter().enumerate() {
+				let mut segments = vec![
+					LineSegment::new(
+						if branch.update_ref_index.is_some() {
+							"[x] "
+						}
+						else {
+							"[ ] "
+						},
+					),
+					LineSegment::new_with_color(branch.short_name.as_str(), DisplayColor::ActionUpdateRef),
+				];
+				if let Some(commit) = todo_file.get_line(branch.commit_index) {
+					segments.push(LineSegment::new_with_color(
+						format!(" {} {}", commit.get_hash(), commit.get_content()).as_str(),
+						DisplayColor::DiffContextColor,
+					));
+				}
+				let update_ref_target = branch
+					.update_ref_index
+					.and_then(|index| find_preceding_commit(&todo_file, index));
+				if let Some(target) = update_ref_target {
+					if target.get_hash() != todo_file.get_line(branch.commit_index).map_or("", Line::get_hash) {
+						segments.push(LineSegment::new_with_color(
+							format!(" (moved after {})", target.get_hash()).as_str(),
+							DisplayColor::IndicatorColor,
+						));
+					}
+				}
+				if branch.commit_index == selected_line_index {
+					segments.push(LineSegment::new_with_color(
+						" (selected commit)",
+						DisplayColor::IndicatorColor,
+					));
+				}
+				let mut view_line = ViewLine::from(segments);
+				if i == selected_branch {
+					view_line = view_line.set_selected(true).set_padding(' ');
+				}
+				updater.push_line(view_line);
+			}
+			updater.push_trailing_line(ViewLine::new_empty_line());
+			updater.push_trailing_line(ViewLine::from(LineSegment::new_with_color(
+				prompt,
+				DisplayColor::IndicatorColor,
+			)));
+			updater.ensure_line_visible(selected_branch);
+		});
+		&self.view_data
+	}
+
+	fn input_options(&self) -> &InputOptions {
+		&INPUT_OPTIONS
+	}
+
+	fn read_event(&self, event: Event, key_bindings: &KeyBindings) -> Event {
+		if key_bindings.custom.toggle_update_ref.contains(&event) {
+			Event::from(MetaEvent::ToggleUpdateRef)
+		}
+		else if key_bindings.custom.move_update_ref.contains(&event) {
+			Event::from(MetaEvent::MoveUpdateRef)
+		}
+		else {
+			event
+		}
+	}
+
+	fn handle_event(&mut self, event: Event, _: &view::State) -> Results {
+		let mut results = Results::new();
+		match event {
+			Event::Standard(StandardEvent::ScrollUp) => {
+				self.selected_branch = self.selected_branch.saturating_sub(1);
+			},
+			Event::Standard(StandardEvent::ScrollDown) if self.selected_branch + 1 < self.branches.len() => {
+				self.selected_branch += 1;
+			},
+			Event::MetaEvent(MetaEvent::ToggleUpdateRef) => self.toggle_update_ref(),
+			Event::MetaEvent(MetaEvent::MoveUpdateRef) => self.move_update_ref(),
+			Event::Key(KeyEvent { code: KeyCode::Esc, .. }) => results.state(State::List),
+			_ => {},
+		}
+		results
+	}
+}
+
+impl UpdateRefs {
+	pub(crate) fn new(config: &Config, repository: Repository, todo_file: Arc<Mutex<TodoFile>>) -> Self {
+		Self {
+			branches: vec![],
+			prompt: format!(
+				"Press {} to toggle the update-ref, {} to move it after the selected commit or Esc to return",
+				config.key_bindings.toggle_update_ref.join(","),
+				config.key_bindings.move_update_ref.join(",")
+			),
+			repository,
+			selected_branch: 0,
+			todo_file,
+			view_data: ViewData::new(|updater| {
+				updater.set_show_title(true);
+			}),
+		}
+	}
+
+	fn toggle_update_ref(&mut self) {
+		let Some(branch) = self.branches.get(self.selected_branch).cloned()
+		else {
+			return;
+		};
+		let mut todo_file = self.todo_file.lock();
+		if let Some(index) = branch.update_ref_index {
+			todo_file.remove_lines(index, index);
+		}
+		else {
+			// the branch should point to the commit after any fixups and squashes have been applied
+			let index = find_commit_group_end(&todo_file, branch.commit_index);
+			todo_file.add_line(index + 1, Line::new_update_ref(branch.name.as_str()));
+		}
+		if let Ok(branches) = find_rebase_branches(&self.repository, &todo_file) {
+			self.branches = branches;
+		}
+	}
+
+	fn move_update_ref(&mut self) {
+		let Some(branch) = self.branches.get(self.selected_branch).cloned()
+		else {
+			return;
+		};
+		let mut todo_file = self.todo_file.lock();
+		let mut target_index = find_commit_group_end(&todo_file, todo_file.get_selected_line_index());
+		if let Some(index) = branch.update_ref_index {
+			if index == target_index + 1 {
+				return;
+			}
+			todo_file.remove_lines(index, index);
+			if index <= target_index {
+				target_index -= 1;
+			}
+		}
+		todo_file.add_line(target_index + 1, Line::new_update_ref(branch.name.as_str()));
+		_ = todo_file.set_selected_line_index(target_index);
+		if let Ok(branches) = find_rebase_branches(&self.repository, &todo_file) {
+			self.branches = branches;
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use git::testutil::{create_branch, create_commit, head_id, with_temp_repository, CreateCommitOptions};
+	use view::{assert_rendered_output, render_line};
+
+	use super::*;
+	use crate::{
+		assert_results,
+		process::Artifact,
+		testutil::{module_test, ModuleTestContext},
+	};
+
+	// creates two commits after the initial commit, with a branch pointing to each
+	fn update_refs_test<C>(extra_lines: &[&str], events: &[Event], callback: C)
+	where C: FnOnce(ModuleTestContext, UpdateRefs) {
+		with_temp_repository(|repository| {
+			create_commit(&repository, Some(CreateCommitOptions::new().message("first")));
+			create_branch(&repository, "feature-a", "refs/heads/main");
+			create_commit(&repository, Some(CreateCommitOptions::new().message("second")));
+			create_branch(&repository, "feature-b", "refs/heads/main");
+			create_commit(&repository, Some(CreateCommitOptions::new().message("third")));
+			let second = head_id(&repository, "feature-b").to_string();
+			let first = head_id(&repository, "feature-a").to_string();
+			let mut lines = vec![format!("pick {first:.7} first"), format!("pick {second:.7} second")];
+			lines.extend(extra_lines.iter().map(|line| String::from(*line)));
+			let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+			module_test(&lines, events, |mut test_context| {
+				let module = UpdateRefs::new(
+					&Config::new(),
+					repository,
+					Arc::new(Mutex::new(test_context.take_todo_file())),
+				);
+				callback(test_context, module);
+			});
+		});
+	}
+
+	fn get_lines(module: &UpdateRefs) -> Vec<String> {
+		module.todo_file.lock().lines_iter().map(Line::to_text).collect()
+	}
+
+	#[test]
+	fn build_view_data() {
+		update_refs_test(
+			&["update-ref refs/heads/feature-b"],
+			&[],
+			|mut test_context, mut module| {
+				_ = test_context.activate(&mut module, State::List);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"Branches pointing to commits in the rebase:",
+					"",
+					"{BODY}",
+					render_line!(EndsWith "first (selected commit){Pad( )}"),
+					render_line!(EndsWith "second"),
+					"{TRAILING}",
+					"",
+					"Press Space,Enter to toggle the update-ref, m to move it after the selected commit or Esc to return"
+				);
+				let branches = &module.branches;
+				assert_eq!(branches[0].short_name, "feature-a");
+				assert_eq!(branches[0].update_ref_index, None);
+				assert_eq!(branches[1].short_name, "feature-b");
+				assert_eq!(branches[1].update_ref_index, Some(2));
+			},
+		);
+	}
+
+	#[test]
+	fn build_view_data_no_branches() {
+		with_temp_repository(|repository| {
+			module_test(&["pick aaa c1"], &[], |mut test_context| {
+				let mut module = UpdateRefs::new(
+					&Config::new(),
+					repository,
+					Arc::new(Mutex::new(test_context.take_todo_file())),
+				);
+				_ = test_context.activate(&mut module, State::List);
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"No branches point to commits in the rebase",
+					"{TRAILING}",
+					"Press Esc to return"
+				);
+			});
+		});
+	}
+
+	#[test]
+	fn toggle_add_update_ref() {
+		update_refs_test(&[], &[Event::from(' ')], |mut test_context, mut module| {
+			_ = test_context.activate(&mut module, State::List);
+			_ = test_context.handle_all_events(&mut module);
+			assert_eq!(get_lines(&module)[1], "update-ref refs/heads/feature-a");
+			assert_eq!(module.branches[0].update_ref_index, Some(1));
+		});
+	}
+
+	#[test]
+	fn toggle_add_update_ref_after_fixups() {
+		update_refs_test(
+			&["fixup ccc fixup! second", "squash ddd squash! second", "exec make"],
+			&[Event::from(StandardEvent::ScrollDown), Event::from(KeyCode::Enter)],
+			|mut test_context, mut module| {
+				_ = test_context.activate(&mut module, State::List);
+				_ = test_context.handle_all_events(&mut module);
+				let lines = get_lines(&module);
+				assert_eq!(lines[4], "update-ref refs/heads/feature-b");
+				assert_eq!(lines[5], "exec make");
+				let view_data = test_context.build_view_data(&mut module);
+				assert_rendered_output!(
+					Options AssertRenderOptions::EXCLUDE_STYLE,
+					view_data,
+					"{TITLE}",
+					"{LEADING}",
+					"Branches pointing to commits in the rebase:",
+					"",
+					"{BODY}",
+					render_line!(EndsWith "first (selected commit)"),
+					render_line!(EndsWith "second{Pad( )}"),
+					"{TRAILING}",
+					"",
+					"Press Space,Enter to toggle the update-ref, m to move it after the selected commit or Esc to return"
+				);
+			},
+		);
+	}
+
+	#[test]
+	fn toggle_remove_update_ref() {
+		update_refs_test(
+			&["update-ref refs/heads/feature-b"],
+			&[Event::from(StandardEvent::ScrollDown), Event::from(KeyCode::Enter)],
+			|mut test_context, mut module| {
+				_ = test_context.activate(&mut module, State::List);
+				_ = test_context.handle_all_events(&mut module);
+				assert_eq!(get_lines(&module).len(), 2);
+				assert_eq!(module.branches[1].update_ref_index, None);
+			},
+		);
+	}
+
+	#[test]
+	fn move_update_ref() {
+		update_refs_test(
+			&["update-ref refs/heads/feature-b", "exec make"],
+			&[Event::from(StandardEvent::ScrollDown), Event::from('m')],
+			|mut test_context, mut module| {
+				_ = module.todo_file.lock().set_selected_line_index(3);
+				_ = test_context.activate(&mut module, State::List);
+				_ = test_context.handle_all_events(&mut module);
+				let lines = get_lines(&module);
+				assert_eq!(lines[2], "exec make");
+				assert_eq!(lines[3], "update-ref refs/heads/feature-b");
+				assert_eq!(module.todo_file.lock().get_selected_line_index(), 2);
+			},
+		);
+	}
+
+	#[test]
+	fn move_update_ref_without_existing() {
+		update_refs_test(
+			&[],
+			&[Event::from(StandardEvent::ScrollUp), Event::from('m')],
+			|mut test_context, mut module| {
+				_ = module.todo_file.lock().set_selected_line_index(1);
+				_ = test_context.activate(&mut module, State::List);
+				_ = test_context.handle_all_events(&mut module);
+				assert_eq!(get_lines(&module)[2], "update-ref refs/heads/feature-a");
+			},
+		);
+	}
+
+	#[test]
+	fn select_previous_branch() {
+		update_refs_test(
+			&[],
+			&[
+				Event::from(StandardEvent::ScrollDown),
+				Event::from(StandardEvent::ScrollDown),
+				Event::from(StandardEvent::ScrollUp),
+				Event::from(StandardEvent::ScrollUp),
+			],
+			|mut test_context, mut module| {
+				_ = test_context.activate(&mut module, State::List);
+				_ = test_context.handle_event(&mut module);
+				_ = test_context.handle_event(&mut module);
+				assert_eq!(module.selected_branch, 1);
+				_ = test_context.handle_all_events(&mut module);
+				assert_eq!(module.selected_branch, 0);
+			},
+		);
+	}
+
+	#[test]
+	fn return_to_list() {
+		update_refs_test(&[], &[Event::from(KeyCode::Esc)], |mut test_context, mut module| {
+			_ = test_context.activate(&mut module, State::List);
+			assert_results!(
+				test_context.handle_event(&mut module),
+				Artifact::Event(Event::from(KeyCode::Esc)),
+				Artifact::ChangeState(State::List)
+			);
+		});
+	}
+}
@@ -0,0 +1,162 @@
+use git::{errors::GitError, Repository};
+use todo_file::{Action, Line, TodoFile};
+
+/// A local branch that points to a commit in the rebase todo list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RebaseBranch {
+	/// The full name of the branch.
+	pub(crate) name: String,
+	/// The short name of the branch.
+	pub(crate) short_name: String,
+	/// The index of the line with the commit the branch points to.
+	pub(crate) commit_index: usize,
+	/// The index of the update-ref line for the branch, if one exists.
+	pub(crate) update_ref_index: Option<usize>,
+}
+
+pub(crate) fn find_update_ref_index(todo_file: &TodoFile, name: &str) -> Option<usize> {
+	todo_file
+		.lines_iter()
+		.position(|line| line.get_action() == &Action::UpdateRef && line.get_content() == name)
+}
+
+fn is_fixup_or_squash(line: &Line) -> bool {
+	matches!(*line.get_action(), Action::Fixup | Action::Squash)
+}
+
+/// Find the index of the last fixup or squash line that directly follows the line at the index, or the index itself.
+pub(crate) fn find_commit_group_end(todo_file: &TodoFile, index: usize) -> usize {
+	index
+		+ todo_file
+			.lines_iter()
+			.skip(index + 1)
+			.take_while(|line| is_fixup_or_squash(line))
+			.count()
+}
+
+/// Find the closest line, at or before the index, that references a commit, skipping over fixup and squash lines,
+/// since those are combined into the commit before them.
+pub(crate) fn find_preceding_commit(todo_file: &TodoFile, index: usize) -> Option<&Line> {
+	todo_file
+		.lines_iter()
+		.take(index + 1)
+		.rev()
+		.find(|line| line.has_reference() && !is_fixup_or_squash(line))
+}
+
+/// Find the local branches that point to a commit in the todo list, excluding the branch being rebased.
+pub(crate) fn find_rebase_branches(
+	repository: &Repository,
+	todo_file: &TodoFile,
+) -> Result<Vec<RebaseBranch>, GitError> {
+	let head_branch_name = repository.head_branch_name();
+	let mut branches: Vec<RebaseBranch> = repository
+		.find_local_branches()?
+		.into_iter()
+		.filter(|branch| head_branch_name.as_deref() != Some(branch.name()))
+		.filter_map(|branch| {
+			let commit_index = todo_file
+				.lines_iter()
+				.position(|line| line.has_reference() && branch.hash().starts_with(line.get_hash()))?;
+			Some(RebaseBranch {
+				name: String::from(branch.name()),
+				short_name: String::from(branch.shortname()),
+				commit_index,
+				update_ref_index: find_update_ref_index(todo_file, branch.name()),
+			})
+		})
+		.collect();
+	branches.sort_by(|a, b| a.commit_index.cmp(&b.commit_index).then_with(|| a.name.cmp(&b.name)));
+	Ok(branches)
+}
+
+#[cfg(test)]
+mod tests {
+	use git::testutil::{create_branch, create_commit, head_id, with_temp_repository, CreateCommitOptions};
+
+	use super::*;
+	use crate::testutil::module_test;
+
+	#[test]
+	fn find_rebase_branches_in_range() {
+		with_temp_repository(|repository| {
+			let first = head_id(&repository, "main").to_string();
+			create_branch(&repository, "feature-a", "refs/heads/main");
+			create_commit(&repository, Some(CreateCommitOptions::new().message("second")));
+			let second = head_id(&repository, "main").to_string();
+			create_branch(&repository, "feature-b", "refs/heads/main");
+			create_commit(&repository, Some(CreateCommitOptions::new().message("third")));
+			create_branch(&repository, "outside", "refs/heads/main");
+			let lines = [
+				format!("pick {first:.7} first"),
+				format!("pick {second:.7} second"),
+				String::from("update-ref refs/heads/feature-b"),
+			];
+			let lines: Vec<&str> = lines.iter().map(String::as_str).collect();
+			module_test(&lines, &[], |mut test_context| {
+				let todo_file = test_context.take_todo_file();
+				assert_eq!(find_rebase_branches(&repository, &todo_file).unwrap(), vec![
+					RebaseBranch {
+						name: String::from("refs/heads/feature-a"),
+						short_name: String::from("feature-a"),
+						commit_index: 0,
+						update_ref_index: None,
+					},
+					RebaseBranch {
+						name: String::from("refs/heads/feature-b"),
+						short_name: String::from("feature-b"),
+						commit_index: 1,
+						update_ref_index: Some(2),
+					},
+				]);
+			});
+		});
+	}
+
+	#[test]
+	fn find_commit_group_end_with_fixups() {
+		module_test(
+			&[
+				"pick aaa c1",
+				"fixup bbb c2",
+				"squash ccc c3",
+				"pick ddd c4",
+				"fixup eee c5",
+			],
+			&[],
+			|mut test_context| {
+				let todo_file = test_context.take_todo_file();
+				assert_eq!(find_commit_group_end(&todo_file, 0), 2);
+				assert_eq!(find_commit_group_end(&todo_file, 3), 4);
+			},
+		);
+	}
+
+	#[test]
+	fn find_commit_group_end_without_fixups() {
+		module_test(&["pick aaa c1", "pick bbb c2"], &[], |mut test_context| {
+			let todo_file = test_context.take_todo_file();
+			assert_eq!(find_commit_group_end(&todo_file, 0), 0);
+		});
+	}
+
+	#[test]
+	fn find_preceding_commit_skips_fixup_lines() {
+		module_test(&["pick aaa c1", "fixup bbb c2"], &[], |mut test_context| {
+			let todo_file = test_context.take_todo_file();
+			assert_eq!(find_preceding_commit(&todo_file, 1).unwrap().get_hash(), "aaa");
+		});
+	}
+
+	#[test]
+	fn find_preceding_commit_skips_non_commit_lines() {
+		module_test(
+			&["pick aaa c1", "exec make", "update-ref refs/heads/a", "label b"],
+			&[],
+			|mut test_context| {
+				let todo_file = test_context.take_todo_file();
+				assert_eq!(find_preceding_commit(&todo_file, 3).unwrap().get_hash(), "aaa");
+			},
+		);
+	}
+}
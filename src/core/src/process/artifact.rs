@@ -1,38 +1,63 @@
-use std::fmt::{Debug, Formatter};
+use std::{
+	fmt::{Debug, Formatter},
+	sync::Arc,
+};
 
 use anyhow::Error;
+use parking_lot::Mutex;
+use todo_file::Line;
 
 use crate::{
+	diff::{Interrupter, LoadedDiff},
 	events::Event,
 	module::{ExitStatus, State},
+	process::CommandOutput,
 	search::Searchable,
 };
 
 #[allow(variant_size_differences)]
 pub(crate) enum Artifact {
+	CapturedCommand((String, Vec<String>), Arc<Mutex<Option<CommandOutput>>>),
 	ChangeState(State),
 	EnqueueResize,
 	Error(Error, Option<State>),
 	Event(Event),
 	ExitStatus(ExitStatus),
 	ExternalCommand((String, Vec<String>)),
+	LoadDiff(String, Arc<Mutex<Option<LoadedDiff>>>, Interrupter),
+	LoadRangeDiff(String, String, Arc<Mutex<Option<LoadedDiff>>>, Interrupter),
 	SearchCancel,
 	SearchTerm(String),
 	Searchable(Box<dyn Searchable>),
+	ShowRange(String, String),
+	ShowSquash(Vec<Line>),
 }
 
 impl Debug for Artifact {
 	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 		match *self {
+			Self::CapturedCommand((ref command, ref args), _) => write!(f, "CapturedCommand({command:?}, {args:?})"),
 			Self::ChangeState(state) => write!(f, "ChangeState({state:?})"),
 			Self::EnqueueResize => write!(f, "EnqueueResize"),
 			Self::Error(ref err, state) => write!(f, "Error({err:?}, {state:?})"),
-			Self::Event(event) => write!(f, "Event({event:?})"),
+			Self::Event(ref event) => write!(f, "Event({event:?})"),
 			Self::ExitStatus(status) => write!(f, "ExitStatus({status:?})"),
 			Self::ExternalCommand((ref command, ref args)) => write!(f, "ExternalCommand({command:?}, {args:?})"),
+			Self::LoadDiff(ref hash, ..) => write!(f, "LoadDiff({hash:?})"),
+			Self::LoadRangeDiff(ref first_hash, ref last_hash, ..) => {
+				write!(f, "LoadRangeDiff({first_hash:?}, {last_hash:?})")
+			},
 			Self::SearchCancel => write!(f, "SearchCancel"),
 			Self::SearchTerm(ref term) => write!(f, "SearchTerm({term:?})"),
 			Self::Searchable(_) => write!(f, "Searchable(dyn Searchable)"),
+			Self::ShowRange(ref first_hash, ref last_hash) => write!(f, "ShowRange({first_hash:?}, {last_hash:?})"),
+			Self::ShowSquash(ref lines) => {
+				write!(
+					f,
+					"ShowSquash({:?})",
+					lines.iter().map(Line::get_hash).collect::<Vec<&str>>()
+				)
+			},
 		}
 	}
 }
@@ -44,23 +69,49 @@ mod tests {
 
 	use super::*;
 	use crate::{
+		diff,
 		search::{Interrupter, SearchResult},
 		testutil::MockedSearchable,
 	};
 
 	#[rstest]
+	#[case::captured_command(
+		Artifact::CapturedCommand((String::from("foo"), vec![]), Arc::new(Mutex::new(None))),
+		"CapturedCommand(\"foo\", [])"
+	)]
 	#[case::change_state(Artifact::ChangeState(State::List), "ChangeState(List)")]
 	#[case::enqueue_resize(Artifact::EnqueueResize, "EnqueueResize")]
 	#[case::error(Artifact::Error(anyhow!("Error"), Some(State::List)), "Error(Error, Some(List))")]
 	#[case::event(Artifact::Event(Event::None), "Event(None)")]
 	#[case::exit_status(Artifact::ExitStatus(ExitStatus::Abort), "ExitStatus(Abort)")]
 	#[case::external_command(Artifact::ExternalCommand((String::from("foo"), vec![])), "ExternalCommand(\"foo\", [])")]
+	#[case::load_diff(
+		Artifact::LoadDiff(String::from("abc"), Arc::new(Mutex::new(None)), diff::Interrupter::new()),
+		"LoadDiff(\"abc\")"
+	)]
+	#[case::load_range_diff(
+		Artifact::LoadRangeDiff(
+			String::from("abc"),
+			String::from("def"),
+			Arc::new(Mutex::new(None)),
+			diff::Interrupter::new()
+		),
+		"LoadRangeDiff(\"abc\", \"def\")"
+	)]
 	#[case::search_cancel(Artifact::SearchCancel, "SearchCancel")]
 	#[case::search_term(Artifact::SearchTerm(String::from("foo")), "SearchTerm(\"foo\")")]
 	#[case::searchable(
 		Artifact::Searchable(Box::new(MockedSearchable::new())),
 		"Searchable(dyn Searchable)"
 	)]
+	#[case::show_range(
+		Artifact::ShowRange(String::from("abc"), String::from("def")),
+		"ShowRange(\"abc\", \"def\")"
+	)]
+	#[case::show_squash(
+		Artifact::ShowSquash(vec![Line::new("pick abc comment").unwrap(), Line::new("fixup def comment").unwrap()]),
+		"ShowSquash([\"abc\", \"def\"])"
+	)]
 	fn debug(#[case] artifact: Artifact, #[case] expected: &str) {
 		assert_eq!(format!("{artifact:?}"), expected);
 	}
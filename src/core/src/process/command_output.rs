@@ -0,0 +1,67 @@
+use std::process::Output;
+
+/// The captured output and exit status of an external command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct CommandOutput {
+	status: Option<i32>,
+	stdout: String,
+	stderr: String,
+}
+
+impl CommandOutput {
+	pub(crate) fn new(status: Option<i32>, stdout: &str, stderr: &str) -> Self {
+		Self {
+			status,
+			stdout: String::from(stdout),
+			stderr: String::from(stderr),
+		}
+	}
+
+	/// The exit code of the command, `None` if the command was terminated by a signal.
+	pub(crate) const fn status(&self) -> Option<i32> {
+		self.status
+	}
+
+	pub(crate) fn stdout(&self) -> &str {
+		self.stdout.as_str()
+	}
+
+	pub(crate) fn stderr(&self) -> &str {
+		self.stderr.as_str()
+	}
+}
+
+impl From<Output> for CommandOutput {
+	fn from(output: Output) -> Self {
+		Self {
+			status: output.status.code(),
+			stdout: String::from_utf8_lossy(output.stdout.as_slice()).into_owned(),
+			stderr: String::from_utf8_lossy(output.stderr.as_slice()).into_owned(),
+		}
+	}
+}
+
+#[cfg(all(unix, test))]
+mod tests {
+	use std::process::Command;
+
+	use super::*;
+
+	#[test]
+	fn from_output() {
+		let output = Command::new("sh")
+			.args(["-c", "echo out; echo err >&2; exit 3"])
+			.output()
+			.unwrap();
+		let command_output = CommandOutput::from(output);
+		assert_eq!(command_output.status(), Some(3));
+		assert_eq!(command_output.stdout(), "out\n");
+		assert_eq!(command_output.stderr(), "err\n");
+	}
+
+	#[test]
+	fn from_output_invalid_utf8() {
+		let output = Command::new("printf").arg("\\377").output().unwrap();
+		assert_eq!(CommandOutput::from(output).stdout(), "\u{fffd}");
+	}
+}
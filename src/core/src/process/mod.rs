@@ -1,10 +1,12 @@
 mod artifact;
+mod command_output;
 mod results;
 #[cfg(test)]
 mod tests;
 pub(crate) mod thread;
 
 use std::{
+	io,
 	io::ErrorKind,
 	process::Command,
 	sync::{
@@ -18,11 +20,12 @@ use display::Size;
 use input::StandardEvent;
 use parking_lot::Mutex;
 use runtime::ThreadStatuses;
-use todo_file::TodoFile;
+use todo_file::{Line, TodoFile};
 use view::RenderContext;
 
-pub(crate) use self::{artifact::Artifact, results::Results, thread::Thread};
+pub(crate) use self::{artifact::Artifact, command_output::CommandOutput, results::Results, thread::Thread};
 use crate::{
+	diff::{self, Interrupter, LoadedDiff},
 	events,
 	events::{Event, MetaEvent},
 	module::{self, ExitStatus, ModuleHandler, State},
@@ -41,6 +44,7 @@ pub(crate) struct Process<ModuleProvider: module::ModuleProvider> {
 	todo_file: Arc<Mutex<TodoFile>>,
 	view_state: view::State,
 	search_state: search::State,
+	diff_state: diff::State,
 }
 
 impl<ModuleProvider: module::ModuleProvider> Clone for Process<ModuleProvider> {
@@ -57,11 +61,13 @@ impl<ModuleProvider: module::ModuleProvider> Clone for Process<ModuleProvider> {
 			todo_file: Arc::clone(&self.todo_file),
 			view_state: self.view_state.clone(),
 			search_state: self.search_state.clone(),
+			diff_state: self.diff_state.clone(),
 		}
 	}
 }
 
 impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
+	#[allow(clippy::too_many_arguments)]
 	pub(crate) fn new(
 		initial_display_size: Size,
 		todo_file: Arc<Mutex<TodoFile>>,
@@ -69,9 +75,11 @@ impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
 		input_state: events::State,
 		view_state: view::State,
 		search_state: search::State,
+		diff_state: diff::State,
 		thread_statuses: ThreadStatuses,
 	) -> Self {
 		Self {
+			diff_state,
 			ended: Arc::new(AtomicBool::from(false)),
 			exit_status: Arc::new(Mutex::new(ExitStatus::None)),
 			input_state,
@@ -133,6 +141,12 @@ impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
 	pub(crate) fn render(&self) {
 		let render_context = *self.render_context.lock();
 		let mut module_handler = self.module_handler.lock();
+		if let Some((view_data, secondary_view_data)) =
+			module_handler.build_split_view_data(self.state(), &render_context)
+		{
+			self.view_state.render_split(view_data, secondary_view_data);
+			return;
+		}
 		let view_data = module_handler.build_view_data(self.state(), &render_context);
 		// TODO It is not possible for this to fail. view::State should be updated to not return an error
 		self.view_state.render(view_data);
@@ -153,9 +167,9 @@ impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
 			.handle_event(self.state(), &self.input_state.clone(), &self.view_state.clone())
 	}
 
-	fn handle_event_artifact(&self, event: Event) -> Results {
+	fn handle_event_artifact(&self, event: &Event) -> Results {
 		let mut results = Results::new();
-		match event {
+		match *event {
 			Event::Standard(StandardEvent::Exit) => {
 				results.exit_status(ExitStatus::Abort);
 			},
@@ -214,25 +228,61 @@ impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
 	fn handle_external_command(&self, external_command: &(String, Vec<String>)) -> Results {
 		let mut results = Results::new();
 
-		match self.run_command(external_command) {
+		match self.run_command(external_command, |cmd| {
+			cmd.status().map(|status| {
+				if status.success() {
+					MetaEvent::ExternalCommandSuccess
+				}
+				else {
+					MetaEvent::ExternalCommandError
+				}
+			})
+		}) {
 			Ok(meta_event) => {
 				self.input_state.enqueue_event(Event::from(meta_event));
 			},
 			Err(err) => {
-				results.error_with_return(
-					err.context(format!(
-						"Unable to run {} {}",
-						external_command.0,
-						external_command.1.join(" ")
-					)),
-					State::List,
-				);
+				results.error_with_return(Self::command_error(err, external_command), State::List);
 			},
 		}
 		results
 	}
 
-	fn run_command(&self, external_command: &(String, Vec<String>)) -> Result<MetaEvent> {
+	fn handle_captured_command(
+		&self,
+		external_command: &(String, Vec<String>),
+		command_output: &Arc<Mutex<Option<CommandOutput>>>,
+	) -> Results {
+		let mut results = Results::new();
+
+		match self.run_command(external_command, Command::output) {
+			Ok(output) => {
+				let meta_event = if output.status.success() {
+					MetaEvent::ExternalCommandSuccess
+				}
+				else {
+					MetaEvent::ExternalCommandError
+				};
+				*command_output.lock() = Some(CommandOutput::from(output));
+				self.input_state.enqueue_event(Event::from(meta_event));
+			},
+			Err(err) => {
+				results.error_with_return(Self::command_error(err, external_command), State::List);
+			},
+		}
+		results
+	}
+
+	fn command_error(err: Error, external_command: &(String, Vec<String>)) -> Error {
+		err.context(format!(
+			"Unable to run {} {}",
+			external_command.0,
+			external_command.1.join(" ")
+		))
+	}
+
+	fn run_command<T, F>(&self, external_command: &(String, Vec<String>), run: F) -> Result<T>
+	where F: FnOnce(&mut Command) -> io::Result<T> {
 		self.view_state.stop();
 		self.input_state.pause();
 
@@ -244,27 +294,17 @@ impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
 		let mut cmd = Command::new(external_command.0.clone());
 		_ = cmd.args(external_command.1.clone());
 
-		let result = cmd
-			.status()
-			.map(|status| {
-				if status.success() {
-					MetaEvent::ExternalCommandSuccess
-				}
-				else {
-					MetaEvent::ExternalCommandError
-				}
-			})
-			.map_err(|err| {
-				match err.kind() {
-					ErrorKind::NotFound => {
-						anyhow!("File does not exist: {}", external_command.0)
-					},
-					ErrorKind::PermissionDenied => {
-						anyhow!("File not executable: {}", external_command.0)
-					},
-					_ => Error::from(err),
-				}
-			});
+		let result = run(&mut cmd).map_err(|err| {
+			match err.kind() {
+				ErrorKind::NotFound => {
+					anyhow!("File does not exist: {}", external_command.0)
+				},
+				ErrorKind::PermissionDenied => {
+					anyhow!("File not executable: {}", external_command.0)
+				},
+				_ => Error::from(err),
+			}
+		});
 
 		self.input_state.resume();
 		self.view_state.start();
@@ -286,18 +326,65 @@ impl<ModuleProvider: module::ModuleProvider> Process<ModuleProvider> {
 		Results::new()
 	}
 
+	fn handle_load_diff(
+		&self,
+		hash: String,
+		output: Arc<Mutex<Option<LoadedDiff>>>,
+		interrupter: Interrupter,
+	) -> Results {
+		self.diff_state.send_update(diff::Action::Load(hash, output, interrupter));
+		Results::new()
+	}
+
+	fn handle_load_range_diff(
+		&self,
+		first_hash: String,
+		last_hash: String,
+		output: Arc<Mutex<Option<LoadedDiff>>>,
+		interrupter: Interrupter,
+	) -> Results {
+		self.diff_state
+			.send_update(diff::Action::LoadRange(first_hash, last_hash, output, interrupter));
+		Results::new()
+	}
+
+	// the range is given to the show commit module before it is activated, so the range is loaded instead of the
+	// selected commit
+	fn handle_show_range(&self, first_hash: &str, last_hash: &str) -> Results {
+		let mut results = self
+			.module_handler
+			.lock()
+			.range(State::ShowCommit, first_hash, last_hash);
+		results.append(self.handle_state(State::ShowCommit));
+		results
+	}
+
+	// the same as a range, with the lines that are squashed given to the show commit module
+	fn handle_show_squash(&self, lines: &[Line]) -> Results {
+		let mut results = self.module_handler.lock().squash(State::ShowCommit, lines);
+		results.append(self.handle_state(State::ShowCommit));
+		results
+	}
+
 	fn handle_results(&self, mut results: Results) {
 		while let Some(artifact) = results.artifact() {
 			results.append(match artifact {
+				Artifact::CapturedCommand(command, output) => self.handle_captured_command(&command, &output),
 				Artifact::ChangeState(state) => self.handle_state(state),
 				Artifact::EnqueueResize => self.handle_enqueue_resize(),
 				Artifact::Error(err, previous_state) => self.handle_error(&err, previous_state),
-				Artifact::Event(event) => self.handle_event_artifact(event),
+				Artifact::Event(event) => self.handle_event_artifact(&event),
 				Artifact::ExitStatus(exit_status) => self.handle_exit_status(exit_status),
 				Artifact::ExternalCommand(command) => self.handle_external_command(&command),
+				Artifact::LoadDiff(hash, output, interrupter) => self.handle_load_diff(hash, output, interrupter),
+				Artifact::LoadRangeDiff(first_hash, last_hash, output, interrupter) => {
+					self.handle_load_range_diff(first_hash, last_hash, output, interrupter)
+				},
 				Artifact::SearchCancel => self.handle_search_cancel(),
 				Artifact::SearchTerm(search_term) => self.handle_search_term(search_term),
 				Artifact::Searchable(searchable) => self.handle_searchable(searchable),
+				Artifact::ShowRange(first_hash, last_hash) => self.handle_show_range(&first_hash, &last_hash),
+				Artifact::ShowSquash(lines) => self.handle_show_squash(&lines),
 			});
 		}
 	}
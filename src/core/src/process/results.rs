@@ -1,11 +1,14 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, sync::Arc};
 
 use anyhow::Error;
+use parking_lot::Mutex;
+use todo_file::Line;
 
 use crate::{
+	diff::{Interrupter, LoadedDiff},
 	events::Event,
 	module::{ExitStatus, State},
-	process::artifact::Artifact,
+	process::{artifact::Artifact, CommandOutput},
 	search::Searchable,
 };
 
@@ -54,6 +57,53 @@ impl Results {
 			.push_back(Artifact::ExternalCommand((command, arguments)));
 	}
 
+	pub(crate) fn captured_command(
+		&mut self,
+		command: String,
+		arguments: Vec<String>,
+		output: &Arc<Mutex<Option<CommandOutput>>>,
+	) {
+		self.artifacts
+			.push_back(Artifact::CapturedCommand((command, arguments), Arc::clone(output)));
+	}
+
+	pub(crate) fn load_diff(
+		&mut self,
+		hash: &str,
+		output: &Arc<Mutex<Option<LoadedDiff>>>,
+		interrupter: &Interrupter,
+	) {
+		self.artifacts.push_back(Artifact::LoadDiff(
+			String::from(hash),
+			Arc::clone(output),
+			interrupter.clone(),
+		));
+	}
+
+	pub(crate) fn load_range_diff(
+		&mut self,
+		first_hash: &str,
+		last_hash: &str,
+		output: &Arc<Mutex<Option<LoadedDiff>>>,
+		interrupter: &Interrupter,
+	) {
+		self.artifacts.push_back(Artifact::LoadRangeDiff(
+			String::from(first_hash),
+			String::from(last_hash),
+			Arc::clone(output),
+			interrupter.clone(),
+		));
+	}
+
+	pub(crate) fn show_range(&mut self, first_hash: &str, last_hash: &str) {
+		self.artifacts
+			.push_back(Artifact::ShowRange(String::from(first_hash), String::from(last_hash)));
+	}
+
+	pub(crate) fn show_squash(&mut self, lines: Vec<Line>) {
+		self.artifacts.push_back(Artifact::ShowSquash(lines));
+	}
+
 	pub(crate) fn enqueue_resize(&mut self) {
 		self.artifacts.push_back(Artifact::EnqueueResize);
 	}
@@ -193,6 +243,41 @@ mod tests {
 		assert!(matches!(results.artifact(), Some(Artifact::ExternalCommand(_))));
 	}
 
+	#[test]
+	fn captured_command() {
+		let mut results = Results::new();
+		results.captured_command(String::from("sh"), vec![String::from("-c")], &Arc::new(Mutex::new(None)));
+		assert!(matches!(results.artifact(), Some(Artifact::CapturedCommand(..))));
+	}
+
+	#[test]
+	fn load_diff() {
+		let mut results = Results::new();
+		results.load_diff("abc", &Arc::new(Mutex::new(None)), &Interrupter::new());
+		assert!(matches!(results.artifact(), Some(Artifact::LoadDiff(..))));
+	}
+
+	#[test]
+	fn load_range_diff() {
+		let mut results = Results::new();
+		results.load_range_diff("abc", "def", &Arc::new(Mutex::new(None)), &Interrupter::new());
+		assert!(matches!(results.artifact(), Some(Artifact::LoadRangeDiff(..))));
+	}
+
+	#[test]
+	fn show_range() {
+		let mut results = Results::new();
+		results.show_range("abc", "def");
+		assert!(matches!(results.artifact(), Some(Artifact::ShowRange(..))));
+	}
+
+	#[test]
+	fn show_squash() {
+		let mut results = Results::new();
+		results.show_squash(vec![Line::new("pick abc comment").unwrap()]);
+		assert!(matches!(results.artifact(), Some(Artifact::ShowSquash(..))));
+	}
+
 	#[test]
 	fn enqueue_resize() {
 		let mut results = Results::new();
@@ -74,6 +74,20 @@ impl Module for TestModule {
 		self.trace.lock().push(format!("handle_error(error = {error})"));
 		Results::new()
 	}
+
+	fn handle_range(&mut self, first_hash: &str, last_hash: &str) -> Results {
+		self.trace
+			.lock()
+			.push(format!("handle_range({first_hash}..{last_hash})"));
+		Results::new()
+	}
+
+	fn handle_squash(&mut self, lines: &[Line]) -> Results {
+		self.trace
+			.lock()
+			.push(format!("handle_squash(lines = {})", lines.len()));
+		Results::new()
+	}
 }
 
 #[test]
@@ -190,6 +204,37 @@ fn render() {
 	);
 }
 
+#[test]
+fn render_split() {
+	struct SplitModule {
+		view_data: ViewData,
+		secondary_view_data: ViewData,
+	}
+
+	impl Module for SplitModule {
+		fn build_view_data(&mut self, _render_context: &RenderContext) -> &ViewData {
+			unreachable!()
+		}
+
+		fn build_split_view_data(&mut self, _render_context: &RenderContext) -> Option<(&ViewData, &ViewData)> {
+			Some((&self.view_data, &self.secondary_view_data))
+		}
+	}
+
+	process_test(
+		create_test_module_handler(SplitModule {
+			view_data: ViewData::new(|_| {}),
+			secondary_view_data: ViewData::new(|_| {}),
+		}),
+		|ProcessTestContext {
+		     process, view_context, ..
+		 }| {
+			process.render();
+			view_context.assert_sent_messages(vec!["Render"]);
+		},
+	);
+}
+
 #[test]
 fn write_todo_file() {
 	process_test(
@@ -245,7 +290,7 @@ fn handle_event_artifact_exit_event() {
 		|ProcessTestContext { process, .. }| {
 			let event = Event::from(StandardEvent::Exit);
 			assert_results!(
-				process.handle_event_artifact(event),
+				process.handle_event_artifact(&event),
 				Artifact::ExitStatus(ExitStatus::Abort)
 			);
 		},
@@ -259,7 +304,7 @@ fn handle_event_artifact_kill_event() {
 		|ProcessTestContext { process, .. }| {
 			let event = Event::from(StandardEvent::Kill);
 			assert_results!(
-				process.handle_event_artifact(event),
+				process.handle_event_artifact(&event),
 				Artifact::ExitStatus(ExitStatus::Kill)
 			);
 		},
@@ -274,7 +319,7 @@ fn handle_event_artifact_resize_event() {
 		     process, view_context, ..
 		 }| {
 			let event = Event::Resize(100, 200);
-			assert_results!(process.handle_event_artifact(event));
+			assert_results!(process.handle_event_artifact(&event));
 			view_context.assert_sent_messages(vec!["Resize(100, 200)"]);
 		},
 	);
@@ -290,7 +335,7 @@ fn handle_event_artifact_resize_event_to_small() {
 			process.set_state(State::List);
 			let event = Event::Resize(1, 1);
 			assert_results!(
-				process.handle_event_artifact(event),
+				process.handle_event_artifact(&event),
 				Artifact::ChangeState(State::WindowSizeError)
 			);
 			view_context.assert_sent_messages(vec!["Resize(1, 1)"]);
@@ -307,7 +352,7 @@ fn handle_event_artifact_resize_event_to_small_already_window_size_error_state()
 		 }| {
 			process.set_state(State::WindowSizeError);
 			let event = Event::Resize(1, 1);
-			assert_results!(process.handle_event_artifact(event));
+			assert_results!(process.handle_event_artifact(&event));
 			view_context.assert_sent_messages(vec!["Resize(1, 1)"]);
 		},
 	);
@@ -319,7 +364,7 @@ fn handle_event_artifact_other_event() {
 		create_default_test_module_handler(),
 		|ProcessTestContext { process, .. }| {
 			let event = Event::from('a');
-			assert_results!(process.handle_event_artifact(event));
+			assert_results!(process.handle_event_artifact(&event));
 		},
 	);
 }
@@ -501,6 +546,87 @@ fn handle_external_command_not_found() {
 	);
 }
 
+#[cfg(unix)]
+#[test]
+fn handle_captured_command_success() {
+	let module = TestModule::new();
+	process_test(
+		create_test_module_handler(module),
+		|ProcessTestContext { process, .. }| {
+			_ = process.input_state.read_event(); // clear existing event
+			let mut notifier = MockNotifier::new(&process.thread_statuses);
+			notifier.register_thread(view::REFRESH_THREAD_NAME, Status::Waiting);
+			notifier.register_thread(input::THREAD_NAME, Status::Waiting);
+			let output = Arc::new(Mutex::new(None));
+			assert_results!(process.handle_captured_command(
+				&(String::from("sh"), vec![String::from("-c"), String::from("echo foo")]),
+				&output
+			));
+			assert_eq!(
+				process.input_state.read_event(),
+				Event::from(MetaEvent::ExternalCommandSuccess)
+			);
+			assert_eq!(output.lock().take(), Some(CommandOutput::new(Some(0), "foo\n", "")));
+		},
+	);
+}
+
+#[cfg(unix)]
+#[test]
+fn handle_captured_command_failure() {
+	let module = TestModule::new();
+	process_test(
+		create_test_module_handler(module),
+		|ProcessTestContext { process, .. }| {
+			_ = process.input_state.read_event(); // clear existing event
+			let mut notifier = MockNotifier::new(&process.thread_statuses);
+			notifier.register_thread(view::REFRESH_THREAD_NAME, Status::Waiting);
+			notifier.register_thread(input::THREAD_NAME, Status::Waiting);
+			let output = Arc::new(Mutex::new(None));
+			assert_results!(process.handle_captured_command(
+				&(String::from("sh"), vec![String::from("-c"), String::from("echo bar >&2; exit 2")]),
+				&output
+			));
+			assert_eq!(
+				process.input_state.read_event(),
+				Event::from(MetaEvent::ExternalCommandError)
+			);
+			assert_eq!(output.lock().take(), Some(CommandOutput::new(Some(2), "", "bar\n")));
+		},
+	);
+}
+
+#[cfg(unix)]
+#[test]
+fn handle_captured_command_not_found() {
+	let command = String::from(
+		Path::new(env!("CARGO_MANIFEST_DIR"))
+			.join("test")
+			.join("not-found.sh")
+			.to_str()
+			.unwrap(),
+	);
+	let module = TestModule::new();
+	process_test(
+		create_test_module_handler(module),
+		|ProcessTestContext { process, .. }| {
+			_ = process.input_state.read_event(); // clear existing event
+			let mut notifier = MockNotifier::new(&process.thread_statuses);
+			notifier.register_thread(view::REFRESH_THREAD_NAME, Status::Waiting);
+			notifier.register_thread(input::THREAD_NAME, Status::Waiting);
+			let output = Arc::new(Mutex::new(None));
+			assert_results!(
+				process.handle_captured_command(&(command, vec![]), &output),
+				Artifact::Error(
+					anyhow!("Unable to run {0} : File does not exist: {0}", command),
+					Some(State::List)
+				)
+			);
+			assert!(output.lock().is_none());
+		},
+	);
+}
+
 #[test]
 fn handle_results_change_state() {
 	let module = TestModule::new();
@@ -591,6 +717,29 @@ fn handle_results_external_command_success() {
 	);
 }
 
+#[test]
+fn handle_results_captured_command_success() {
+	let module = TestModule::new();
+	process_test(
+		create_test_module_handler(module),
+		|ProcessTestContext { process, .. }| {
+			_ = process.input_state.read_event(); // clear existing event
+			let mut notifier = MockNotifier::new(&process.thread_statuses);
+			notifier.register_thread(view::REFRESH_THREAD_NAME, Status::Waiting);
+			notifier.register_thread(input::THREAD_NAME, Status::Waiting);
+			let output = Arc::new(Mutex::new(None));
+			let mut results = Results::new();
+			results.captured_command(String::from("true"), vec![], &output);
+			process.handle_results(results);
+			assert_eq!(
+				process.input_state.read_event(),
+				Event::from(MetaEvent::ExternalCommandSuccess)
+			);
+			assert!(output.lock().is_some());
+		},
+	);
+}
+
 #[test]
 fn handle_search_cancel() {
 	let module = TestModule::new();
@@ -651,3 +800,72 @@ fn handle_searchable() {
 		},
 	);
 }
+
+#[test]
+fn handle_load_diff() {
+	let module = TestModule::new();
+	process_test(
+		create_test_module_handler(module),
+		|ProcessTestContext { process, diff_state, .. }| {
+			let mut results = Results::new();
+			results.load_diff("abc", &Arc::new(Mutex::new(None)), &diff::Interrupter::new());
+			process.handle_results(results);
+			assert!(matches!(
+				diff_state.receive_update(),
+				diff::Action::Load(hash, ..) if hash == "abc"
+			));
+		},
+	);
+}
+
+#[test]
+fn handle_load_range_diff() {
+	let module = TestModule::new();
+	process_test(
+		create_test_module_handler(module),
+		|ProcessTestContext { process, diff_state, .. }| {
+			let mut results = Results::new();
+			results.load_range_diff("abc", "def", &Arc::new(Mutex::new(None)), &diff::Interrupter::new());
+			process.handle_results(results);
+			assert!(matches!(
+				diff_state.receive_update(),
+				diff::Action::LoadRange(first_hash, last_hash, ..) if first_hash == "abc" && last_hash == "def"
+			));
+		},
+	);
+}
+
+#[test]
+fn handle_show_range() {
+	let module = TestModule::new();
+	process_test(
+		create_test_module_handler(module.clone()),
+		|ProcessTestContext { process, .. }| {
+			process.set_state(State::List);
+			let mut results = Results::new();
+			results.show_range("abc", "def");
+			process.handle_results(results);
+			assert_eq!(process.state(), State::ShowCommit);
+			module.assert_trace(&["handle_range(abc..def)", "deactivate", "activate(state = List)"]);
+		},
+	);
+}
+
+#[test]
+fn handle_show_squash() {
+	let module = TestModule::new();
+	process_test(
+		create_test_module_handler(module.clone()),
+		|ProcessTestContext { process, .. }| {
+			process.set_state(State::List);
+			let mut results = Results::new();
+			results.show_squash(vec![
+				Line::new("pick abc comment").unwrap(),
+				Line::new("fixup def comment").unwrap(),
+			]);
+			process.handle_results(results);
+			assert_eq!(process.state(), State::ShowCommit);
+			module.assert_trace(&["handle_squash(lines = 2)", "deactivate", "activate(state = List)"]);
+		},
+	);
+}
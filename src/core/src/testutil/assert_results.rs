@@ -1,4 +1,5 @@
 use pretty_assertions::assert_eq;
+use todo_file::Line;
 
 use crate::process::{Artifact, Results};
 
@@ -7,7 +8,10 @@ fn _assert_results_format(artifacts: &[Artifact]) -> String {
 		.iter()
 		.map(|artifact| {
 			match *artifact {
-				Artifact::Event(event) => format!("Event({event:?})"),
+				Artifact::CapturedCommand(ref command, _) => {
+					format!("CapturedCommand({:?} {:?})", command.0, command.1.join(","))
+				},
+				Artifact::Event(ref event) => format!("Event({event:?})"),
 				Artifact::ChangeState(state) => format!("ChangeState({state:?})"),
 				Artifact::Error(ref err, state) => {
 					format!(
@@ -20,9 +24,20 @@ fn _assert_results_format(artifacts: &[Artifact]) -> String {
 					format!("ExternalCommand({:?} {:?})", command.0, command.1.join(","))
 				},
 				Artifact::EnqueueResize => String::from("EnqueueResize"),
+				Artifact::LoadDiff(ref hash, ..) => format!("LoadDiff({hash})"),
+				Artifact::LoadRangeDiff(ref first_hash, ref last_hash, ..) => {
+					format!("LoadRangeDiff({first_hash}..{last_hash})")
+				},
 				Artifact::SearchCancel => String::from("SearchCancel"),
 				Artifact::SearchTerm(ref term) => format!("SearchTerm({term})"),
 				Artifact::Searchable(ref _searchable) => String::from("SearchCancel(_)"),
+				Artifact::ShowRange(ref first_hash, ref last_hash) => format!("ShowRange({first_hash}..{last_hash})"),
+				Artifact::ShowSquash(ref lines) => {
+					format!(
+						"ShowSquash({})",
+						lines.iter().map(Line::get_hash).collect::<Vec<&str>>().join(", ")
+					)
+				},
 			}
 		})
 		.collect::<Vec<String>>()
@@ -18,6 +18,10 @@ pub(crate) fn create_test_custom_keybindings() -> AppKeyBindings {
 		action_squash: vec![Event::from(KeyCode::Char('s'))],
 		confirm_yes: vec![Event::from(KeyCode::Char('y'))],
 		edit: vec![Event::from(KeyCode::Char('E'))],
+		expand_file: vec![Event::from(KeyCode::Char('x'))],
+		fold_all: vec![Event::from(KeyCode::Char('F'))],
+		fold_file: vec![Event::from(KeyCode::Char('Z'))],
+		fold_hunk: vec![Event::from(KeyCode::Char('z'))],
 		force_abort: vec![Event::from(KeyCode::Char('Q'))],
 		force_rebase: vec![Event::from(KeyCode::Char('W'))],
 		insert_line: vec![Event::from(KeyCode::Char('I'))],
@@ -29,14 +33,30 @@ pub(crate) fn create_test_custom_keybindings() -> AppKeyBindings {
 		move_right: vec![Event::from(KeyCode::Right)],
 		move_selection_down: vec![Event::from(KeyCode::Char('j'))],
 		move_selection_up: vec![Event::from(KeyCode::Char('k'))],
+		move_block_down: vec![Event::from(KeyCode::Char('J'))],
+		move_block_up: vec![Event::from(KeyCode::Char('K'))],
+		move_update_ref: vec![Event::from(KeyCode::Char('m'))],
 		move_up: vec![Event::from(KeyCode::Up)],
 		move_up_step: vec![Event::from(KeyCode::PageUp)],
+		next_file: vec![Event::from(KeyCode::Char('}'))],
+		next_hunk: vec![Event::from(KeyCode::Char(']'))],
 		open_in_external_editor: vec![Event::from(KeyCode::Char('!'))],
+		previous_file: vec![Event::from(KeyCode::Char('{'))],
+		previous_hunk: vec![Event::from(KeyCode::Char('['))],
 		rebase: vec![Event::from(KeyCode::Char('w'))],
 		remove_line: vec![Event::from(KeyCode::Delete)],
+		run_command: vec![Event::from(KeyCode::Char('x'))],
+		select_block: vec![Event::from(KeyCode::Char('B'))],
 		show_commit: vec![Event::from(KeyCode::Char('c'))],
 		show_diff: vec![Event::from(KeyCode::Char('d'))],
+		show_next_parent: vec![Event::from(KeyCode::Char('p'))],
+		show_squash_preview: vec![Event::from(KeyCode::Char('S'))],
+		show_todo_diff: vec![Event::from(KeyCode::Char('D'))],
+		toggle_preview: vec![Event::from(KeyCode::Char('P'))],
+		toggle_update_ref: vec![Event::from(KeyCode::Char(' ')), Event::from(KeyCode::Enter)],
 		toggle_visual_mode: vec![Event::from(KeyCode::Char('v'))],
+		toggle_wrap: vec![Event::from(KeyCode::Char('w'))],
+		update_refs: vec![Event::from(KeyCode::Char('R'))],
 		fixup_keep_message: vec![Event::from(KeyCode::Char('u'))],
 		fixup_keep_message_with_editor: vec![Event::from(KeyCode::Char('U'))],
 	}
@@ -15,7 +15,7 @@ pub(crate) use self::{
 	create_event_reader::create_event_reader,
 	create_test_keybindings::{create_test_custom_keybindings, create_test_keybindings},
 	mocked_searchable::MockedSearchable,
-	module_test::module_test,
+	module_test::{module_test, TestContext as ModuleTestContext},
 	process_test::{process_test, TestContext as ProcessTestContext},
 	read_event_test::read_event_test,
 	set_git_directory::set_git_directory,
@@ -51,7 +51,7 @@ impl TestContext {
 	pub(crate) fn handle_event(&mut self, module: &'_ mut dyn Module) -> Results {
 		let event = self.read_event(module);
 		let mut results = Results::new();
-		results.event(event);
+		results.event(event.clone());
 		results.append(module.handle_event(event, &self.view_context.state));
 		results
 	}
@@ -7,6 +7,7 @@ use todo_file::testutil::with_todo_file;
 use view::testutil::{with_view_state, TestContext as ViewContext};
 
 use crate::{
+	diff,
 	events::Event,
 	module::{self, ModuleHandler},
 	process::Process,
@@ -14,6 +15,7 @@ use crate::{
 };
 
 pub(crate) struct TestContext<ModuleProvider: module::ModuleProvider + Send + 'static> {
+	pub(crate) diff_state: diff::State,
 	pub(crate) event_handler_context: EventHandlerTestContext,
 	pub(crate) process: Process<ModuleProvider>,
 	pub(crate) search_context: SearchTestContext,
@@ -35,8 +37,10 @@ pub(crate) fn process_test<C, ModuleProvider: module::ModuleProvider + Send + 's
 					let view_state = view_context.state.clone();
 					let input_state = event_handler_context.state.clone();
 					let todo_file_path = PathBuf::from(todo_file_tmp_path.path());
+					let diff_state = diff::State::new();
 
 					callback(TestContext {
+						diff_state: diff_state.clone(),
 						event_handler_context,
 						process: Process::new(
 							Size::new(300, 120),
@@ -45,6 +49,7 @@ pub(crate) fn process_test<C, ModuleProvider: module::ModuleProvider + Send + 's
 							input_state,
 							view_state,
 							search_context.state.clone(),
+							diff_state,
 							ThreadStatuses::new(),
 						),
 						search_context,
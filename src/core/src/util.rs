@@ -38,8 +38,8 @@ macro_rules! first {
 /// Utility function to handle scroll events.
 #[inline]
 #[must_use]
-pub(crate) fn handle_view_data_scroll(event: Event, view_state: &view::State) -> Option<Event> {
-	match event {
+pub(crate) fn handle_view_data_scroll(event: &Event, view_state: &view::State) -> Option<Event> {
+	match *event {
 		Event::Standard(meta_event) if meta_event == StandardEvent::ScrollLeft => view_state.scroll_left(),
 		Event::Standard(meta_event) if meta_event == StandardEvent::ScrollRight => view_state.scroll_right(),
 		Event::Standard(meta_event) if meta_event == StandardEvent::ScrollDown => view_state.scroll_down(),
@@ -50,7 +50,7 @@ pub(crate) fn handle_view_data_scroll(event: Event, view_state: &view::State) ->
 		Event::Standard(meta_event) if meta_event == StandardEvent::ScrollJumpUp => view_state.scroll_page_up(),
 		_ => return None,
 	};
-	Some(event)
+	Some(event.clone())
 }
 
 #[cfg(test)]
@@ -72,7 +72,7 @@ mod tests {
 		with_view_state(|context| {
 			capture!(action);
 			let event = Event::from(meta_event);
-			assert_eq!(handle_view_data_scroll(event, &context.state), Some(event));
+			assert_eq!(handle_view_data_scroll(&event, &context.state), Some(event));
 			context.assert_render_action(&[action]);
 		});
 	}
@@ -81,7 +81,7 @@ mod tests {
 	fn handle_view_data_scroll_event_other() {
 		with_view_state(|context| {
 			let event = Event::from('a');
-			assert!(handle_view_data_scroll(event, &context.state).is_none());
+			assert!(handle_view_data_scroll(&event, &context.state).is_none());
 			context.assert_render_action(&[]);
 		});
 	}
@@ -3,7 +3,9 @@ use std::io::{stdout, BufWriter, Stdout, Write};
 use crossterm::{
 	cursor::{Hide, MoveTo, MoveToColumn, MoveToNextLine, Show},
 	event::{
+		DisableBracketedPaste,
 		DisableMouseCapture,
+		EnableBracketedPaste,
 		EnableMouseCapture,
 		KeyboardEnhancementFlags,
 		PopKeyboardEnhancementFlags,
@@ -124,6 +126,8 @@ impl Tui for CrossTerm {
 		self.queue_command(DisableLineWrap)?;
 		self.queue_command(Hide)?;
 		self.queue_command(EnableMouseCapture)?;
+		// this will fail with the legacy Windows API, so ignore any errors
+		let _paste_result = self.queue_command(EnableBracketedPaste);
 		// this will fail on terminals without support, so ignore any errors
 		let _command_result = self.queue_command(PushKeyboardEnhancementFlags(
 			KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
@@ -139,6 +143,8 @@ impl Tui for CrossTerm {
 	fn end(&mut self) -> Result<(), DisplayError> {
 		// this will fail on terminals without support, so ignore any errors
 		let _command_result = self.queue_command(PopKeyboardEnhancementFlags);
+		// this will fail with the legacy Windows API, so ignore any errors
+		let _paste_result = self.queue_command(DisableBracketedPaste);
 		self.queue_command(DisableMouseCapture)?;
 		self.queue_command(Show)?;
 		self.queue_command(EnableLineWrap)?;
@@ -38,6 +38,12 @@ pub enum DisplayColor {
 	DiffWhitespaceColor,
 	/// The color for indicator text.
 	IndicatorColor,
+	/// The color for branch references.
+	RefBranchColor,
+	/// The color for remote references.
+	RefRemoteColor,
+	/// The color for tag references.
+	RefTagColor,
 	/// The color for the standard text.
 	Normal,
 }
@@ -198,6 +198,9 @@ pub struct Display<T: Tui> {
 	diff_whitespace: (Colors, Colors),
 	indicator: (Colors, Colors),
 	normal: (Colors, Colors),
+	ref_branch: (Colors, Colors),
+	ref_remote: (Colors, Colors),
+	ref_tag: (Colors, Colors),
 }
 
 impl<T: Tui> Display<T> {
@@ -319,6 +322,24 @@ impl<T: Tui> Display<T> {
 			theme.color_background,
 			theme.color_selected_background,
 		);
+		let ref_branch = register_selectable_color_pairs(
+			color_mode,
+			theme.color_ref_branch,
+			theme.color_background,
+			theme.color_selected_background,
+		);
+		let ref_remote = register_selectable_color_pairs(
+			color_mode,
+			theme.color_ref_remote,
+			theme.color_background,
+			theme.color_selected_background,
+		);
+		let ref_tag = register_selectable_color_pairs(
+			color_mode,
+			theme.color_ref_tag,
+			theme.color_background,
+			theme.color_selected_background,
+		);
 
 		Self {
 			action_break,
@@ -341,6 +362,9 @@ impl<T: Tui> Display<T> {
 			diff_whitespace,
 			indicator,
 			normal,
+			ref_branch,
+			ref_remote,
+			ref_tag,
 		}
 	}
 
@@ -404,6 +428,9 @@ impl<T: Tui> Display<T> {
 					DisplayColor::DiffChangeColor => self.diff_change.1,
 					DisplayColor::DiffContextColor => self.diff_context.1,
 					DisplayColor::DiffWhitespaceColor => self.diff_whitespace.1,
+					DisplayColor::RefBranchColor => self.ref_branch.1,
+					DisplayColor::RefRemoteColor => self.ref_remote.1,
+					DisplayColor::RefTagColor => self.ref_tag.1,
 				}
 			}
 			else {
@@ -427,6 +454,9 @@ impl<T: Tui> Display<T> {
 					DisplayColor::DiffChangeColor => self.diff_change.0,
 					DisplayColor::DiffContextColor => self.diff_context.0,
 					DisplayColor::DiffWhitespaceColor => self.diff_whitespace.0,
+					DisplayColor::RefBranchColor => self.ref_branch.0,
+					DisplayColor::RefRemoteColor => self.ref_remote.0,
+					DisplayColor::RefTagColor => self.ref_tag.0,
 				}
 			},
 		)
@@ -683,6 +713,27 @@ mod tests {
 		CrosstermColor::DarkGrey,
 		CrosstermColor::AnsiValue(237)
 	)]
+	#[case::ref_branch(DisplayColor::RefBranchColor, false, CrosstermColor::Green, CrosstermColor::Reset)]
+	#[case::ref_branch_selected(
+		DisplayColor::RefBranchColor,
+		true,
+		CrosstermColor::Green,
+		CrosstermColor::AnsiValue(237)
+	)]
+	#[case::ref_remote(DisplayColor::RefRemoteColor, false, CrosstermColor::Red, CrosstermColor::Reset)]
+	#[case::ref_remote_selected(
+		DisplayColor::RefRemoteColor,
+		true,
+		CrosstermColor::Red,
+		CrosstermColor::AnsiValue(237)
+	)]
+	#[case::ref_tag(DisplayColor::RefTagColor, false, CrosstermColor::Yellow, CrosstermColor::Reset)]
+	#[case::ref_tag_selected(
+		DisplayColor::RefTagColor,
+		true,
+		CrosstermColor::Yellow,
+		CrosstermColor::AnsiValue(237)
+	)]
 	fn color(
 		#[case] display_color: DisplayColor,
 		#[case] selected: bool,
@@ -0,0 +1,38 @@
+/// The reason the lines of a file change were not loaded with the rest of the diff
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum CollapseReason {
+	/// The file is binary, or is marked with the `-diff` attribute
+	Binary,
+	/// The file is marked as generated with the `linguist-generated` attribute
+	Generated,
+	/// The file has more changed lines than the file or total line limit
+	TooLarge,
+}
+
+impl CollapseReason {
+	/// Can the lines of the file change be loaded on request.
+	#[must_use]
+	#[inline]
+	pub const fn is_expandable(self) -> bool {
+		match self {
+			Self::Binary => false,
+			Self::Generated | Self::TooLarge => true,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::binary(CollapseReason::Binary, false)]
+	#[case::generated(CollapseReason::Generated, true)]
+	#[case::too_large(CollapseReason::TooLarge, true)]
+	fn is_expandable(#[case] reason: CollapseReason, #[case] expected: bool) {
+		assert_eq!(reason.is_expandable(), expected);
+	}
+}
@@ -1,9 +1,9 @@
 use chrono::{DateTime, Local, TimeZone};
 
-use crate::{errors::GitError, reference::Reference, user::User};
+use crate::{errors::GitError, reference::Reference, signature_kind::SignatureKind, trailer::Trailer, user::User};
 
 /// Represents a commit.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Commit {
 	pub(crate) hash: String,
 	pub(crate) reference: Option<Reference>,
@@ -13,6 +13,9 @@ pub struct Commit {
 	pub(crate) committer: Option<User>,
 	pub(crate) committed_date: DateTime<Local>,
 	pub(crate) summary: Option<String>,
+	pub(crate) signature: Option<SignatureKind>,
+	pub(crate) trailers: Vec<Trailer>,
+	pub(crate) parent_hash: Option<String>,
 }
 
 impl Commit {
@@ -72,7 +75,28 @@ impl Commit {
 		self.message.as_deref()
 	}
 
-	fn new(commit: &git2::Commit<'_>, reference: Option<&git2::Reference<'_>>) -> Self {
+	/// Get the kind of the signature of the commit, if the commit is signed. The signature has not been verified.
+	#[must_use]
+	#[inline]
+	pub const fn signature(&self) -> Option<SignatureKind> {
+		self.signature
+	}
+
+	/// Get the trailers of the commit message.
+	#[must_use]
+	#[inline]
+	pub fn trailers(&self) -> &[Trailer] {
+		self.trailers.as_slice()
+	}
+
+	/// Get the hash of the first parent of the commit, if the commit has a parent.
+	#[must_use]
+	#[inline]
+	pub fn parent_hash(&self) -> Option<&str> {
+		self.parent_hash.as_deref()
+	}
+
+	pub(crate) fn new(commit: &git2::Commit<'_>, reference: Option<&git2::Reference<'_>>) -> Self {
 		let author = User::new(commit.author().name(), commit.author().email());
 		let message = commit.message().map(String::from);
 		let summary = commit.summary().map(String::from);
@@ -85,6 +109,12 @@ impl Commit {
 
 		let try_committer = User::new(commit.committer().name(), commit.committer().email());
 		let committer = (try_committer.is_some() && try_committer != author).then_some(try_committer);
+		let signature = commit
+			.header_field_bytes("gpgsig")
+			.or_else(|_| commit.header_field_bytes("gpgsig-sha256"))
+			.ok()
+			.map(|buffer| buffer.as_str().map_or(SignatureKind::Unknown, SignatureKind::from));
+		let trailers = message.as_deref().map(Trailer::from_message).unwrap_or_default();
 
 		Self {
 			hash: format!("{}", commit.id()),
@@ -100,6 +130,9 @@ impl Commit {
 			committer,
 			committed_date,
 			summary,
+			signature,
+			trailers,
+			parent_hash: commit.parent_id(0).ok().map(|oid| format!("{oid}")),
 		}
 	}
 }
@@ -172,6 +205,14 @@ mod tests {
 		);
 	}
 
+	#[test]
+	fn signature() {
+		let commit = CommitBuilder::new("0123456789ABCDEF")
+			.signature(SignatureKind::Gpg)
+			.build();
+		assert_some_eq!(commit.signature(), SignatureKind::Gpg);
+	}
+
 	#[test]
 	fn summary() {
 		let commit = CommitBuilder::new("0123456789ABCDEF").summary("title").build();
@@ -235,6 +276,63 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn new_signed() {
+		with_temp_repository(|repository| {
+			create_commit(
+				&repository,
+				Some(CreateCommitOptions::new().signature("-----BEGIN SSH SIGNATURE-----\n-----END SSH SIGNATURE-----")),
+			);
+			let commit = repository.find_commit("refs/heads/main").unwrap();
+			assert_some_eq!(commit.signature(), SignatureKind::Ssh);
+		});
+	}
+
+	#[test]
+	fn new_signed_with_invalid_signature() {
+		with_temp_repository(|repository| {
+			create_commit(
+				&repository,
+				Some(CreateCommitOptions::new().signature("not a signature")),
+			);
+			let commit = repository.find_commit("refs/heads/main").unwrap();
+			assert_some_eq!(commit.signature(), SignatureKind::Unknown);
+		});
+	}
+
+	#[test]
+	fn new_unsigned() {
+		with_temp_repository(|repository| {
+			let commit = repository.find_commit("refs/heads/main").unwrap();
+			assert_none!(commit.signature());
+		});
+	}
+
+	#[test]
+	fn new_trailers() {
+		with_temp_repository(|repository| {
+			create_commit(
+				&repository,
+				Some(CreateCommitOptions::new().message("title\n\nbody\n\nSigned-off-by: Name <name@example.com>\n")),
+			);
+			let commit = repository.find_commit("refs/heads/main").unwrap();
+			assert_eq!(commit.trailers(), &[Trailer::new(
+				"Signed-off-by",
+				"Name <name@example.com>"
+			)]);
+		});
+	}
+
+	#[test]
+	fn new_parent_hash() {
+		with_temp_repository(|repository| {
+			let parent = repository.find_commit("refs/heads/main").unwrap();
+			create_commit(&repository, None);
+			let commit = repository.find_commit("refs/heads/main").unwrap();
+			assert_some_eq!(commit.parent_hash(), parent.hash());
+		});
+	}
+
 	#[test]
 	fn try_from_success() {
 		with_temp_repository(|repository| {
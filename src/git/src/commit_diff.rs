@@ -1,4 +1,15 @@
-use crate::{commit::Commit, file_status::FileStatus};
+use std::iter;
+
+use crate::{commit::Commit, file_status::FileStatus, Origin};
+
+fn is_line_added(status: &FileStatus, new_line_number: Option<u32>) -> bool {
+	status.deltas().iter().any(|delta| {
+		delta
+			.lines()
+			.iter()
+			.any(|line| line.origin() == Origin::Addition && line.new_line_number() == new_line_number)
+	})
+}
 
 /// Represents a commit with a diff
 #[derive(Debug)]
@@ -12,6 +23,86 @@ pub struct CommitDiff {
 }
 
 impl CommitDiff {
+	/// Create a combined diff from the diffs of a merge commit against each of its parents. Similar to
+	/// `git diff --cc`, only the hunks of files that were modified from every parent, and that contain
+	/// lines that differ from every parent, are retained. This is generally the result of a conflict
+	/// resolution. The hunks are shown relative to the first parent.
+	///
+	/// Returns `None` if there are fewer than two diffs.
+	#[inline]
+	#[must_use]
+	pub fn new_combined(diffs: &[Self]) -> Option<Self> {
+		let (first, others) = diffs.split_first()?;
+		if others.is_empty() {
+			return None;
+		}
+
+		let mut file_statuses = vec![];
+		let mut number_insertions = 0;
+		let mut number_deletions = 0;
+		for status in &first.file_statuses {
+			let other_statuses: Vec<&FileStatus> = others
+				.iter()
+				.filter_map(|diff| {
+					diff.file_statuses
+						.iter()
+						.find(|s| s.destination_path == status.destination_path)
+				})
+				.collect();
+			if other_statuses.len() != others.len() {
+				continue;
+			}
+
+			// the lines of a collapsed file are not loaded, so the file can only be shown collapsed
+			if let Some(reason) = iter::once(status)
+				.chain(other_statuses.iter().copied())
+				.find_map(FileStatus::collapse_reason)
+			{
+				let mut collapsed_status = status.clone();
+				collapsed_status.collapse(reason);
+				file_statuses.push(collapsed_status);
+				continue;
+			}
+
+			let mut combined_status = FileStatus {
+				deltas: vec![],
+				largest_new_line_number: 0,
+				largest_old_line_number: 0,
+				..status.clone()
+			};
+			for delta in status.deltas() {
+				let differs_from_all = delta.lines().iter().any(|line| {
+					line.origin() == Origin::Addition
+						&& other_statuses
+							.iter()
+							.all(|other_status| is_line_added(other_status, line.new_line_number()))
+				});
+				if differs_from_all {
+					for line in delta.lines() {
+						match line.origin() {
+							Origin::Addition => number_insertions += 1,
+							Origin::Deletion => number_deletions += 1,
+							Origin::Binary | Origin::Context | Origin::Header => {},
+						}
+					}
+					combined_status.add_delta(delta.clone());
+				}
+			}
+			if !combined_status.deltas.is_empty() {
+				file_statuses.push(combined_status);
+			}
+		}
+
+		Some(Self {
+			commit: first.commit.clone(),
+			parent: None,
+			number_files_changed: file_statuses.len(),
+			file_statuses,
+			number_insertions,
+			number_deletions,
+		})
+	}
+
 	/// The commit of the diff
 	#[inline]
 	#[must_use]
@@ -33,6 +124,19 @@ impl CommitDiff {
 		&self.file_statuses
 	}
 
+	/// Replace the file status that has the same destination path, such as with the loaded lines of a collapsed
+	/// file status.
+	#[inline]
+	pub fn replace_file_status(&mut self, file_status: FileStatus) {
+		if let Some(status) = self
+			.file_statuses
+			.iter_mut()
+			.find(|status| status.destination_path == file_status.destination_path)
+		{
+			*status = file_status;
+		}
+	}
+
 	/// The total number of files changed in the diff
 	#[inline]
 	#[must_use]
@@ -59,6 +163,7 @@ impl CommitDiff {
 mod tests {
 	use claims::assert_some_eq;
 
+	use super::CommitDiff;
 	use crate::{
 		delta::Delta,
 		diff_line::DiffLine,
@@ -67,6 +172,7 @@ mod tests {
 		file_status_builder::FileStatusBuilder,
 		status::Status,
 		testutil::{CommitBuilder, CommitDiffBuilder},
+		CollapseReason,
 		Origin,
 	};
 
@@ -86,7 +192,7 @@ mod tests {
 
 	#[test]
 	fn file_statuses() {
-		let mut builder = FileStatusBuilder::new();
+		let mut builder = FileStatusBuilder::new_with_line_limits(0, 0);
 		builder.add_file_stat(FileStatus::new(
 			"foo",
 			FileMode::Normal,
@@ -128,4 +234,120 @@ mod tests {
 			.build();
 		assert_eq!(diff.number_deletions(), 3);
 	}
+
+	fn create_file_status(path: &str, delta: Delta) -> FileStatus {
+		let mut builder = FileStatusBuilder::new_with_line_limits(0, 0);
+		builder.add_file_stat(FileStatus::new(
+			path,
+			FileMode::Normal,
+			false,
+			path,
+			FileMode::Normal,
+			false,
+			Status::Modified,
+		));
+		builder.add_delta(delta);
+		builder.build().remove(0)
+	}
+
+	fn create_delta(old_line: &str, new_line: &str) -> Delta {
+		let mut delta = Delta::new("@@ -1,1 +1,1 @@", 1, 1, 1, 1);
+		delta.add_line(DiffLine::new(Origin::Deletion, old_line, Some(1), None, false));
+		delta.add_line(DiffLine::new(Origin::Addition, new_line, None, Some(1), false));
+		delta
+	}
+
+	#[test]
+	fn new_combined_single_diff() {
+		let diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build()).build();
+		assert!(CommitDiff::new_combined(&[diff]).is_none());
+	}
+
+	#[test]
+	fn new_combined_file_not_changed_from_all_parents() {
+		let diffs = [
+			CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+				.file_statuses(vec![create_file_status("a", create_delta("a", "b"))])
+				.build(),
+			CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build()).build(),
+		];
+		let combined = CommitDiff::new_combined(&diffs).unwrap();
+		assert!(combined.file_statuses().is_empty());
+		assert_eq!(combined.number_files_changed(), 0);
+	}
+
+	#[test]
+	fn new_combined_line_matches_a_parent() {
+		let diffs = [
+			CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+				.file_statuses(vec![create_file_status("a", create_delta("a", "b"))])
+				.build(),
+			CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+				.file_statuses(vec![create_file_status("a", {
+					let mut delta = Delta::new("@@ -2,1 +2,1 @@", 2, 2, 1, 1);
+					delta.add_line(DiffLine::new(Origin::Deletion, "c", Some(2), None, false));
+					delta.add_line(DiffLine::new(Origin::Addition, "d", None, Some(2), false));
+					delta
+				})])
+				.build(),
+		];
+		let combined = CommitDiff::new_combined(&diffs).unwrap();
+		assert!(combined.file_statuses().is_empty());
+	}
+
+	#[test]
+	fn new_combined_line_differs_from_all_parents() {
+		let diffs = [
+			CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+				.parent(CommitBuilder::new("ABCDEF0123456789").build())
+				.file_statuses(vec![create_file_status("a", create_delta("a", "b"))])
+				.build(),
+			CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+				.parent(CommitBuilder::new("FEDCBA9876543210").build())
+				.file_statuses(vec![create_file_status("a", create_delta("c", "b"))])
+				.build(),
+		];
+		let combined = CommitDiff::new_combined(&diffs).unwrap();
+		assert!(combined.parent().is_none());
+		assert_eq!(combined.commit(), &CommitBuilder::new("0123456789ABCDEF").build());
+		assert_eq!(combined.file_statuses()[0].deltas()[0].lines()[0].line(), "a");
+		assert_eq!(combined.number_files_changed(), 1);
+		assert_eq!(combined.number_insertions(), 1);
+		assert_eq!(combined.number_deletions(), 1);
+	}
+
+	#[test]
+	fn new_combined_collapsed_file() {
+		let mut collapsed_status = create_file_status("a", create_delta("c", "b"));
+		collapsed_status.collapse(CollapseReason::TooLarge);
+		let diffs = [
+			CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+				.file_statuses(vec![create_file_status("a", create_delta("a", "b"))])
+				.build(),
+			CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+				.file_statuses(vec![collapsed_status])
+				.build(),
+		];
+		let combined = CommitDiff::new_combined(&diffs).unwrap();
+		assert_eq!(
+			combined.file_statuses()[0].collapse_reason(),
+			Some(CollapseReason::TooLarge)
+		);
+		assert_eq!(combined.file_statuses()[0].hidden_lines(), 2);
+		assert!(combined.file_statuses()[0].deltas().is_empty());
+		assert_eq!(combined.number_files_changed(), 1);
+	}
+
+	#[test]
+	fn replace_file_status() {
+		let mut diff = CommitDiffBuilder::new(CommitBuilder::new("0123456789ABCDEF").build())
+			.file_statuses(vec![
+				create_file_status("a", create_delta("a", "b")),
+				create_file_status("b", create_delta("a", "b")),
+			])
+			.build();
+		diff.replace_file_status(create_file_status("b", create_delta("c", "d")));
+		assert_eq!(diff.file_statuses()[0].deltas()[0].lines()[0].line(), "a");
+		assert_eq!(diff.file_statuses()[1].deltas()[0].lines()[0].line(), "c");
+	}
 }
@@ -1,10 +1,14 @@
-use std::{path::PathBuf, sync::Arc};
+use std::{
+	path::{Path, PathBuf},
+	sync::Arc,
+};
 
-use git2::{DiffFindOptions, DiffOptions, Oid, Repository};
+use git2::{AttrCheckFlags, AttrValue, DiffFindOptions, DiffOptions, Oid, Repository};
 use lazy_static::lazy_static;
 use parking_lot::{Mutex, MutexGuard};
 
 use crate::{
+	collapse_reason::CollapseReason,
 	commit::Commit,
 	commit_diff::CommitDiff,
 	commit_diff_loader_options::CommitDiffLoaderOptions,
@@ -31,33 +35,114 @@ impl<'options> CommitDiffLoader<'options> {
 	}
 
 	pub(crate) fn load_from_hash(&self, oid: Oid) -> Result<Vec<CommitDiff>, git2::Error> {
+		self.load_from_hash_with_progress(oid, &mut |_, _| true)
+	}
+
+	// The progress function is called with the index of the diff, and each file status once it is fully loaded.
+	// Loading is aborted, with an error, when the progress function returns false.
+	pub(crate) fn load_from_hash_with_progress(
+		&self,
+		oid: Oid,
+		progress: &mut dyn FnMut(usize, &FileStatus) -> bool,
+	) -> Result<Vec<CommitDiff>, git2::Error> {
 		let repo = self.repo.lock();
 		let commit = repo.find_commit(oid)?;
 		let no_parents = commit.parent_ids().count() == 0;
 
 		// some commits do not have parents, and can't have file stats
 		let diffs = if no_parents {
-			vec![self.load_diff(&repo, None, &commit)?]
+			vec![self.load_diff(&repo, None, &commit, &[], &mut |status| progress(0, status))?]
 		}
 		else {
 			//
 			let mut diffs = vec![];
-			for parent in commit.parents() {
-				diffs.push(self.load_diff(&repo, Some(&parent), &commit)?);
+			for (index, parent) in commit.parents().enumerate() {
+				diffs.push(self.load_diff(&repo, Some(&parent), &commit, &[], &mut |status| {
+					progress(index, status)
+				})?);
 			}
 			diffs
 		};
 		Ok(diffs)
 	}
 
+	// Load the diff from the first parent of the first commit to the last commit, which is the combined change of the
+	// commits from the first commit to the last commit. The progress function is called with each file status once it
+	// is fully loaded, and loading is aborted, with an error, when the progress function returns false.
+	pub(crate) fn load_range_from_hashes_with_progress(
+		&self,
+		first_oid: Oid,
+		last_oid: Oid,
+		progress: &mut dyn FnMut(&FileStatus) -> bool,
+	) -> Result<CommitDiff, git2::Error> {
+		let repo = self.repo.lock();
+		let first_commit = repo.find_commit(first_oid)?;
+		let last_commit = repo.find_commit(last_oid)?;
+		// the first commit may be a root commit, in which case the range starts from an empty tree
+		let parent = first_commit.parents().next();
+		self.load_diff(&repo, parent.as_ref(), &last_commit, &[], progress)
+	}
+
+	// Load the change of a single file, the paths are used to limit the diff, and the source path is needed to detect
+	// renames and copies.
+	pub(crate) fn load_file_from_hash(
+		&self,
+		oid: Oid,
+		parent_oid: Option<Oid>,
+		source_path: &Path,
+		destination_path: &Path,
+	) -> Result<Option<FileStatus>, git2::Error> {
+		let repo = self.repo.lock();
+		let commit = repo.find_commit(oid)?;
+		let parent = parent_oid.map(|parent_id| repo.find_commit(parent_id)).transpose()?;
+		let diff = self.load_diff(
+			&repo,
+			parent.as_ref(),
+			&commit,
+			&[source_path, destination_path],
+			&mut |_| true,
+		)?;
+		Ok(diff
+			.file_statuses
+			.into_iter()
+			.find(|status| status.destination_path() == destination_path))
+	}
+
+	// binary files have no lines to show, and generated files are rarely useful to review
+	fn get_collapse_reason(&self, repo: &Repository, diff_delta: &git2::DiffDelta<'_>) -> Option<CollapseReason> {
+		let path = diff_delta.new_file().path().or_else(|| diff_delta.old_file().path())?;
+		let get_attr =
+			|name: &str| AttrValue::from_string(repo.get_attr(path, name, AttrCheckFlags::default()).ok().flatten());
+
+		if diff_delta.old_file().is_binary()
+			|| diff_delta.new_file().is_binary()
+			|| get_attr("diff") == AttrValue::False
+		{
+			return Some(CollapseReason::Binary);
+		}
+		if self.config.collapse_generated
+			&& matches!(
+				get_attr("linguist-generated"),
+				AttrValue::True | AttrValue::String("true")
+			) {
+			return Some(CollapseReason::Generated);
+		}
+		None
+	}
+
 	#[allow(clippy::as_conversions, clippy::unwrap_in_result)]
 	fn load_diff(
 		&self,
 		repo: &MutexGuard<'_, Repository>,
 		parent: Option<&git2::Commit<'_>>,
 		commit: &git2::Commit<'_>,
+		pathspecs: &[&Path],
+		progress: &mut dyn FnMut(&FileStatus) -> bool,
 	) -> Result<CommitDiff, git2::Error> {
 		let mut diff_options = DiffOptions::new();
+		for pathspec in pathspecs {
+			_ = diff_options.pathspec(*pathspec);
+		}
 		// include_unmodified added to find copies from unmodified files
 		_ = diff_options
 			.context_lines(self.config.context_lines)
@@ -70,7 +155,8 @@ impl<'options> CommitDiffLoader<'options> {
 			.include_unmodified(self.config.copies)
 			.indent_heuristic(true)
 			.interhunk_lines(self.config.interhunk_context)
-			.minimal(true);
+			.minimal(true)
+			.disable_pathspec_match(!pathspecs.is_empty());
 
 		let mut diff_find_options = DiffFindOptions::new();
 		_ = diff_find_options
@@ -92,7 +178,11 @@ impl<'options> CommitDiffLoader<'options> {
 
 		let mut unmodified_file_count: usize = 0;
 
-		let file_stats_builder = Mutex::new(FileStatusBuilder::new());
+		let file_stats_builder = Mutex::new(FileStatusBuilder::new_with_line_limits(
+			self.config.file_line_limit as usize,
+			self.config.total_line_limit as usize,
+		));
+		let mut reported_file_stats: usize = 0;
 
 		diff.foreach(
 			&mut |diff_delta, _| {
@@ -112,7 +202,7 @@ impl<'options> CommitDiffLoader<'options> {
 				let destination_file_mode = FileMode::from(destination_file.mode());
 				let destination_file_path = destination_file.path().unwrap_or(UNKNOWN_PATH.as_path());
 
-				fsb.add_file_stat(FileStatus::new(
+				let mut file_stat = FileStatus::new(
 					source_file_path,
 					source_file_mode,
 					source_file.is_binary(),
@@ -120,9 +210,21 @@ impl<'options> CommitDiffLoader<'options> {
 					destination_file_mode,
 					destination_file.is_binary(),
 					Status::from(diff_delta.status()),
-				));
-
-				true
+				);
+				if let Some(reason) = self.get_collapse_reason(repo, &diff_delta) {
+					file_stat.collapse(reason);
+				}
+				fsb.add_file_stat(file_stat);
+
+				// starting a file completes the previous file
+				let completed_file_stats = fsb.completed_file_stats();
+				let should_continue = completed_file_stats
+					.get(reported_file_stats..)
+					.unwrap_or_default()
+					.iter()
+					.all(&mut *progress);
+				reported_file_stats = completed_file_stats.len();
+				should_continue
 			},
 			None,
 			Some(&mut |_, diff_hunk| {
@@ -135,20 +237,31 @@ impl<'options> CommitDiffLoader<'options> {
 				fsb.add_diff_line(DiffLine::from(&diff_line));
 				true
 			}),
-		)
-		.expect("diff.foreach failed. Please report this as a bug.");
+		)?; // only fails when the loading is aborted by the progress function
 
 		let stats = diff.stats()?;
 		let number_files_changed = stats.files_changed() - unmodified_file_count;
 		let number_insertions = stats.insertions();
 		let number_deletions = stats.deletions();
 
-		let fsb = file_stats_builder.into_inner();
+		let file_statuses = file_stats_builder.into_inner().build();
+		let aborted = !file_statuses
+			.get(reported_file_stats..)
+			.unwrap_or_default()
+			.iter()
+			.all(&mut *progress);
+		if aborted {
+			return Err(git2::Error::new(
+				git2::ErrorCode::User,
+				git2::ErrorClass::Callback,
+				"Loading the diff was aborted",
+			));
+		}
 
 		Ok(CommitDiff {
 			commit: Commit::from(commit),
 			parent: parent.map(Commit::from),
-			file_statuses: fsb.build(),
+			file_statuses,
 			number_files_changed,
 			number_insertions,
 			number_deletions,
@@ -162,6 +275,7 @@ mod tests {
 		fs::{remove_file, File},
 		io::Write,
 		os::unix::fs::symlink,
+		path::Path,
 	};
 
 	use super::*;
@@ -302,7 +416,7 @@ mod tests {
 	fn create_commit(repository: &crate::Repository) {
 		let sig = git2::Signature::new("name", "name@example.com", &git2::Time::new(1_609_459_200, 0)).unwrap();
 		repository
-			.create_commit_on_index("refs/heads/main", &sig, &sig, "title")
+			.create_commit_on_index("refs/heads/main", &sig, &sig, "title", None)
 			.unwrap();
 	}
 
@@ -624,4 +738,133 @@ mod tests {
 			assert_commit_diff!(&diff, "a (n,b)", "Status Modified");
 		});
 	}
+
+	#[test]
+	fn load_from_hash_merge_commit() {
+		with_temp_repository(|repo| {
+			write_normal_file(&repo, "a", &["a", "b", "c", "d", "e"]);
+			create_commit(&repo);
+			let base_id = repo.commit_id_from_ref("refs/heads/main").unwrap();
+			write_normal_file(&repo, "a", &["A", "b", "c", "d", "e"]);
+			create_commit(&repo);
+			let main_id = repo.commit_id_from_ref("refs/heads/main").unwrap();
+			write_normal_file(&repo, "a", &["X", "b", "c", "d", "E"]);
+			let merge_id = {
+				let git2_repository = repo.repository();
+				let git2_lock = git2_repository.lock();
+				let sig = git2::Signature::new("name", "name@example.com", &git2::Time::new(1_609_459_200, 0)).unwrap();
+				let mut index = git2_lock.index().unwrap();
+				let merged_tree = git2_lock.find_tree(index.write_tree().unwrap()).unwrap();
+				let side_tree = {
+					let mut side_index = git2::Index::new().unwrap();
+					side_index.read_tree(&merged_tree).unwrap();
+					let mut entry = side_index.get_path(Path::new("a"), 0).unwrap();
+					entry.id = git2_lock.blob(b"a\nb\nc\nd\nE\n").unwrap();
+					side_index.add(&entry).unwrap();
+					git2_lock.find_tree(side_index.write_tree_to(&git2_lock).unwrap()).unwrap()
+				};
+				let base = git2_lock.find_commit(base_id).unwrap();
+				let side_id = git2_lock.commit(None, &sig, &sig, "side", &side_tree, &[&base]).unwrap();
+				let side = git2_lock.find_commit(side_id).unwrap();
+				let main = git2_lock.find_commit(main_id).unwrap();
+				git2_lock
+					.commit(Some("HEAD"), &sig, &sig, "merge", &merged_tree, &[&main, &side])
+					.unwrap()
+			};
+
+			let options = CommitDiffLoaderOptions::new().context_lines(0);
+			let loader = CommitDiffLoader::new(repo.repository(), &options);
+			let diffs = loader.load_from_hash(merge_id).unwrap();
+			assert_eq!(diffs.len(), 2);
+			assert_eq!(diffs[0].parent().as_ref().unwrap().hash(), main_id.to_string());
+			assert_commit_diff!(
+				&diffs[0],
+				"a (n)",
+				"Status Modified",
+				"@@ -1,1 +1,1 @@",
+				"-1  | A",
+				"+  1| X",
+				"@@ -5,1 +5,1 @@ d",
+				"-5  | e",
+				"+  5| E"
+			);
+			assert_commit_diff!(
+				&diffs[1],
+				"a (n)",
+				"Status Modified",
+				"@@ -1,1 +1,1 @@",
+				"-1  | a",
+				"+  1| X"
+			);
+
+			let combined = CommitDiff::new_combined(&diffs).unwrap();
+			assert!(combined.parent().is_none());
+			assert_eq!(combined.number_files_changed(), 1);
+			assert_eq!(combined.number_insertions(), 1);
+			assert_eq!(combined.number_deletions(), 1);
+			assert_commit_diff!(&combined, "a (n)", "Status Modified", "@@ -1,1 +1,1 @@", "-1  | A", "+  1| X");
+		});
+	}
+
+	#[test]
+	fn load_from_hash_with_progress() {
+		with_temp_repository(|repo| {
+			write_normal_file(&repo, "a", &["line1"]);
+			write_normal_file(&repo, "b", &["line1", "line2"]);
+			create_commit(&repo);
+			let id = repo.commit_id_from_ref("refs/heads/main").unwrap();
+			let options = CommitDiffLoaderOptions::new();
+			let loader = CommitDiffLoader::new(repo.repository(), &options);
+			let mut progress = vec![];
+			let diffs = loader
+				.load_from_hash_with_progress(id, &mut |index, status| {
+					progress.push((index, status.clone()));
+					true
+				})
+				.unwrap();
+			assert_eq!(
+				progress,
+				diffs[0]
+					.file_statuses()
+					.iter()
+					.map(|status| (0, status.clone()))
+					.collect::<Vec<(usize, FileStatus)>>()
+			);
+			assert_eq!(progress.len(), 2);
+			assert_eq!(progress[1].1.deltas().len(), 1);
+		});
+	}
+
+	#[test]
+	fn load_from_hash_with_progress_aborted() {
+		with_temp_repository(|repo| {
+			write_normal_file(&repo, "a", &["line1"]);
+			write_normal_file(&repo, "b", &["line1"]);
+			write_normal_file(&repo, "c", &["line1"]);
+			create_commit(&repo);
+			let id = repo.commit_id_from_ref("refs/heads/main").unwrap();
+			let options = CommitDiffLoaderOptions::new();
+			let loader = CommitDiffLoader::new(repo.repository(), &options);
+			let mut progress_calls = 0;
+			let result = loader.load_from_hash_with_progress(id, &mut |_, _| {
+				progress_calls += 1;
+				false
+			});
+			assert_eq!(result.unwrap_err().code(), git2::ErrorCode::User);
+			assert_eq!(progress_calls, 1);
+		});
+	}
+
+	#[test]
+	fn load_from_hash_with_progress_aborted_on_last_file() {
+		with_temp_repository(|repo| {
+			write_normal_file(&repo, "a", &["line1"]);
+			create_commit(&repo);
+			let id = repo.commit_id_from_ref("refs/heads/main").unwrap();
+			let options = CommitDiffLoaderOptions::new();
+			let loader = CommitDiffLoader::new(repo.repository(), &options);
+			let result = loader.load_from_hash_with_progress(id, &mut |_, _| false);
+			assert_eq!(result.unwrap_err().code(), git2::ErrorCode::User);
+		});
+	}
 }
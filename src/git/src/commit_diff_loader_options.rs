@@ -2,14 +2,17 @@
 #[derive(Copy, Clone, Debug)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct CommitDiffLoaderOptions {
+	pub(crate) collapse_generated: bool,
 	pub(crate) context_lines: u32,
 	pub(crate) copies: bool,
+	pub(crate) file_line_limit: u32,
 	pub(crate) ignore_whitespace: bool,
 	pub(crate) ignore_whitespace_change: bool,
 	pub(crate) ignore_blank_lines: bool,
 	pub(crate) interhunk_context: u32,
 	pub(crate) rename_limit: u32,
 	pub(crate) renames: bool,
+	pub(crate) total_line_limit: u32,
 }
 
 impl CommitDiffLoaderOptions {
@@ -18,14 +21,17 @@ impl CommitDiffLoaderOptions {
 	#[must_use]
 	pub const fn new() -> Self {
 		Self {
+			collapse_generated: false,
 			context_lines: 0,
 			copies: false,
+			file_line_limit: 0,
 			ignore_whitespace: false,
 			ignore_whitespace_change: false,
 			ignore_blank_lines: false,
 			interhunk_context: 0,
 			rename_limit: 0,
 			renames: false,
+			total_line_limit: 0,
 		}
 	}
 
@@ -77,6 +83,24 @@ impl CommitDiffLoaderOptions {
 		self
 	}
 
+	/// Set the maximum number of lines loaded for a single file, and for all files. Files with more lines are
+	/// collapsed. A limit of zero is no limit.
+	#[inline]
+	#[must_use]
+	pub const fn line_limits(mut self, file_line_limit: u32, total_line_limit: u32) -> Self {
+		self.file_line_limit = file_line_limit;
+		self.total_line_limit = total_line_limit;
+		self
+	}
+
+	/// Set if to collapse files marked as generated with the `linguist-generated` attribute.
+	#[inline]
+	#[must_use]
+	pub const fn collapse_generated(mut self, collapse_generated: bool) -> Self {
+		self.collapse_generated = collapse_generated;
+		self
+	}
+
 	/// Set if to detect renames, as well as the file rename limit.
 	#[inline]
 	#[must_use]
@@ -138,4 +162,20 @@ mod tests {
 		assert!(load_commit_diff_options.renames);
 		assert_eq!(load_commit_diff_options.rename_limit, 42);
 	}
+
+	#[test]
+	fn line_limits() {
+		let load_commit_diff_options = CommitDiffLoaderOptions::new().line_limits(42, 142);
+		assert_eq!(load_commit_diff_options.file_line_limit, 42);
+		assert_eq!(load_commit_diff_options.total_line_limit, 142);
+	}
+
+	#[test]
+	fn collapse_generated() {
+		assert!(
+			CommitDiffLoaderOptions::new()
+				.collapse_generated(true)
+				.collapse_generated
+		);
+	}
 }
@@ -61,6 +61,27 @@ pub enum GitError {
 		#[source]
 		cause: git2::Error,
 	},
+	/// The branches could not be loaded
+	#[error("Could not load branches")]
+	BranchLoad {
+		/// The internal cause of the load error.
+		#[source]
+		cause: git2::Error,
+	},
+	/// The references could not be loaded
+	#[error("Could not load references")]
+	ReferenceLoad {
+		/// The internal cause of the load error.
+		#[source]
+		cause: git2::Error,
+	},
+	/// A file could not be loaded from a commit
+	#[error("Could not load file from commit")]
+	FileLoad {
+		/// The internal cause of the load error.
+		#[source]
+		cause: git2::Error,
+	},
 }
 
 #[cfg(test)]
@@ -1,15 +1,17 @@
 use std::path::{Path, PathBuf};
 
 use super::{delta::Delta, status::Status};
-use crate::file_mode::FileMode;
+use crate::{collapse_reason::CollapseReason, file_mode::FileMode};
 
 /// Represents a file change within a Git repository
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FileStatus {
+	pub(crate) collapse_reason: Option<CollapseReason>,
 	pub(crate) deltas: Vec<Delta>,
 	pub(crate) destination_is_binary: bool,
 	pub(crate) destination_mode: FileMode,
 	pub(crate) destination_path: PathBuf,
+	pub(crate) hidden_lines: usize,
 	pub(crate) largest_new_line_number: u32,
 	pub(crate) largest_old_line_number: u32,
 	pub(crate) source_is_binary: bool,
@@ -32,10 +34,12 @@ impl FileStatus {
 		status: Status,
 	) -> Self {
 		Self {
+			collapse_reason: None,
 			deltas: vec![],
 			destination_is_binary,
 			destination_mode,
 			destination_path: PathBuf::from(destination_path.as_ref()),
+			hidden_lines: 0,
 			largest_new_line_number: 0,
 			largest_old_line_number: 0,
 			source_is_binary,
@@ -59,6 +63,19 @@ impl FileStatus {
 		self.deltas.push(delta);
 	}
 
+	// remove the loaded lines of the change, the lines that were loaded are counted as hidden
+	pub(crate) fn collapse(&mut self, reason: CollapseReason) {
+		self.hidden_lines += self.deltas.iter().map(|delta| delta.lines().len()).sum::<usize>();
+		self.deltas.clear();
+		self.largest_new_line_number = 0;
+		self.largest_old_line_number = 0;
+		self.collapse_reason = Some(reason);
+	}
+
+	pub(crate) fn add_hidden_lines(&mut self, hidden_lines: usize) {
+		self.hidden_lines += hidden_lines;
+	}
+
 	/// Get the status of this file change.
 	#[inline]
 	#[must_use]
@@ -115,6 +132,20 @@ impl FileStatus {
 		&self.deltas
 	}
 
+	/// Get the reason the lines of this change were not loaded, if they were not loaded.
+	#[inline]
+	#[must_use]
+	pub const fn collapse_reason(&self) -> Option<CollapseReason> {
+		self.collapse_reason
+	}
+
+	/// Get the number of changed lines that were not loaded.
+	#[inline]
+	#[must_use]
+	pub const fn hidden_lines(&self) -> usize {
+		self.hidden_lines
+	}
+
 	/// Get the line number of the last old changed line.
 	#[inline]
 	#[must_use]
@@ -135,6 +166,7 @@ mod tests {
 	use testutils::assert_empty;
 
 	use super::*;
+	use crate::{DiffLine, Origin};
 
 	fn create_file_stat() -> FileStatus {
 		FileStatus::new(
@@ -244,4 +276,27 @@ mod tests {
 		file_stat.add_delta(Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 10, 5, 5));
 		assert_eq!(file_stat.last_new_line_number(), 25);
 	}
+
+	#[test]
+	fn collapse() {
+		let mut file_stat = create_file_stat();
+		let mut delta = Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 12, 3, 4);
+		delta.add_line(DiffLine::new(Origin::Addition, "line", None, Some(12), false));
+		delta.add_line(DiffLine::new(Origin::Deletion, "line", Some(10), None, false));
+		file_stat.add_delta(delta);
+		file_stat.add_hidden_lines(3);
+		file_stat.collapse(CollapseReason::TooLarge);
+		assert_empty!(file_stat.deltas());
+		assert_eq!(file_stat.collapse_reason(), Some(CollapseReason::TooLarge));
+		assert_eq!(file_stat.hidden_lines(), 5);
+		assert_eq!(file_stat.last_old_line_number(), 0);
+		assert_eq!(file_stat.last_new_line_number(), 0);
+	}
+
+	#[test]
+	fn not_collapsed() {
+		let file_stat = create_file_stat();
+		assert_eq!(file_stat.collapse_reason(), None);
+		assert_eq!(file_stat.hidden_lines(), 0);
+	}
 }
@@ -1,18 +1,27 @@
-use crate::{Delta, DiffLine, FileStatus};
+use crate::{CollapseReason, Delta, DiffLine, FileStatus};
 
 #[derive(Debug, Clone)]
 pub(crate) struct FileStatusBuilder {
 	delta: Option<Delta>,
+	file_line_limit: usize,
+	file_lines: usize,
 	file_stat: Option<FileStatus>,
 	file_stats: Vec<FileStatus>,
+	total_line_limit: usize,
+	total_lines: usize,
 }
 
 impl FileStatusBuilder {
-	pub(crate) const fn new() -> Self {
+	// a file with more lines than either limit is collapsed, a limit of zero is no limit
+	pub(crate) const fn new_with_line_limits(file_line_limit: usize, total_line_limit: usize) -> Self {
 		Self {
 			delta: None,
+			file_line_limit,
+			file_lines: 0,
 			file_stat: None,
 			file_stats: vec![],
+			total_line_limit,
+			total_lines: 0,
 		}
 	}
 
@@ -31,23 +40,59 @@ impl FileStatusBuilder {
 		}
 	}
 
+	fn is_collapsed(&self) -> bool {
+		self.file_stat
+			.as_ref()
+			.is_some_and(|file_stat| file_stat.collapse_reason().is_some())
+	}
+
+	fn is_over_line_limit(&self) -> bool {
+		(self.file_line_limit != 0 && self.file_lines > self.file_line_limit)
+			|| (self.total_line_limit != 0 && self.total_lines > self.total_line_limit)
+	}
+
 	pub(crate) fn add_file_stat(&mut self, file_stat: FileStatus) {
 		self.close_delta();
 		self.close_file_stat();
 		self.delta = None;
+		self.file_lines = 0;
 		self.file_stat = Some(file_stat);
 	}
 
 	pub(crate) fn add_delta(&mut self, delta: Delta) {
 		self.close_delta();
-		self.delta = Some(delta);
+		self.delta = if self.is_collapsed() { None } else { Some(delta) };
 	}
 
 	pub(crate) fn add_diff_line(&mut self, diff_line: DiffLine) {
+		if self.is_collapsed() {
+			if let Some(file_stat) = self.file_stat.as_mut() {
+				file_stat.add_hidden_lines(1);
+			}
+			return;
+		}
+
 		self.delta
 			.as_mut()
 			.expect("add_delta must be called once before adding a diff line")
 			.add_line(diff_line);
+		self.file_lines += 1;
+		self.total_lines += 1;
+
+		// the lines of a file that is too large do not count towards the total, so smaller files can still be shown
+		if self.is_over_line_limit() {
+			self.close_delta();
+			self.delta = None;
+			self.total_lines -= self.file_lines;
+			if let Some(file_stat) = self.file_stat.as_mut() {
+				file_stat.collapse(CollapseReason::TooLarge);
+			}
+		}
+	}
+
+	// the file stats that no more deltas or lines can be added to
+	pub(crate) fn completed_file_stats(&self) -> &[FileStatus] {
+		self.file_stats.as_slice()
 	}
 
 	pub(crate) fn build(mut self) -> Vec<FileStatus> {
@@ -64,6 +109,25 @@ mod tests {
 	use super::*;
 	use crate::{FileMode, Origin, Status};
 
+	fn create_file_stat(path: &str) -> FileStatus {
+		FileStatus::new(
+			PathBuf::from(path).as_path(),
+			FileMode::Normal,
+			false,
+			PathBuf::from(path).as_path(),
+			FileMode::Normal,
+			false,
+			Status::Modified,
+		)
+	}
+
+	fn add_lines(file_stats_builder: &mut FileStatusBuilder, count: u32) {
+		file_stats_builder.add_delta(Delta::new("@@ -1,0 +1,1 @@", 1, 1, 0, count));
+		for line_number in 1..=count {
+			file_stats_builder.add_diff_line(DiffLine::new(Origin::Addition, "line", None, Some(line_number), false));
+		}
+	}
+
 	#[test]
 	fn build_file_stat_with_file_stat_without_delta() {
 		let file_stat_1 = FileStatus::new(
@@ -75,7 +139,7 @@ mod tests {
 			false,
 			Status::Added,
 		);
-		let mut file_stats_builder = FileStatusBuilder::new();
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 0);
 		file_stats_builder.add_file_stat(file_stat_1.clone());
 		assert_eq!(file_stats_builder.build(), vec![file_stat_1]);
 	}
@@ -92,7 +156,7 @@ mod tests {
 			Status::Added,
 		);
 		let delta_1 = Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 12, 3, 4);
-		let mut file_stats_builder = FileStatusBuilder::new();
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 0);
 		file_stats_builder.add_file_stat(file_stat_1.clone());
 		file_stats_builder.add_delta(delta_1.clone());
 		file_stat_1.add_delta(delta_1);
@@ -120,7 +184,7 @@ mod tests {
 			Status::Deleted,
 		);
 		let delta_1 = Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 12, 3, 4);
-		let mut file_stats_builder = FileStatusBuilder::new();
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 0);
 		file_stats_builder.add_file_stat(file_stat_1.clone());
 		file_stats_builder.add_file_stat(file_stat_2.clone());
 		file_stats_builder.add_delta(delta_1.clone());
@@ -149,7 +213,7 @@ mod tests {
 			Status::Deleted,
 		);
 		let delta_1 = Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 12, 3, 4);
-		let mut file_stats_builder = FileStatusBuilder::new();
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 0);
 		file_stats_builder.add_file_stat(file_stat_1.clone());
 		file_stats_builder.add_file_stat(file_stat_2.clone());
 		file_stats_builder.add_delta(delta_1.clone());
@@ -175,7 +239,7 @@ mod tests {
 		);
 		let mut delta_1 = Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 12, 3, 4);
 		let diff_line_1 = DiffLine::new(Origin::Addition, "My Line", Some(1), Some(2), false);
-		let mut file_stats_builder = FileStatusBuilder::new();
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 0);
 		file_stats_builder.add_file_stat(file_stat_1.clone());
 		file_stats_builder.add_delta(delta_1.clone());
 		file_stats_builder.add_diff_line(diff_line_1.clone());
@@ -206,7 +270,7 @@ mod tests {
 		);
 		let mut delta_1 = Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 12, 3, 4);
 		let diff_line_1 = DiffLine::new(Origin::Addition, "My Line", Some(1), Some(2), false);
-		let mut file_stats_builder = FileStatusBuilder::new();
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 0);
 		file_stats_builder.add_file_stat(file_stat_1.clone());
 		file_stats_builder.add_delta(delta_1.clone());
 		file_stats_builder.add_diff_line(diff_line_1.clone());
@@ -230,7 +294,7 @@ mod tests {
 		let mut delta_1 = Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 12, 3, 4);
 		let delta_2 = Delta::new("@ path/to/file.rs:56 @ impl Delta2 {", 11, 10, 9, 8);
 		let diff_line_1 = DiffLine::new(Origin::Addition, "My Line", Some(1), Some(2), false);
-		let mut file_stats_builder = FileStatusBuilder::new();
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 0);
 		file_stats_builder.add_file_stat(file_stat_1.clone());
 		file_stats_builder.add_delta(delta_1.clone());
 		file_stats_builder.add_diff_line(diff_line_1.clone());
@@ -244,7 +308,7 @@ mod tests {
 	#[test]
 	#[should_panic]
 	fn add_delta_without_file_stat() {
-		let mut file_stats_builder = FileStatusBuilder::new();
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 0);
 		file_stats_builder.add_delta(Delta::new("@ path/to/file.rs:56 @ impl Delta {", 10, 12, 3, 4));
 		_ = file_stats_builder.build();
 	}
@@ -252,7 +316,7 @@ mod tests {
 	#[test]
 	#[should_panic]
 	fn add_diff_line_before_delta() {
-		let mut file_stats_builder = FileStatusBuilder::new();
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 0);
 		file_stats_builder.add_file_stat(FileStatus::new(
 			PathBuf::from("from").as_path(),
 			FileMode::Normal,
@@ -265,4 +329,58 @@ mod tests {
 		file_stats_builder.add_diff_line(DiffLine::new(Origin::Addition, "My Line", Some(1), Some(2), false));
 		_ = file_stats_builder.build();
 	}
+
+	#[test]
+	fn build_file_stat_under_file_line_limit() {
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(3, 0);
+		file_stats_builder.add_file_stat(create_file_stat("a"));
+		add_lines(&mut file_stats_builder, 3);
+		let file_stats = file_stats_builder.build();
+		assert_eq!(file_stats[0].collapse_reason(), None);
+		assert_eq!(file_stats[0].deltas()[0].lines().len(), 3);
+	}
+
+	#[test]
+	fn build_file_stat_over_file_line_limit() {
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(3, 0);
+		file_stats_builder.add_file_stat(create_file_stat("a"));
+		add_lines(&mut file_stats_builder, 2);
+		add_lines(&mut file_stats_builder, 3);
+		file_stats_builder.add_file_stat(create_file_stat("b"));
+		add_lines(&mut file_stats_builder, 3);
+		let file_stats = file_stats_builder.build();
+		assert_eq!(file_stats[0].collapse_reason(), Some(CollapseReason::TooLarge));
+		assert!(file_stats[0].deltas().is_empty());
+		assert_eq!(file_stats[0].hidden_lines(), 5);
+		assert_eq!(file_stats[1].collapse_reason(), None);
+	}
+
+	#[test]
+	fn build_file_stat_over_total_line_limit() {
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 4);
+		file_stats_builder.add_file_stat(create_file_stat("a"));
+		add_lines(&mut file_stats_builder, 2);
+		file_stats_builder.add_file_stat(create_file_stat("b"));
+		add_lines(&mut file_stats_builder, 3);
+		file_stats_builder.add_file_stat(create_file_stat("c"));
+		add_lines(&mut file_stats_builder, 2);
+		let file_stats = file_stats_builder.build();
+		assert_eq!(file_stats[0].collapse_reason(), None);
+		assert_eq!(file_stats[1].collapse_reason(), Some(CollapseReason::TooLarge));
+		assert_eq!(file_stats[1].hidden_lines(), 3);
+		assert_eq!(file_stats[2].collapse_reason(), None);
+	}
+
+	#[test]
+	fn build_file_stat_collapsed_counts_hidden_lines() {
+		let mut file_stat = create_file_stat("a");
+		file_stat.collapse(CollapseReason::Generated);
+		let mut file_stats_builder = FileStatusBuilder::new_with_line_limits(0, 0);
+		file_stats_builder.add_file_stat(file_stat);
+		add_lines(&mut file_stats_builder, 4);
+		let file_stats = file_stats_builder.build();
+		assert_eq!(file_stats[0].collapse_reason(), Some(CollapseReason::Generated));
+		assert!(file_stats[0].deltas().is_empty());
+		assert_eq!(file_stats[0].hidden_lines(), 4);
+	}
 }
@@ -142,6 +142,7 @@
 //! these utilities are not tested, and often are optimized for developer experience than
 //! performance, they should only be used in test code.
 
+mod collapse_reason;
 mod commit;
 mod commit_diff;
 mod commit_diff_loader;
@@ -153,16 +154,20 @@ mod file_mode;
 mod file_status;
 mod file_status_builder;
 mod origin;
+mod rebase_state;
 mod reference;
 mod reference_kind;
 mod repository;
+mod signature_kind;
 mod status;
 pub mod testutil;
+mod trailer;
 mod user;
 
 pub use git2::{Config, ErrorCode};
 
 pub use crate::{
+	collapse_reason::CollapseReason,
 	commit::Commit,
 	commit_diff::CommitDiff,
 	commit_diff_loader_options::CommitDiffLoaderOptions,
@@ -171,9 +176,12 @@ pub use crate::{
 	file_mode::FileMode,
 	file_status::FileStatus,
 	origin::Origin,
+	rebase_state::RebaseState,
 	reference::Reference,
 	reference_kind::ReferenceKind,
 	repository::Repository,
+	signature_kind::SignatureKind,
 	status::Status,
+	trailer::Trailer,
 	user::User,
 };
@@ -0,0 +1,144 @@
+use std::{fs::read_to_string, path::Path};
+
+/// The state of an interactive rebase that is in progress, read from the `rebase-merge` directory of the repository.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RebaseState {
+	done: Vec<String>,
+	head_name: Option<String>,
+	onto: Option<String>,
+	orig_head: Option<String>,
+	rewritten: Vec<(String, String)>,
+}
+
+impl RebaseState {
+	/// Get the full name of the branch being rebased, or `None` when rebasing a detached head.
+	#[must_use]
+	#[inline]
+	pub fn head_name(&self) -> Option<&str> {
+		self.head_name.as_deref()
+	}
+
+	/// Get the hash of the commit that the rebase is onto.
+	#[must_use]
+	#[inline]
+	pub fn onto(&self) -> Option<&str> {
+		self.onto.as_deref()
+	}
+
+	/// Get the hash of the commit that was checked out when the rebase started.
+	#[must_use]
+	#[inline]
+	pub fn orig_head(&self) -> Option<&str> {
+		self.orig_head.as_deref()
+	}
+
+	/// Get the todo lines that have already been applied, in the order they were applied.
+	#[must_use]
+	#[inline]
+	pub fn done(&self) -> &[String] {
+		self.done.as_slice()
+	}
+
+	/// Get the commits that have been rewritten so far, as pairs of the original and the rewritten commit hash.
+	#[must_use]
+	#[inline]
+	pub fn rewritten(&self) -> &[(String, String)] {
+		self.rewritten.as_slice()
+	}
+
+	// a missing directory means that no rebase is in progress, while a missing file within it is treated as empty
+	pub(crate) fn load(rebase_path: &Path) -> Option<Self> {
+		if !rebase_path.is_dir() {
+			return None;
+		}
+		let read_value = |name: &str| {
+			read_to_string(rebase_path.join(name))
+				.ok()
+				.map(|contents| String::from(contents.trim()))
+				.filter(|value| !value.is_empty())
+		};
+		let read_lines = |name: &str| {
+			read_to_string(rebase_path.join(name))
+				.map(|contents| {
+					contents
+						.lines()
+						.map(str::trim)
+						.filter(|line| !line.is_empty() && !line.starts_with('#'))
+						.map(String::from)
+						.collect::<Vec<String>>()
+				})
+				.unwrap_or_default()
+		};
+
+		Some(Self {
+			done: read_lines("done"),
+			head_name: read_value("head-name").filter(|head_name| head_name.starts_with("refs/")),
+			onto: read_value("onto"),
+			orig_head: read_value("orig-head"),
+			rewritten: read_lines("rewritten-list")
+				.iter()
+				.filter_map(|line| {
+					let mut hashes = line.split_whitespace();
+					Some((String::from(hashes.next()?), String::from(hashes.next()?)))
+				})
+				.collect(),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::fs::{create_dir_all, write};
+
+	use tempfile::tempdir;
+
+	use super::*;
+
+	#[test]
+	fn load_not_rebasing() {
+		let directory = tempdir().unwrap();
+		assert_eq!(RebaseState::load(&directory.path().join("rebase-merge")), None);
+	}
+
+	#[test]
+	fn load_empty() {
+		let directory = tempdir().unwrap();
+		let state = RebaseState::load(directory.path()).unwrap();
+		assert_eq!(state.head_name(), None);
+		assert_eq!(state.onto(), None);
+		assert_eq!(state.orig_head(), None);
+		assert!(state.done().is_empty());
+		assert!(state.rewritten().is_empty());
+	}
+
+	#[test]
+	fn load() {
+		let directory = tempdir().unwrap();
+		let rebase_path = directory.path().join("rebase-merge");
+		create_dir_all(&rebase_path).unwrap();
+		write(rebase_path.join("head-name"), "refs/heads/feature\n").unwrap();
+		write(rebase_path.join("onto"), "aaa\n").unwrap();
+		write(rebase_path.join("orig-head"), "bbb\n").unwrap();
+		write(rebase_path.join("done"), "pick ccc c1\n# comment\n\nfixup ddd c2\n").unwrap();
+		write(rebase_path.join("rewritten-list"), "ccc eee\nddd fff extra\ninvalid\n").unwrap();
+		let state = RebaseState::load(&rebase_path).unwrap();
+		assert_eq!(state.head_name(), Some("refs/heads/feature"));
+		assert_eq!(state.onto(), Some("aaa"));
+		assert_eq!(state.orig_head(), Some("bbb"));
+		assert_eq!(state.done(), &[
+			String::from("pick ccc c1"),
+			String::from("fixup ddd c2")
+		]);
+		assert_eq!(state.rewritten(), &[
+			(String::from("ccc"), String::from("eee")),
+			(String::from("ddd"), String::from("fff"))
+		]);
+	}
+
+	#[test]
+	fn load_detached_head() {
+		let directory = tempdir().unwrap();
+		write(directory.path().join("head-name"), "detached HEAD\n").unwrap();
+		assert_eq!(RebaseState::load(directory.path()).unwrap().head_name(), None);
+	}
+}
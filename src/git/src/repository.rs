@@ -1,4 +1,5 @@
 use std::{
+	collections::HashMap,
 	path::{Path, PathBuf},
 	sync::Arc,
 };
@@ -13,7 +14,10 @@ use crate::{
 	CommitDiff,
 	CommitDiffLoaderOptions,
 	Config,
+	FileStatus,
+	RebaseState,
 	Reference,
+	ReferenceKind,
 };
 
 /// A light cloneable, simple wrapper around the `git2::Repository` struct
@@ -71,12 +75,17 @@ impl Repository {
 			.map_err(|e| GitError::ConfigLoad { cause: e })
 	}
 
-	/// Load a diff for a commit hash
+	/// Load the diffs for a commit hash, one against each parent of the commit, in parent order. A commit
+	/// without any parents will result in a single diff.
 	///
 	/// # Errors
 	/// Will result in an error if the commit cannot be loaded.
 	#[inline]
-	pub fn load_commit_diff(&self, hash: &str, config: &CommitDiffLoaderOptions) -> Result<CommitDiff, GitError> {
+	pub fn load_commit_diffs(
+		&self,
+		hash: &str,
+		config: &CommitDiffLoaderOptions,
+	) -> Result<Vec<CommitDiff>, GitError> {
 		let oid = self
 			.repository
 			.lock()
@@ -85,11 +94,189 @@ impl Repository {
 			.id();
 		let diff_loader_repository = Arc::clone(&self.repository);
 		let loader = CommitDiffLoader::new(diff_loader_repository, config);
-		// TODO this is ugly because it assumes one parent
-		Ok(loader
+		loader
 			.load_from_hash(oid)
+			.map_err(|e| GitError::CommitLoad { cause: e })
+	}
+
+	/// Load the diffs for a commit hash, the same as `load_commit_diffs`, calling `progress` with the index of the
+	/// diff and each file status as soon as the file status is loaded. Returning false from `progress` stops the
+	/// loading.
+	///
+	/// # Errors
+	/// Will result in an error if the commit cannot be loaded, or if the loading was stopped.
+	#[inline]
+	pub fn load_commit_diffs_with_progress<F>(
+		&self,
+		hash: &str,
+		config: &CommitDiffLoaderOptions,
+		mut progress: F,
+	) -> Result<Vec<CommitDiff>, GitError>
+	where
+		F: FnMut(usize, &FileStatus) -> bool,
+	{
+		let oid = self
+			.repository
+			.lock()
+			.revparse_single(hash)
 			.map_err(|e| GitError::CommitLoad { cause: e })?
-			.remove(0))
+			.id();
+		let diff_loader_repository = Arc::clone(&self.repository);
+		let loader = CommitDiffLoader::new(diff_loader_repository, config);
+		loader
+			.load_from_hash_with_progress(oid, &mut progress)
+			.map_err(|e| GitError::CommitLoad { cause: e })
+	}
+
+	/// Load the diff of a range of commits, from the first parent of the commit with `first_hash`, to the commit with
+	/// `last_hash`. This is the combined change of every commit in the range, with the commit of the diff being the
+	/// last commit, and the parent being the parent of the first commit, or none when the first commit has no parents.
+	///
+	/// # Errors
+	/// Will result in an error if either commit cannot be loaded.
+	#[inline]
+	pub fn load_range_diff(
+		&self,
+		first_hash: &str,
+		last_hash: &str,
+		config: &CommitDiffLoaderOptions,
+	) -> Result<CommitDiff, GitError> {
+		self.load_range_diff_with_progress(first_hash, last_hash, config, |_| true)
+	}
+
+	/// Load the diff of a range of commits, the same as `load_range_diff`, calling `progress` with each file status as
+	/// soon as the file status is loaded. Returning false from `progress` stops the loading.
+	///
+	/// # Errors
+	/// Will result in an error if either commit cannot be loaded, or if the loading was stopped.
+	#[inline]
+	pub fn load_range_diff_with_progress<F>(
+		&self,
+		first_hash: &str,
+		last_hash: &str,
+		config: &CommitDiffLoaderOptions,
+		mut progress: F,
+	) -> Result<CommitDiff, GitError>
+	where
+		F: FnMut(&FileStatus) -> bool,
+	{
+		let (first_oid, last_oid) = {
+			let repo = self.repository.lock();
+			let first_oid = repo
+				.revparse_single(first_hash)
+				.map_err(|e| GitError::CommitLoad { cause: e })?
+				.id();
+			let last_oid = repo
+				.revparse_single(last_hash)
+				.map_err(|e| GitError::CommitLoad { cause: e })?
+				.id();
+			(first_oid, last_oid)
+		};
+		let loader = CommitDiffLoader::new(Arc::clone(&self.repository), config);
+		loader
+			.load_range_from_hashes_with_progress(first_oid, last_oid, &mut progress)
+			.map_err(|e| GitError::CommitLoad { cause: e })
+	}
+
+	/// Load a commit by a commit hash, or any other revision. The reference of the loaded commit is the first local
+	/// branch that points at the commit, if any.
+	///
+	/// # Errors
+	/// Will result in an error if the revision cannot be found or does not resolve to a commit.
+	#[inline]
+	pub fn load_commit(&self, revision: &str) -> Result<Commit, GitError> {
+		let repo = self.repository.lock();
+		let branch_map = local_branch_map(&repo);
+		load_commit_with_branches(&repo, revision, &branch_map)
+	}
+
+	/// Load the commits of many revisions, as with `load_commit`, grouped by revision. The local branches are only read
+	/// once for all the revisions. Revisions that cannot be loaded map to `None`.
+	#[must_use]
+	#[inline]
+	pub fn load_commits(
+		&self,
+		revisions: impl IntoIterator<Item = impl AsRef<str>>,
+	) -> HashMap<String, Option<Commit>> {
+		let repo = self.repository.lock();
+		let branch_map = local_branch_map(&repo);
+		revisions
+			.into_iter()
+			.map(|revision| {
+				let commit = load_commit_with_branches(&repo, revision.as_ref(), &branch_map).ok();
+				(String::from(revision.as_ref()), commit)
+			})
+			.collect()
+	}
+
+	/// Load the change of a single file of a commit, against the parent with `parent_hash`, or against nothing for
+	/// a commit without parents. The lines of the file are loaded regardless of the line limits and collapsing of
+	/// generated files in `config`, for loading a file that was collapsed by `load_commit_diffs`.
+	///
+	/// # Errors
+	/// Will result in an error if the commit cannot be loaded, or if the file was not changed in the commit.
+	#[inline]
+	pub fn load_file_diff(
+		&self,
+		hash: &str,
+		parent_hash: Option<&str>,
+		file_status: &FileStatus,
+		config: &CommitDiffLoaderOptions,
+	) -> Result<FileStatus, GitError> {
+		let (oid, parent_oid) = {
+			let repo = self.repository.lock();
+			let oid = repo
+				.revparse_single(hash)
+				.map_err(|e| GitError::CommitLoad { cause: e })?
+				.id();
+			let parent_oid = parent_hash
+				.map(|parent| repo.revparse_single(parent).map(|object| object.id()))
+				.transpose()
+				.map_err(|e| GitError::CommitLoad { cause: e })?;
+			(oid, parent_oid)
+		};
+		let options = config.line_limits(0, 0).collapse_generated(false);
+		let loader = CommitDiffLoader::new(Arc::clone(&self.repository), &options);
+		loader
+			.load_file_from_hash(
+				oid,
+				parent_oid,
+				file_status.source_path(),
+				file_status.destination_path(),
+			)
+			.and_then(|status| {
+				status.ok_or_else(|| {
+					git2::Error::new(
+						git2::ErrorCode::NotFound,
+						git2::ErrorClass::Tree,
+						format!(
+							"the path '{}' was not changed in the commit",
+							file_status.destination_path().display()
+						),
+					)
+				})
+			})
+			.map_err(|e| GitError::FileLoad { cause: e })
+	}
+
+	/// Load the contents of the file at `path` from the tree of the commit for a commit hash.
+	///
+	/// # Errors
+	/// Will result in an error if the commit cannot be loaded, or if the file does not exist in the commit.
+	#[inline]
+	pub fn load_file_from_commit(&self, hash: &str, path: &Path) -> Result<Vec<u8>, GitError> {
+		let repo = self.repository.lock();
+		let commit = repo
+			.revparse_single(hash)
+			.and_then(|object| object.peel_to_commit())
+			.map_err(|e| GitError::CommitLoad { cause: e })?;
+		let blob = commit
+			.tree()
+			.and_then(|tree| tree.get_path(path))
+			.and_then(|entry| entry.to_object(&repo))
+			.and_then(|object| object.peel_to_blob())
+			.map_err(|e| GitError::FileLoad { cause: e })?;
+		Ok(Vec::from(blob.content()))
 	}
 
 	/// Find a reference by the reference name.
@@ -118,6 +305,95 @@ impl Repository {
 		Commit::try_from(&git2_reference)
 	}
 
+	/// Find all local branches.
+	///
+	/// # Errors
+	/// Will result in an error if the branches cannot be loaded.
+	#[inline]
+	pub fn find_local_branches(&self) -> Result<Vec<Reference>, GitError> {
+		let repo = self.repository.lock();
+		let branches = repo
+			.branches(Some(git2::BranchType::Local))
+			.map_err(|e| GitError::BranchLoad { cause: e })?;
+		let mut references = vec![];
+		for result in branches {
+			let (branch, _) = result.map_err(|e| GitError::BranchLoad { cause: e })?;
+			if let Some(name) = branch.get().name() {
+				let git2_reference = repo
+					.find_reference(name)
+					.map_err(|e| GitError::ReferenceNotFound { cause: e })?;
+				references.push(Reference::from(&git2_reference));
+			}
+		}
+		Ok(references)
+	}
+
+	/// Load the branch, remote and tag references of the repository, grouped by the hash of the commit that each
+	/// reference points at. References within each group are ordered by kind, and then by name. References that do not
+	/// resolve to a commit are ignored.
+	///
+	/// # Errors
+	/// Will result in an error if the references cannot be loaded.
+	#[inline]
+	pub fn load_reference_map(&self) -> Result<HashMap<String, Vec<Reference>>, GitError> {
+		let repo = self.repository.lock();
+		let git2_references = repo.references().map_err(|e| GitError::ReferenceLoad { cause: e })?;
+		let mut references = vec![];
+		for result in git2_references {
+			let git2_reference = result.map_err(|e| GitError::ReferenceLoad { cause: e })?;
+			if !(git2_reference.is_branch() || git2_reference.is_remote() || git2_reference.is_tag()) {
+				continue;
+			}
+			if let Ok(commit) = git2_reference.peel_to_commit() {
+				references.push((format!("{}", commit.id()), Reference::from(&git2_reference)));
+			}
+		}
+		references.sort_by(|a, b| {
+			reference_kind_order(a.1.kind())
+				.cmp(&reference_kind_order(b.1.kind()))
+				.then_with(|| a.1.name().cmp(b.1.name()))
+		});
+		let mut reference_map: HashMap<String, Vec<Reference>> = HashMap::new();
+		for (hash, reference) in references {
+			reference_map.entry(hash).or_default().push(reference);
+		}
+		Ok(reference_map)
+	}
+
+	/// Load the state of the interactive rebase that is in progress, or `None` when not rebasing.
+	#[must_use]
+	#[inline]
+	pub fn load_rebase_state(&self) -> Option<RebaseState> {
+		RebaseState::load(&self.repo_path().join("rebase-merge"))
+	}
+
+	/// Get the full name of the branch being rebased, or the checked out branch when not rebasing.
+	#[must_use]
+	#[inline]
+	pub fn head_branch_name(&self) -> Option<String> {
+		if let Some(rebase_state) = self.load_rebase_state() {
+			return rebase_state
+				.head_name()
+				.filter(|head_name| head_name.starts_with("refs/heads/"))
+				.map(String::from);
+		}
+		let repo = self.repository.lock();
+		let head = repo.head().ok()?;
+		if head.is_branch() {
+			head.name().map(String::from)
+		}
+		else {
+			None
+		}
+	}
+
+	/// Check if a revision, such as a reference name or a commit hash, can be resolved.
+	#[must_use]
+	#[inline]
+	pub fn has_revision(&self, revision: &str) -> bool {
+		self.repository.lock().revparse_single(revision).is_ok()
+	}
+
 	pub(crate) fn repo_path(&self) -> PathBuf {
 		self.repository.lock().path().to_path_buf()
 	}
@@ -135,6 +411,25 @@ impl Repository {
 		Ok(commit.id())
 	}
 
+	pub(crate) fn create_branch(&self, name: &str, reference: &str) -> Result<(), git2::Error> {
+		let repo = self.repository.lock();
+		let commit = repo.find_reference(reference)?.peel_to_commit()?;
+		repo.branch(name, &commit, true).map(drop)
+	}
+
+	pub(crate) fn create_reference(&self, name: &str, reference: &str) -> Result<(), git2::Error> {
+		let repo = self.repository.lock();
+		let commit = repo.find_reference(reference)?.peel_to_commit()?;
+		repo.reference(name, commit.id(), true, "create reference").map(drop)
+	}
+
+	pub(crate) fn create_tag(&self, name: &str, reference: &str) -> Result<(), git2::Error> {
+		let repo = self.repository.lock();
+		let object = repo.find_reference(reference)?.peel(git2::ObjectType::Commit)?;
+		let signature = Signature::now("name", "name@example.com")?;
+		repo.tag(name, &object, &signature, name, true).map(drop)
+	}
+
 	pub(crate) fn add_path_to_index(&self, path: &Path) -> Result<(), git2::Error> {
 		let repo = self.repository.lock();
 		let mut index = repo.index()?;
@@ -153,11 +448,19 @@ impl Repository {
 		author: &Signature<'_>,
 		committer: &Signature<'_>,
 		message: &str,
+		signature: Option<&str>,
 	) -> Result<(), git2::Error> {
 		let repo = self.repository.lock();
 		let tree = repo.find_tree(repo.index()?.write_tree()?)?;
 		let head = repo.find_reference(reference)?.peel_to_commit()?;
-		_ = repo.commit(Some("HEAD"), author, committer, message, &tree, &[&head])?;
+		if let Some(sig) = signature {
+			let buffer = repo.commit_create_buffer(author, committer, message, &tree, &[&head])?;
+			let oid = repo.commit_signed(buffer.as_str().unwrap_or(""), sig, None)?;
+			repo.head()?.resolve()?.set_target(oid, "commit (signed)").map(drop)?;
+		}
+		else {
+			_ = repo.commit(Some("HEAD"), author, committer, message, &tree, &[&head])?;
+		}
 		Ok(())
 	}
 
@@ -185,17 +488,71 @@ impl std::fmt::Debug for Repository {
 	}
 }
 
+// the target of each local branch, mapped to the first local branch that points at it
+fn local_branch_map(repo: &git2::Repository) -> HashMap<Oid, git2::Reference<'_>> {
+	let mut branch_map = HashMap::new();
+	if let Ok(branches) = repo.branches(Some(git2::BranchType::Local)) {
+		for (branch, _) in branches.filter_map(Result::ok) {
+			let reference = branch.into_reference();
+			if let Some(target) = reference.target() {
+				_ = branch_map.entry(target).or_insert(reference);
+			}
+		}
+	}
+	branch_map
+}
+
+fn load_commit_with_branches(
+	repo: &git2::Repository,
+	revision: &str,
+	branch_map: &HashMap<Oid, git2::Reference<'_>>,
+) -> Result<Commit, GitError> {
+	let commit = repo
+		.revparse_single(revision)
+		.and_then(|object| object.peel_to_commit())
+		.map_err(|e| GitError::CommitLoad { cause: e })?;
+	Ok(Commit::new(&commit, branch_map.get(&commit.id())))
+}
+
+const fn reference_kind_order(kind: ReferenceKind) -> u8 {
+	match kind {
+		ReferenceKind::Branch => 0,
+		ReferenceKind::Remote => 1,
+		ReferenceKind::Tag => 2,
+		ReferenceKind::Note | ReferenceKind::Other => 3,
+	}
+}
+
 // Paths in Windows makes these tests difficult, so disable
 #[cfg(all(unix, test))]
 mod tests {
-	use std::env::set_var;
+	use std::{
+		env::set_var,
+		fs::{create_dir_all, write},
+	};
 
 	use claims::assert_ok;
 	use git2::{ErrorClass, ErrorCode};
 	use testutils::assert_err_eq;
 
 	use super::*;
-	use crate::testutil::{commit_id_from_ref, create_commit, with_temp_bare_repository, with_temp_repository};
+	use crate::{
+		testutil::{
+			add_path_to_index,
+			commit_id_from_ref,
+			create_branch,
+			create_commit,
+			create_reference,
+			create_tag,
+			head_id,
+			with_temp_bare_repository,
+			with_temp_repository,
+			FileStatusBuilder,
+			JAN_2021_EPOCH,
+		},
+		CollapseReason,
+		Status,
+	};
 
 	#[test]
 	#[serial_test::serial]
@@ -265,16 +622,56 @@ mod tests {
 	}
 
 	#[test]
-	fn load_commit_diff() {
+	fn load_commit_diffs() {
 		with_temp_repository(|repository| {
 			create_commit(&repository, None);
 			let id = commit_id_from_ref(&repository, "refs/heads/main");
-			assert_ok!(repository.load_commit_diff(id.to_string().as_str(), &CommitDiffLoaderOptions::new()));
+			let diffs = repository
+				.load_commit_diffs(id.to_string().as_str(), &CommitDiffLoaderOptions::new())
+				.unwrap();
+			assert_eq!(diffs.len(), 1);
 		});
 	}
 
+	fn add_file(repository: &Repository, name: &str) {
+		let root = repository.repo_path().parent().unwrap().to_path_buf();
+		write(root.join(name), "contents\n").unwrap();
+		add_path_to_index(repository, Path::new(name));
+	}
+
 	#[test]
-	fn load_commit_diff_with_non_commit() {
+	fn load_commit_diffs_with_progress() {
+		with_temp_repository(|repository| {
+			add_file(&repository, "a");
+			add_file(&repository, "b");
+			create_commit(&repository, None);
+			let id = commit_id_from_ref(&repository, "refs/heads/main");
+			let mut progress_calls = 0;
+			let diffs = repository
+				.load_commit_diffs_with_progress(id.to_string().as_str(), &CommitDiffLoaderOptions::new(), |_, _| {
+					progress_calls += 1;
+					true
+				})
+				.unwrap();
+			assert_eq!(progress_calls, 2);
+			assert_eq!(diffs[0].file_statuses().len(), 2);
+		});
+	}
+
+	#[test]
+	fn load_commit_diffs_with_progress_stopped() {
+		with_temp_repository(|repository| {
+			add_file(&repository, "a");
+			create_commit(&repository, None);
+			let id = commit_id_from_ref(&repository, "refs/heads/main");
+			_ = repository
+				.load_commit_diffs_with_progress(id.to_string().as_str(), &CommitDiffLoaderOptions::new(), |_, _| false)
+				.unwrap_err();
+		});
+	}
+
+	#[test]
+	fn load_commit_diffs_with_non_commit() {
 		with_temp_repository(|repository| {
 			let blob_ref = {
 				let git2_repository = repository.repository();
@@ -285,7 +682,7 @@ mod tests {
 			};
 
 			assert_err_eq!(
-				repository.load_commit_diff(blob_ref.as_str(), &CommitDiffLoaderOptions::new()),
+				repository.load_commit_diffs(blob_ref.as_str(), &CommitDiffLoaderOptions::new()),
 				GitError::CommitLoad {
 					cause: git2::Error::new(
 						ErrorCode::NotFound,
@@ -297,6 +694,297 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn load_commit() {
+		with_temp_repository(|repository| {
+			create_commit(&repository, None);
+			let id = commit_id_from_ref(&repository, "refs/heads/main");
+			let commit = repository.load_commit(id.to_string().as_str()).unwrap();
+			assert_eq!(commit.hash(), id.to_string());
+			assert_eq!(commit.reference().as_ref().unwrap().name(), "refs/heads/main");
+		});
+	}
+
+	#[test]
+	fn load_commit_abbreviated_hash() {
+		with_temp_repository(|repository| {
+			let id = commit_id_from_ref(&repository, "refs/heads/main").to_string();
+			assert_eq!(repository.load_commit(id.get(0..7).unwrap()).unwrap().hash(), id);
+		});
+	}
+
+	#[test]
+	fn load_commit_without_branch() {
+		with_temp_repository(|repository| {
+			let id = commit_id_from_ref(&repository, "refs/heads/main");
+			create_commit(&repository, None);
+			assert!(repository
+				.load_commit(id.to_string().as_str())
+				.unwrap()
+				.reference()
+				.is_none());
+		});
+	}
+
+	#[test]
+	fn load_commits() {
+		with_temp_repository(|repository| {
+			let first = commit_id_from_ref(&repository, "refs/heads/main").to_string();
+			create_commit(&repository, None);
+			let second = commit_id_from_ref(&repository, "refs/heads/main").to_string();
+			let commits = repository.load_commits([first.as_str(), second.as_str(), "invalid"]);
+			assert_eq!(commits.len(), 3);
+			let first_commit = commits.get(first.as_str()).unwrap().as_ref().unwrap();
+			assert_eq!(first_commit.hash(), first);
+			assert!(first_commit.reference().is_none());
+			let second_commit = commits.get(second.as_str()).unwrap().as_ref().unwrap();
+			assert_eq!(second_commit.reference().as_ref().unwrap().name(), "refs/heads/main");
+			assert!(commits.get("invalid").unwrap().is_none());
+		});
+	}
+
+	#[test]
+	fn load_commit_invalid_hash() {
+		with_temp_repository(|repository| {
+			assert!(matches!(
+				repository.load_commit("invalid"),
+				Err(GitError::CommitLoad { .. })
+			));
+		});
+	}
+
+	#[test]
+	fn load_commit_diffs_with_line_limits() {
+		with_temp_repository(|repository| {
+			let root = repository.repo_path().parent().unwrap().to_path_buf();
+			write(root.join("a.txt"), "line 1\nline 2\nline 3\n").unwrap();
+			add_path_to_index(&repository, Path::new("a.txt"));
+			add_file(&repository, "b.txt");
+			create_commit(&repository, None);
+			let id = commit_id_from_ref(&repository, "refs/heads/main");
+			let diffs = repository
+				.load_commit_diffs(
+					id.to_string().as_str(),
+					&CommitDiffLoaderOptions::new().line_limits(2, 0),
+				)
+				.unwrap();
+			let file_statuses = diffs[0].file_statuses();
+			assert_eq!(file_statuses[0].collapse_reason(), Some(CollapseReason::TooLarge));
+			assert_eq!(file_statuses[0].hidden_lines(), 3);
+			assert!(file_statuses[0].deltas().is_empty());
+			assert_eq!(file_statuses[1].collapse_reason(), None);
+			assert_eq!(diffs[0].number_insertions(), 4);
+		});
+	}
+
+	#[test]
+	fn load_commit_diffs_generated_file() {
+		with_temp_repository(|repository| {
+			let root = repository.repo_path().parent().unwrap().to_path_buf();
+			write(root.join(".gitattributes"), "a.txt linguist-generated\nb.txt -diff\n").unwrap();
+			add_file(&repository, "a.txt");
+			add_file(&repository, "b.txt");
+			create_commit(&repository, None);
+			let id = commit_id_from_ref(&repository, "refs/heads/main");
+			let diffs = repository
+				.load_commit_diffs(
+					id.to_string().as_str(),
+					&CommitDiffLoaderOptions::new().collapse_generated(true),
+				)
+				.unwrap();
+			let file_statuses = diffs[0].file_statuses();
+			assert_eq!(file_statuses[0].collapse_reason(), Some(CollapseReason::Generated));
+			assert_eq!(file_statuses[0].hidden_lines(), 1);
+			assert_eq!(file_statuses[1].collapse_reason(), Some(CollapseReason::Binary));
+		});
+	}
+
+	#[test]
+	fn load_file_diff() {
+		with_temp_repository(|repository| {
+			let root = repository.repo_path().parent().unwrap().to_path_buf();
+			write(root.join("a.txt"), "line 1\nline 2\nline 3\n").unwrap();
+			add_path_to_index(&repository, Path::new("a.txt"));
+			create_commit(&repository, None);
+			let id = commit_id_from_ref(&repository, "refs/heads/main").to_string();
+			let options = CommitDiffLoaderOptions::new().line_limits(2, 0);
+			let diffs = repository.load_commit_diffs(id.as_str(), &options).unwrap();
+			let parent = diffs[0].parent().as_ref().map(|commit| String::from(commit.hash()));
+			let file_status = repository
+				.load_file_diff(id.as_str(), parent.as_deref(), &diffs[0].file_statuses()[0], &options)
+				.unwrap();
+			assert_eq!(file_status.destination_path(), Path::new("a.txt"));
+			assert_eq!(file_status.collapse_reason(), None);
+			assert_eq!(file_status.deltas()[0].lines().len(), 3);
+		});
+	}
+
+	#[test]
+	fn load_file_diff_file_not_in_commit() {
+		with_temp_repository(|repository| {
+			create_commit(&repository, None);
+			let id = commit_id_from_ref(&repository, "refs/heads/main").to_string();
+			let file_status = FileStatusBuilder::new()
+				.source_path("missing.txt")
+				.destination_path("missing.txt")
+				.build();
+			assert_err_eq!(
+				repository.load_file_diff(id.as_str(), None, &file_status, &CommitDiffLoaderOptions::new()),
+				GitError::FileLoad {
+					cause: git2::Error::new(
+						ErrorCode::NotFound,
+						ErrorClass::Tree,
+						"the path 'missing.txt' was not changed in the commit",
+					),
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn load_file_diff_invalid_hash() {
+		with_temp_repository(|repository| {
+			assert!(matches!(
+				repository.load_file_diff(
+					"invalid",
+					None,
+					&FileStatusBuilder::new().build(),
+					&CommitDiffLoaderOptions::new()
+				),
+				Err(GitError::CommitLoad { .. })
+			));
+		});
+	}
+
+	// creates three commits, the first adding a file, the second changing the file, and the third adding another file
+	fn create_range_commits(repository: &Repository) -> Vec<String> {
+		let root = repository.repo_path().parent().unwrap().to_path_buf();
+		let mut hashes = vec![];
+		for (path, contents) in [
+			("a.txt", "line 1\n"),
+			("a.txt", "line 1\nline 2\n"),
+			("b.txt", "line 1\n"),
+		] {
+			write(root.join(path), contents).unwrap();
+			add_path_to_index(repository, Path::new(path));
+			create_commit(repository, None);
+			hashes.push(commit_id_from_ref(repository, "refs/heads/main").to_string());
+		}
+		hashes
+	}
+
+	#[test]
+	fn load_range_diff() {
+		with_temp_repository(|repository| {
+			let hashes = create_range_commits(&repository);
+			let diff = repository
+				.load_range_diff(hashes[1].as_str(), hashes[2].as_str(), &CommitDiffLoaderOptions::new())
+				.unwrap();
+			assert_eq!(diff.commit().hash(), hashes[2]);
+			assert_eq!(diff.parent().as_ref().unwrap().hash(), hashes[0]);
+			let files = diff
+				.file_statuses()
+				.iter()
+				.map(|status| (status.destination_path().to_str().unwrap(), status.status()))
+				.collect::<Vec<_>>();
+			assert_eq!(files, vec![("a.txt", Status::Modified), ("b.txt", Status::Added)]);
+		});
+	}
+
+	#[test]
+	fn load_range_diff_from_root_commit() {
+		with_temp_repository(|repository| {
+			let root_hash = commit_id_from_ref(&repository, "refs/heads/main").to_string();
+			let hashes = create_range_commits(&repository);
+			let diff = repository
+				.load_range_diff(root_hash.as_str(), hashes[2].as_str(), &CommitDiffLoaderOptions::new())
+				.unwrap();
+			assert!(diff.parent().is_none());
+			assert_eq!(diff.number_files_changed(), 2);
+		});
+	}
+
+	#[test]
+	fn load_range_diff_with_progress_aborted() {
+		with_temp_repository(|repository| {
+			let hashes = create_range_commits(&repository);
+			let mut progress_calls = 0;
+			assert!(matches!(
+				repository.load_range_diff_with_progress(
+					hashes[0].as_str(),
+					hashes[2].as_str(),
+					&CommitDiffLoaderOptions::new(),
+					|_| {
+						progress_calls += 1;
+						false
+					}
+				),
+				Err(GitError::CommitLoad { .. })
+			));
+			assert_eq!(progress_calls, 1);
+		});
+	}
+
+	#[test]
+	fn load_range_diff_invalid_hash() {
+		with_temp_repository(|repository| {
+			let hashes = create_range_commits(&repository);
+			assert!(matches!(
+				repository.load_range_diff("invalid", hashes[2].as_str(), &CommitDiffLoaderOptions::new()),
+				Err(GitError::CommitLoad { .. })
+			));
+			assert!(matches!(
+				repository.load_range_diff(hashes[0].as_str(), "invalid", &CommitDiffLoaderOptions::new()),
+				Err(GitError::CommitLoad { .. })
+			));
+		});
+	}
+
+	#[test]
+	fn load_file_from_commit() {
+		with_temp_repository(|repository| {
+			let root = repository.repo_path().parent().unwrap().to_path_buf();
+			write(root.join("a.txt"), "line 1\nline 2\n").unwrap();
+			add_path_to_index(&repository, Path::new("a.txt"));
+			create_commit(&repository, None);
+			write(root.join("a.txt"), "changed\n").unwrap();
+			let id = commit_id_from_ref(&repository, "refs/heads/main");
+			assert_eq!(
+				repository
+					.load_file_from_commit(id.to_string().as_str(), Path::new("a.txt"))
+					.unwrap(),
+				b"line 1\nline 2\n"
+			);
+		});
+	}
+
+	#[test]
+	fn load_file_from_commit_missing_file() {
+		with_temp_repository(|repository| {
+			let id = commit_id_from_ref(&repository, "refs/heads/main");
+			assert_err_eq!(
+				repository.load_file_from_commit(id.to_string().as_str(), Path::new("missing.txt")),
+				GitError::FileLoad {
+					cause: git2::Error::new(
+						ErrorCode::NotFound,
+						ErrorClass::Tree,
+						"the path 'missing.txt' does not exist in the given tree",
+					),
+				}
+			);
+		});
+	}
+
+	#[test]
+	fn load_file_from_commit_invalid_hash() {
+		with_temp_repository(|repository| {
+			assert!(matches!(
+				repository.load_file_from_commit("invalid", Path::new("a.txt")),
+				Err(GitError::CommitLoad { .. })
+			));
+		});
+	}
+
 	#[test]
 	fn find_reference() {
 		with_temp_repository(|repository| {
@@ -343,6 +1031,112 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn find_local_branches() {
+		with_temp_repository(|repository| {
+			create_branch(&repository, "feature", "refs/heads/main");
+			let branches = repository.find_local_branches().unwrap();
+			let names: Vec<&str> = branches.iter().map(Reference::name).collect();
+			assert_eq!(names, vec!["refs/heads/feature", "refs/heads/main"]);
+			assert_eq!(branches[0].hash(), branches[1].hash());
+		});
+	}
+
+	#[test]
+	fn load_reference_map() {
+		with_temp_repository(|repository| {
+			let head_oid = head_id(&repository, "main");
+			let head = format!("{head_oid}");
+			create_branch(&repository, "feature", "refs/heads/main");
+			create_reference(&repository, "refs/remotes/origin/main", "refs/heads/main");
+			create_tag(&repository, "v1.0.0", "refs/heads/main");
+			let git2_repository = repository.repository();
+			let git2_lock = git2_repository.lock();
+			let sig = Signature::new("name", "name@example.com", &git2::Time::new(JAN_2021_EPOCH, 0)).unwrap();
+			_ = git2_lock.note(&sig, &sig, None, head_oid, "note", false);
+			let blob = git2_lock.blob(b"foo").unwrap();
+			_ = git2_lock.reference("refs/blob", blob, false, "blob");
+			drop(git2_lock);
+			let reference_map = repository.load_reference_map().unwrap();
+			assert_eq!(reference_map.len(), 1);
+			let references = &reference_map[&head];
+			let names: Vec<&str> = references.iter().map(Reference::name).collect();
+			assert_eq!(names, vec![
+				"refs/heads/feature",
+				"refs/heads/main",
+				"refs/remotes/origin/main",
+				"refs/tags/v1.0.0"
+			]);
+			let kinds: Vec<ReferenceKind> = references.iter().map(Reference::kind).collect();
+			assert_eq!(kinds, vec![
+				ReferenceKind::Branch,
+				ReferenceKind::Branch,
+				ReferenceKind::Remote,
+				ReferenceKind::Tag
+			]);
+		});
+	}
+
+	#[test]
+	fn head_branch_name() {
+		with_temp_repository(|repository| {
+			assert_eq!(repository.head_branch_name(), Some(String::from("refs/heads/main")));
+		});
+	}
+
+	#[test]
+	fn head_branch_name_during_rebase() {
+		with_temp_repository(|repository| {
+			let rebase_path = repository.repo_path().join("rebase-merge");
+			create_dir_all(&rebase_path).unwrap();
+			write(rebase_path.join("head-name"), "refs/heads/feature\n").unwrap();
+			assert_eq!(repository.head_branch_name(), Some(String::from("refs/heads/feature")));
+		});
+	}
+
+	#[test]
+	fn load_rebase_state() {
+		with_temp_repository(|repository| {
+			let rebase_path = repository.repo_path().join("rebase-merge");
+			create_dir_all(&rebase_path).unwrap();
+			write(rebase_path.join("done"), "pick 0123456789abcdef comment\n").unwrap();
+			let rebase_state = repository.load_rebase_state().unwrap();
+			assert_eq!(rebase_state.done(), &[String::from("pick 0123456789abcdef comment")]);
+		});
+	}
+
+	#[test]
+	fn load_rebase_state_not_rebasing() {
+		with_temp_repository(|repository| {
+			assert_eq!(repository.load_rebase_state(), None);
+		});
+	}
+
+	#[test]
+	fn head_branch_name_detached() {
+		with_temp_repository(|repository| {
+			let git2_repository = repository.repository();
+			let id = git2_repository.lock().head().unwrap().target().unwrap();
+			git2_repository.lock().set_head_detached(id).unwrap();
+			assert_eq!(repository.head_branch_name(), None);
+		});
+	}
+
+	#[test]
+	fn has_revision() {
+		with_temp_repository(|repository| {
+			assert!(repository.has_revision("refs/heads/main"));
+			assert!(repository.has_revision("main"));
+		});
+	}
+
+	#[test]
+	fn has_revision_missing() {
+		with_temp_repository(|repository| {
+			assert!(!repository.has_revision("refs/heads/invalid"));
+		});
+	}
+
 	#[test]
 	fn fmt() {
 		with_temp_bare_repository(|repository| {
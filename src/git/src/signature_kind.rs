@@ -0,0 +1,68 @@
+use std::fmt::{Display, Formatter};
+
+/// Represents the kind of signature on a commit
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[allow(clippy::exhaustive_enums)]
+pub enum SignatureKind {
+	/// Signature is a GPG signature.
+	Gpg,
+	/// Signature is an SSH signature.
+	Ssh,
+	/// Signature is an X.509 signature.
+	X509,
+	/// Signature is of an unknown format.
+	Unknown,
+}
+
+impl SignatureKind {
+	pub(crate) fn from(signature: &str) -> Self {
+		let first_line = signature.lines().next().unwrap_or("").trim();
+		match first_line {
+			"-----BEGIN PGP SIGNATURE-----" => Self::Gpg,
+			"-----BEGIN SSH SIGNATURE-----" => Self::Ssh,
+			"-----BEGIN SIGNED MESSAGE-----" => Self::X509,
+			_ => Self::Unknown,
+		}
+	}
+}
+
+impl Display for SignatureKind {
+	#[inline]
+	fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::Gpg => write!(f, "GPG"),
+			Self::Ssh => write!(f, "SSH"),
+			Self::X509 => write!(f, "X.509"),
+			Self::Unknown => write!(f, "Unknown"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	#[rstest]
+	#[case::gpg("-----BEGIN PGP SIGNATURE-----\n\ndata\n-----END PGP SIGNATURE-----", SignatureKind::Gpg)]
+	#[case::ssh("-----BEGIN SSH SIGNATURE-----\ndata\n-----END SSH SIGNATURE-----", SignatureKind::Ssh)]
+	#[case::x509("-----BEGIN SIGNED MESSAGE-----\ndata\n-----END SIGNED MESSAGE-----", SignatureKind::X509)]
+	#[case::unknown("data", SignatureKind::Unknown)]
+	#[case::empty("", SignatureKind::Unknown)]
+	#[case::truncated_armor("-----BEGIN PGP SIG", SignatureKind::Unknown)]
+	#[case::armor_not_first("data\n-----BEGIN PGP SIGNATURE-----", SignatureKind::Unknown)]
+	#[case::indented_armor("  -----BEGIN SSH SIGNATURE-----  \ndata", SignatureKind::Ssh)]
+	fn from(#[case] signature: &str, #[case] expected: SignatureKind) {
+		assert_eq!(SignatureKind::from(signature), expected);
+	}
+
+	#[rstest]
+	#[case::gpg(SignatureKind::Gpg, "GPG")]
+	#[case::ssh(SignatureKind::Ssh, "SSH")]
+	#[case::x509(SignatureKind::X509, "X.509")]
+	#[case::unknown(SignatureKind::Unknown, "Unknown")]
+	fn display(#[case] kind: SignatureKind, #[case] expected: &str) {
+		assert_eq!(format!("{kind}"), expected);
+	}
+}
@@ -1,6 +1,6 @@
 use chrono::{Local, TimeZone};
 
-use crate::{testutil::JAN_2021_EPOCH, Commit, Reference, User};
+use crate::{testutil::JAN_2021_EPOCH, Commit, Reference, SignatureKind, Trailer, User};
 
 /// Builder for creating a new commit.
 #[derive(Debug)]
@@ -25,6 +25,9 @@ impl CommitBuilder {
 				committer: None,
 				message: None,
 				summary: None,
+				signature: None,
+				trailers: vec![],
+				parent_hash: None,
 			},
 		}
 	}
@@ -96,6 +99,30 @@ impl CommitBuilder {
 		self
 	}
 
+	/// Set the signature.
+	#[inline]
+	#[must_use]
+	pub fn signature(mut self, kind: SignatureKind) -> Self {
+		self.commit.signature = Some(kind);
+		self
+	}
+
+	/// Set the commit message trailers.
+	#[inline]
+	#[must_use]
+	pub fn trailers(mut self, trailers: &[Trailer]) -> Self {
+		self.commit.trailers = trailers.to_vec();
+		self
+	}
+
+	/// Set the hash of the parent commit.
+	#[inline]
+	#[must_use]
+	pub fn parent_hash(mut self, hash: &str) -> Self {
+		self.commit.parent_hash = Some(String::from(hash));
+		self
+	}
+
 	/// Build the `Commit`.
 	#[inline]
 	#[must_use]
@@ -1,6 +1,6 @@
 use std::path::{Path, PathBuf};
 
-use crate::{Delta, FileMode, FileStatus, Status};
+use crate::{CollapseReason, Delta, FileMode, FileStatus, Status};
 
 /// Builder for creating a new reference.
 #[derive(Debug)]
@@ -15,10 +15,12 @@ impl FileStatusBuilder {
 	pub fn new() -> Self {
 		Self {
 			file_status: FileStatus {
+				collapse_reason: None,
 				deltas: vec![],
 				destination_is_binary: false,
 				destination_mode: FileMode::Normal,
 				destination_path: PathBuf::default(),
+				hidden_lines: 0,
 				largest_new_line_number: 0,
 				largest_old_line_number: 0,
 				source_is_binary: false,
@@ -61,6 +63,15 @@ impl FileStatusBuilder {
 		self
 	}
 
+	/// Set the reason the lines of the file status were not loaded, and the number of lines that were not loaded.
+	#[inline]
+	#[must_use]
+	pub const fn collapsed(mut self, reason: CollapseReason, hidden_lines: usize) -> Self {
+		self.file_status.collapse_reason = Some(reason);
+		self.file_status.hidden_lines = hidden_lines;
+		self
+	}
+
 	/// Set the largest new line number.
 	#[inline]
 	#[must_use]
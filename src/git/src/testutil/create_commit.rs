@@ -21,6 +21,7 @@ pub struct CreateCommitOptions {
 	committer_time: i64,
 	head_name: String,
 	message: String,
+	signature: Option<String>,
 }
 
 impl CreateCommitOptions {
@@ -37,6 +38,7 @@ impl CreateCommitOptions {
 			committer_time: JAN_2021_EPOCH,
 			head_name: String::from("main"),
 			message: String::from("title\n\nbody"),
+			signature: None,
 		}
 	}
 
@@ -111,6 +113,13 @@ impl CreateCommitOptions {
 		self.message = String::from(message);
 		self
 	}
+
+	/// Set the signature, such as an ASCII armored GPG signature, of the commit.
+	#[inline]
+	pub fn signature(&mut self, signature: &str) -> &mut Self {
+		self.signature = Some(String::from(signature));
+		self
+	}
 }
 
 /// Add a path to the working index.
@@ -155,6 +164,12 @@ pub fn create_commit(repository: &Repository, options: Option<&CreateCommitOptio
 	let ref_name = format!("refs/heads/{}", opts.head_name);
 
 	repository
-		.create_commit_on_index(ref_name.as_str(), &author_sig, &committer_sig, opts.message.as_str())
+		.create_commit_on_index(
+			ref_name.as_str(),
+			&author_sig,
+			&committer_sig,
+			opts.message.as_str(),
+			opts.signature.as_deref(),
+		)
 		.unwrap();
 }
@@ -41,6 +41,33 @@ pub fn head_id(repo: &Repository, head_name: &str) -> Oid {
 	repo.head_id(head_name).unwrap()
 }
 
+/// Create, or reset, a branch pointing to the commit of a reference.
+///
+/// # Panics
+/// If the branch cannot be created.
+#[inline]
+pub fn create_branch(repo: &Repository, name: &str, reference: &str) {
+	repo.create_branch(name, reference).unwrap();
+}
+
+/// Create, or reset, a reference pointing to the commit of another reference.
+///
+/// # Panics
+/// If the reference cannot be created.
+#[inline]
+pub fn create_reference(repo: &Repository, name: &str, reference: &str) {
+	repo.create_reference(name, reference).unwrap();
+}
+
+/// Create, or reset, an annotated tag pointing to the commit of a reference.
+///
+/// # Panics
+/// If the tag cannot be created.
+#[inline]
+pub fn create_tag(repo: &Repository, name: &str, reference: &str) {
+	repo.create_tag(name, reference).unwrap();
+}
+
 /// Get the Commit Oid from a reference name.
 ///
 /// # Panics
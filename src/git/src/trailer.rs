@@ -0,0 +1,74 @@
+/// Represents a trailer of a commit message, such as `Signed-off-by: Name <email>`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trailer {
+	pub(crate) key: String,
+	pub(crate) value: String,
+}
+
+impl Trailer {
+	/// Create a new trailer.
+	#[must_use]
+	#[inline]
+	pub fn new(key: &str, value: &str) -> Self {
+		Self {
+			key: String::from(key),
+			value: String::from(value),
+		}
+	}
+
+	/// Get the key of the trailer.
+	#[must_use]
+	#[inline]
+	pub fn key(&self) -> &str {
+		self.key.as_str()
+	}
+
+	/// Get the value of the trailer.
+	#[must_use]
+	#[inline]
+	pub fn value(&self) -> &str {
+		self.value.as_str()
+	}
+
+	pub(crate) fn from_message(message: &str) -> Vec<Self> {
+		git2::message_trailers_strs(message).map_or_else(
+			|_| vec![],
+			|trailers| trailers.iter().map(|(key, value)| Self::new(key, value)).collect(),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn new() {
+		let trailer = Trailer::new("Signed-off-by", "Name <name@example.com>");
+		assert_eq!(trailer.key(), "Signed-off-by");
+		assert_eq!(trailer.value(), "Name <name@example.com>");
+	}
+
+	#[test]
+	fn from_message() {
+		assert_eq!(
+			Trailer::from_message(
+				"Title\n\nBody\n\nSigned-off-by: Name <name@example.com>\nReviewed-by: Other <other@example.com>\n"
+			),
+			vec![
+				Trailer::new("Signed-off-by", "Name <name@example.com>"),
+				Trailer::new("Reviewed-by", "Other <other@example.com>")
+			]
+		);
+	}
+
+	#[test]
+	fn from_message_without_trailers() {
+		assert!(Trailer::from_message("Title\n\nBody\n").is_empty());
+	}
+
+	#[test]
+	fn from_message_title_only() {
+		assert!(Trailer::from_message("Signed-off-by: Name <name@example.com>\n").is_empty());
+	}
+}
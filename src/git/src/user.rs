@@ -1,5 +1,5 @@
 /// Represents a user within a commit with a name and email address
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq)]
 pub struct User {
 	name: Option<String>,
 	email: Option<String>,
@@ -3,7 +3,7 @@ use crossterm::event::{KeyCode, MouseEvent};
 use crate::{KeyEvent, StandardEvent};
 
 /// An event, either from an input device, system change or action event.
-#[derive(Debug, PartialOrd, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialOrd, PartialEq, Eq, Clone)]
 #[allow(clippy::exhaustive_enums)]
 pub enum Event<CustomEvent: crate::CustomEvent> {
 	/// A keyboard event.
@@ -12,6 +12,8 @@ pub enum Event<CustomEvent: crate::CustomEvent> {
 	Standard(StandardEvent),
 	/// A mouse event.
 	Mouse(MouseEvent),
+	/// A bracketed paste event, containing the pasted text.
+	Paste(String),
 	/// An empty event.
 	None,
 	/// A terminal resize event.
@@ -27,10 +29,9 @@ impl<CustomEvent: crate::CustomEvent> From<crossterm::event::Event> for Event<Cu
 			crossterm::event::Event::Key(evt) => Self::Key(KeyEvent::from(evt)),
 			crossterm::event::Event::Mouse(evt) => Self::Mouse(evt),
 			crossterm::event::Event::Resize(width, height) => Self::Resize(width, height),
+			crossterm::event::Event::Paste(content) => Self::Paste(content),
 			// ignore these events for now, as we don't need them
-			crossterm::event::Event::FocusGained
-			| crossterm::event::Event::FocusLost
-			| crossterm::event::Event::Paste(_) => Self::None,
+			crossterm::event::Event::FocusGained | crossterm::event::Event::FocusLost => Self::None,
 		}
 	}
 }
@@ -132,7 +133,7 @@ mod tests {
 	#[test]
 	fn from_crossterm_paste_event() {
 		let event = Event::from(crossterm::event::Event::Paste(String::from("test")));
-		assert_eq!(event, Event::None);
+		assert_eq!(event, Event::Paste(String::from("test")));
 	}
 
 	#[test]
@@ -1,5 +1,5 @@
 use super::{Event, KeyCode, KeyModifiers};
-use crate::{key_bindings::KeyBindings, InputOptions, KeyEvent, StandardEvent};
+use crate::{key_bindings::KeyBindings, InputOptions, KeyEvent, MouseEvent, MouseEventKind, StandardEvent};
 
 /// A handler for reading and processing events.
 #[derive(Debug)]
@@ -32,7 +32,7 @@ impl<CustomKeybinding: crate::CustomKeybinding, CustomEvent: crate::CustomEvent>
 			return event;
 		}
 
-		if let Some(e) = Self::handle_standard_inputs(event) {
+		if let Some(e) = Self::handle_standard_inputs(&event) {
 			return e;
 		}
 
@@ -43,13 +43,13 @@ impl<CustomKeybinding: crate::CustomKeybinding, CustomEvent: crate::CustomEvent>
 		}
 
 		if input_options.contains(InputOptions::MOVEMENT) {
-			if let Some(evt) = Self::handle_movement_inputs(&self.key_bindings, event) {
+			if let Some(evt) = Self::handle_movement_inputs(&self.key_bindings, &event) {
 				return evt;
 			}
 		}
 
 		if input_options.contains(InputOptions::SEARCH) {
-			if let Some(evt) = Self::handle_search(&self.key_bindings, event) {
+			if let Some(evt) = Self::handle_search(&self.key_bindings, &event) {
 				return evt;
 			}
 		}
@@ -59,7 +59,7 @@ impl<CustomKeybinding: crate::CustomKeybinding, CustomEvent: crate::CustomEvent>
 		}
 
 		if input_options.contains(InputOptions::UNDO_REDO) {
-			if let Some(evt) = Self::handle_undo_redo(&self.key_bindings, event) {
+			if let Some(evt) = Self::handle_undo_redo(&self.key_bindings, &event) {
 				return evt;
 			}
 		}
@@ -68,8 +68,8 @@ impl<CustomKeybinding: crate::CustomKeybinding, CustomEvent: crate::CustomEvent>
 	}
 
 	#[allow(clippy::wildcard_enum_match_arm)]
-	fn handle_standard_inputs(event: Event<CustomEvent>) -> Option<Event<CustomEvent>> {
-		match event {
+	fn handle_standard_inputs(event: &Event<CustomEvent>) -> Option<Event<CustomEvent>> {
+		match *event {
 			Event::Key(KeyEvent {
 				code: KeyCode::Char('c'),
 				modifiers: KeyModifiers::CONTROL,
@@ -81,25 +81,33 @@ impl<CustomKeybinding: crate::CustomKeybinding, CustomEvent: crate::CustomEvent>
 	#[allow(clippy::wildcard_enum_match_arm)]
 	fn handle_movement_inputs(
 		key_bindings: &KeyBindings<CustomKeybinding, CustomEvent>,
-		event: Event<CustomEvent>,
+		event: &Event<CustomEvent>,
 	) -> Option<Event<CustomEvent>> {
-		Some(match event {
-			e if key_bindings.scroll_down.contains(&e) => Event::from(StandardEvent::ScrollDown),
-			e if key_bindings.scroll_end.contains(&e) => Event::from(StandardEvent::ScrollBottom),
-			e if key_bindings.scroll_home.contains(&e) => Event::from(StandardEvent::ScrollTop),
-			e if key_bindings.scroll_left.contains(&e) => Event::from(StandardEvent::ScrollLeft),
-			e if key_bindings.scroll_right.contains(&e) => Event::from(StandardEvent::ScrollRight),
-			e if key_bindings.scroll_up.contains(&e) => Event::from(StandardEvent::ScrollUp),
-			e if key_bindings.scroll_step_down.contains(&e) => Event::from(StandardEvent::ScrollJumpDown),
-			e if key_bindings.scroll_step_up.contains(&e) => Event::from(StandardEvent::ScrollJumpUp),
+		Some(match *event {
+			ref e if key_bindings.scroll_down.contains(e) => Event::from(StandardEvent::ScrollDown),
+			ref e if key_bindings.scroll_end.contains(e) => Event::from(StandardEvent::ScrollBottom),
+			ref e if key_bindings.scroll_home.contains(e) => Event::from(StandardEvent::ScrollTop),
+			ref e if key_bindings.scroll_left.contains(e) => Event::from(StandardEvent::ScrollLeft),
+			ref e if key_bindings.scroll_right.contains(e) => Event::from(StandardEvent::ScrollRight),
+			ref e if key_bindings.scroll_up.contains(e) => Event::from(StandardEvent::ScrollUp),
+			ref e if key_bindings.scroll_step_down.contains(e) => Event::from(StandardEvent::ScrollJumpDown),
+			ref e if key_bindings.scroll_step_up.contains(e) => Event::from(StandardEvent::ScrollJumpUp),
 			// these are required, since in some contexts (like editing), other keybindings will not work
 			Event::Key(KeyEvent {
 				code: KeyCode::Up,
 				modifiers: KeyModifiers::NONE,
+			})
+			| Event::Mouse(MouseEvent {
+				kind: MouseEventKind::ScrollUp,
+				..
 			}) => Event::from(StandardEvent::ScrollUp),
 			Event::Key(KeyEvent {
 				code: KeyCode::Down,
 				modifiers: KeyModifiers::NONE,
+			})
+			| Event::Mouse(MouseEvent {
+				kind: MouseEventKind::ScrollDown,
+				..
 			}) => Event::from(StandardEvent::ScrollDown),
 			Event::Key(KeyEvent {
 				code: KeyCode::Left,
@@ -131,24 +139,24 @@ impl<CustomKeybinding: crate::CustomKeybinding, CustomEvent: crate::CustomEvent>
 
 	fn handle_search(
 		key_bindings: &KeyBindings<CustomKeybinding, CustomEvent>,
-		event: Event<CustomEvent>,
+		event: &Event<CustomEvent>,
 	) -> Option<Event<CustomEvent>> {
 		match event {
-			e if key_bindings.search_next.contains(&e) => Some(Event::from(StandardEvent::SearchNext)),
-			e if key_bindings.search_previous.contains(&e) => Some(Event::from(StandardEvent::SearchPrevious)),
-			e if key_bindings.search_start.contains(&e) => Some(Event::from(StandardEvent::SearchStart)),
+			e if key_bindings.search_next.contains(e) => Some(Event::from(StandardEvent::SearchNext)),
+			e if key_bindings.search_previous.contains(e) => Some(Event::from(StandardEvent::SearchPrevious)),
+			e if key_bindings.search_start.contains(e) => Some(Event::from(StandardEvent::SearchStart)),
 			_ => None,
 		}
 	}
 
 	fn handle_undo_redo(
 		key_bindings: &KeyBindings<CustomKeybinding, CustomEvent>,
-		event: Event<CustomEvent>,
+		event: &Event<CustomEvent>,
 	) -> Option<Event<CustomEvent>> {
-		if key_bindings.undo.contains(&event) {
+		if key_bindings.undo.contains(event) {
 			Some(Event::from(StandardEvent::Undo))
 		}
-		else if key_bindings.redo.contains(&event) {
+		else if key_bindings.redo.contains(event) {
 			Some(Event::from(StandardEvent::Redo))
 		}
 		else {
@@ -215,6 +223,13 @@ mod tests {
 		}
 	}
 
+	#[test]
+	fn paste_event() {
+		let event_handler = EventHandler::new(create_test_keybindings());
+		let result = event_handler.read_event(Event::Paste(String::from("foo")), &InputOptions::all(), |event, _| event);
+		assert_eq!(result, Event::Paste(String::from("foo")));
+	}
+
 	#[test]
 	fn none_event() {
 		let event_handler = EventHandler::new(create_test_keybindings());
@@ -243,6 +258,18 @@ mod tests {
 	#[case::standard(Event::from(KeyCode::PageDown), Event::from(StandardEvent::ScrollJumpDown))]
 	#[case::standard(Event::from(KeyCode::Home), Event::from(StandardEvent::ScrollTop))]
 	#[case::standard(Event::from(KeyCode::End), Event::from(StandardEvent::ScrollBottom))]
+	#[case::mouse_scroll_up(Event::from(MouseEvent {
+		kind: MouseEventKind::ScrollUp,
+		column: 0,
+		row: 0,
+		modifiers: KeyModifiers::NONE,
+	}), Event::from(StandardEvent::ScrollUp))]
+	#[case::mouse_scroll_down(Event::from(MouseEvent {
+		kind: MouseEventKind::ScrollDown,
+		column: 0,
+		row: 0,
+		modifiers: KeyModifiers::NONE,
+	}), Event::from(StandardEvent::ScrollDown))]
 	#[case::other(Event::from('a'), Event::from(KeyCode::Null))]
 	fn movement_inputs(#[case] event: Event, #[case] expected: Event) {
 		let event_handler = EventHandler::new(create_test_keybindings());
@@ -30,11 +30,15 @@ pub fn read_event() -> Result<Option<Event>> {
 						..
 					})
 					| Event::Mouse(MouseEvent {
-						kind: MouseEventKind::Down(_) | MouseEventKind::ScrollDown | MouseEventKind::ScrollUp,
+						kind: MouseEventKind::Down(_)
+							| MouseEventKind::Drag(_)
+							| MouseEventKind::ScrollDown
+							| MouseEventKind::ScrollUp,
 						..
 					})
+					| Event::Paste(_)
 					| Event::Resize(..)) => Some(e),
-					Event::Key(_) | Event::Mouse(_) | Event::Paste(_) | Event::FocusGained | Event::FocusLost => None,
+					Event::Key(_) | Event::Mouse(_) | Event::FocusGained | Event::FocusLost => None,
 				}
 			})
 			.map_err(|err| anyhow!("{:#}", err).context("Unexpected Error"))
@@ -179,6 +183,33 @@ mod tests {
 		);
 	}
 
+	#[test]
+	#[serial_test::serial]
+	fn read_event_read_mouse_drag() {
+		let mut lock = read_event_mocks::NEXT_EVENT.lock();
+		*lock = Ok(Event::Mouse(MouseEvent {
+			kind: MouseEventKind::Drag(MouseButton::Left),
+			column: 0,
+			row: 0,
+			modifiers: KeyModifiers::NONE,
+		}));
+		drop(lock);
+
+		let mut lock = read_event_mocks::HAS_POLLED_EVENT.lock();
+		*lock = Ok(true);
+		drop(lock);
+
+		assert_eq!(
+			read_event().unwrap(),
+			Some(Event::Mouse(MouseEvent {
+				kind: MouseEventKind::Drag(MouseButton::Left),
+				column: 0,
+				row: 0,
+				modifiers: KeyModifiers::NONE
+			}))
+		);
+	}
+
 	#[test]
 	#[serial_test::serial]
 	fn read_event_read_mouse_scroll_down() {
@@ -295,7 +326,7 @@ mod tests {
 		*lock = Ok(true);
 		drop(lock);
 
-		assert_eq!(read_event().unwrap(), None);
+		assert_eq!(read_event().unwrap(), Some(Event::Paste(String::from("Foo"))));
 	}
 
 	#[test]
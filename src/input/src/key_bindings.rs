@@ -77,6 +77,7 @@ pub fn map_keybindings<CustomEvent: crate::CustomEvent>(bindings: &[String]) ->
 				"PageDown" => KeyCode::PageDown,
 				"PageUp" => KeyCode::PageUp,
 				"Right" => KeyCode::Right,
+				"Space" => KeyCode::Char(' '),
 				"Tab" => KeyCode::Tab,
 				"Up" => KeyCode::Up,
 				// assume that this is an F key
@@ -155,6 +156,7 @@ mod tests {
 	#[case::page_down("PageDown", KeyCode::PageDown)]
 	#[case::page_up("PageUp", KeyCode::PageUp)]
 	#[case::right("Right", KeyCode::Right)]
+	#[case::space("Space", KeyCode::Char(' '))]
 	#[case::tab("Tab", KeyCode::Tab)]
 	#[case::up("Up", KeyCode::Up)]
 	#[case::function_in_range("F10", KeyCode::F(10))]
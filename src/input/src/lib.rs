@@ -147,7 +147,7 @@ mod standard_event;
 pub mod testutil;
 mod thread;
 
-pub use crossterm::event::{Event as RawEvent, KeyCode, KeyModifiers, MouseEvent, MouseEventKind};
+pub use crossterm::event::{Event as RawEvent, KeyCode, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
 
 pub use self::{
 	custom_event::CustomEvent,
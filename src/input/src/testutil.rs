@@ -99,7 +99,7 @@ pub fn with_event_handler<C, TestKeybinding: crate::CustomKeybinding, CustomEven
 	let state = State::new();
 
 	for event in events {
-		state.enqueue_event(*event);
+		state.enqueue_event(event.clone());
 	}
 
 	callback(TestContext {
@@ -140,6 +140,7 @@ where
 						))))
 					},
 					Event::Mouse(mouse_event) => Ok(Some(c_event::Event::Mouse(mouse_event))),
+					Event::Paste(content) => Ok(Some(c_event::Event::Paste(content))),
 					Event::None => Ok(None),
 					Event::Resize(width, height) => Ok(Some(c_event::Event::Resize(width, height))),
 					Event::MetaEvent(_) | Event::Standard(_) => {
@@ -65,6 +65,25 @@ impl Action {
 			Self::Drop | Self::Edit | Self::Fixup | Self::Pick | Self::Reword | Self::Squash => false,
 		}
 	}
+
+	/// Is the action used to describe the structure of a merge rebase.
+	#[must_use]
+	#[inline]
+	pub const fn is_merge_structure(self) -> bool {
+		match self {
+			Self::Label | Self::Reset | Self::Merge => true,
+			Self::Break
+			| Self::Drop
+			| Self::Edit
+			| Self::Exec
+			| Self::Fixup
+			| Self::Noop
+			| Self::Pick
+			| Self::Reword
+			| Self::Squash
+			| Self::UpdateRef => false,
+		}
+	}
 }
 
 impl Display for Action {
@@ -210,4 +229,22 @@ mod tests {
 	fn module_lifecycle(#[case] action: Action, #[case] expected: bool) {
 		assert_eq!(action.is_static(), expected);
 	}
+
+	#[rstest]
+	#[case::break_action(Action::Break, false)]
+	#[case::drop(Action::Drop, false)]
+	#[case::edit(Action::Edit, false)]
+	#[case::exec(Action::Exec, false)]
+	#[case::fixup(Action::Fixup, false)]
+	#[case::noop(Action::Noop, false)]
+	#[case::pick(Action::Pick, false)]
+	#[case::reword(Action::Reword, false)]
+	#[case::squash(Action::Squash, false)]
+	#[case::label(Action::Label, true)]
+	#[case::reset(Action::Reset, true)]
+	#[case::merge(Action::Merge, true)]
+	#[case::update_ref(Action::UpdateRef, false)]
+	fn is_merge_structure(#[case] action: Action, #[case] expected: bool) {
+		assert_eq!(action.is_merge_structure(), expected);
+	}
 }
@@ -5,8 +5,10 @@
 
 mod io;
 mod parse;
+mod validation;
 
 pub use self::{
 	io::{FileReadErrorCause, IoError},
 	parse::ParseError,
+	validation::ValidationError,
 };
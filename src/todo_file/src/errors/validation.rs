@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::Action;
+
+/// Validation errors
+#[derive(Error, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValidationError {
+	/// A fixup or squash line does not follow a commit that can be modified
+	#[error("Cannot {0} without a previous commit")]
+	MissingPreviousCommit(Action),
+	/// The reference of an update-ref line is not a valid reference name
+	#[error("The reference `{0}` is not a valid reference name")]
+	InvalidReferenceName(String),
+	/// A label used by a merge or reset line is not defined before it is used
+	#[error("The label `{0}` is not defined")]
+	UndefinedLabel(String),
+	/// An exec line has no command
+	#[error("The exec command is empty")]
+	EmptyExec,
+}
@@ -134,11 +134,13 @@ mod edit_content;
 pub mod errors;
 mod history;
 mod line;
+mod line_diff;
 mod line_parser;
 mod search;
 #[cfg(not(tarpaulin_include))]
 pub mod testutil;
 mod utils;
+mod validation;
 
 use std::{
 	fs::{read_to_string, File},
@@ -149,12 +151,14 @@ use std::{
 
 pub use version_track::Version;
 
-pub use self::{action::Action, edit_content::EditContext, line::Line, search::Search};
+pub use self::{action::Action, edit_content::EditContext, line::Line, line_diff::LineDiff, search::Search};
 use self::{
 	history::{History, HistoryItem},
-	utils::{remove_range, swap_range_down, swap_range_up},
+	line_diff::diff_lines,
+	utils::{are_labels_ordered, get_block_range, remove_range, swap_range_down, swap_range_up},
+	validation::validate,
 };
-use crate::errors::{FileReadErrorCause, IoError};
+use crate::errors::{FileReadErrorCause, IoError, ValidationError};
 
 /// Represents a rebase file.
 #[derive(Debug)]
@@ -164,6 +168,7 @@ pub struct TodoFile {
 	history: History,
 	is_noop: bool,
 	lines: Vec<Line>,
+	original_lines: Option<Vec<Line>>,
 	selected_line_index: usize,
 	version: Version,
 }
@@ -179,12 +184,14 @@ impl TodoFile {
 			history: History::new(undo_limit),
 			lines: vec![],
 			is_noop: false,
+			original_lines: None,
 			selected_line_index: 0,
 			version: Version::new(),
 		}
 	}
 
-	/// Set the rebase lines.
+	/// Set the rebase lines. The first set of lines provided are kept as the original lines, and are used to find the
+	/// commits that were removed from the list.
 	#[inline]
 	pub fn set_lines(&mut self, lines: Vec<Line>) {
 		self.is_noop = !lines.is_empty() && lines[0].get_action() == &Action::Noop;
@@ -194,6 +201,9 @@ impl TodoFile {
 		else {
 			lines.into_iter().filter(|l| l.get_action() != &Action::Noop).collect()
 		};
+		if self.original_lines.is_none() {
+			self.original_lines = Some(self.lines.clone());
+		}
 		if self.selected_line_index >= self.lines.len() {
 			self.selected_line_index = if self.lines.is_empty() { 0 } else { self.lines.len() - 1 };
 		}
@@ -314,6 +324,64 @@ impl TodoFile {
 		true
 	}
 
+	/// Get the range of the merge branch block, starting with a `reset` and ending with a `label`, that
+	/// contains the line at the index.
+	#[must_use]
+	#[inline]
+	pub fn get_block_range(&self, index: usize) -> Option<(usize, usize)> {
+		get_block_range(&self.lines, index)
+	}
+
+	/// Swap the merge branch block containing the line at the index with the block, or line, above it,
+	/// returning the new range of the block.
+	#[inline]
+	pub fn swap_block_up(&mut self, index: usize) -> Option<(usize, usize)> {
+		let (start, end) = self.get_block_range(index)?;
+		if start == 0 {
+			return None;
+		}
+		let previous_start = match self.get_block_range(start - 1) {
+			Some((previous_start, _)) => previous_start,
+			None if self.lines[start - 1].get_action().is_merge_structure() => return None,
+			None => start - 1,
+		};
+		self.rotate_range(previous_start, end, start - previous_start)
+			.then(|| (previous_start, previous_start + end - start))
+	}
+
+	/// Swap the merge branch block containing the line at the index with the block, or line, below it,
+	/// returning the new range of the block.
+	#[inline]
+	pub fn swap_block_down(&mut self, index: usize) -> Option<(usize, usize)> {
+		let (start, end) = self.get_block_range(index)?;
+		if end + 1 >= self.lines.len() {
+			return None;
+		}
+		let next_end = match self.get_block_range(end + 1) {
+			Some((_, next_end)) => next_end,
+			None if self.lines[end + 1].get_action().is_merge_structure() => return None,
+			None => end + 1,
+		};
+		self.rotate_range(start, next_end, end + 1 - start)
+			.then(|| (next_end - (end - start), next_end))
+	}
+
+	fn rotate_range(&mut self, start: usize, end: usize, amount: usize) -> bool {
+		let mut new_lines = self.lines.clone();
+		new_lines[start..=end].rotate_left(amount);
+
+		// moving a block must not cause a label to be used before it is defined
+		if are_labels_ordered(&self.lines) && !are_labels_ordered(&new_lines) {
+			return false;
+		}
+
+		let previous_lines = self.lines[start..=end].to_vec();
+		self.lines = new_lines;
+		self.version.increment();
+		self.history.record(HistoryItem::new_modify(start, end, previous_lines));
+		true
+	}
+
 	/// Add a new line.
 	#[inline]
 	pub fn add_line(&mut self, index: usize, line: Line) {
@@ -387,6 +455,61 @@ impl TodoFile {
 		self.history.record(HistoryItem::new_modify(start, end, lines));
 	}
 
+	/// Validate the lines, returning the index of each invalid line along with the problem found.
+	#[must_use]
+	#[inline]
+	pub fn validate(&self) -> Vec<(usize, ValidationError)> {
+		validate(&self.lines)
+	}
+
+	/// Get the lines that have been marked with the drop action.
+	#[must_use]
+	#[inline]
+	pub fn get_dropped_lines(&self) -> Vec<&Line> {
+		self.lines
+			.iter()
+			.filter(|line| line.get_action() == &Action::Drop)
+			.collect()
+	}
+
+	/// Get the lines from the originally loaded list, with a commit that is no longer referenced by any line.
+	#[must_use]
+	#[inline]
+	pub fn get_removed_lines(&self) -> Vec<&Line> {
+		self.original_lines.as_ref().map_or_else(Vec::new, |original_lines| {
+			original_lines
+				.iter()
+				.filter(|original| {
+					original.has_reference() && !self.lines.iter().any(|line| line.get_hash() == original.get_hash())
+				})
+				.collect()
+		})
+	}
+
+	/// Compare the lines with the originally loaded lines.
+	#[must_use]
+	#[inline]
+	pub fn diff_original(&self) -> Vec<LineDiff> {
+		diff_lines(self.original_lines.as_deref().unwrap_or_default(), &self.lines)
+	}
+
+	/// Check if the lines differ from the originally loaded lines.
+	#[must_use]
+	#[inline]
+	pub fn is_modified(&self) -> bool {
+		self.original_lines
+			.as_ref()
+			.is_some_and(|original_lines| original_lines != &self.lines)
+	}
+
+	/// Replace the lines with the originally loaded lines, discarding all modifications.
+	#[inline]
+	pub fn restore_original_lines(&mut self) {
+		if let Some(original_lines) = self.original_lines.clone() {
+			self.set_lines(original_lines);
+		}
+	}
+
 	/// Undo the last modification.
 	#[inline]
 	pub fn undo(&mut self) -> Option<(usize, usize)> {
@@ -888,6 +1011,245 @@ mod tests {
 		assert_todo_lines!(todo_file, "pick aaa comment", "pick bbb comment", "pick ccc comment");
 	}
 
+	const MERGE_BLOCKS: [&str; 11] = [
+		"label onto",
+		"reset onto",
+		"pick aaa c1",
+		"label branch-a",
+		"reset onto",
+		"pick bbb c2",
+		"pick ccc c3",
+		"label branch-b",
+		"reset onto",
+		"merge -C ddd branch-a # Merge a",
+		"merge -C eee branch-b # Merge b",
+	];
+
+	#[test]
+	fn get_block_range_within_block() {
+		let (todo_file, _) = create_and_load_todo_file(&MERGE_BLOCKS);
+		assert_some_eq!(todo_file.get_block_range(4), (4, 7));
+		assert_some_eq!(todo_file.get_block_range(5), (4, 7));
+		assert_some_eq!(todo_file.get_block_range(7), (4, 7));
+	}
+
+	#[test]
+	fn get_block_range_outside_block() {
+		let (todo_file, _) = create_and_load_todo_file(&MERGE_BLOCKS);
+		assert_none!(todo_file.get_block_range(0));
+		assert_none!(todo_file.get_block_range(9));
+		assert_none!(todo_file.get_block_range(20));
+	}
+
+	#[test]
+	fn get_block_range_without_merges() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		assert_none!(todo_file.get_block_range(1));
+	}
+
+	#[test]
+	fn swap_block_up() {
+		let (mut todo_file, _) = create_and_load_todo_file(&MERGE_BLOCKS);
+		assert_some_eq!(todo_file.swap_block_up(5), (1, 4));
+		assert_todo_lines!(
+			todo_file,
+			"label onto",
+			"reset onto",
+			"pick bbb c2",
+			"pick ccc c3",
+			"label branch-b",
+			"reset onto",
+			"pick aaa c1",
+			"label branch-a",
+			"reset onto",
+			"merge -C ddd branch-a # Merge a",
+			"merge -C eee branch-b # Merge b"
+		);
+	}
+
+	#[test]
+	fn swap_block_up_past_line() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "reset onto", "pick bbb c2", "label b"]);
+		assert_some_eq!(todo_file.swap_block_up(2), (0, 2));
+		assert_todo_lines!(todo_file, "reset onto", "pick bbb c2", "label b", "pick aaa c1");
+	}
+
+	#[test]
+	fn swap_block_up_at_top() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["reset onto", "pick bbb c2", "label b"]);
+		assert_none!(todo_file.swap_block_up(1));
+	}
+
+	#[test]
+	fn swap_block_up_records_history() {
+		let (mut todo_file, _) = create_and_load_todo_file(&MERGE_BLOCKS);
+		_ = todo_file.swap_block_up(5);
+		assert_some_eq!(todo_file.undo(), (1, 7));
+		assert_todo_lines!(
+			todo_file,
+			"label onto",
+			"reset onto",
+			"pick aaa c1",
+			"label branch-a",
+			"reset onto",
+			"pick bbb c2",
+			"pick ccc c3",
+			"label branch-b",
+			"reset onto",
+			"merge -C ddd branch-a # Merge a",
+			"merge -C eee branch-b # Merge b"
+		);
+	}
+
+	#[test]
+	fn swap_block_down() {
+		let (mut todo_file, _) = create_and_load_todo_file(&MERGE_BLOCKS);
+		assert_some_eq!(todo_file.swap_block_down(2), (5, 7));
+		assert_todo_lines!(
+			todo_file,
+			"label onto",
+			"reset onto",
+			"pick bbb c2",
+			"pick ccc c3",
+			"label branch-b",
+			"reset onto",
+			"pick aaa c1",
+			"label branch-a",
+			"reset onto",
+			"merge -C ddd branch-a # Merge a",
+			"merge -C eee branch-b # Merge b"
+		);
+	}
+
+	#[test]
+	fn swap_block_down_past_line() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["reset onto", "pick bbb c2", "label b", "pick aaa c1"]);
+		assert_some_eq!(todo_file.swap_block_down(0), (1, 3));
+		assert_todo_lines!(todo_file, "pick aaa c1", "reset onto", "pick bbb c2", "label b");
+	}
+
+	#[test]
+	fn swap_block_down_at_bottom() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["reset onto", "pick bbb c2", "label b"]);
+		assert_none!(todo_file.swap_block_down(1));
+	}
+
+	#[test]
+	fn swap_block_down_past_reset() {
+		let (mut todo_file, _) = create_and_load_todo_file(&MERGE_BLOCKS);
+		assert_none!(todo_file.swap_block_down(5));
+		assert_some_eq!(todo_file.get_line(4), &create_line("reset onto"));
+	}
+
+	#[test]
+	fn swap_block_down_after_label_usage() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[
+			"label onto",
+			"reset onto",
+			"pick aaa c1",
+			"label branch-a",
+			"reset branch-a",
+			"pick bbb c2",
+			"label branch-b",
+		]);
+		assert_none!(todo_file.swap_block_down(2));
+	}
+
+	#[test]
+	fn swap_block_up_before_label_definition() {
+		let (mut todo_file, _) = create_and_load_todo_file(&[
+			"label onto",
+			"reset onto",
+			"pick aaa c1",
+			"label branch-a",
+			"reset branch-a",
+			"pick bbb c2",
+			"label branch-b",
+		]);
+		assert_none!(todo_file.swap_block_up(5));
+	}
+
+	#[test]
+	fn validate() {
+		let (todo_file, _) = create_and_load_todo_file(&["fixup aaa comment", "pick bbb comment"]);
+		assert_eq!(todo_file.validate(), vec![(
+			0,
+			ValidationError::MissingPreviousCommit(Action::Fixup)
+		)]);
+	}
+
+	#[test]
+	fn get_dropped_lines() {
+		let (todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "drop bbb c2", "exec foo", "drop ccc c3"]);
+		assert_eq!(todo_file.get_dropped_lines(), vec![
+			&create_line("drop bbb c2"),
+			&create_line("drop ccc c3")
+		]);
+	}
+
+	#[test]
+	fn get_removed_lines() {
+		let (mut todo_file, _) =
+			create_and_load_todo_file(&["pick aaa c1", "exec foo", "pick bbb c2", "pick ccc c3", "label bar"]);
+		todo_file.remove_lines(1, 3);
+		assert_eq!(todo_file.get_removed_lines(), vec![
+			&create_line("pick bbb c2"),
+			&create_line("pick ccc c3")
+		]);
+	}
+
+	#[test]
+	fn get_removed_lines_with_changed_action() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		todo_file.update_range(1, 1, &EditContext::new().action(Action::Drop));
+		assert_empty!(todo_file.get_removed_lines());
+	}
+
+	#[test]
+	fn get_removed_lines_keeps_first_loaded_lines() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		todo_file.set_lines(vec![create_line("pick aaa c1")]);
+		assert_eq!(todo_file.get_removed_lines(), vec![&create_line("pick bbb c2")]);
+	}
+
+	#[test]
+	fn diff_original() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		_ = todo_file.swap_range_up(1, 1);
+		assert_eq!(todo_file.diff_original(), vec![
+			LineDiff::Moved(create_line("pick bbb c2")),
+			LineDiff::Unchanged(create_line("pick aaa c1"))
+		]);
+	}
+
+	#[test]
+	fn is_modified() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		assert!(!todo_file.is_modified());
+		_ = todo_file.swap_range_up(1, 1);
+		assert!(todo_file.is_modified());
+		_ = todo_file.undo();
+		assert!(!todo_file.is_modified());
+	}
+
+	#[test]
+	fn restore_original_lines() {
+		let (mut todo_file, _) = create_and_load_todo_file(&["pick aaa c1", "pick bbb c2"]);
+		_ = todo_file.swap_range_up(1, 1);
+		todo_file.remove_lines(1, 1);
+		todo_file.restore_original_lines();
+		assert_todo_lines!(todo_file, "pick aaa c1", "pick bbb c2");
+		assert!(!todo_file.is_modified());
+		assert_none!(todo_file.undo());
+	}
+
+	#[test]
+	fn restore_original_lines_without_original() {
+		let mut todo_file = TodoFile::new("unused", 1, "#");
+		todo_file.restore_original_lines();
+		assert!(todo_file.is_empty());
+	}
+
 	#[test]
 	fn selected_line_index() {
 		let (mut todo_file, _) = create_and_load_todo_file(&["exec foo", "exec bar", "exec foobar"]);
@@ -36,6 +36,33 @@ impl Line {
 		}
 	}
 
+	/// Create a new line for a commit, with an action that references a commit, such as a pick or a fixup.
+	///
+	/// # Errors
+	///
+	/// Returns an error if the action does not reference a commit.
+	#[inline]
+	pub fn new_commit(action: Action, hash: &str, content: &str) -> Result<Self, ParseError> {
+		match action {
+			Action::Drop | Action::Edit | Action::Fixup | Action::Pick | Action::Reword | Action::Squash => {
+				Ok(Self {
+					action,
+					content: String::from(content),
+					hash: String::from(hash),
+					mutated: false,
+					option: None,
+				})
+			},
+			Action::Break
+			| Action::Exec
+			| Action::Label
+			| Action::Merge
+			| Action::Noop
+			| Action::Reset
+			| Action::UpdateRef => Err(ParseError::InvalidAction(action.to_string())),
+		}
+	}
+
 	/// Create a new break line.
 	#[must_use]
 	#[inline]
@@ -233,6 +260,18 @@ impl Line {
 		self.option.as_deref()
 	}
 
+	/// Get the commit hash of the original merge commit, for a merge line that uses `-C` or `-c`.
+	#[must_use]
+	#[inline]
+	pub fn get_merge_commit(&self) -> Option<&str> {
+		if self.action != Action::Merge {
+			return None;
+		}
+		let mut tokens = self.content.split_whitespace();
+		let option = tokens.next()?;
+		(option == "-C" || option == "-c").then(|| tokens.next()).flatten()
+	}
+
 	/// Does this line contain a commit reference.
 	#[must_use]
 	#[inline]
@@ -407,6 +446,25 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn line_new_commit() {
+		assert_ok_eq!(Line::new_commit(Action::Fixup, "abc123", "comment"), Line {
+			action: Action::Fixup,
+			hash: String::from("abc123"),
+			content: String::from("comment"),
+			mutated: false,
+			option: None,
+		});
+	}
+
+	#[test]
+	fn line_new_commit_non_commit_action() {
+		assert_err_eq!(
+			Line::new_commit(Action::Exec, "abc123", "comment"),
+			ParseError::InvalidAction(String::from("exec"))
+		);
+	}
+
 	#[test]
 	fn line_new_break() {
 		assert_eq!(Line::new_break(), Line {
@@ -629,6 +687,16 @@ mod tests {
 		assert_eq!(Line::new(line).unwrap().has_reference(), expected);
 	}
 
+	#[rstest]
+	#[case::pick("pick aaa comment", None)]
+	#[case::merge_without_commit("merge label", None)]
+	#[case::merge_with_keep_message("merge -C aaa label # comment", Some("aaa"))]
+	#[case::merge_with_edit_message("merge -c aaa label", Some("aaa"))]
+	#[case::merge_with_missing_commit("merge -C", None)]
+	fn get_merge_commit(#[case] line: &str, #[case] expected: Option<&str>) {
+		assert_eq!(Line::new(line).unwrap().get_merge_commit(), expected);
+	}
+
 	#[rstest]
 	#[case::drop(Action::Break, false)]
 	#[case::drop(Action::Drop, false)]
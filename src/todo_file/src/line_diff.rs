@@ -0,0 +1,235 @@
+use crate::Line;
+
+/// A line of the todo list, compared with the originally loaded todo list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LineDiff {
+	/// The line is unchanged and in its original position.
+	Unchanged(Line),
+	/// The line is unchanged, but was moved from its original position.
+	Moved(Line),
+	/// The action, option or content of the line was changed.
+	Changed {
+		/// The original line.
+		original: Line,
+		/// The changed line.
+		line: Line,
+		/// If the line was also moved from its original position.
+		moved: bool,
+	},
+	/// The line was inserted into the list.
+	Inserted(Line),
+	/// The line was removed from the list.
+	Removed(Line),
+}
+
+// Find the indexes of the longest strictly increasing subsequence of values.
+fn longest_increasing_subsequence(values: &[usize]) -> Vec<bool> {
+	// index into values of the smallest tail of each subsequence length
+	let mut tails: Vec<usize> = vec![];
+	let mut previous: Vec<Option<usize>> = vec![None; values.len()];
+
+	for (index, value) in values.iter().enumerate() {
+		let length = tails.partition_point(|&tail| values[tail] < *value);
+		if length > 0 {
+			previous[index] = Some(tails[length - 1]);
+		}
+		if length == tails.len() {
+			tails.push(index);
+		}
+		else {
+			tails[length] = index;
+		}
+	}
+
+	let mut in_sequence = vec![false; values.len()];
+	let mut next = tails.last().copied();
+	while let Some(index) = next {
+		in_sequence[index] = true;
+		next = previous[index];
+	}
+	in_sequence
+}
+
+// Match each line to an original line, first by identical lines, then by lines referencing the same commit.
+fn match_lines(original_lines: &[Line], lines: &[Line]) -> Vec<Option<usize>> {
+	let original_texts: Vec<String> = original_lines.iter().map(Line::to_text).collect();
+	let mut used = vec![false; original_lines.len()];
+	let mut matches: Vec<Option<usize>> = lines
+		.iter()
+		.map(|line| {
+			let text = line.to_text();
+			let original_index = (0..original_lines.len()).find(|&i| !used[i] && original_texts[i] == text);
+			if let Some(i) = original_index {
+				used[i] = true;
+			}
+			original_index
+		})
+		.collect();
+
+	for (line, line_match) in lines.iter().zip(matches.iter_mut()) {
+		if line_match.is_some() || !line.has_reference() {
+			continue;
+		}
+		*line_match = (0..original_lines.len()).find(|&i| {
+			!used[i] && original_lines[i].has_reference() && original_lines[i].get_hash() == line.get_hash()
+		});
+		if let Some(i) = *line_match {
+			used[i] = true;
+		}
+	}
+	matches
+}
+
+pub(crate) fn diff_lines(original_lines: &[Line], lines: &[Line]) -> Vec<LineDiff> {
+	let matches = match_lines(original_lines, lines);
+	let matched_indexes: Vec<usize> = matches.iter().filter_map(|m| *m).collect();
+	let mut in_place = longest_increasing_subsequence(&matched_indexes).into_iter();
+
+	let mut removed: Vec<bool> = vec![true; original_lines.len()];
+	for original_index in &matched_indexes {
+		removed[*original_index] = false;
+	}
+
+	let mut diff = vec![];
+	let mut next_original_index = 0;
+	for (line, line_match) in lines.iter().zip(matches) {
+		let Some(original_index) = line_match
+		else {
+			diff.push(LineDiff::Inserted(line.clone()));
+			continue;
+		};
+		let moved = !in_place.next().unwrap_or(false);
+		if !moved {
+			// removed lines are shown before the next line that remains in its original position
+			for index in next_original_index..original_index {
+				if removed[index] {
+					diff.push(LineDiff::Removed(original_lines[index].clone()));
+				}
+			}
+			next_original_index = original_index + 1;
+		}
+		let original = &original_lines[original_index];
+		diff.push(if original.to_text() != line.to_text() {
+			LineDiff::Changed {
+				original: original.clone(),
+				line: line.clone(),
+				moved,
+			}
+		}
+		else if moved {
+			LineDiff::Moved(line.clone())
+		}
+		else {
+			LineDiff::Unchanged(line.clone())
+		});
+	}
+	for index in next_original_index..original_lines.len() {
+		if removed[index] {
+			diff.push(LineDiff::Removed(original_lines[index].clone()));
+		}
+	}
+	diff
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	fn create_lines(lines: &[&str]) -> Vec<Line> {
+		lines.iter().map(|line| Line::new(line).unwrap()).collect()
+	}
+
+	fn line(line: &str) -> Line {
+		Line::new(line).unwrap()
+	}
+
+	#[rstest]
+	#[case::empty(&[], vec![])]
+	#[case::increasing(&[0, 1, 2], vec![true, true, true])]
+	#[case::decreasing(&[2, 1, 0], vec![false, false, true])]
+	#[case::one_moved_up(&[2, 0, 1, 3], vec![false, true, true, true])]
+	#[case::one_moved_down(&[1, 2, 0, 3], vec![true, true, false, true])]
+	fn longest_increasing_subsequence_cases(#[case] values: &[usize], #[case] expected: Vec<bool>) {
+		assert_eq!(longest_increasing_subsequence(values), expected);
+	}
+
+	#[test]
+	fn diff_lines_unchanged() {
+		let lines = create_lines(&["pick aaa c1", "exec make"]);
+		assert_eq!(diff_lines(&lines, &lines), vec![
+			LineDiff::Unchanged(line("pick aaa c1")),
+			LineDiff::Unchanged(line("exec make"))
+		]);
+	}
+
+	#[test]
+	fn diff_lines_moved() {
+		let original = create_lines(&["pick aaa c1", "pick bbb c2", "pick ccc c3"]);
+		let lines = create_lines(&["pick ccc c3", "pick aaa c1", "pick bbb c2"]);
+		assert_eq!(diff_lines(&original, &lines), vec![
+			LineDiff::Moved(line("pick ccc c3")),
+			LineDiff::Unchanged(line("pick aaa c1")),
+			LineDiff::Unchanged(line("pick bbb c2"))
+		]);
+	}
+
+	#[test]
+	fn diff_lines_changed() {
+		let original = create_lines(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"]);
+		let lines = create_lines(&["pick aaa c1", "pick ccc c3", "pick ddd c4", "fixup bbb c2"]);
+		assert_eq!(diff_lines(&original, &lines), vec![
+			LineDiff::Unchanged(line("pick aaa c1")),
+			LineDiff::Unchanged(line("pick ccc c3")),
+			LineDiff::Unchanged(line("pick ddd c4")),
+			LineDiff::Changed {
+				original: line("pick bbb c2"),
+				line: line("fixup bbb c2"),
+				moved: true,
+			}
+		]);
+	}
+
+	#[test]
+	fn diff_lines_changed_in_place() {
+		let original = create_lines(&["pick aaa c1", "exec make"]);
+		let lines = create_lines(&["reword aaa c1", "exec make test"]);
+		assert_eq!(diff_lines(&original, &lines), vec![
+			LineDiff::Changed {
+				original: line("pick aaa c1"),
+				line: line("reword aaa c1"),
+				moved: false,
+			},
+			LineDiff::Inserted(line("exec make test")),
+			LineDiff::Removed(line("exec make"))
+		]);
+	}
+
+	#[test]
+	fn diff_lines_inserted_and_removed() {
+		let original = create_lines(&["pick aaa c1", "pick bbb c2", "pick ccc c3", "pick ddd c4"]);
+		let lines = create_lines(&["exec make", "pick aaa c1", "pick ccc c3", "break"]);
+		assert_eq!(diff_lines(&original, &lines), vec![
+			LineDiff::Inserted(line("exec make")),
+			LineDiff::Unchanged(line("pick aaa c1")),
+			LineDiff::Removed(line("pick bbb c2")),
+			LineDiff::Unchanged(line("pick ccc c3")),
+			LineDiff::Inserted(line("break")),
+			LineDiff::Removed(line("pick ddd c4"))
+		]);
+	}
+
+	#[test]
+	fn diff_lines_duplicate_lines() {
+		let original = create_lines(&["pick aaa c1", "exec make", "pick bbb c2", "exec make"]);
+		let lines = create_lines(&["pick aaa c1", "exec make", "pick bbb c2"]);
+		assert_eq!(diff_lines(&original, &lines), vec![
+			LineDiff::Unchanged(line("pick aaa c1")),
+			LineDiff::Unchanged(line("exec make")),
+			LineDiff::Unchanged(line("pick bbb c2")),
+			LineDiff::Removed(line("exec make"))
+		]);
+	}
+}
@@ -1,4 +1,4 @@
-use super::line::Line;
+use super::{line::Line, Action};
 
 pub(crate) fn swap_range_up(lines: &mut [Line], start_index: usize, end_index: usize) {
 	let range = if end_index <= start_index {
@@ -53,3 +53,91 @@ pub(crate) fn add_range(lines: &mut Vec<Line>, new_lines: &[Line], start_index:
 		lines.insert(index, new_lines[add_index].clone());
 	}
 }
+
+pub(crate) fn get_block_range(lines: &[Line], index: usize) -> Option<(usize, usize)> {
+	if index >= lines.len() {
+		return None;
+	}
+
+	let mut start_index = None;
+	for i in (0..=index).rev() {
+		let action = *lines[i].get_action();
+		if action == Action::Reset {
+			start_index = Some(i);
+			break;
+		}
+		// a label before the index ends a previous block, so the index is not within a block
+		if action == Action::Label && i != index {
+			return None;
+		}
+	}
+	let start = start_index?;
+
+	for (i, line) in lines.iter().enumerate().skip(index) {
+		let action = *line.get_action();
+		if action == Action::Label {
+			return Some((start, i));
+		}
+		if action == Action::Reset && i != start {
+			return None;
+		}
+	}
+	None
+}
+
+pub(crate) fn get_label_references(line: &Line) -> Vec<&str> {
+	match *line.get_action() {
+		Action::Reset => line.get_content().split_whitespace().take(1).collect(),
+		Action::Merge => {
+			let content = line.get_content();
+			let references = content.split(" # ").next().unwrap_or(content);
+			let mut labels = vec![];
+			let mut tokens = references.split_whitespace();
+			while let Some(token) = tokens.next() {
+				if token == "-C" || token == "-c" {
+					_ = tokens.next();
+				}
+				else if token != "#" {
+					labels.push(token);
+				}
+			}
+			labels
+		},
+		Action::Break
+		| Action::Drop
+		| Action::Edit
+		| Action::Exec
+		| Action::Fixup
+		| Action::Label
+		| Action::Noop
+		| Action::Pick
+		| Action::Reword
+		| Action::Squash
+		| Action::UpdateRef => vec![],
+	}
+}
+
+pub(crate) fn get_label(line: &Line) -> Option<&str> {
+	(line.get_action() == &Action::Label)
+		.then(|| line.get_content().split_whitespace().next())
+		.flatten()
+}
+
+pub(crate) fn are_labels_ordered(lines: &[Line]) -> bool {
+	let labels: Vec<&str> = lines.iter().filter_map(get_label).collect();
+
+	let mut defined_labels = vec![];
+	for line in lines {
+		if let Some(label) = get_label(line) {
+			defined_labels.push(label);
+		}
+		// only labels that are defined within the todo list need to be defined before use
+		if get_label_references(line)
+			.iter()
+			.any(|reference| labels.contains(reference) && !defined_labels.contains(reference))
+		{
+			return false;
+		}
+	}
+	true
+}
@@ -0,0 +1,166 @@
+use crate::{
+	errors::ValidationError,
+	utils::{get_label, get_label_references},
+	Action,
+	Line,
+};
+
+// reference names are case sensitive, so the `.lock` suffix is as well
+#[allow(clippy::case_sensitive_file_extension_comparisons)]
+fn is_valid_reference_name(name: &str) -> bool {
+	!name.is_empty()
+		&& name != "@"
+		&& !name.starts_with('/')
+		&& !name.ends_with('/')
+		&& !name.ends_with('.')
+		&& !name.contains("..")
+		&& !name.contains("//")
+		&& !name.contains("@{")
+		&& !name.chars().any(|c| c.is_ascii_control() || " ~^:?*[\\".contains(c))
+		&& name
+			.split('/')
+			.all(|component| !component.starts_with('.') && !component.ends_with(".lock"))
+}
+
+pub(crate) fn validate(lines: &[Line]) -> Vec<(usize, ValidationError)> {
+	let mut errors = vec![];
+	// matches git, where any action other than a drop allows for a following fixup or squash
+	let mut fixup_allowed = false;
+	let mut defined_labels = vec![];
+
+	for (index, line) in lines.iter().enumerate() {
+		let action = *line.get_action();
+		match action {
+			Action::Fixup | Action::Squash => {
+				if !fixup_allowed {
+					errors.push((index, ValidationError::MissingPreviousCommit(action)));
+				}
+			},
+			Action::Drop | Action::Noop => {},
+			Action::Break
+			| Action::Edit
+			| Action::Exec
+			| Action::Label
+			| Action::Merge
+			| Action::Pick
+			| Action::Reset
+			| Action::Reword
+			| Action::UpdateRef => fixup_allowed = true,
+		}
+
+		if action == Action::Exec && line.get_content().trim().is_empty() {
+			errors.push((index, ValidationError::EmptyExec));
+		}
+
+		if action == Action::UpdateRef && !is_valid_reference_name(line.get_content().trim()) {
+			errors.push((
+				index,
+				ValidationError::InvalidReferenceName(String::from(line.get_content().trim())),
+			));
+		}
+
+		for reference in get_label_references(line) {
+			// the root commit special value, used when rebasing onto the root
+			if reference.starts_with('[') {
+				continue;
+			}
+			if !defined_labels.contains(&reference) {
+				errors.push((index, ValidationError::UndefinedLabel(String::from(reference))));
+			}
+		}
+
+		if let Some(label) = get_label(line) {
+			defined_labels.push(label);
+		}
+	}
+	errors
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+
+	fn validate_lines(lines: &[&str]) -> Vec<(usize, ValidationError)> {
+		validate(&lines.iter().map(|l| Line::new(l).unwrap()).collect::<Vec<Line>>())
+	}
+
+	#[test]
+	fn valid() {
+		assert_eq!(
+			validate_lines(&[
+				"label onto",
+				"reset onto",
+				"pick aaa c1",
+				"fixup bbb c2",
+				"update-ref refs/heads/branch",
+				"label branch",
+				"reset [new root]",
+				"merge -C ccc branch # Merge",
+				"exec make test",
+			]),
+			vec![]
+		);
+	}
+
+	#[rstest]
+	#[case::fixup_first(&["fixup aaa c1", "pick bbb c2"], 0, Action::Fixup)]
+	#[case::squash_first(&["squash aaa c1", "pick bbb c2"], 0, Action::Squash)]
+	#[case::fixup_after_drop(&["drop aaa c1", "fixup bbb c2"], 1, Action::Fixup)]
+	fn missing_previous_commit(#[case] lines: &[&str], #[case] index: usize, #[case] action: Action) {
+		assert_eq!(validate_lines(lines), vec![(
+			index,
+			ValidationError::MissingPreviousCommit(action)
+		)]);
+	}
+
+	#[test]
+	fn fixup_after_exec() {
+		assert_eq!(validate_lines(&["exec make", "fixup aaa c1"]), vec![]);
+	}
+
+	#[rstest]
+	#[case::double_dot("refs/heads/a..b")]
+	#[case::space("refs/heads/a b")]
+	#[case::tilde("refs/heads/a~b")]
+	#[case::caret("refs/heads/a^b")]
+	#[case::colon("refs/heads/a:b")]
+	#[case::question("refs/heads/a?b")]
+	#[case::asterisk("refs/heads/a*b")]
+	#[case::bracket("refs/heads/a[b")]
+	#[case::backslash("refs/heads/a\\b")]
+	#[case::leading_slash("/refs/heads/a")]
+	#[case::trailing_slash("refs/heads/a/")]
+	#[case::double_slash("refs/heads//a")]
+	#[case::trailing_dot("refs/heads/a.")]
+	#[case::at_brace("refs/heads/a@{b")]
+	#[case::at("@")]
+	#[case::component_dot("refs/heads/.a")]
+	#[case::lock("refs/heads/a.lock")]
+	fn invalid_reference_name(#[case] reference: &str) {
+		let line = Line::new_update_ref(reference);
+		assert_eq!(validate(&[line]), vec![(
+			0,
+			ValidationError::InvalidReferenceName(String::from(reference))
+		)]);
+	}
+
+	#[test]
+	fn undefined_label() {
+		assert_eq!(
+			validate_lines(&["reset onto", "merge -C aaa feature # Merge", "label onto"]),
+			vec![
+				(0, ValidationError::UndefinedLabel(String::from("onto"))),
+				(1, ValidationError::UndefinedLabel(String::from("feature"))),
+			]
+		);
+	}
+
+	#[test]
+	fn empty_exec() {
+		let mut line = Line::new_exec("make");
+		line.edit_content(" ");
+		assert_eq!(validate(&[line]), vec![(0, ValidationError::EmptyExec)]);
+	}
+}
@@ -139,6 +139,7 @@ mod line_segment;
 mod render_context;
 mod render_slice;
 mod scroll_position;
+mod split_layout;
 #[cfg(all(feature = "testutils", not(tarpaulin_include)))]
 pub mod testutil;
 mod thread;
@@ -160,12 +161,18 @@ pub use self::{
 	view_data_updater::ViewDataUpdater,
 	view_line::ViewLine,
 };
-use self::{render_slice::RenderSlice, thread::ViewAction};
+use self::{
+	render_slice::RenderSlice,
+	split_layout::{pane_rows, PaneRow, SplitLayout},
+	thread::ViewAction,
+};
 
 const TITLE: &str = "Git Interactive Rebase Tool";
 const TITLE_SHORT: &str = "Git Rebase";
 const TITLE_HELP_INDICATOR_LABEL: &str = "Help: ";
 const SCROLLBAR_INDICATOR_CHARACTER: &str = "\u{2588}"; // "█"
+const HORIZONTAL_SEPARATOR_CHARACTER: &str = "\u{2500}"; // "─"
+const VERTICAL_SEPARATOR_CHARACTER: &str = "\u{2502}"; // "│"
 
 /// Represents a view.
 #[derive(Debug)]
@@ -174,6 +181,7 @@ pub struct View<C: Tui> {
 	display: Display<C>,
 	help_indicator_key: String,
 	last_render_version: u32,
+	last_secondary_render_version: u32,
 }
 
 impl<C: Tui> View<C> {
@@ -185,6 +193,7 @@ impl<C: Tui> View<C> {
 			display,
 			help_indicator_key: String::from(help_indicator_key),
 			last_render_version: u32::MAX,
+			last_secondary_render_version: u32::MAX,
 		}
 	}
 
@@ -213,10 +222,11 @@ impl<C: Tui> View<C> {
 	#[inline]
 	pub fn render(&mut self, render_slice: &RenderSlice) -> Result<()> {
 		let current_render_version = render_slice.get_version();
-		if self.last_render_version == current_render_version {
+		if self.last_render_version == current_render_version && self.last_secondary_render_version == u32::MAX {
 			return Ok(());
 		}
 		self.last_render_version = current_render_version;
+		self.last_secondary_render_version = u32::MAX;
 		let view_size = self.display.get_window_size();
 		let window_height = view_size.height();
 
@@ -287,6 +297,119 @@ impl<C: Tui> View<C> {
 		Ok(())
 	}
 
+	/// Render a primary and a secondary slice as two panes, arranged by the split layout. The title, when shown by the
+	/// primary slice, spans the full width of the view.
+	///
+	/// # Errors
+	/// Results in an error if there are errors with interacting with the terminal.
+	pub(crate) fn render_split(
+		&mut self,
+		render_slice: &RenderSlice,
+		secondary_render_slice: &RenderSlice,
+		layout: SplitLayout,
+	) -> Result<()> {
+		let current_render_version = render_slice.get_version();
+		let current_secondary_render_version = secondary_render_slice.get_version();
+		if self.last_render_version == current_render_version
+			&& self.last_secondary_render_version == current_secondary_render_version
+		{
+			return Ok(());
+		}
+		self.last_render_version = current_render_version;
+		self.last_secondary_render_version = current_secondary_render_version;
+		let view_size = self.display.get_window_size();
+		let window_width = view_size.width();
+		let window_height = view_size.height();
+		let (primary_width, primary_height) = layout.primary_size(window_width, window_height);
+		let (secondary_width, secondary_height) = layout.secondary_size(window_width, window_height);
+		let title_height = if render_slice.show_title() { 1 } else { 0 };
+
+		self.display.clear()?;
+
+		self.display.ensure_at_line_start()?;
+		if render_slice.show_title() {
+			self.draw_title(render_slice.show_help())?;
+			self.display.next_line()?;
+		}
+
+		match layout {
+			SplitLayout::SideBySide => {
+				let pane_height = window_height - title_height;
+				let primary_rows = pane_rows(render_slice, pane_height);
+				let mut secondary_rows = pane_rows(secondary_render_slice, secondary_height).into_iter();
+				for primary_row in &primary_rows {
+					self.display.ensure_at_line_start()?;
+					self.draw_pane_row(primary_row, primary_width, true)?;
+					self.draw_separator(VERTICAL_SEPARATOR_CHARACTER)?;
+					self.draw_pane_row(
+						&secondary_rows.next().unwrap_or(PaneRow::Spacer),
+						secondary_width,
+						false,
+					)?;
+					self.display.next_line()?;
+				}
+			},
+			SplitLayout::Stacked => {
+				for row in &pane_rows(render_slice, primary_height - title_height) {
+					self.display.ensure_at_line_start()?;
+					self.draw_pane_row(row, primary_width, false)?;
+					self.display.next_line()?;
+				}
+				self.display.ensure_at_line_start()?;
+				self.draw_separator(HORIZONTAL_SEPARATOR_CHARACTER.repeat(window_width).as_str())?;
+				self.display.next_line()?;
+				for row in &pane_rows(secondary_render_slice, secondary_height) {
+					self.display.ensure_at_line_start()?;
+					self.draw_pane_row(row, secondary_width, false)?;
+					self.display.next_line()?;
+				}
+			},
+		}
+		self.display.refresh()?;
+		Ok(())
+	}
+
+	fn draw_pane_row(&mut self, row: &PaneRow<'_>, width: usize, pad: bool) -> Result<()> {
+		let length = match *row {
+			PaneRow::Line(line, scroll_bar) => {
+				self.draw_view_line(line)?;
+				let length = line.get_segments().iter().map(LineSegment::get_length).sum::<usize>();
+				if let Some(is_indicator) = scroll_bar {
+					self.display
+						.draw_str(" ".repeat(width.saturating_sub(length + 1)).as_str())?;
+					self.display.color(DisplayColor::Normal, true)?;
+					self.display.draw_str(
+						if is_indicator {
+							SCROLLBAR_INDICATOR_CHARACTER
+						}
+						else {
+							" "
+						},
+					)?;
+					self.display.color(DisplayColor::Normal, false)?;
+					return Ok(());
+				}
+				length
+			},
+			PaneRow::Spacer => {
+				self.display.draw_str(self.character_vertical_spacing.as_str())?;
+				self.character_vertical_spacing.chars().count()
+			},
+		};
+		if pad && width > length {
+			self.display.draw_str(" ".repeat(width - length).as_str())?;
+		}
+		Ok(())
+	}
+
+	fn draw_separator(&mut self, separator: &str) -> Result<()> {
+		self.display.color(DisplayColor::Normal, false)?;
+		self.display.set_style(true, false, false)?;
+		self.display.draw_str(separator)?;
+		self.display.set_style(false, false, false)?;
+		Ok(())
+	}
+
 	fn draw_view_line(&mut self, line: &ViewLine) -> Result<()> {
 		for segment in line.get_segments() {
 			self.display.color(segment.get_color(), line.get_selected())?;
@@ -1,3 +1,5 @@
+use crate::split_layout::SplitLayout;
+
 const MINIMUM_WINDOW_HEIGHT: usize = 5; // title + pad top + line + pad bottom + help
 const MINIMUM_COMPACT_WINDOW_WIDTH: usize = 20; // ">s ccc mmmmmmmmmmmmm".len()
 const MINIMUM_FULL_WINDOW_WIDTH: usize = 34; // " > squash cccccccc mmmmmmmmmmmmm %".len()
@@ -65,6 +67,20 @@ impl RenderContext {
 	pub const fn is_window_too_small(&self) -> bool {
 		!self.is_minimum_view_width() || !self.is_minimum_view_height()
 	}
+
+	/// Get the context of the primary pane, when the view is split into two panes. If the terminal window is too small
+	/// to be split, the view is not split, and the context is unchanged.
+	#[must_use]
+	#[inline]
+	pub const fn primary_pane(&self) -> Self {
+		if let Some(layout) = SplitLayout::new(self.width, self.height) {
+			let (width, height) = layout.primary_size(self.width, self.height);
+			Self::new(width, height)
+		}
+		else {
+			*self
+		}
+	}
 }
 
 #[cfg(test)]
@@ -114,4 +130,22 @@ mod tests {
 		};
 		assert!(!context.is_window_too_small());
 	}
+
+	#[test]
+	fn primary_pane_side_by_side() {
+		let context = RenderContext::new(200, 50).primary_pane();
+		assert_eq!((context.width(), context.height()), (99, 50));
+	}
+
+	#[test]
+	fn primary_pane_stacked() {
+		let context = RenderContext::new(80, 50).primary_pane();
+		assert_eq!((context.width(), context.height()), (80, 24));
+	}
+
+	#[test]
+	fn primary_pane_too_small_to_split() {
+		let context = RenderContext::new(80, 10).primary_pane();
+		assert_eq!((context.width(), context.height()), (80, 10));
+	}
 }
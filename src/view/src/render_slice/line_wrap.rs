@@ -0,0 +1,296 @@
+use std::mem;
+
+use display::DisplayColor;
+
+use crate::{LineSegment, ViewLine};
+
+const WRAP_MARKER_CHARACTER: &str = "↪";
+
+// the visual rows of the body lines of a view data, when wrapped to fit a view
+#[derive(Debug)]
+pub(super) struct WrapLayout {
+	line_rows: Vec<usize>,
+	name: String,
+	version: u32,
+	view_height: usize,
+	view_width: usize,
+	width: usize,
+}
+
+impl WrapLayout {
+	pub(super) fn new(
+		name: &str,
+		version: u32,
+		view_lines: &[ViewLine],
+		view_width: usize,
+		view_height: usize,
+	) -> Self {
+		let mut width = view_width;
+		let mut line_rows = Self::wrap_lines(view_lines, width);
+		// the scroll bar takes the last column when the rows do not fit within the view
+		if line_rows.last().is_some_and(|rows| *rows > view_height) && view_width > 0 {
+			width -= 1;
+			line_rows = Self::wrap_lines(view_lines, width);
+		}
+		Self {
+			line_rows,
+			name: String::from(name),
+			version,
+			view_height,
+			view_width,
+			width,
+		}
+	}
+
+	fn wrap_lines(view_lines: &[ViewLine], width: usize) -> Vec<usize> {
+		let mut line_rows = Vec::with_capacity(view_lines.len() + 1);
+		let mut rows = 0;
+		line_rows.push(rows);
+		for line in view_lines {
+			rows += wrap_line(line, width).len();
+			line_rows.push(rows);
+		}
+		line_rows
+	}
+
+	pub(super) fn is_current(&self, name: &str, version: u32, view_width: usize, view_height: usize) -> bool {
+		self.name == name && self.version == version && self.view_width == view_width && self.view_height == view_height
+	}
+
+	pub(super) const fn width(&self) -> usize {
+		self.width
+	}
+
+	pub(super) fn rows_length(&self) -> usize {
+		self.line_rows.last().copied().unwrap_or(0)
+	}
+
+	// the first row of the line, or the row after the last line when the line is out of range
+	pub(super) fn line_start_row(&self, line_index: usize) -> usize {
+		self.line_rows
+			.get(line_index)
+			.copied()
+			.unwrap_or_else(|| self.rows_length())
+	}
+
+	// the last row of the line
+	pub(super) fn line_end_row(&self, line_index: usize) -> usize {
+		self.line_start_row(line_index + 1).saturating_sub(1)
+	}
+
+	// the line that the row belongs to, rows past the end map to the last line
+	pub(super) fn line_at_row(&self, row: usize) -> usize {
+		self.line_rows
+			.partition_point(|start| *start <= row)
+			.saturating_sub(1)
+			.min(self.line_rows.len().saturating_sub(2))
+	}
+}
+
+// Split a line into the rows needed to show it within the width. Continuation rows are indented by the width of the
+// pinned segments, so that they stay aligned with the content, and start with a marker.
+pub(super) fn wrap_line(line: &ViewLine, width: usize) -> Vec<ViewLine> {
+	let segments = line.get_segments();
+	let pinned_segments = line.get_number_of_pinned_segment();
+	let pinned_width = segments
+		.iter()
+		.take(pinned_segments)
+		.map(LineSegment::get_length)
+		.sum::<usize>();
+	// the continuation needs room for the marker and at least one column of content
+	let indent = if pinned_width + 2 <= width { pinned_width } else { 0 };
+	let continuation_start = indent + 1;
+	let can_wrap = width > continuation_start;
+
+	let mut rows = vec![];
+	let mut row = vec![];
+	let mut cursor = 0;
+	'segments: for segment in segments {
+		let mut offset = 0;
+		loop {
+			let partial = segment.get_partial_segment(offset, width - cursor);
+			if partial.get_length() > 0 {
+				row.push(LineSegment::new_with_color_and_style(
+					partial.get_content(),
+					segment.get_color(),
+					segment.is_dimmed(),
+					segment.is_underlined(),
+					segment.is_reversed(),
+				));
+				cursor += partial.get_length();
+				offset += partial.get_length();
+			}
+			if offset >= segment.get_length() {
+				break;
+			}
+			// too narrow to wrap, or a grapheme wider than an empty continuation row, so the rest is cut off
+			if !can_wrap || (cursor <= continuation_start && !rows.is_empty()) {
+				break 'segments;
+			}
+			rows.push((mem::take(&mut row), cursor));
+			if indent > 0 {
+				row.push(LineSegment::new(" ".repeat(indent).as_str()));
+			}
+			row.push(LineSegment::new_with_color(
+				WRAP_MARKER_CHARACTER,
+				DisplayColor::IndicatorColor,
+			));
+			cursor = continuation_start;
+		}
+	}
+	rows.push((row, cursor));
+
+	rows.into_iter()
+		.enumerate()
+		.map(|(index, (mut row_segments, row_width))| {
+			if row_width < width {
+				if let Some(padding) = line.get_padding().as_ref() {
+					row_segments.push(LineSegment::new_with_color_and_style(
+						padding.get_content().repeat(width - row_width).as_str(),
+						padding.get_color(),
+						padding.is_dimmed(),
+						padding.is_underlined(),
+						padding.is_reversed(),
+					));
+				}
+			}
+			let pinned = if index == 0 { pinned_segments } else { 0 };
+			ViewLine::new_with_pinned_segments(row_segments, pinned).set_selected(line.get_selected())
+		})
+		.collect()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::testutil::render_view_line;
+
+	fn render_rows(line: &ViewLine, width: usize) -> Vec<String> {
+		wrap_line(line, width)
+			.iter()
+			.map(|row| render_view_line(row, None))
+			.collect()
+	}
+
+	#[test]
+	fn wrap_line_short() {
+		assert_eq!(render_rows(&ViewLine::from("abc"), 5), vec!["{Normal}abc"]);
+	}
+
+	#[test]
+	fn wrap_line_exact() {
+		assert_eq!(render_rows(&ViewLine::from("abcde"), 5), vec!["{Normal}abcde"]);
+	}
+
+	#[test]
+	fn wrap_line_long() {
+		assert_eq!(render_rows(&ViewLine::from("abcdefghijk"), 5), vec![
+			"{Normal}abcde",
+			"{IndicatorColor}↪{Normal}fghi",
+			"{IndicatorColor}↪{Normal}jk",
+		]);
+	}
+
+	#[test]
+	fn wrap_line_across_segments() {
+		let line = ViewLine::from(vec![
+			LineSegment::new("abc"),
+			LineSegment::new_with_color("defg", DisplayColor::IndicatorColor),
+		]);
+		assert_eq!(render_rows(&line, 5), vec![
+			"{Normal}abc{IndicatorColor}de",
+			"{IndicatorColor}↪fg",
+		]);
+	}
+
+	#[test]
+	fn wrap_line_pinned_segments_indented() {
+		let line = ViewLine::new_with_pinned_segments(vec![LineSegment::new("12 "), LineSegment::new("abcdefgh")], 1);
+		assert_eq!(render_rows(&line, 7), vec![
+			"{Normal}12 abcd",
+			"{Normal}   {IndicatorColor}↪{Normal}efg",
+			"{Normal}   {IndicatorColor}↪{Normal}h",
+		]);
+	}
+
+	#[test]
+	fn wrap_line_pinned_segments_too_wide_to_indent() {
+		let line = ViewLine::new_with_pinned_segments(vec![LineSegment::new("1234 "), LineSegment::new("abc")], 1);
+		assert_eq!(render_rows(&line, 6), vec![
+			"{Normal}1234 a",
+			"{IndicatorColor}↪{Normal}bc",
+		]);
+	}
+
+	#[test]
+	fn wrap_line_wide_graphemes() {
+		assert_eq!(render_rows(&ViewLine::from("ab🙂c"), 3), vec![
+			"{Normal}ab",
+			"{IndicatorColor}↪{Normal}🙂",
+			"{IndicatorColor}↪{Normal}c",
+		]);
+	}
+
+	#[test]
+	fn wrap_line_with_padding() {
+		let line = ViewLine::from("abcdefg").set_padding('-');
+		assert_eq!(render_rows(&line, 5), vec![
+			"{Normal}abcde",
+			"{IndicatorColor}↪{Normal}fg--",
+		]);
+	}
+
+	#[test]
+	fn wrap_line_selected() {
+		let rows = wrap_line(&ViewLine::from("abcdefg").set_selected(true), 5);
+		assert!(rows.iter().all(ViewLine::get_selected));
+	}
+
+	#[test]
+	fn wrap_line_too_narrow() {
+		assert_eq!(render_rows(&ViewLine::from("abc"), 1), vec!["{Normal}a"]);
+	}
+
+	#[test]
+	fn wrap_layout() {
+		let lines = [
+			ViewLine::from("abcdefghijk"),
+			ViewLine::from("ab"),
+			ViewLine::from("abcdefg"),
+		];
+		let layout = WrapLayout::new("name", 1, &lines, 5, 10);
+		assert_eq!(layout.width(), 5);
+		assert_eq!(layout.rows_length(), 6);
+		assert_eq!(layout.line_start_row(0), 0);
+		assert_eq!(layout.line_start_row(1), 3);
+		assert_eq!(layout.line_start_row(2), 4);
+		assert_eq!(layout.line_start_row(3), 6);
+		assert_eq!(layout.line_end_row(0), 2);
+		assert_eq!(layout.line_end_row(2), 5);
+		assert_eq!(layout.line_at_row(0), 0);
+		assert_eq!(layout.line_at_row(2), 0);
+		assert_eq!(layout.line_at_row(3), 1);
+		assert_eq!(layout.line_at_row(5), 2);
+		assert_eq!(layout.line_at_row(10), 2);
+		assert!(layout.is_current("name", 1, 5, 10));
+		assert!(!layout.is_current("other", 1, 5, 10));
+		assert!(!layout.is_current("name", 2, 5, 10));
+		assert!(!layout.is_current("name", 1, 6, 10));
+		assert!(!layout.is_current("name", 1, 5, 11));
+	}
+
+	#[test]
+	fn wrap_layout_with_scroll_bar() {
+		let lines = [ViewLine::from("abcde"), ViewLine::from("abcde")];
+		let layout = WrapLayout::new("name", 1, &lines, 5, 1);
+		assert_eq!(layout.width(), 4);
+		assert_eq!(layout.rows_length(), 4);
+	}
+
+	#[test]
+	fn wrap_layout_empty() {
+		let layout = WrapLayout::new("name", 1, &[], 5, 10);
+		assert_eq!(layout.rows_length(), 0);
+		assert_eq!(layout.line_at_row(0), 0);
+	}
+}
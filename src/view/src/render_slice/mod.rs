@@ -1,3 +1,4 @@
+mod line_wrap;
 mod render_action;
 
 #[cfg(test)]
@@ -8,11 +9,12 @@ use std::{
 	mem,
 };
 
-pub(crate) use render_action::RenderAction;
-
+use self::line_wrap::{wrap_line, WrapLayout};
+pub(crate) use self::render_action::RenderAction;
 use super::{scroll_position::ScrollPosition, LineSegment, ViewData, ViewLine};
 
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct RenderSlice {
 	actions: VecDeque<RenderAction>,
 	height: usize,
@@ -22,7 +24,8 @@ pub struct RenderSlice {
 	lines_trailing_count: usize,
 	padding_height: usize,
 	scroll_position: ScrollPosition,
-	scroll_position_cache: HashMap<String, ScrollPosition>,
+	scroll_position_cache: HashMap<String, (ScrollPosition, bool)>,
+	scroll_to_line: Option<usize>,
 	should_show_scrollbar: bool,
 	show_help: bool,
 	show_title: bool,
@@ -30,6 +33,9 @@ pub struct RenderSlice {
 	view_data_name: String,
 	view_data_version: u32,
 	width: usize,
+	// if the scroll position counts wrapped rows, rather than lines
+	wrap: bool,
+	wrap_layout: Option<WrapLayout>,
 }
 
 impl RenderSlice {
@@ -44,6 +50,7 @@ impl RenderSlice {
 			padding_height: 0,
 			scroll_position: ScrollPosition::new(),
 			scroll_position_cache: HashMap::new(),
+			scroll_to_line: None,
 			should_show_scrollbar: false,
 			show_help: false,
 			show_title: false,
@@ -51,6 +58,8 @@ impl RenderSlice {
 			view_data_name: String::new(),
 			view_data_version: 0,
 			width: 0,
+			wrap: false,
+			wrap_layout: None,
 		}
 	}
 
@@ -86,6 +95,10 @@ impl RenderSlice {
 		self.actions.push_back(RenderAction::ScrollBottom);
 	}
 
+	pub fn record_scroll_to(&mut self, line_index: usize) {
+		self.actions.push_back(RenderAction::ScrollTo(line_index));
+	}
+
 	pub fn record_resize(&mut self, width: usize, height: usize) {
 		self.actions.push_back(RenderAction::Resize(width, height));
 	}
@@ -108,6 +121,9 @@ impl RenderSlice {
 				RenderAction::ScrollBottom => self.scroll_position.scroll_bottom(),
 				RenderAction::PageUp => self.scroll_position.page_up(),
 				RenderAction::PageDown => self.scroll_position.page_down(),
+				// wrapped rows are only known after the rebuild, so the scroll is delayed until then
+				RenderAction::ScrollTo(line_index) if view_data.wrap() => self.scroll_to_line = Some(line_index),
+				RenderAction::ScrollTo(line_index) => self.scroll_position.scroll_to(line_index),
 				RenderAction::Resize(width, height) => self.set_size(width, height),
 			}
 		}
@@ -171,6 +187,24 @@ impl RenderSlice {
 		slope.mul_add(value - input_start, output_start).round() as usize
 	}
 
+	pub(crate) fn get_top_line_index(&self) -> usize {
+		self.get_line_index_at_scroll_row(self.scroll_position.get_top_position())
+	}
+
+	pub(crate) fn get_line_index_at_row(&self, row: usize) -> Option<usize> {
+		let body_start = if self.show_title { 1 } else { 0 } + self.lines_leading_count;
+		let body_length = self.lines.len() - self.lines_leading_count - self.lines_trailing_count;
+
+		if row < body_start || row >= body_start + body_length {
+			return None;
+		}
+		Some(self.get_line_index_at_scroll_row(self.scroll_position.get_top_position() + row - body_start))
+	}
+
+	fn get_line_index_at_scroll_row(&self, row: usize) -> usize {
+		self.wrap_layout.as_ref().map_or(row, |layout| layout.line_at_row(row))
+	}
+
 	pub(crate) const fn show_title(&self) -> bool {
 		self.show_title
 	}
@@ -225,19 +259,21 @@ impl RenderSlice {
 	fn set_active_scroll_position(&mut self, view_data: &ViewData) {
 		let name = view_data.get_name();
 		if name != self.view_data_name {
-			let previous_scroll_position = mem::replace(
-				&mut self.scroll_position,
-				self.scroll_position_cache
-					.remove(&String::from(name))
-					.unwrap_or_else(ScrollPosition::new),
-			);
-			_ = self
+			let (scroll_position, wrap) = self
 				.scroll_position_cache
-				.insert(String::from(self.view_data_name.as_str()), previous_scroll_position);
+				.remove(&String::from(name))
+				.unwrap_or_else(|| (ScrollPosition::new(), view_data.wrap()));
+			let previous_scroll_position = mem::replace(&mut self.scroll_position, scroll_position);
+			let previous_wrap = mem::replace(&mut self.wrap, wrap);
+			_ = self.scroll_position_cache.insert(
+				String::from(self.view_data_name.as_str()),
+				(previous_scroll_position, previous_wrap),
+			);
 			let version = view_data.get_scroll_version();
 			if self.scroll_position.get_version() != version || !view_data.retain_scroll_position() {
 				self.scroll_position.reset();
 				self.scroll_position.set_version(version);
+				self.wrap = view_data.wrap();
 			}
 			self.update_scroll_position_size();
 		}
@@ -264,7 +300,12 @@ impl RenderSlice {
 	fn rebuild(&mut self, view_data: &ViewData) {
 		let leading_lines_length = view_data.get_leading_lines().len();
 		let trailing_lines_length = view_data.get_trailing_lines().len();
-		let lines_length = view_data.get_lines().len();
+		self.update_wrap_layout(view_data);
+		// when wrapping, the scroll position and scroll bar count rows rather than lines
+		let lines_length = self
+			.wrap_layout
+			.as_ref()
+			.map_or_else(|| view_data.get_lines().len(), WrapLayout::rows_length);
 
 		self.version += 1;
 		self.view_data_name = String::from(view_data.get_name());
@@ -274,9 +315,23 @@ impl RenderSlice {
 		self.should_show_scrollbar =
 			self.padding_height < self.height && lines_length > (self.height - self.padding_height);
 
+		if let Some(line_index) = self.scroll_to_line.take() {
+			self.scroll_position.scroll_to(
+				self.wrap_layout
+					.as_ref()
+					.map_or(line_index, |layout| layout.line_start_row(line_index)),
+			);
+		}
 		self.scroll_position.set_lines_length(lines_length);
 		if let Some(row) = view_data.get_visible_row().as_ref() {
-			self.scroll_position.ensure_line_visible(*row);
+			if let Some(layout) = self.wrap_layout.as_ref() {
+				// show as much of the line as possible, while keeping the start of the line visible
+				self.scroll_position.ensure_line_visible(layout.line_end_row(*row));
+				self.scroll_position.ensure_line_visible(layout.line_start_row(*row));
+			}
+			else {
+				self.scroll_position.ensure_line_visible(*row);
+			}
 		}
 
 		let (leading_lines_end, max_leading_line_length) = if leading_lines_length == 0 {
@@ -362,26 +417,68 @@ impl RenderSlice {
 		self.lines_leading_count = leading_lines_end;
 		self.lines_trailing_count = trailing_lines_end;
 		self.lines_count = lines_length;
-		self.scroll_position.set_max_line_length(
-			max_line_length
-				.max(max_leading_line_length)
-				.max(max_trailing_line_length),
-		);
-		if let Some(column) = view_data.get_visible_column().as_ref() {
-			self.scroll_position.ensure_column_visible(*column);
+		if self.wrap_layout.is_some() {
+			// wrapped lines always fit the width, so there is nothing to scroll horizontally
+			self.scroll_position.set_max_line_length(0);
+		}
+		else {
+			self.scroll_position.set_max_line_length(
+				max_line_length
+					.max(max_leading_line_length)
+					.max(max_trailing_line_length),
+			);
+			if let Some(column) = view_data.get_visible_column().as_ref() {
+				self.scroll_position.ensure_column_visible(*column);
+			}
 		}
 
 		self.lines.clear();
 		self.push_lines(view_data.get_leading_lines(), 0, leading_lines_end, false);
-		self.push_lines(
-			view_data.get_lines(),
-			lines_start,
-			lines_end,
-			self.should_show_scrollbar,
-		);
+		if self.wrap_layout.is_some() {
+			self.push_wrapped_lines(view_data.get_lines(), lines_start, lines_end);
+		}
+		else {
+			self.push_lines(
+				view_data.get_lines(),
+				lines_start,
+				lines_end,
+				self.should_show_scrollbar,
+			);
+		}
 		self.push_lines(view_data.get_trailing_lines(), 0, trailing_lines_end, false);
 	}
 
+	// Keep the wrapped rows of the view data current, and move the scroll position to the same line when wrapping is
+	// toggled, since the scroll position counts rows while wrapping and lines otherwise.
+	fn update_wrap_layout(&mut self, view_data: &ViewData) {
+		let wrap = view_data.wrap();
+		if !wrap && !self.wrap {
+			self.wrap_layout = None;
+			return;
+		}
+
+		let name = view_data.get_name();
+		let version = view_data.get_version();
+		let view_height = self.height.saturating_sub(self.padding_height);
+		let layout = match self.wrap_layout.take() {
+			Some(layout) if layout.is_current(name, version, self.width, view_height) => layout,
+			_ => WrapLayout::new(name, version, view_data.get_lines(), self.width, view_height),
+		};
+
+		if wrap != self.wrap {
+			let top = self.scroll_position.get_top_position();
+			let top_wrapped = if wrap {
+				layout.line_start_row(top)
+			}
+			else {
+				layout.line_at_row(top)
+			};
+			self.scroll_position.scroll_to(top_wrapped);
+			self.wrap = wrap;
+		}
+		self.wrap_layout = wrap.then_some(layout);
+	}
+
 	fn calculate_max_line_length(view_lines: &[ViewLine], start: usize, length: usize) -> usize {
 		view_lines
 			.iter()
@@ -399,6 +496,22 @@ impl RenderSlice {
 			})
 	}
 
+	fn push_wrapped_lines(&mut self, view_lines: &[ViewLine], start_row: usize, rows: usize) {
+		if let Some(layout) = self.wrap_layout.as_ref() {
+			let first_line = layout.line_at_row(start_row);
+			let skip = start_row.saturating_sub(layout.line_start_row(first_line));
+			let width = layout.width();
+			self.lines.extend(
+				view_lines
+					.iter()
+					.skip(first_line)
+					.flat_map(|line| wrap_line(line, width))
+					.skip(skip)
+					.take(rows),
+			);
+		}
+	}
+
 	fn push_lines(&mut self, view_lines: &[ViewLine], start: usize, end: usize, scroll_bar: bool) {
 		let window_width = if scroll_bar && self.width > 0 {
 			self.width - 1
@@ -8,5 +8,6 @@ pub(crate) enum RenderAction {
 	ScrollBottom,
 	PageUp,
 	PageDown,
+	ScrollTo(usize),
 	Resize(usize, usize),
 }
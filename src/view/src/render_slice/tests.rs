@@ -254,6 +254,48 @@ fn scroll_bottom_action() {
 	]);
 }
 
+#[test]
+fn scroll_to_action() {
+	let view_data = create_view_data(2, 10, 2);
+	let mut render_slice = create_render_slice(100, 8, &view_data);
+	render_slice.record_scroll_to(3);
+	render_slice.sync_view_data(&view_data);
+	assert_rendered(&render_slice, &[
+		"{LEADING}",
+		"{Normal}L(1)",
+		"{Normal}L(2)",
+		"{BODY}",
+		"{Normal}B(4)",
+		"{Normal}B(5)",
+		"{Normal}B(6)",
+		"{Normal}B(7)",
+		"{TRAILING}",
+		"{Normal}T(1)",
+		"{Normal}T(2)",
+	]);
+}
+
+#[test]
+fn scroll_to_action_past_end() {
+	let view_data = create_view_data(2, 10, 2);
+	let mut render_slice = create_render_slice(100, 8, &view_data);
+	render_slice.record_scroll_to(9);
+	render_slice.sync_view_data(&view_data);
+	assert_rendered(&render_slice, &[
+		"{LEADING}",
+		"{Normal}L(1)",
+		"{Normal}L(2)",
+		"{BODY}",
+		"{Normal}B(7)",
+		"{Normal}B(8)",
+		"{Normal}B(9)",
+		"{Normal}B(10)",
+		"{TRAILING}",
+		"{Normal}T(1)",
+		"{Normal}T(2)",
+	]);
+}
+
 #[test]
 fn resize_action_zero_width() {
 	let view_data = create_view_data(0, 3, 0);
@@ -1263,3 +1305,186 @@ fn calculate_max_line_length_ignore_pinned() {
 	];
 	assert_eq!(RenderSlice::calculate_max_line_length(&view_lines, 0, 4), 16);
 }
+
+#[test]
+fn get_line_index_at_row() {
+	let view_data = create_view_data(2, 10, 2);
+	let mut render_slice = create_render_slice(100, 8, &view_data);
+	render_slice.record_scroll_down();
+	render_slice.sync_view_data(&view_data);
+	assert_eq!(render_slice.get_line_index_at_row(1), None);
+	assert_eq!(render_slice.get_line_index_at_row(2), Some(1));
+	assert_eq!(render_slice.get_line_index_at_row(5), Some(4));
+	assert_eq!(render_slice.get_line_index_at_row(6), None);
+}
+
+#[test]
+fn get_line_index_at_row_with_title() {
+	let mut view_data = create_view_data(0, 3, 0);
+	view_data.update_view_data(|updater| updater.set_show_title(true));
+	let render_slice = create_render_slice(100, 8, &view_data);
+	assert_eq!(render_slice.get_line_index_at_row(0), None);
+	assert_eq!(render_slice.get_line_index_at_row(1), Some(0));
+	assert_eq!(render_slice.get_line_index_at_row(3), Some(2));
+	assert_eq!(render_slice.get_line_index_at_row(4), None);
+}
+
+fn create_wrapped_view_data() -> ViewData {
+	ViewData::new(|updater| {
+		updater.set_wrap(true);
+		updater.push_line(ViewLine::from("abcdefghijk"));
+		updater.push_line(ViewLine::from("ab"));
+		updater.push_line(ViewLine::from("abcdefg"));
+	})
+}
+
+#[test]
+fn wrap_lines() {
+	let view_data = create_wrapped_view_data();
+	let render_slice = create_render_slice(5, 10, &view_data);
+	assert_rendered(&render_slice, &[
+		"{BODY}",
+		"{Normal}abcde",
+		"{IndicatorColor}↪{Normal}fghi",
+		"{IndicatorColor}↪{Normal}jk",
+		"{Normal}ab",
+		"{Normal}abcde",
+		"{IndicatorColor}↪{Normal}fg",
+	]);
+	assert!(!render_slice.should_show_scroll_bar());
+}
+
+#[test]
+fn wrap_lines_with_scroll_bar() {
+	let view_data = create_wrapped_view_data();
+	let render_slice = create_render_slice(6, 3, &view_data);
+	assert_rendered(&render_slice, &[
+		"{BODY}",
+		"{Normal}abcde",
+		"{IndicatorColor}↪{Normal}fghi",
+		"{IndicatorColor}↪{Normal}jk",
+	]);
+	assert!(render_slice.should_show_scroll_bar());
+}
+
+#[test]
+fn wrap_scroll_down_by_row() {
+	let view_data = create_wrapped_view_data();
+	let mut render_slice = create_render_slice(5, 3, &view_data);
+	render_slice.record_scroll_down();
+	render_slice.sync_view_data(&view_data);
+	assert_rendered(&render_slice, &[
+		"{BODY}",
+		"{IndicatorColor}↪{Normal}efg",
+		"{IndicatorColor}↪{Normal}hij",
+		"{IndicatorColor}↪{Normal}k",
+	]);
+	assert_eq!(render_slice.get_top_line_index(), 0);
+	assert_eq!(render_slice.get_line_index_at_row(0), Some(0));
+	assert_eq!(render_slice.get_line_index_at_row(2), Some(0));
+	assert_eq!(render_slice.get_line_index_at_row(3), None);
+	render_slice.record_scroll_down();
+	render_slice.sync_view_data(&view_data);
+	assert_eq!(render_slice.get_line_index_at_row(2), Some(1));
+}
+
+#[test]
+fn wrap_scroll_bottom() {
+	let view_data = create_wrapped_view_data();
+	let mut render_slice = create_render_slice(5, 3, &view_data);
+	render_slice.record_scroll_bottom();
+	render_slice.sync_view_data(&view_data);
+	assert_rendered(&render_slice, &[
+		"{BODY}",
+		"{Normal}ab",
+		"{Normal}abcd",
+		"{IndicatorColor}↪{Normal}efg",
+	]);
+	assert_eq!(render_slice.get_top_line_index(), 1);
+}
+
+#[test]
+fn wrap_scroll_to_line() {
+	let view_data = create_wrapped_view_data();
+	let mut render_slice = create_render_slice(5, 3, &view_data);
+	render_slice.record_scroll_to(2);
+	render_slice.sync_view_data(&view_data);
+	assert_rendered(&render_slice, &[
+		"{BODY}",
+		"{Normal}ab",
+		"{Normal}abcd",
+		"{IndicatorColor}↪{Normal}efg",
+	]);
+}
+
+#[test]
+fn wrap_ignores_scroll_right() {
+	let view_data = create_wrapped_view_data();
+	let mut render_slice = create_render_slice(5, 10, &view_data);
+	render_slice.record_scroll_right();
+	render_slice.sync_view_data(&view_data);
+	assert_rendered(&render_slice, &[
+		"{BODY}",
+		"{Normal}abcde",
+		"{IndicatorColor}↪{Normal}fghi",
+		"{IndicatorColor}↪{Normal}jk",
+		"{Normal}ab",
+		"{Normal}abcde",
+		"{IndicatorColor}↪{Normal}fg",
+	]);
+}
+
+#[test]
+fn wrap_ensure_row_visible() {
+	let mut view_data = create_wrapped_view_data();
+	view_data.update_view_data(|updater| updater.ensure_line_visible(2));
+	let render_slice = create_render_slice(5, 2, &view_data);
+	assert_rendered(&render_slice, &[
+		"{BODY}",
+		"{Normal}abcd",
+		"{IndicatorColor}↪{Normal}efg",
+	]);
+}
+
+#[test]
+fn wrap_toggle_retains_top_line() {
+	let mut view_data = create_wrapped_view_data();
+	view_data.update_view_data(|updater| {
+		updater.set_wrap(false);
+		updater.push_line(ViewLine::from("x"));
+		updater.push_line(ViewLine::from("y"));
+	});
+	let mut render_slice = create_render_slice(5, 2, &view_data);
+	render_slice.record_scroll_down();
+	render_slice.sync_view_data(&view_data);
+	assert_eq!(render_slice.get_top_line_index(), 1);
+
+	view_data.update_view_data(|updater| updater.set_wrap(true));
+	render_slice.sync_view_data(&view_data);
+	assert_eq!(render_slice.get_top_line_index(), 1);
+	assert_rendered(&render_slice, &["{BODY}", "{Normal}ab", "{Normal}abcd"]);
+
+	render_slice.record_scroll_down();
+	render_slice.sync_view_data(&view_data);
+	view_data.update_view_data(|updater| updater.set_wrap(false));
+	render_slice.sync_view_data(&view_data);
+	assert_eq!(render_slice.get_top_line_index(), 2);
+}
+
+#[test]
+fn wrap_scroll_position_restored_with_view_data() {
+	let wrapped_view_data = create_wrapped_view_data();
+	let view_data = create_view_data(0, 10, 0);
+	let mut render_slice = create_render_slice(5, 3, &wrapped_view_data);
+	render_slice.record_scroll_down();
+	render_slice.record_scroll_down();
+	render_slice.sync_view_data(&wrapped_view_data);
+	render_slice.sync_view_data(&view_data);
+	render_slice.sync_view_data(&wrapped_view_data);
+	assert_rendered(&render_slice, &[
+		"{BODY}",
+		"{IndicatorColor}↪{Normal}hij",
+		"{IndicatorColor}↪{Normal}k",
+		"{Normal}ab",
+	]);
+}
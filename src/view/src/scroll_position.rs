@@ -71,6 +71,11 @@ impl ScrollPosition {
 		self.update_top(ScrollDirection::Bottom);
 	}
 
+	// the top is corrected when the lines length is next updated
+	pub(crate) fn scroll_to(&mut self, line_index: usize) {
+		self.top_value = line_index;
+	}
+
 	pub(crate) fn ensure_line_visible(&mut self, line_index: usize) {
 		let current_value = self.top_value;
 
@@ -0,0 +1,161 @@
+use std::iter;
+
+use crate::{render_slice::RenderSlice, ViewLine};
+
+// each pane must be at least this wide for the panes to be side by side
+const MINIMUM_SIDE_BY_SIDE_PANE_WIDTH: usize = 60;
+// each pane must be at least this tall for the panes to be stacked
+const MINIMUM_STACKED_PANE_HEIGHT: usize = 5;
+
+/// The arrangement of a primary and a secondary pane within the view, separated by a single row or column.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum SplitLayout {
+	/// The primary pane is on the left, and the secondary pane is on the right, below the title row.
+	SideBySide,
+	/// The primary pane is on the top, and the secondary pane is on the bottom.
+	Stacked,
+}
+
+impl SplitLayout {
+	/// Get the layout for a view size, side by side on wide terminals and stacked otherwise. Results in `None` if the
+	/// view is too small for two panes.
+	pub(crate) const fn new(width: usize, height: usize) -> Option<Self> {
+		if width > MINIMUM_SIDE_BY_SIDE_PANE_WIDTH * 2 && height > MINIMUM_STACKED_PANE_HEIGHT {
+			Some(Self::SideBySide)
+		}
+		else if height > MINIMUM_STACKED_PANE_HEIGHT * 2 {
+			Some(Self::Stacked)
+		}
+		else {
+			None
+		}
+	}
+
+	/// Get the width and height of the primary pane, including the title row.
+	#[allow(clippy::integer_division)]
+	pub(crate) const fn primary_size(self, width: usize, height: usize) -> (usize, usize) {
+		match self {
+			Self::SideBySide => (width.saturating_sub(1) / 2, height),
+			Self::Stacked => (width, height.saturating_sub(1) / 2),
+		}
+	}
+
+	/// Get the width and height of the secondary pane.
+	pub(crate) const fn secondary_size(self, width: usize, height: usize) -> (usize, usize) {
+		let (primary_width, primary_height) = self.primary_size(width, height);
+		match self {
+			Self::SideBySide => (width.saturating_sub(primary_width + 1), height.saturating_sub(1)),
+			Self::Stacked => (width, height.saturating_sub(primary_height + 1)),
+		}
+	}
+}
+
+/// A single row of a pane within a split view.
+#[derive(Debug)]
+pub(crate) enum PaneRow<'line> {
+	/// A line, with whether the scrollbar indicator is on the row, if the pane shows a scrollbar.
+	Line(&'line ViewLine, Option<bool>),
+	/// An empty row, between the body and trailing lines of a pane that is not full.
+	Spacer,
+}
+
+/// Arrange the lines of a render slice into exactly `height` rows of a pane.
+pub(crate) fn pane_rows(render_slice: &RenderSlice, height: usize) -> Vec<PaneRow<'_>> {
+	let lines = render_slice.get_lines();
+	let leading_line_count = render_slice.get_leading_lines_count();
+	let trailing_line_count = render_slice.get_trailing_lines_count();
+	let lines_count = lines.len() - leading_line_count - trailing_line_count;
+	let show_scroll_bar = render_slice.should_show_scroll_bar();
+	let scroll_indicator_index = render_slice.get_scroll_index();
+
+	let mut rows = Vec::with_capacity(height);
+	rows.extend(
+		lines
+			.iter()
+			.take(leading_line_count)
+			.map(|line| PaneRow::Line(line, None)),
+	);
+	rows.extend(
+		lines
+			.iter()
+			.skip(leading_line_count)
+			.take(lines_count)
+			.enumerate()
+			.map(|(index, line)| PaneRow::Line(line, show_scroll_bar.then_some(index == scroll_indicator_index))),
+	);
+	rows.extend(iter::repeat_with(|| PaneRow::Spacer).take(height.saturating_sub(lines.len())));
+	rows.extend(
+		lines
+			.iter()
+			.skip(leading_line_count + lines_count)
+			.map(|line| PaneRow::Line(line, None)),
+	);
+	rows.truncate(height);
+	rows
+}
+
+#[cfg(test)]
+mod tests {
+	use rstest::rstest;
+
+	use super::*;
+	use crate::ViewData;
+
+	#[rstest]
+	#[case::side_by_side(121, 6, Some(SplitLayout::SideBySide))]
+	#[case::narrow(120, 11, Some(SplitLayout::Stacked))]
+	#[case::short_and_wide(200, 5, None)]
+	#[case::small(120, 10, None)]
+	fn new(#[case] width: usize, #[case] height: usize, #[case] expected: Option<SplitLayout>) {
+		assert_eq!(SplitLayout::new(width, height), expected);
+	}
+
+	#[rstest]
+	#[case::side_by_side_even(SplitLayout::SideBySide, 122, 20, (60, 20), (61, 19))]
+	#[case::side_by_side_odd(SplitLayout::SideBySide, 121, 20, (60, 20), (60, 19))]
+	#[case::stacked_even(SplitLayout::Stacked, 80, 20, (80, 9), (80, 10))]
+	#[case::stacked_odd(SplitLayout::Stacked, 80, 21, (80, 10), (80, 10))]
+	fn sizes(
+		#[case] layout: SplitLayout,
+		#[case] width: usize,
+		#[case] height: usize,
+		#[case] primary: (usize, usize),
+		#[case] secondary: (usize, usize),
+	) {
+		assert_eq!(layout.primary_size(width, height), primary);
+		assert_eq!(layout.secondary_size(width, height), secondary);
+	}
+
+	#[test]
+	fn pane_rows_fill() {
+		let mut render_slice = RenderSlice::new();
+		render_slice.record_resize(20, 5);
+		render_slice.sync_view_data(&ViewData::new(|updater| {
+			updater.push_leading_line(ViewLine::from("Leading"));
+			updater.push_line(ViewLine::from("Foo"));
+			updater.push_trailing_line(ViewLine::from("Trailing"));
+		}));
+		let rows = pane_rows(&render_slice, 5);
+		assert_eq!(rows.len(), 5);
+		assert!(matches!(rows.first(), Some(&PaneRow::Line(_, None))));
+		assert!(matches!(rows.get(1), Some(&PaneRow::Line(_, None))));
+		assert!(matches!(rows.get(2), Some(&PaneRow::Spacer)));
+		assert!(matches!(rows.get(3), Some(&PaneRow::Spacer)));
+		assert!(matches!(rows.get(4), Some(&PaneRow::Line(_, None))));
+	}
+
+	#[test]
+	fn pane_rows_scroll_bar() {
+		let mut render_slice = RenderSlice::new();
+		render_slice.record_resize(20, 2);
+		render_slice.sync_view_data(&ViewData::new(|updater| {
+			updater.push_line(ViewLine::from("Foo"));
+			updater.push_line(ViewLine::from("Bar"));
+			updater.push_line(ViewLine::from("Baz"));
+		}));
+		let rows = pane_rows(&render_slice, 2);
+		assert_eq!(rows.len(), 2);
+		assert!(matches!(rows.first(), Some(&PaneRow::Line(_, Some(true)))));
+		assert!(matches!(rows.get(1), Some(&PaneRow::Line(_, Some(false)))));
+	}
+}
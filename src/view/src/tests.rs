@@ -5,6 +5,7 @@ use display::{
 };
 
 use super::*;
+use crate::split_layout::SplitLayout;
 
 fn assert_render(width: usize, height: usize, view_data: &ViewData, expected: &[&str]) {
 	let theme = Theme::new();
@@ -21,6 +22,33 @@ fn assert_render(width: usize, height: usize, view_data: &ViewData, expected: &[
 	assert_output(&view.display, expected);
 }
 
+fn assert_render_split(
+	width: usize,
+	height: usize,
+	view_data: &ViewData,
+	secondary_view_data: &ViewData,
+	expected: &[&str],
+) {
+	let theme = Theme::new();
+	let mut crossterm = CrossTerm::new();
+	crossterm.set_size(Size::new(width, height));
+	let display = Display::new(crossterm, &theme);
+	let mut view = View::new(display, "~", "?");
+	let layout = SplitLayout::new(width, height).unwrap();
+
+	let mut render_slice = RenderSlice::new();
+	let (primary_width, primary_height) = layout.primary_size(width, height);
+	render_slice.record_resize(primary_width, primary_height);
+	render_slice.sync_view_data(view_data);
+	let mut secondary_render_slice = RenderSlice::new();
+	let (secondary_width, secondary_height) = layout.secondary_size(width, height);
+	secondary_render_slice.record_resize(secondary_width, secondary_height);
+	secondary_render_slice.sync_view_data(secondary_view_data);
+	view.render_split(&render_slice, &secondary_render_slice, layout).unwrap();
+
+	assert_output(&view.display, expected);
+}
+
 #[test]
 fn render_empty() {
 	assert_render(20, 10, &ViewData::new(|_| {}), &["~"; 10]);
@@ -187,3 +215,84 @@ fn render_with_scroll_bar() {
 		],
 	);
 }
+
+#[test]
+fn render_split_stacked() {
+	let separator = "─".repeat(20);
+	assert_render_split(
+		20,
+		11,
+		&ViewData::new(|updater| {
+			updater.set_show_title(true);
+			updater.push_line(ViewLine::from("Foo"));
+		}),
+		&ViewData::new(|updater| updater.push_line(ViewLine::from("Bar"))),
+		&[
+			"Git Rebase          ",
+			"Foo",
+			"~",
+			"~",
+			"~",
+			separator.as_str(),
+			"Bar",
+			"~",
+			"~",
+			"~",
+			"~",
+		],
+	);
+}
+
+#[test]
+fn render_split_side_by_side() {
+	let title = format!("Git Interactive Rebase Tool{}", " ".repeat(94));
+	let first = format!("Foo{}│Bar", " ".repeat(57));
+	let spacer = format!("~{}│~", " ".repeat(59));
+	assert_render_split(
+		121,
+		6,
+		&ViewData::new(|updater| {
+			updater.set_show_title(true);
+			updater.push_line(ViewLine::from("Foo"));
+		}),
+		&ViewData::new(|updater| updater.push_line(ViewLine::from("Bar"))),
+		&[
+			title.as_str(),
+			first.as_str(),
+			spacer.as_str(),
+			spacer.as_str(),
+			spacer.as_str(),
+			spacer.as_str(),
+		],
+	);
+}
+
+#[test]
+fn render_split_with_scroll_bar() {
+	let separator = "─".repeat(20);
+	assert_render_split(
+		20,
+		11,
+		&ViewData::new(|updater| {
+			updater.push_line(ViewLine::from("Foo"));
+		}),
+		&ViewData::new(|updater| {
+			for index in 0..10 {
+				updater.push_line(ViewLine::from(format!("Line {index}").as_str()));
+			}
+		}),
+		&[
+			"Foo",
+			"~",
+			"~",
+			"~",
+			"~",
+			separator.as_str(),
+			format!("Line 0{}█", " ".repeat(13)).as_str(),
+			format!("Line 1{}", " ".repeat(14)).as_str(),
+			format!("Line 2{}", " ".repeat(14)).as_str(),
+			format!("Line 3{}", " ".repeat(14)).as_str(),
+			format!("Line 4{}", " ".repeat(14)).as_str(),
+		],
+	);
+}
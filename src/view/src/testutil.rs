@@ -61,6 +61,9 @@ fn render_style(line_segment: &LineSegment) -> String {
 		DisplayColor::DiffRemoveColor => String::from("DiffRemoveColor"),
 		DisplayColor::DiffContextColor => String::from("DiffContextColor"),
 		DisplayColor::DiffWhitespaceColor => String::from("DiffWhitespaceColor"),
+		DisplayColor::RefBranchColor => String::from("RefBranchColor"),
+		DisplayColor::RefRemoteColor => String::from("RefRemoteColor"),
+		DisplayColor::RefTagColor => String::from("RefTagColor"),
 		DisplayColor::IndicatorColor => String::from("IndicatorColor"),
 		DisplayColor::Normal => String::from("Normal"),
 		DisplayColor::ActionLabel => String::from("ActionLabel"),
@@ -322,6 +325,7 @@ fn assert_view_state_actions(state: &State, expected_actions: &[String]) {
 				RenderAction::ScrollBottom => String::from("ScrollBottom"),
 				RenderAction::PageUp => String::from("PageUp"),
 				RenderAction::PageDown => String::from("PageDown"),
+				RenderAction::ScrollTo(line_index) => format!("ScrollTo({line_index})"),
 				RenderAction::Resize(width, height) => format!("Resize({width}, {height})"),
 			}
 		})
@@ -83,6 +83,7 @@ impl<ViewTui: Tui + Send + 'static> Thread<ViewTui> {
 				notifier.wait();
 
 				let render_slice = state.render_slice();
+				let secondary_render_slice = state.secondary_render_slice();
 				let update_receiver = state.update_receiver();
 				let mut last_render_time = Instant::now();
 				let mut should_render = true;
@@ -114,7 +115,14 @@ impl<ViewTui: Tui + Send + 'static> Thread<ViewTui> {
 						last_render_time += MINIMUM_TICK_RATE;
 						should_render = false;
 						let render_slice_mutex = render_slice.lock();
-						if let Err(err) = view.lock().render(&render_slice_mutex) {
+						let result = if let Some(layout) = state.split_layout() {
+							view.lock()
+								.render_split(&render_slice_mutex, &secondary_render_slice.lock(), layout)
+						}
+						else {
+							view.lock().render(&render_slice_mutex)
+						};
+						if let Err(err) = result {
 							notifier.error(RuntimeError::ThreadError(err.to_string()));
 							break;
 						}
@@ -9,7 +9,15 @@ use std::{
 use crossbeam_channel::unbounded;
 use parking_lot::Mutex;
 
-use crate::{RenderSlice, ViewAction, ViewData};
+use crate::{split_layout::SplitLayout, RenderSlice, ViewAction, ViewData};
+
+#[derive(Debug)]
+struct Split {
+	height: usize,
+	layout: Option<SplitLayout>,
+	show_secondary: bool,
+	width: usize,
+}
 
 /// Represents a message sender and receiver for passing actions between threads.
 #[derive(Clone, Debug)]
@@ -17,6 +25,8 @@ pub struct State {
 	ended: Arc<AtomicBool>,
 	paused: Arc<AtomicBool>,
 	render_slice: Arc<Mutex<RenderSlice>>,
+	secondary_render_slice: Arc<Mutex<RenderSlice>>,
+	split: Arc<Mutex<Split>>,
 	pub(crate) update_receiver: crossbeam_channel::Receiver<ViewAction>,
 	update_sender: crossbeam_channel::Sender<ViewAction>,
 }
@@ -31,6 +41,13 @@ impl State {
 			ended: Arc::new(AtomicBool::from(false)),
 			paused: Arc::new(AtomicBool::from(false)),
 			render_slice: Arc::new(Mutex::new(RenderSlice::new())),
+			secondary_render_slice: Arc::new(Mutex::new(RenderSlice::new())),
+			split: Arc::new(Mutex::new(Split {
+				height: 0,
+				layout: None,
+				show_secondary: false,
+				width: 0,
+			})),
 			update_receiver,
 			update_sender,
 		}
@@ -59,6 +76,44 @@ impl State {
 		Arc::clone(&self.render_slice)
 	}
 
+	pub(crate) fn secondary_render_slice(&self) -> Arc<Mutex<RenderSlice>> {
+		Arc::clone(&self.secondary_render_slice)
+	}
+
+	pub(crate) fn split_layout(&self) -> Option<SplitLayout> {
+		self.split.lock().layout
+	}
+
+	/// Update the layout of the panes for the current size and resize the render slices to match.
+	fn update_split(&self, split: &mut Split) {
+		split.layout = if split.show_secondary {
+			SplitLayout::new(split.width, split.height)
+		}
+		else {
+			None
+		};
+		let (width, height) = split.layout.map_or((split.width, split.height), |layout| {
+			layout.primary_size(split.width, split.height)
+		});
+		self.render_slice.lock().borrow_mut().record_resize(width, height);
+		if let Some(layout) = split.layout {
+			let (secondary_width, secondary_height) = layout.secondary_size(split.width, split.height);
+			self.secondary_render_slice
+				.lock()
+				.borrow_mut()
+				.record_resize(secondary_width, secondary_height);
+		}
+	}
+
+	fn set_show_secondary(&self, show_secondary: bool) -> Option<SplitLayout> {
+		let mut split = self.split.lock();
+		if split.show_secondary != show_secondary {
+			split.show_secondary = show_secondary;
+			self.update_split(&mut split);
+		}
+		split.layout
+	}
+
 	/// Queue a start action.
 	///
 	/// # Errors
@@ -147,13 +202,34 @@ impl State {
 		self.render_slice.lock().borrow_mut().record_page_down();
 	}
 
+	/// Queue an action to scroll the line at the provided index to the top of the view.
+	#[inline]
+	pub fn scroll_to(&self, line_index: usize) {
+		self.render_slice.lock().borrow_mut().record_scroll_to(line_index);
+	}
+
+	/// Get the index of the first line, within the lines of the last rendered `ViewData`, that is shown in the view.
+	#[inline]
+	#[must_use]
+	pub fn get_top_line_index(&self) -> usize {
+		self.render_slice.lock().get_top_line_index()
+	}
+
+	/// Get the index of the line, within the lines of the last rendered `ViewData`, that is shown at the provided
+	/// row of the view. Title, leading and trailing rows do not map to a line.
+	#[inline]
+	#[must_use]
+	pub fn get_line_index_at_row(&self, row: usize) -> Option<usize> {
+		self.render_slice.lock().get_line_index_at_row(row)
+	}
+
 	/// Queue a resize action.
 	#[inline]
 	pub fn resize(&self, width: u16, height: u16) {
-		self.render_slice
-			.lock()
-			.borrow_mut()
-			.record_resize(width as usize, height as usize);
+		let mut split = self.split.lock();
+		split.width = width as usize;
+		split.height = height as usize;
+		self.update_split(&mut split);
 	}
 
 	/// Sync the `ViewData` and queue a render action.
@@ -162,7 +238,26 @@ impl State {
 	/// Results in an error if the sender has been closed.
 	#[inline]
 	pub fn render(&self, view_data: &ViewData) {
+		_ = self.set_show_secondary(false);
+		self.render_slice.lock().borrow_mut().sync_view_data(view_data);
+		self.send_update(ViewAction::Render);
+	}
+
+	/// Sync the `ViewData` of both panes of a split view and queue a render action. When the view is too small for two
+	/// panes, only the primary `ViewData` is rendered.
+	///
+	/// # Errors
+	/// Results in an error if the sender has been closed.
+	#[inline]
+	pub fn render_split(&self, view_data: &ViewData, secondary_view_data: &ViewData) {
+		let layout = self.set_show_secondary(true);
 		self.render_slice.lock().borrow_mut().sync_view_data(view_data);
+		if layout.is_some() {
+			self.secondary_render_slice
+				.lock()
+				.borrow_mut()
+				.sync_view_data(secondary_view_data);
+		}
 		self.send_update(ViewAction::Render);
 	}
 }
@@ -170,6 +265,7 @@ impl State {
 #[cfg(test)]
 mod tests {
 	use crate::{
+		split_layout::SplitLayout,
 		testutil::{render_view_line, with_view_state},
 		ViewData,
 		ViewLine,
@@ -273,6 +369,43 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn scroll_to() {
+		with_view_state(|context| {
+			context.state.scroll_to(5);
+			context.assert_render_action(&["ScrollTo(5)"]);
+		});
+	}
+
+	#[test]
+	fn get_line_index_at_row() {
+		with_view_state(|context| {
+			context.state.resize(300, 100);
+			context.state.render(&ViewData::new(|updater| {
+				updater.push_leading_line(ViewLine::from("Leading"));
+				updater.push_line(ViewLine::from("Foo"));
+			}));
+			assert_eq!(context.state.get_line_index_at_row(0), None);
+			assert_eq!(context.state.get_line_index_at_row(1), Some(0));
+			assert_eq!(context.state.get_line_index_at_row(2), None);
+		});
+	}
+
+	#[test]
+	fn get_top_line_index() {
+		with_view_state(|context| {
+			let view_data = ViewData::new(|updater| {
+				updater.push_line(ViewLine::from("Foo"));
+				updater.push_line(ViewLine::from("Bar"));
+			});
+			context.state.resize(300, 1);
+			context.state.render(&view_data);
+			context.state.scroll_down();
+			context.state.render(&view_data);
+			assert_eq!(context.state.get_top_line_index(), 1);
+		});
+	}
+
 	#[test]
 	fn resize() {
 		with_view_state(|context| {
@@ -281,6 +414,56 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn resize_split() {
+		with_view_state(|context| {
+			context.state.resize(80, 20);
+			context.state.render_split(&ViewData::new(|_| {}), &ViewData::new(|_| {}));
+			assert_eq!(context.state.split_layout(), Some(SplitLayout::Stacked));
+			context.state.resize(200, 20);
+			context.assert_render_action(&["Resize(99, 20)"]);
+			assert_eq!(
+				format!(
+					"{:?}",
+					context.state.secondary_render_slice().lock().get_actions().back().unwrap()
+				),
+				"Resize(100, 19)"
+			);
+		});
+	}
+
+	#[test]
+	fn render_split() {
+		with_view_state(|context| {
+			context.state.resize(80, 20);
+			context.state.render_split(
+				&ViewData::new(|updater| updater.push_line(ViewLine::from("Foo"))),
+				&ViewData::new(|updater| updater.push_line(ViewLine::from("Bar"))),
+			);
+			assert_eq!(context.state.split_layout(), Some(SplitLayout::Stacked));
+			assert_eq!(
+				render_view_line(
+					context.state.secondary_render_slice().lock().get_lines().first().unwrap(),
+					None
+				),
+				"{Normal}Bar"
+			);
+			context
+				.state
+				.render(&ViewData::new(|updater| updater.push_line(ViewLine::from("Foo"))));
+			assert_eq!(context.state.split_layout(), None);
+		});
+	}
+
+	#[test]
+	fn render_split_too_small() {
+		with_view_state(|context| {
+			context.state.resize(80, 10);
+			context.state.render_split(&ViewData::new(|_| {}), &ViewData::new(|_| {}));
+			assert_eq!(context.state.split_layout(), None);
+		});
+	}
+
 	#[test]
 	fn render() {
 		with_view_state(|context| {
@@ -4,6 +4,7 @@ use super::{ViewDataUpdater, ViewLine};
 
 /// Represents the content to be rendered to the `View`.
 #[derive(Debug)]
+#[allow(clippy::struct_excessive_bools)]
 pub struct ViewData {
 	lines: Vec<ViewLine>,
 	lines_leading: Vec<ViewLine>,
@@ -16,6 +17,7 @@ pub struct ViewData {
 	version: u32,
 	visible_column: Option<usize>,
 	visible_row: Option<usize>,
+	wrap: bool,
 }
 
 impl ViewData {
@@ -35,6 +37,7 @@ impl ViewData {
 			version: 0,
 			visible_column: None,
 			visible_row: None,
+			wrap: false,
 		};
 		let mut view_data_updater = ViewDataUpdater::new(&mut view_data);
 		callback(&mut view_data_updater);
@@ -88,6 +91,10 @@ impl ViewData {
 		self.show_help = show;
 	}
 
+	pub(crate) fn set_wrap(&mut self, wrap: bool) {
+		self.wrap = wrap;
+	}
+
 	pub(crate) fn push_leading_line(&mut self, view_line: ViewLine) {
 		self.lines_leading.push(view_line);
 	}
@@ -116,6 +123,10 @@ impl ViewData {
 		self.show_help
 	}
 
+	pub(crate) const fn wrap(&self) -> bool {
+		self.wrap
+	}
+
 	pub(crate) const fn get_leading_lines(&self) -> &Vec<ViewLine> {
 		&self.lines_leading
 	}
@@ -237,6 +248,13 @@ mod tests {
 		assert!(!view_data.show_help());
 	}
 
+	#[test]
+	fn set_wrap() {
+		let mut view_data = ViewData::new(|_| {});
+		view_data.set_wrap(true);
+		assert!(view_data.wrap());
+	}
+
 	#[test]
 	fn push_leading_line() {
 		let mut view_data = ViewData::new(|_| {});
@@ -57,6 +57,13 @@ impl<'view_data> ViewDataUpdater<'view_data> {
 		self.view_data.set_show_help(show);
 	}
 
+	/// Set if body lines that are wider than the view are soft wrapped onto multiple rows.
+	#[inline]
+	pub fn set_wrap(&mut self, wrap: bool) {
+		self.modified = true;
+		self.view_data.set_wrap(wrap);
+	}
+
 	/// Push a new leading line to the view data.
 	#[inline]
 	pub fn push_leading_line(&mut self, view_line: ViewLine) {
@@ -170,6 +177,15 @@ mod tests {
 		assert!(view_data.show_help());
 	}
 
+	#[test]
+	fn set_wrap() {
+		let mut view_data = ViewData::new(|_| {});
+		let mut updater = ViewDataUpdater::new(&mut view_data);
+		updater.set_wrap(true);
+		assert!(updater.is_modified());
+		assert!(view_data.wrap());
+	}
+
 	#[test]
 	fn push_leading_line() {
 		let mut view_data = ViewData::new(|_| {});